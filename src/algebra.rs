@@ -5,7 +5,39 @@
 //! * [`SemiGroup`]
 //! * [`Band`]
 //! * [`Monoid`]
+//! * [`ActedMonoid`]
+//! * [`Group`]
+//! * [`AbelianGroup`]
+//! * [`Semiring`]
 //!
+//! [`Min`], [`Max`], [`Add`], [`Mul`] などは整数型に対して実装されている。
+//! また、`Add<ModInt<P>>`, `Mul<ModInt<P>>` も [`Monoid`] として使える。
+//!
+//! 符号付き整数型と `ModInt<P>` の [`Add`]、整数型の [`BitXor`] は [`AbelianGroup`] として使える。
+//!
+//! 値と位置・個数の組を扱うモノイドも用意している。
+//!
+//! * [`MinIndex`], [`MaxIndex`]: 最小値 (最大値) とその位置 (複数あれば最も左)
+//! * [`MinCount`]: 最小値とその個数
+//! * [`SumCount`]: 総和と要素数
+//! * [`Affine`]: アフィン変換の合成
+//!
+//! 遅延評価セグメント木 ([`LazySegmentTree`](crate::lazy_segtree::LazySegmentTree)) のために、よく使う作用付きモノイドを用意している。
+//!
+//! * [`AddAction`]: 加算による作用
+//! * [`AssignAction`]: 代入による作用
+//! * [`AffineAction`]: アフィン変換による作用
+//!
+//! 特によく使う組み合わせには別名をつけている。
+//!
+//! * [`RangeAddRangeSum`]
+//! * [`RangeAddRangeMin`]
+//! * [`RangeAddRangeMax`]
+//! * [`RangeAssignRangeSum`]
+//! * [`RangeAffineRangeSum`]
+//!
+
+use crate::modint::ModInt;
 
 /// 半群
 pub trait SemiGroup {
@@ -35,6 +67,161 @@ pub trait Monoid {
     const E: Self::S;
 }
 
+/// モノイドの作用付きモノイド
+///
+/// モノイド `M` の元に、モノイド `F` の元 (作用素) が作用する。
+/// $`f`$ の作用を $`f(x)`$ と書くとき、以下を満たす必要がある。
+///
+/// * $`f(x \cdot y) = f(x) \cdot f(y)`$
+/// * $`(f \circ g)(x) = f(g(x))`$
+/// * $`\text{id}(x) = x`$
+pub trait ActedMonoid {
+    /// 作用されるモノイド
+    type M: Monoid;
+    /// 作用素の集合
+    type F: Clone + PartialEq + Eq;
+    /// 恒等写像
+    const ID: Self::F;
+    /// $`f(x)`$ を返す
+    fn mapping(f: &Self::F, x: &<Self::M as Monoid>::S) -> <Self::M as Monoid>::S;
+    /// $`f \circ g`$ を返す ($`g`$ を作用させた後に $`f`$ を作用させることに相当する)
+    fn composition(f: &Self::F, g: &Self::F) -> Self::F;
+}
+
+/// 群
+///
+/// モノイドであって、各元が逆元をもつ。
+pub trait Group: Monoid {
+    /// 逆元
+    fn inv(x: &Self::S) -> Self::S;
+}
+
+/// 可換群
+///
+/// 二項演算が交換法則を満たす群。
+pub trait AbelianGroup: Group {}
+
+/// 半環
+///
+/// 加法 $`\oplus`$ は単位元 $`\bar{0}`$ をもつ可換モノイド、乗法 $`\otimes`$ は単位元 $`\bar{1}`$ をもつモノイドで、
+/// 分配法則と $`\bar{0} \otimes a = a \otimes \bar{0} = \bar{0}`$ を満たす必要がある。
+///
+/// 半環上の行列積を使うと、行列累乗や DP の遷移の合成を半環によらずに書ける。
+/// [`Matrix::semiring_mul()`](crate::matrix::Matrix::semiring_mul) や [`Matrix::semiring_pow()`](crate::matrix::Matrix::semiring_pow) で使われる。
+///
+/// * [`PlusTimes`]: 通常の $`(+, \times)`$
+/// * [`MinPlus`]: $`(\min, +)`$ (`T::MAX` を $`\infty`$ とする)
+/// * [`MaxPlus`]: $`(\max, +)`$ (`T::MIN` を $`-\infty`$ とする)
+///
+/// ## Examples
+///
+/// ```
+/// use library::algebra::{MinPlus, PlusTimes};
+/// use library::matrix::Matrix;
+///
+/// let a = Matrix::from(&[vec![1u64, 1], vec![1, 0]]);
+/// let a2 = a.semiring_mul::<PlusTimes<u64>>(&a);
+/// assert_eq!(a2.to_vec(), [[2, 1], [1, 1]]);
+///
+/// let inf = i64::MAX;
+/// let d = Matrix::from(&[vec![0, 3, inf], vec![inf, 0, 4], vec![1, inf, 0]]);
+/// let d2 = d.semiring_mul::<MinPlus<i64>>(&d);
+/// assert_eq!(d2.to_vec(), [[0, 3, 7], [5, 0, 4], [1, 4, 0]]);
+/// ```
+pub trait Semiring {
+    /// 集合
+    type S: Clone + PartialEq + Eq;
+    /// 加法の単位元
+    const ZERO: Self::S;
+    /// 乗法の単位元
+    const ONE: Self::S;
+    /// 加法
+    fn add(lhs: &Self::S, rhs: &Self::S) -> Self::S;
+    /// 乗法
+    fn mul(lhs: &Self::S, rhs: &Self::S) -> Self::S;
+}
+
+/// 通常の加法・乗法からなる半環
+pub struct PlusTimes<T> {
+    _marker: std::marker::PhantomData<T>,
+}
+
+/// $`(\min, +)`$ 半環  
+/// `T::MAX` を $`\infty`$ として扱い、$`\infty`$ との和は $`\infty`$ とする
+pub struct MinPlus<T> {
+    _marker: std::marker::PhantomData<T>,
+}
+
+/// $`(\max, +)`$ 半環  
+/// `T::MIN` を $`-\infty`$ として扱い、$`-\infty`$ との和は $`-\infty`$ とする
+pub struct MaxPlus<T> {
+    _marker: std::marker::PhantomData<T>,
+}
+
+macro_rules! impl_semiring_to_integers {
+    ($($t: ty), *) => {
+        $(
+            impl Semiring for PlusTimes<$t> {
+                type S = $t;
+                const ZERO: $t = 0;
+                const ONE: $t = 1;
+                fn add(lhs: &$t, rhs: &$t) -> $t {
+                    lhs + rhs
+                }
+                fn mul(lhs: &$t, rhs: &$t) -> $t {
+                    lhs * rhs
+                }
+            }
+
+            impl Semiring for MinPlus<$t> {
+                type S = $t;
+                const ZERO: $t = <$t>::MAX;
+                const ONE: $t = 0;
+                fn add(lhs: &$t, rhs: &$t) -> $t {
+                    std::cmp::min(*lhs, *rhs)
+                }
+                fn mul(lhs: &$t, rhs: &$t) -> $t {
+                    if *lhs == <$t>::MAX || *rhs == <$t>::MAX {
+                        <$t>::MAX
+                    } else {
+                        lhs + rhs
+                    }
+                }
+            }
+
+            impl Semiring for MaxPlus<$t> {
+                type S = $t;
+                const ZERO: $t = <$t>::MIN;
+                const ONE: $t = 0;
+                fn add(lhs: &$t, rhs: &$t) -> $t {
+                    std::cmp::max(*lhs, *rhs)
+                }
+                fn mul(lhs: &$t, rhs: &$t) -> $t {
+                    if *lhs == <$t>::MIN || *rhs == <$t>::MIN {
+                        <$t>::MIN
+                    } else {
+                        lhs + rhs
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_semiring_to_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<const P: u32> Semiring for PlusTimes<ModInt<P>> {
+    type S = ModInt<P>;
+    const ZERO: ModInt<P> = ModInt::ZERO;
+    const ONE: ModInt<P> = ModInt::ONE;
+    fn add(lhs: &ModInt<P>, rhs: &ModInt<P>) -> ModInt<P> {
+        *lhs + *rhs
+    }
+    fn mul(lhs: &ModInt<P>, rhs: &ModInt<P>) -> ModInt<P> {
+        *lhs * *rhs
+    }
+}
+
 pub struct Min<T> {
     _marker: std::marker::PhantomData<T>,
 }
@@ -202,5 +389,448 @@ macro_rules! impl_to_integers {
     };
 }
 
+impl<const P: u32> SemiGroup for Add<ModInt<P>> {
+    type S = ModInt<P>;
+    fn op(lhs: &ModInt<P>, rhs: &ModInt<P>) -> ModInt<P> {
+        *lhs + *rhs
+    }
+}
+
+impl<const P: u32> SemiGroup for Mul<ModInt<P>> {
+    type S = ModInt<P>;
+    fn op(lhs: &ModInt<P>, rhs: &ModInt<P>) -> ModInt<P> {
+        *lhs * *rhs
+    }
+}
+
+impl<const P: u32> Monoid for Add<ModInt<P>> {
+    type S = ModInt<P>;
+    fn op(lhs: &ModInt<P>, rhs: &ModInt<P>) -> ModInt<P> {
+        *lhs + *rhs
+    }
+    const E: ModInt<P> = ModInt::ZERO;
+}
+
+impl<const P: u32> Group for Add<ModInt<P>> {
+    fn inv(x: &ModInt<P>) -> ModInt<P> {
+        -*x
+    }
+}
+
+impl<const P: u32> AbelianGroup for Add<ModInt<P>> {}
+
+impl<const P: u32> Monoid for Mul<ModInt<P>> {
+    type S = ModInt<P>;
+    fn op(lhs: &ModInt<P>, rhs: &ModInt<P>) -> ModInt<P> {
+        *lhs * *rhs
+    }
+    const E: ModInt<P> = ModInt::ONE;
+}
+
+impl<const P: u32> Monoid for Affine<ModInt<P>> {
+    type S = (ModInt<P>, ModInt<P>);
+    fn op(lhs: &Self::S, rhs: &Self::S) -> Self::S {
+        (lhs.0 * rhs.0, rhs.0 * lhs.1 + rhs.1)
+    }
+    const E: Self::S = (ModInt::ONE, ModInt::ZERO);
+}
+
+impl<const P: u32> Monoid for SumCount<ModInt<P>> {
+    type S = (ModInt<P>, usize);
+    fn op(lhs: &Self::S, rhs: &Self::S) -> Self::S {
+        (lhs.0 + rhs.0, lhs.1 + rhs.1)
+    }
+    const E: Self::S = (ModInt::ZERO, 0);
+}
+
+/// 最小値とその位置
+///
+/// 値は (値, 位置) の組で持ち、最小値が複数あれば最も左の位置を返す。
+///
+/// ## Examples
+///
+/// 区間の最小値の位置で区間を分割していく再帰 (Cartesian tree の構築など) に使える。
+///
+/// ```
+/// use library::algebra::MinIndex;
+/// use library::segtree::SegmentTree;
+///
+/// let a = [3, 1, 4, 1, 5, 9, 2, 6];
+/// let stree: SegmentTree<MinIndex<u32>> = a.iter().copied().zip(0..).collect();
+///
+/// assert_eq!(stree.prod(..), (1, 1));
+/// assert_eq!(stree.prod(2..), (1, 3));
+///
+/// // 各要素の Cartesian tree での親を求める
+/// fn build(stree: &SegmentTree<MinIndex<u32>>, l: usize, r: usize, parent: usize, p: &mut [usize]) {
+///     if l < r {
+///         let (_, m) = stree.prod(l..r);
+///         p[m] = parent;
+///         build(stree, l, m, m, p);
+///         build(stree, m + 1, r, m, p);
+///     }
+/// }
+///
+/// let mut p = vec![0; a.len()];
+/// build(&stree, 0, a.len(), usize::MAX, &mut p);
+/// assert_eq!(p, [1, usize::MAX, 3, 1, 6, 4, 3, 6]);
+/// ```
+pub struct MinIndex<T> {
+    _marker: std::marker::PhantomData<T>,
+}
+
+/// 最大値とその位置
+///
+/// 値は (値, 位置) の組で持ち、最大値が複数あれば最も左の位置を返す。
+pub struct MaxIndex<T> {
+    _marker: std::marker::PhantomData<T>,
+}
+
+/// 最小値とその個数
+///
+/// 値は (値, 個数) の組で持つ。長さ $`1`$ の要素 $`a`$ は `(a, 1)` とする。
+pub struct MinCount<T> {
+    _marker: std::marker::PhantomData<T>,
+}
+
+/// 総和と要素数
+///
+/// 値は (総和, 要素数) の組で持つ。長さ $`1`$ の要素 $`a`$ は `(a, 1)` とする。
+pub struct SumCount<T> {
+    _marker: std::marker::PhantomData<T>,
+}
+
+/// アフィン変換の合成
+///
+/// 値 $`(a, b)`$ は $`x \mapsto ax + b`$ を表す。
+/// 二項演算は左の変換を先に適用する合成で、$`(a_1, b_1) \cdot (a_2, b_2) = (a_1 a_2, a_2 b_1 + b_2)`$ である。
+/// したがって、区間 $`\lbrack l, r)`$ の積は $`f_{r-1} \circ \cdots \circ f_l`$ を表す。
+pub struct Affine<T> {
+    _marker: std::marker::PhantomData<T>,
+}
+
+macro_rules! impl_pair_to_integers {
+    ($($t: ty), *) => {
+        $(
+            impl SemiGroup for MinIndex<$t> {
+                type S = ($t, usize);
+                fn op(lhs: &($t, usize), rhs: &($t, usize)) -> ($t, usize) {
+                    std::cmp::min(*lhs, *rhs)
+                }
+            }
+
+            impl Band for MinIndex<$t> {
+                type S = ($t, usize);
+                fn op(lhs: &($t, usize), rhs: &($t, usize)) -> ($t, usize) {
+                    std::cmp::min(*lhs, *rhs)
+                }
+            }
+
+            impl Monoid for MinIndex<$t> {
+                type S = ($t, usize);
+                fn op(lhs: &($t, usize), rhs: &($t, usize)) -> ($t, usize) {
+                    std::cmp::min(*lhs, *rhs)
+                }
+                const E: ($t, usize) = (<$t>::MAX, usize::MAX);
+            }
+
+            impl SemiGroup for MaxIndex<$t> {
+                type S = ($t, usize);
+                fn op(lhs: &($t, usize), rhs: &($t, usize)) -> ($t, usize) {
+                    <Self as Monoid>::op(lhs, rhs)
+                }
+            }
+
+            impl Band for MaxIndex<$t> {
+                type S = ($t, usize);
+                fn op(lhs: &($t, usize), rhs: &($t, usize)) -> ($t, usize) {
+                    <Self as Monoid>::op(lhs, rhs)
+                }
+            }
+
+            impl Monoid for MaxIndex<$t> {
+                type S = ($t, usize);
+                fn op(lhs: &($t, usize), rhs: &($t, usize)) -> ($t, usize) {
+                    if lhs.0 > rhs.0 || (lhs.0 == rhs.0 && lhs.1 <= rhs.1) {
+                        *lhs
+                    } else {
+                        *rhs
+                    }
+                }
+                const E: ($t, usize) = (<$t>::MIN, usize::MAX);
+            }
+
+            impl SemiGroup for MinCount<$t> {
+                type S = ($t, usize);
+                fn op(lhs: &($t, usize), rhs: &($t, usize)) -> ($t, usize) {
+                    <Self as Monoid>::op(lhs, rhs)
+                }
+            }
+
+            impl Monoid for MinCount<$t> {
+                type S = ($t, usize);
+                fn op(lhs: &($t, usize), rhs: &($t, usize)) -> ($t, usize) {
+                    match lhs.0.cmp(&rhs.0) {
+                        std::cmp::Ordering::Less => *lhs,
+                        std::cmp::Ordering::Greater => *rhs,
+                        std::cmp::Ordering::Equal => (lhs.0, lhs.1 + rhs.1),
+                    }
+                }
+                const E: ($t, usize) = (<$t>::MAX, 0);
+            }
+
+            impl SemiGroup for Affine<$t> {
+                type S = ($t, $t);
+                fn op(lhs: &($t, $t), rhs: &($t, $t)) -> ($t, $t) {
+                    <Self as Monoid>::op(lhs, rhs)
+                }
+            }
+
+            impl Monoid for Affine<$t> {
+                type S = ($t, $t);
+                fn op(lhs: &($t, $t), rhs: &($t, $t)) -> ($t, $t) {
+                    (lhs.0 * rhs.0, rhs.0 * lhs.1 + rhs.1)
+                }
+                const E: ($t, $t) = (1, 0);
+            }
+
+            impl SemiGroup for SumCount<$t> {
+                type S = ($t, usize);
+                fn op(lhs: &($t, usize), rhs: &($t, usize)) -> ($t, usize) {
+                    (lhs.0 + rhs.0, lhs.1 + rhs.1)
+                }
+            }
+
+            impl Monoid for SumCount<$t> {
+                type S = ($t, usize);
+                fn op(lhs: &($t, usize), rhs: &($t, usize)) -> ($t, usize) {
+                    (lhs.0 + rhs.0, lhs.1 + rhs.1)
+                }
+                const E: ($t, usize) = (0, 0);
+            }
+        )*
+    };
+}
+
+impl_pair_to_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 // \[WARN\] 符号付き整数の bitwise な演算は単位元を間違えている気がする
 impl_to_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_group_to_signed_integers {
+    ($($t: ty), *) => {
+        $(
+            impl Group for Add<$t> {
+                fn inv(x: &$t) -> $t {
+                    -x
+                }
+            }
+
+            impl AbelianGroup for Add<$t> {}
+        )*
+    };
+}
+
+impl_group_to_signed_integers!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_group_to_integers {
+    ($($t: ty), *) => {
+        $(
+            impl Group for BitXor<$t> {
+                fn inv(x: &$t) -> $t {
+                    *x
+                }
+            }
+
+            impl AbelianGroup for BitXor<$t> {}
+        )*
+    };
+}
+
+impl_group_to_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// 加算による作用
+///
+/// `AddAction<M>` は、モノイド `M` の各要素に作用素 $`f`$ を足す作用を表す。
+///
+/// * `AddAction<Min<T>>`, `AddAction<Max<T>>`: 単位元 (`T::MAX`, `T::MIN`) には加算しない。
+/// * `AddAction<MinCount<T>>`: 最小値に加算し、個数は変えない。
+/// * `AddAction<SumCount<T>>`: 総和に $`f \times (\text{要素数})`$ を足す。
+///   区間和への加算には要素数が必要なので、`Add<T>` ではなく [`SumCount`] を使う。
+pub struct AddAction<M> {
+    _marker: std::marker::PhantomData<M>,
+}
+
+/// 代入による作用
+///
+/// `AssignAction<M>` は、モノイド `M` の各要素を作用素 $`x`$ に置き換える作用を表す。
+/// 作用素 `None` は恒等写像、`Some(x)` は $`x`$ の代入を表す。
+///
+/// * `AssignAction<Min<T>>`, `AssignAction<Max<T>>`
+/// * `AssignAction<SumCount<T>>`: 総和は $`x \times (\text{要素数})`$ になる。
+pub struct AssignAction<M> {
+    _marker: std::marker::PhantomData<M>,
+}
+
+/// 区間加算・区間和
+///
+/// 値は (総和, 要素数) の組で持つ。長さ $`1`$ の要素 $`a`$ は `(a, 1)` とする。
+pub type RangeAddRangeSum<T> = AddAction<SumCount<T>>;
+
+/// 区間加算・区間最小値
+pub type RangeAddRangeMin<T> = AddAction<Min<T>>;
+
+/// 区間加算・区間最大値
+pub type RangeAddRangeMax<T> = AddAction<Max<T>>;
+
+/// 区間代入・区間和
+///
+/// 値は (総和, 要素数) の組で持つ。長さ $`1`$ の要素 $`a`$ は `(a, 1)` とする。
+pub type RangeAssignRangeSum<T> = AssignAction<SumCount<T>>;
+
+/// アフィン変換による作用
+///
+/// `AffineAction<M>` は、作用素 $`(b, c)`$ で各要素を $`x \mapsto bx + c`$ と変換する作用を表す。
+/// 作用素の合成は [`Affine`] と同じである。
+///
+/// * `AffineAction<SumCount<T>>`: 総和 $`s`$ は $`bs + c \times (\text{要素数})`$ になる。
+pub struct AffineAction<M> {
+    _marker: std::marker::PhantomData<M>,
+}
+
+/// $`\mathbb{Z} / P \mathbb{Z}`$ 上の区間アフィン変換・区間和
+///
+/// 値は (総和, 要素数) の組で持つ。長さ $`1`$ の要素 $`a`$ は `(a, 1)` とする。
+/// 作用素 $`(b, c)`$ は $`x \mapsto bx + c`$ を表す。
+pub type RangeAffineRangeSum<const P: u32> = AffineAction<SumCount<ModInt<P>>>;
+
+macro_rules! impl_acted_to_integers {
+    ($($t: ty), *) => {
+        $(
+            impl ActedMonoid for AddAction<Min<$t>> {
+                type M = Min<$t>;
+                type F = $t;
+                const ID: $t = 0;
+                fn mapping(f: &$t, x: &$t) -> $t {
+                    if *x == <$t>::MAX {
+                        *x
+                    } else {
+                        x + f
+                    }
+                }
+                fn composition(f: &$t, g: &$t) -> $t {
+                    f + g
+                }
+            }
+
+            impl ActedMonoid for AddAction<Max<$t>> {
+                type M = Max<$t>;
+                type F = $t;
+                const ID: $t = 0;
+                fn mapping(f: &$t, x: &$t) -> $t {
+                    if *x == <$t>::MIN {
+                        *x
+                    } else {
+                        x + f
+                    }
+                }
+                fn composition(f: &$t, g: &$t) -> $t {
+                    f + g
+                }
+            }
+
+            impl ActedMonoid for AddAction<MinCount<$t>> {
+                type M = MinCount<$t>;
+                type F = $t;
+                const ID: $t = 0;
+                fn mapping(f: &$t, x: &($t, usize)) -> ($t, usize) {
+                    if x.0 == <$t>::MAX {
+                        *x
+                    } else {
+                        (x.0 + f, x.1)
+                    }
+                }
+                fn composition(f: &$t, g: &$t) -> $t {
+                    f + g
+                }
+            }
+
+            impl ActedMonoid for AddAction<SumCount<$t>> {
+                type M = SumCount<$t>;
+                type F = $t;
+                const ID: $t = 0;
+                fn mapping(f: &$t, x: &($t, usize)) -> ($t, usize) {
+                    (x.0 + f * x.1 as $t, x.1)
+                }
+                fn composition(f: &$t, g: &$t) -> $t {
+                    f + g
+                }
+            }
+
+            impl ActedMonoid for AffineAction<SumCount<$t>> {
+                type M = SumCount<$t>;
+                type F = ($t, $t);
+                const ID: ($t, $t) = (1, 0);
+                fn mapping(f: &($t, $t), x: &($t, usize)) -> ($t, usize) {
+                    (f.0 * x.0 + f.1 * x.1 as $t, x.1)
+                }
+                fn composition(f: &($t, $t), g: &($t, $t)) -> ($t, $t) {
+                    <Affine<$t> as Monoid>::op(g, f)
+                }
+            }
+
+            impl ActedMonoid for AssignAction<Min<$t>> {
+                type M = Min<$t>;
+                type F = Option<$t>;
+                const ID: Option<$t> = None;
+                fn mapping(f: &Option<$t>, x: &$t) -> $t {
+                    f.unwrap_or(*x)
+                }
+                fn composition(f: &Option<$t>, g: &Option<$t>) -> Option<$t> {
+                    f.or(*g)
+                }
+            }
+
+            impl ActedMonoid for AssignAction<Max<$t>> {
+                type M = Max<$t>;
+                type F = Option<$t>;
+                const ID: Option<$t> = None;
+                fn mapping(f: &Option<$t>, x: &$t) -> $t {
+                    f.unwrap_or(*x)
+                }
+                fn composition(f: &Option<$t>, g: &Option<$t>) -> Option<$t> {
+                    f.or(*g)
+                }
+            }
+
+            impl ActedMonoid for AssignAction<SumCount<$t>> {
+                type M = SumCount<$t>;
+                type F = Option<$t>;
+                const ID: Option<$t> = None;
+                fn mapping(f: &Option<$t>, x: &($t, usize)) -> ($t, usize) {
+                    match f {
+                        Some(a) => (a * x.1 as $t, x.1),
+                        None => *x,
+                    }
+                }
+                fn composition(f: &Option<$t>, g: &Option<$t>) -> Option<$t> {
+                    f.or(*g)
+                }
+            }
+        )*
+    };
+}
+
+impl_acted_to_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<const P: u32> ActedMonoid for AffineAction<SumCount<ModInt<P>>> {
+    type M = SumCount<ModInt<P>>;
+    type F = (ModInt<P>, ModInt<P>);
+    const ID: Self::F = (ModInt::ONE, ModInt::ZERO);
+    fn mapping(f: &Self::F, x: &(ModInt<P>, usize)) -> (ModInt<P>, usize) {
+        (f.0 * x.0 + f.1 * x.1, x.1)
+    }
+    fn composition(f: &Self::F, g: &Self::F) -> Self::F {
+        <Affine<ModInt<P>> as Monoid>::op(g, f)
+    }
+}
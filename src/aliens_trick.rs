@@ -0,0 +1,59 @@
+use crate::binary_search::binary_search;
+
+/// Aliens DP (Lagrange 緩和) を行い、ちょうど $`k`$ 個を選ぶときのコストの最小値を求める
+///
+/// 整数 $`x`$ に対して、ちょうど $`x`$ 個を選ぶときのコストの最小値を $`f(x)`$ とする。$`f`$ は下に凸で、値は整数であることを要求する。
+///
+/// `solve(λ)` は、1 個選ぶごとにペナルティ $`\lambda`$ を加えたときのコストの最小値 $`g(\lambda) = \min_x (f(x) + \lambda x)`$ と、
+/// それを達成する $`x`$ のうち**最小のもの**を組 `(g(λ), x)` として返すこと。
+///
+/// $`\lambda`$ を $`\text{lo} < \lambda \leq \text{hi}`$ の範囲で二分探索し、$`f(k) = g(\lambda) - \lambda k`$ を返す。
+/// $`\lvert f(x + 1) - f(x) \rvert < M`$ であれば、$`\text{lo} = -M, \text{hi} = M`$ とすればよい。
+///
+/// 最大化したい場合は、コストの符号を反転させて使う。
+///
+/// ## Examples
+///
+/// 隣り合わないようにちょうど $`k`$ 個の要素を選ぶときの、和の最大値を求める。
+///
+/// ```
+/// use library::aliens_trick::aliens_trick;
+///
+/// let a = [5i64, 1, 3, 8, 2, 7, 4];
+///
+/// // 要素 a_i を選ぶコストを λ - a_i として、(コストの最小値, 選んだ個数) を求める
+/// let solve = |lambda: i64| {
+///     // dp0: 直前の要素を選んでいない, dp1: 直前の要素を選んだ
+///     let (mut dp0, mut dp1) = ((0, 0), (i64::MAX, 0));
+///
+///     for &x in a.iter() {
+///         let take = (dp0.0 + lambda - x, dp0.1 + 1);
+///         dp0 = std::cmp::min(dp0, dp1);
+///         dp1 = take;
+///     }
+///
+///     std::cmp::min(dp0, dp1)
+/// };
+///
+/// let m = 10;
+/// let max_sum = |k: i64| -aliens_trick(k, -m, m, solve);
+///
+/// assert_eq!(max_sum(1), 8);
+/// assert_eq!(max_sum(2), 15);
+/// assert_eq!(max_sum(3), 20);
+/// assert_eq!(max_sum(4), 14);
+/// ```
+///
+/// ## 計算量
+///
+/// `solve` の計算量を $`T`$ として、$`O(T \log (\text{hi} - \text{lo}))`$ である。
+///
+pub fn aliens_trick(k: i64, lo: i64, hi: i64, mut solve: impl FnMut(i64) -> (i64, i64)) -> i64 {
+    assert!(lo < hi);
+
+    // 選ぶ個数は λ について単調減少なので、個数が k 以下になる最小の λ を探す
+    let lambda = binary_search(hi, lo, |lambda| solve(lambda).1 <= k);
+    let (cost, _) = solve(lambda);
+
+    cost - lambda * k
+}
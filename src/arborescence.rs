@@ -0,0 +1,272 @@
+use crate::graph::{Graph, Index};
+
+const NIL: usize = usize::MAX;
+
+/// 有向グラフの最小全域有向木 (最小有向全域木, minimum spanning arborescence) を求める
+///
+/// `root` から全頂点へ到達できる辺の部分集合で、各頂点 ($`\text{root}`$ 以外) に入る辺がちょうど $`1`$ 本であるもののうち、重みの総和が最小のものを求める。
+/// 返り値は (重みの総和, 各頂点の親) である。根の親は `Index::MAX` とする。
+/// `root` から到達できない頂点がある場合は `None` を返す。
+///
+/// 縮約した頂点に入る辺の集合を遅延評価付きの Leftist Heap で管理する Tarjan の方法を使っている。
+///
+/// ## Examples
+///
+/// ```
+/// use library::arborescence::minimum_spanning_arborescence;
+/// use library::graph::DirectedAdjGraph;
+///
+/// let graph = DirectedAdjGraph::from_edges(
+///     4,
+///     &[(0, 1, 10i64), (0, 2, 10), (0, 3, 10), (3, 1, 1), (1, 2, 1), (2, 3, 1)],
+/// );
+///
+/// let (weight, parent) = minimum_spanning_arborescence(&graph, 0).unwrap();
+/// assert_eq!(weight, 12);
+/// assert_eq!(parent[0], u32::MAX);
+///
+/// let unreachable = DirectedAdjGraph::from_edges(3, &[(0, 1, 1i64), (2, 1, 1)]);
+/// assert_eq!(minimum_spanning_arborescence(&unreachable, 0), None);
+/// ```
+///
+/// ## 計算量
+///
+/// グラフを $`G = (V, E)`$ として、$`O(\lvert E \rvert \log \lvert V \rvert)`$ である。
+///
+/// ## Verified problems
+///
+/// * [Directed MST](../../src/lc_directedmst/lc_directedmst.rs.html)
+///
+pub fn minimum_spanning_arborescence<
+    W: Default + Copy + Ord + std::ops::Add<Output = W> + std::ops::Sub<Output = W>,
+>(
+    graph: &impl Graph<Weight = W>,
+    root: Index,
+) -> Option<(W, Vec<Index>)> {
+    let n = graph.size() as usize;
+
+    let mut heap = LeftistHeap::new();
+    let mut top = vec![NIL; n];
+
+    for u in 0..n {
+        for &(v, w) in graph.adjacent(u as Index) {
+            let node = heap.push(u, v as usize, w);
+            top[v as usize] = heap.merge(top[v as usize], node);
+        }
+    }
+
+    let mut uf = RollbackUnionFind::new(n);
+    let mut total = W::default();
+    let mut seen = vec![NIL; n];
+    let mut path = vec![0; n];
+    let mut queue = vec![(0, 0); n];
+    let mut incoming = vec![(NIL, NIL); n];
+    let mut cycles = vec![];
+    seen[root as usize] = root as usize;
+
+    for s in 0..n {
+        let mut u = s;
+        let mut qi = 0;
+
+        while seen[u] == NIL {
+            if top[u] == NIL {
+                return None;
+            }
+
+            let (from, to, w) = heap.top(top[u]);
+            heap.sub(top[u], w);
+            top[u] = heap.pop(top[u]);
+
+            queue[qi] = (from, to);
+            path[qi] = u;
+            qi += 1;
+            seen[u] = s;
+            total = total + w;
+            u = uf.find(from);
+
+            if seen[u] == s {
+                // 閉路を縮約する
+                let mut cycle = NIL;
+                let end = qi;
+                let time = uf.time();
+
+                loop {
+                    qi -= 1;
+                    let w = path[qi];
+                    cycle = heap.merge(cycle, top[w]);
+                    if !uf.unite(u, w) {
+                        break;
+                    }
+                }
+
+                u = uf.find(u);
+                top[u] = cycle;
+                seen[u] = NIL;
+                cycles.push((u, time, queue[qi..end].to_vec()));
+            }
+        }
+
+        for &(from, to) in &queue[..qi] {
+            incoming[uf.find(to)] = (from, to);
+        }
+    }
+
+    // 縮約した閉路を逆順に展開して、各頂点に入る辺を復元する
+    for (u, time, edges) in cycles.into_iter().rev() {
+        uf.rollback(time);
+        let edge = incoming[u];
+        for (from, to) in edges {
+            incoming[uf.find(to)] = (from, to);
+        }
+        incoming[uf.find(edge.1)] = edge;
+    }
+
+    let parent = (0..n)
+        .map(|v| {
+            if v == root as usize {
+                Index::MAX
+            } else {
+                incoming[v].0 as Index
+            }
+        })
+        .collect();
+
+    Some((total, parent))
+}
+
+/// 経路圧縮を行わず、合併を取り消せる Union-Find
+struct RollbackUnionFind {
+    data: Vec<i32>,
+    history: Vec<(usize, i32)>,
+}
+
+impl RollbackUnionFind {
+    fn new(size: usize) -> Self {
+        Self {
+            data: vec![-1; size],
+            history: vec![],
+        }
+    }
+
+    fn find(&self, mut v: usize) -> usize {
+        while self.data[v] >= 0 {
+            v = self.data[v] as usize;
+        }
+        v
+    }
+
+    fn time(&self) -> usize {
+        self.history.len()
+    }
+
+    fn rollback(&mut self, time: usize) {
+        while self.history.len() > time {
+            let (v, d) = self.history.pop().unwrap();
+            self.data[v] = d;
+        }
+    }
+
+    fn unite(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.data[a] > self.data[b] {
+            (a, b) = (b, a);
+        }
+
+        self.history.push((a, self.data[a]));
+        self.history.push((b, self.data[b]));
+        self.data[a] += self.data[b];
+        self.data[b] = a as i32;
+        true
+    }
+}
+
+/// 全体からの減算を遅延評価する Leftist Heap (最小値)
+///
+/// `lazy` には部分木全体からまだ引いていない値を持ち、`key - lazy` として反映するため、符号なし整数でも負の値を経由しない。
+struct LeftistHeap<W> {
+    // (始点, 終点, 重み)
+    key: Vec<(usize, usize, W)>,
+    lazy: Vec<W>,
+    left: Vec<usize>,
+    right: Vec<usize>,
+    rank: Vec<u32>,
+}
+
+impl<W: Default + Copy + Ord + std::ops::Add<Output = W> + std::ops::Sub<Output = W>>
+    LeftistHeap<W>
+{
+    fn new() -> Self {
+        Self {
+            key: vec![],
+            lazy: vec![],
+            left: vec![],
+            right: vec![],
+            rank: vec![],
+        }
+    }
+
+    fn push(&mut self, from: usize, to: usize, w: W) -> usize {
+        self.key.push((from, to, w));
+        self.lazy.push(W::default());
+        self.left.push(NIL);
+        self.right.push(NIL);
+        self.rank.push(1);
+        self.key.len() - 1
+    }
+
+    fn rank(&self, a: usize) -> u32 {
+        if a == NIL {
+            0
+        } else {
+            self.rank[a]
+        }
+    }
+
+    fn sub(&mut self, a: usize, w: W) {
+        if a != NIL {
+            self.lazy[a] = self.lazy[a] + w;
+        }
+    }
+
+    fn propagate(&mut self, a: usize) {
+        let d = self.lazy[a];
+        self.key[a].2 = self.key[a].2 - d;
+        self.sub(self.left[a], d);
+        self.sub(self.right[a], d);
+        self.lazy[a] = W::default();
+    }
+
+    fn top(&mut self, a: usize) -> (usize, usize, W) {
+        self.propagate(a);
+        self.key[a]
+    }
+
+    fn pop(&mut self, a: usize) -> usize {
+        self.propagate(a);
+        self.merge(self.left[a], self.right[a])
+    }
+
+    fn merge(&mut self, a: usize, b: usize) -> usize {
+        if a == NIL || b == NIL {
+            return if a == NIL { b } else { a };
+        }
+
+        self.propagate(a);
+        self.propagate(b);
+        let (a, b) = if self.key[a].2 <= self.key[b].2 {
+            (a, b)
+        } else {
+            (b, a)
+        };
+
+        self.right[a] = self.merge(self.right[a], b);
+        if self.rank(self.left[a]) < self.rank(self.right[a]) {
+            (self.left[a], self.right[a]) = (self.right[a], self.left[a]);
+        }
+        self.rank[a] = self.rank(self.right[a]) + 1;
+        a
+    }
+}
@@ -0,0 +1,127 @@
+//! 負の重みの辺を含むグラフに対する単一始点最短経路問題を解くことができる
+//!
+//! ## Examples
+//!
+//! 計算した結果は [`BellmanFordResult`] 型の変数に格納される。頂点 $`i`$ への最短距離は `result.get(i)` で取得できる。
+//!
+//! 始点から負閉路を経由して到達できる頂点は、最短距離が $`-\infty`$ になる。
+//! そのような頂点は `result.is_negative_infinity(i)` で判定できる。
+//!
+//! ```
+//! use library::bellman_ford::bellman_ford;
+//! use library::dijkstra::Dist;
+//! use library::graph::DirectedAdjGraph;
+//!
+//! let graph = DirectedAdjGraph::from_edges(
+//!     6,
+//!     &[(0, 1, 2i64), (1, 2, -3), (0, 2, 1), (2, 3, 1), (3, 4, -1), (4, 3, -1)],
+//! );
+//!
+//! let res = bellman_ford(&graph, 0);
+//!
+//! assert_eq!(res.get(1), Dist::VALUE(2));
+//! assert_eq!(res.get(2), Dist::VALUE(-1));
+//! assert_eq!(res.get(5), Dist::UNREACHABLE);
+//!
+//! // 3 -> 4 -> 3 は負閉路
+//! assert!(res.has_negative_cycle());
+//! assert!(!res.is_negative_infinity(2));
+//! assert!(res.is_negative_infinity(3));
+//! assert!(res.is_negative_infinity(4));
+//! ```
+//!
+//! ## 計算量
+//!
+//! グラフの辺の重みの型 `W` の空間計算量が $`O(1)`$ で、加法が $`O(1)`$ で行えることを仮定する。
+//!
+//! 引数の `graph` が $`G = (V, E)`$ であるとして、$`O(|V| |E|)`$ である。
+//!
+//! ## Verified problems
+//!
+//! * [Single Source Shortest Path (Negative Edges)](../../src/aoj_grl_1_b/aoj_grl_1_b.rs.html)
+//!
+
+use crate::dijkstra::Dist;
+use crate::graph::Graph;
+
+/// [`bellman_ford`] の結果を格納するデータ構造
+pub struct BellmanFordResult<W> {
+    dist: Vec<Option<W>>,
+    negative: Vec<bool>,
+}
+
+impl<W: Copy> BellmanFordResult<W> {
+    /// 頂点 $`i`$ への最短距離を返す
+    ///
+    /// 最短距離が $`-\infty`$ の頂点 (`self.is_negative_infinity(i)` が `true`) については、値に意味はない。
+    pub fn get(&self, i: u32) -> Dist<W> {
+        match self.dist[i as usize] {
+            Some(d) => Dist::VALUE(d),
+            None => Dist::UNREACHABLE,
+        }
+    }
+
+    /// 頂点 $`i`$ が始点から負閉路を経由して到達できる (最短距離が $`-\infty`$ である) かを返す
+    pub fn is_negative_infinity(&self, i: u32) -> bool {
+        self.negative[i as usize]
+    }
+
+    /// 始点から到達できる負閉路が存在するかを返す
+    pub fn has_negative_cycle(&self) -> bool {
+        self.negative.iter().any(|&f| f)
+    }
+}
+
+/// `graph` 上で始点 `src` から各頂点への最短距離を計算する
+pub fn bellman_ford<W: Default + std::ops::Add<Output = W> + Ord + Copy>(
+    graph: &impl Graph<Weight = W>,
+    src: u32,
+) -> BellmanFordResult<W> {
+    let size = graph.size() as usize;
+
+    let mut dist: Vec<Option<W>> = vec![None; size];
+    let mut negative = vec![false; size];
+    dist[src as usize] = Some(W::default());
+
+    for _ in 1..size {
+        let mut updated = false;
+
+        for u in 0..size {
+            if let Some(du) = dist[u] {
+                for &(v, w) in graph.adjacent(u as u32) {
+                    let dv = du + w;
+                    if dist[v as usize].map_or(true, |d| dv < d) {
+                        dist[v as usize] = Some(dv);
+                        updated = true;
+                    }
+                }
+            }
+        }
+
+        if !updated {
+            return BellmanFordResult { dist, negative };
+        }
+    }
+
+    // さらに |V| 回緩和を繰り返し、更新される頂点とそこから到達できる頂点を求める
+    for _ in 0..size {
+        for u in 0..size {
+            if let Some(du) = dist[u] {
+                for &(v, w) in graph.adjacent(u as u32) {
+                    if negative[u] {
+                        negative[v as usize] = true;
+                        continue;
+                    }
+
+                    let dv = du + w;
+                    if dist[v as usize].map_or(true, |d| dv < d) {
+                        dist[v as usize] = Some(dv);
+                        negative[v as usize] = true;
+                    }
+                }
+            }
+        }
+    }
+
+    BellmanFordResult { dist, negative }
+}
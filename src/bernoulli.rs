@@ -0,0 +1,87 @@
+//! ベルヌーイ数と、冪乗の和を $`\mathbb{Z} / P \mathbb{Z}`$ 上で計算する。
+//!
+//! ベルヌーイ数 $`B_n`$ を $`\displaystyle \frac{x}{e^x - 1} = \sum_{n} \frac{B_n}{n!} x^n`$ で定める。特に $`B_1 = -\frac{1}{2}`$ である。
+//!
+//! * [`bernoulli_numbers()`] は $`\displaystyle \frac{e^x - 1}{x}`$ の逆元を [`fps_inv()`] で求めて、$`B_0, B_1, \dots, B_n`$ を計算する。
+//! * [`sum_of_powers()`] は、Faulhaber の公式 $`\displaystyle \sum_{i = 0}^{n - 1} i^k = \frac{1}{k + 1} \sum_{j = 0}^{k} \binom{k + 1}{j} B_j n^{k + 1 - j}`$ を用いて、
+//!   $`n`$ が非常に大きい場合でも $`\displaystyle \sum_{i = 1}^{n} i^k`$ を計算する。
+//!
+//! $`P`$ は [`convolution()`](crate::convolution::convolution) が使える、$`k + 1`$ より大きい素数であることを要求する。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::bernoulli::{bernoulli_numbers, sum_of_powers};
+//! use library::modint::ModInt;
+//!
+//! type Mint = ModInt<998244353>;
+//!
+//! let b = bernoulli_numbers::<998244353>(6);
+//! assert_eq!(b[0], Mint::from(1));
+//! assert_eq!(b[1], Mint::from(-1) / 2);
+//! assert_eq!(b[2], Mint::from(1) / 6);
+//! assert_eq!(b[3], Mint::from(0));
+//! assert_eq!(b[4], Mint::from(-1) / 30);
+//! assert_eq!(b[6], Mint::from(1) / 42);
+//!
+//! // 1^2 + 2^2 + ... + 10^2 = 385
+//! assert_eq!(sum_of_powers::<998244353>(10, 2), Mint::from(385));
+//!
+//! // 1 + 2 + ... + 10^18 = 10^18 (10^18 + 1) / 2
+//! let n = 1_000_000_000_000_000_000u64;
+//! assert_eq!(sum_of_powers::<998244353>(n, 1), Mint::from(n) * Mint::from(n + 1) / 2);
+//! ```
+//!
+//! ## 計算量
+//!
+//! | 関数 | 計算量 |
+//! | --- | --- |
+//! | [`bernoulli_numbers()`] | $`O(N \log N)`$ |
+//! | [`sum_of_powers()`] | $`O(k \log k)`$ |
+//!
+//! ## Verified problems
+//!
+//! * [Bernoulli Number](../../src/lc_bernoulli_number/lc_bernoulli_number.rs.html)
+//!
+
+use crate::fps::fps_inv;
+use crate::modint::{factorials, ModInt};
+
+/// $`B_0, B_1, \dots, B_n`$ を求める
+pub fn bernoulli_numbers<const P: u32>(n: usize) -> Vec<ModInt<P>> {
+    let (fact, inv_fact) = factorials::<P>(n + 1);
+
+    // (e^x - 1) / x = sum x^i / (i + 1)!
+    let f = (0..=n).map(|i| inv_fact[i + 1]).collect::<Vec<_>>();
+
+    fps_inv(&f, n + 1)
+        .into_iter()
+        .zip(fact)
+        .map(|(b, f)| b * f)
+        .collect()
+}
+
+/// $`\displaystyle \sum_{i = 1}^{n} i^k`$ を求める
+pub fn sum_of_powers<const P: u32>(n: u64, k: usize) -> ModInt<P> {
+    let b = bernoulli_numbers::<P>(k);
+    let (fact, inv_fact) = factorials::<P>(k + 1);
+
+    // 0 以上 n + 1 未満の和を求めてから、0^k を引く
+    let m = ModInt::<P>::from(n) + ModInt::from_raw(1);
+    let mut sum = ModInt::from_raw(0);
+    let mut pow = ModInt::from_raw(1);
+
+    // j = k, k - 1, ..., 0 の順に、m^{k + 1 - j} を 1 つずつ増やしながら足す
+    for j in (0..=k).rev() {
+        pow *= m;
+        sum += fact[k + 1] * inv_fact[j] * inv_fact[k + 1 - j] * b[j] * pow;
+    }
+
+    sum /= ModInt::from(k + 1);
+
+    if k == 0 {
+        sum -= ModInt::from_raw(1);
+    }
+
+    sum
+}
@@ -0,0 +1,28 @@
+// verification-helper: PROBLEM https://onlinejudge.u-aizu.ac.jp/courses/library/7/DPL/2/DPL_2_A
+#![allow(non_snake_case)]
+use library::tsp::tsp_bitmask_dp;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        V: usize, E: usize,
+        edges: [(usize, usize, u64); E],
+    }
+
+    // 辺がない 2 頂点間の距離は十分大きな値にしておく
+    const INF: u64 = 1 << 40;
+
+    let mut dist = vec![vec![INF; V]; V];
+    for (s, t, d) in edges {
+        dist[s][t] = d;
+    }
+
+    let (len, _) = tsp_bitmask_dp(&dist);
+
+    if len >= INF {
+        println!("-1");
+    } else {
+        println!("{}", len);
+    }
+}
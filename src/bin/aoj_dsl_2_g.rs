@@ -0,0 +1,24 @@
+// verification-helper: PROBLEM https://onlinejudge.u-aizu.ac.jp/courses/library/3/DSL/2/DSL_2_G
+#![allow(non_snake_case)]
+use library::binary_indexed_tree::RangeBinaryIndexedTree;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+    }
+
+    let mut bit = RangeBinaryIndexedTree::<i64>::new(N);
+
+    for _ in 0..Q {
+        input! { c: u8, s: usize, t: usize }
+
+        if c == 0 {
+            input! { x: i64 }
+            bit.add(s - 1..t, x);
+        } else {
+            println!("{}", bit.sum(s - 1..t));
+        }
+    }
+}
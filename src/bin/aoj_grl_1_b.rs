@@ -0,0 +1,33 @@
+// verification-helper: PROBLEM https://onlinejudge.u-aizu.ac.jp/courses/library/5/GRL/1/GRL_1_B
+#![allow(non_snake_case)]
+use library::bellman_ford::bellman_ford;
+use library::dijkstra::Dist;
+use library::graph::DirectedAdjGraph;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: u32, M: u32, src: u32,
+        edges: [(u32, u32, i64); M],
+    }
+
+    let graph = DirectedAdjGraph::from_edges(N, &edges);
+    let res = bellman_ford(&graph, src);
+
+    if res.has_negative_cycle() {
+        println!("NEGATIVE CYCLE");
+        return;
+    }
+
+    for i in 0..N {
+        match res.get(i) {
+            Dist::UNREACHABLE => {
+                println!("INF");
+            }
+            Dist::VALUE(d) => {
+                println!("{}", d);
+            }
+        }
+    }
+}
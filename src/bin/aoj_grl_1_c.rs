@@ -0,0 +1,35 @@
+// verification-helper: PROBLEM https://onlinejudge.u-aizu.ac.jp/courses/library/5/GRL/1/GRL_1_C
+#![allow(non_snake_case)]
+use itertools::Itertools;
+use library::dijkstra::Dist;
+use library::floyd_warshall::floyd_warshall;
+use library::graph::DirectedAdjGraph;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: u32, M: u32,
+        edges: [(u32, u32, i64); M],
+    }
+
+    let graph = DirectedAdjGraph::from_edges(N, &edges);
+    let res = floyd_warshall(&graph);
+
+    if res.has_negative_cycle() {
+        println!("NEGATIVE CYCLE");
+        return;
+    }
+
+    for u in 0..N {
+        println!(
+            "{}",
+            (0..N)
+                .map(|v| match res.get(u, v) {
+                    Dist::UNREACHABLE => "INF".to_string(),
+                    Dist::VALUE(d) => d.to_string(),
+                })
+                .join(" ")
+        );
+    }
+}
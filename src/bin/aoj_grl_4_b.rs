@@ -0,0 +1,19 @@
+// verification-helper: PROBLEM https://onlinejudge.u-aizu.ac.jp/courses/library/5/GRL/4/GRL_4_B
+#![allow(non_snake_case)]
+use library::dag::topological_sort;
+use library::graph::DirectedAdjGraph;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        V: u32, E: u32,
+        edges: [(u32, u32); E],
+    }
+
+    let graph = DirectedAdjGraph::from_edges_no_weight(V, &edges);
+
+    for v in topological_sort(&graph).unwrap() {
+        println!("{}", v);
+    }
+}
@@ -10,17 +10,11 @@ fn main() {
     }
 
     let graph = library::graph::UndirectedAdjGraph::from_edges(N, &edges);
-    let diameter = library::tree_diameter::tree_diameter(&graph);
-    let (u, v) = diameter.furthest_vertex_pair();
-
-    let dist_u = <dyn library::graph::Tree<Weight = u32>>::dist(&graph, u);
-    let dist_v = <dyn library::graph::Tree<Weight = u32>>::dist(&graph, v);
+    let height = library::tree_diameter::eccentricities(&graph);
 
     println!(
         "{}",
-        (0..N as usize)
-            .map(|i| std::cmp::max(dist_u[i], dist_v[i]))
-            .collect::<Vec<_>>()
+        height
             .iter()
             .map(|x| x.to_string())
             .collect::<Vec<_>>()
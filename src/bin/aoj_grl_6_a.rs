@@ -0,0 +1,19 @@
+// verification-helper: PROBLEM https://onlinejudge.u-aizu.ac.jp/courses/library/5/GRL/6/GRL_6_A
+#![allow(non_snake_case)]
+use library::max_flow::MaxFlow;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        V: usize, E: usize,
+        edges: [(usize, usize, u64); E],
+    }
+
+    let mut mf = MaxFlow::new(V);
+    for (u, v, c) in edges {
+        mf.add_edge(u, v, c);
+    }
+
+    println!("{}", mf.flow(0, V - 1));
+}
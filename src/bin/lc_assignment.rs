@@ -0,0 +1,23 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/assignment
+#![allow(non_snake_case)]
+use library::hungarian::assignment;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize,
+        A: [[i64; N]; N],
+    }
+
+    let (cost, p) = assignment(&A);
+
+    println!("{}", cost);
+    println!(
+        "{}",
+        p.iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+}
@@ -0,0 +1,21 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/bernoulli_number
+#![allow(non_snake_case)]
+use library::bernoulli::bernoulli_numbers;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize,
+    }
+
+    let s = bernoulli_numbers::<998244353>(N);
+
+    println!(
+        "{}",
+        s.iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+}
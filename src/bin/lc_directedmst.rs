@@ -0,0 +1,21 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/directedmst
+#![allow(non_snake_case)]
+use itertools::Itertools;
+use library::arborescence::minimum_spanning_arborescence;
+use library::graph::DirectedAdjGraph;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: u32, M: usize, S: u32,
+        edges: [(u32, u32, i64); M],
+    }
+
+    let graph = DirectedAdjGraph::from_edges(N, &edges);
+    let (weight, mut parent) = minimum_spanning_arborescence(&graph, S).unwrap();
+    parent[S as usize] = S;
+
+    println!("{}", weight);
+    println!("{}", parent.iter().join(" "));
+}
@@ -0,0 +1,28 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/dominatortree
+#![allow(non_snake_case)]
+use library::dominator_tree::dominator_tree;
+use library::graph::DirectedAdjGraph;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: u32, M: u32, S: u32,
+        edges: [(u32, u32); M],
+    }
+
+    let graph = DirectedAdjGraph::from_edges_no_weight(N, &edges);
+    let idom = dominator_tree(&graph, S);
+
+    println!(
+        "{}",
+        idom.iter()
+            .map(|&x| if x == u32::MAX {
+                "-1".to_string()
+            } else {
+                x.to_string()
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+}
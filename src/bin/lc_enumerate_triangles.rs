@@ -0,0 +1,26 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/enumerate_triangles
+#![allow(non_snake_case)]
+use library::graph::UndirectedAdjGraph;
+use library::modint::ModInt;
+use library::triangle::enumerate_triangles;
+use proconio::{fastout, input};
+
+type Mint = ModInt<998244353>;
+
+#[fastout]
+fn main() {
+    input! {
+        N: u32, M: u32,
+        X: [u32; N],
+        edges: [(u32, u32); M],
+    }
+
+    let graph = UndirectedAdjGraph::from_edges_no_weight(N, &edges);
+
+    let mut ans = Mint::from(0);
+    enumerate_triangles(&graph, |a, b, c| {
+        ans += Mint::from(X[a as usize]) * Mint::from(X[b as usize]) * Mint::from(X[c as usize]);
+    });
+
+    println!("{}", ans);
+}
@@ -0,0 +1,22 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/find_linear_recurrence
+#![allow(non_snake_case)]
+use itertools::Itertools;
+use library::fps::berlekamp_massey;
+use library::modint::ModInt;
+use proconio::{fastout, input};
+
+type Mint = ModInt<998244353>;
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize,
+        a: [u32; N],
+    }
+
+    let a = a.iter().map(|&x| Mint::from(x)).collect::<Vec<_>>();
+    let c = berlekamp_massey(&a);
+
+    println!("{}", c.len());
+    println!("{}", c.iter().join(" "));
+}
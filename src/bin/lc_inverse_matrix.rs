@@ -0,0 +1,30 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/inverse_matrix
+#![allow(non_snake_case)]
+use itertools::Itertools;
+use library::matrix::Matrix;
+use library::modint::ModInt;
+use proconio::{fastout, input};
+
+type Mint = ModInt<998244353>;
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize,
+        A: [[u32; N]; N],
+    }
+
+    let a = A
+        .iter()
+        .map(|row| row.iter().map(|&x| Mint::from(x)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    match Matrix::from(&a).inv() {
+        Some(b) => {
+            for i in 0..N {
+                println!("{}", b.row(i).iter().join(" "));
+            }
+        }
+        None => println!("-1"),
+    }
+}
@@ -0,0 +1,21 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/kth_term_of_linearly_recurrent_sequence
+#![allow(non_snake_case)]
+use library::fps::kth_term_of_linear_recurrence;
+use library::modint::ModInt;
+use proconio::{fastout, input};
+
+type Mint = ModInt<998244353>;
+
+#[fastout]
+fn main() {
+    input! {
+        d: usize, k: u64,
+        a: [u32; d],
+        c: [u32; d],
+    }
+
+    let a = a.iter().map(|&x| Mint::from(x)).collect::<Vec<_>>();
+    let c = c.iter().map(|&x| Mint::from(x)).collect::<Vec<_>>();
+
+    println!("{}", kth_term_of_linear_recurrence(&a, &c, k));
+}
@@ -0,0 +1,26 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/lca
+#![allow(non_snake_case)]
+use library::graph::UndirectedAdjGraph;
+use library::tree_dist::TreeDist;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        P: [u32; N - 1],
+        query: [(u32, u32); Q],
+    }
+
+    let mut graph = UndirectedAdjGraph::new(N as u32);
+
+    for i in 1..=N - 1 {
+        graph.add_edge(i as u32, P[i - 1], 1u32);
+    }
+
+    let td = TreeDist::from(&graph, 0);
+
+    for (u, v) in query {
+        println!("{}", td.lca(u, v));
+    }
+}
@@ -0,0 +1,52 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/line_add_get_min
+#![allow(non_snake_case)]
+use library::li_chao_tree::LiChaoTree;
+use proconio::{fastout, input};
+
+enum Query {
+    Add(i64, i64),
+    Get(i64),
+}
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        lines: [(i64, i64); N],
+    }
+
+    let mut queries = vec![];
+
+    for _ in 0..Q {
+        input! { t: u8 }
+
+        if t == 0 {
+            input! { a: i64, b: i64 }
+            queries.push(Query::Add(a, b));
+        } else {
+            input! { p: i64 }
+            queries.push(Query::Get(p));
+        }
+    }
+
+    let xs = queries
+        .iter()
+        .filter_map(|q| match q {
+            Query::Get(p) => Some(*p),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut lct = LiChaoTree::from(&xs);
+
+    for (a, b) in lines {
+        lct.add_line(a, b);
+    }
+
+    for q in queries {
+        match q {
+            Query::Add(a, b) => lct.add_line(a, b),
+            Query::Get(p) => println!("{}", lct.min(p).unwrap()),
+        }
+    }
+}
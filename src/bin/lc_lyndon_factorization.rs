@@ -0,0 +1,15 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/lyndon_factorization
+#![allow(non_snake_case)]
+use itertools::Itertools;
+use library::lyndon::lyndon_factorization;
+use proconio::marker::Bytes;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        S: Bytes,
+    }
+
+    println!("{}", lyndon_factorization(&S).iter().join(" "));
+}
@@ -0,0 +1,22 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/matrix_det
+#![allow(non_snake_case)]
+use library::matrix::Matrix;
+use library::modint::ModInt;
+use proconio::{fastout, input};
+
+type Mint = ModInt<998244353>;
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize,
+        A: [[u32; N]; N],
+    }
+
+    let a = A
+        .iter()
+        .map(|row| row.iter().map(|&x| Mint::from(x)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    println!("{}", Matrix::from(&a).det());
+}
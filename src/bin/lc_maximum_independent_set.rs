@@ -0,0 +1,25 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/maximum_independent_set
+#![allow(non_snake_case)]
+use library::graph::UndirectedAdjGraph;
+use library::maximum_independent_set::maximum_independent_set;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: u32, M: u32,
+        edges: [(u32, u32); M],
+    }
+
+    let graph = UndirectedAdjGraph::from_edges_no_weight(N, &edges);
+    let set = maximum_independent_set(&graph);
+
+    println!("{}", set.len());
+    println!(
+        "{}",
+        set.iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+}
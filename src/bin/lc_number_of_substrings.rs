@@ -0,0 +1,22 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/number_of_substrings
+#![allow(non_snake_case)]
+use library::suffix_array::{lcp_array, suffix_array};
+use proconio::marker::Bytes;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        S: Bytes,
+    }
+
+    let n = S.len() as u64;
+    let sa = suffix_array(&S);
+    let lcp = lcp_array(&S, &sa);
+
+    // 全ての接尾辞の接頭辞から、直前の接尾辞と共通する分を除く
+    println!(
+        "{}",
+        n * (n + 1) / 2 - lcp.iter().map(|&l| l as u64).sum::<u64>()
+    );
+}
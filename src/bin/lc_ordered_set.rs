@@ -0,0 +1,42 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/ordered_set
+#![allow(non_snake_case)]
+use library::sorted_list::SortedList;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        A: [u32; N],
+    }
+
+    let mut list = SortedList::from(&A);
+
+    for _ in 0..Q {
+        input! { t: u8, x: u32 }
+
+        match t {
+            0 => {
+                if !list.contains(&x) {
+                    list.insert(x);
+                }
+            }
+            1 => {
+                list.remove(&x);
+            }
+            2 => match list.kth(x as usize - 1) {
+                Some(v) => println!("{}", v),
+                None => println!("-1"),
+            },
+            3 => println!("{}", list.bisect_right(&x)),
+            4 => match list.bisect_right(&x) {
+                0 => println!("-1"),
+                k => println!("{}", list.kth(k - 1).unwrap()),
+            },
+            _ => match list.kth(list.bisect_left(&x)) {
+                Some(v) => println!("{}", v),
+                None => println!("-1"),
+            },
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/ordered_set
+#![allow(non_snake_case)]
+use library::compressed_multiset::CompressedMultiset;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        A: [u32; N],
+        query: [(u8, u32); Q],
+    }
+
+    let values = A
+        .iter()
+        .copied()
+        .chain(query.iter().filter(|q| q.0 == 0).map(|q| q.1))
+        .collect::<Vec<_>>();
+
+    let mut set = CompressedMultiset::from(&values);
+    for &a in &A {
+        set.insert(a);
+    }
+
+    for (t, x) in query {
+        match t {
+            0 => {
+                if set.count(x) == 0 {
+                    set.insert(x);
+                }
+            }
+            1 => {
+                set.remove(x);
+            }
+            2 => match set.kth(x as usize - 1) {
+                Some(v) => println!("{}", v),
+                None => println!("-1"),
+            },
+            3 => println!("{}", set.count_less(x) + set.count(x)),
+            4 => match set.count_less(x) + set.count(x) {
+                0 => println!("-1"),
+                k => println!("{}", set.kth(k - 1).unwrap()),
+            },
+            _ => match set.kth(set.count_less(x)) {
+                Some(v) => println!("{}", v),
+                None => println!("-1"),
+            },
+        }
+    }
+}
@@ -0,0 +1,34 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/point_add_rectangle_sum
+#![allow(non_snake_case)]
+use library::offline_rectangle_sum::OfflineRectangleSum;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        points: [(i64, i64, u64); N],
+    }
+
+    let mut rs = OfflineRectangleSum::new();
+
+    for (x, y, w) in points {
+        rs.add_point(x, y, w);
+    }
+
+    for _ in 0..Q {
+        input! { t: u8 }
+
+        if t == 0 {
+            input! { x: i64, y: i64, w: u64 }
+            rs.add_point(x, y, w);
+        } else {
+            input! { l: i64, d: i64, r: i64, u: i64 }
+            rs.add_query(l, r, d, u);
+        }
+    }
+
+    for ans in rs.solve() {
+        println!("{}", ans);
+    }
+}
@@ -0,0 +1,30 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/point_add_rectangle_sum
+#![allow(non_snake_case)]
+use library::dynamic_binary_indexed_tree::DynamicBinaryIndexedTree2D;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        points: [(usize, usize, u64); N],
+    }
+
+    let mut bit = DynamicBinaryIndexedTree2D::new(1_000_000_000, 1_000_000_000);
+
+    for (x, y, w) in points {
+        bit.add(x, y, w);
+    }
+
+    for _ in 0..Q {
+        input! { t: u8 }
+
+        if t == 0 {
+            input! { x: usize, y: usize, w: u64 }
+            bit.add(x, y, w);
+        } else {
+            input! { l: usize, d: usize, r: usize, u: usize }
+            println!("{}", bit.sum(l..r, d..u));
+        }
+    }
+}
@@ -0,0 +1,32 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/point_set_range_composite
+#![allow(non_snake_case)]
+use library::algebra::Affine;
+use library::modint::ModInt;
+use library::segtree::SegmentTree;
+use proconio::{fastout, input};
+
+type Mint = ModInt<998244353>;
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        F: [(u32, u32); N],
+    }
+
+    let mut stree: SegmentTree<Affine<Mint>> = F
+        .iter()
+        .map(|&(a, b)| (Mint::from(a), Mint::from(b)))
+        .collect();
+
+    for _ in 0..Q {
+        input! { t: u8, x: usize, y: usize, z: u32 }
+
+        if t == 0 {
+            stree.insert(x, (Mint::from(y), Mint::from(z)));
+        } else {
+            let (a, b) = stree.prod(x..y);
+            println!("{}", a * Mint::from(z) + b);
+        }
+    }
+}
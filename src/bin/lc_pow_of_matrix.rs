@@ -0,0 +1,26 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/pow_of_matrix
+#![allow(non_snake_case)]
+use itertools::Itertools;
+use library::matrix::Matrix;
+use library::modint::ModInt;
+use proconio::{fastout, input};
+
+type Mint = ModInt<998244353>;
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, K: u64,
+        A: [[u32; N]; N],
+    }
+
+    let a = A
+        .iter()
+        .map(|row| row.iter().map(|&x| Mint::from(x)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let b = Matrix::from(&a).pow(K);
+
+    for i in 0..N {
+        println!("{}", b.row(i).iter().join(" "));
+    }
+}
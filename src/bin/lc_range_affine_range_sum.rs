@@ -0,0 +1,31 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/range_affine_range_sum
+#![allow(non_snake_case)]
+use library::algebra::RangeAffineRangeSum;
+use library::lazy_segtree::LazySegmentTree;
+use library::modint::ModInt;
+use proconio::{fastout, input};
+
+type Mint = ModInt<998244353>;
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        A: [u32; N],
+    }
+
+    let a = A.iter().map(|&x| (Mint::from(x), 1)).collect::<Vec<_>>();
+    let mut stree: LazySegmentTree<RangeAffineRangeSum<998244353>> = LazySegmentTree::from(&a);
+
+    for _ in 0..Q {
+        input! { t: u8 }
+
+        if t == 0 {
+            input! { l: usize, r: usize, b: u32, c: u32 }
+            stree.apply(l..r, (Mint::from(b), Mint::from(c)));
+        } else {
+            input! { l: usize, r: usize }
+            println!("{}", stree.prod(l..r).0);
+        }
+    }
+}
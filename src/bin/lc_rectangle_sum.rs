@@ -0,0 +1,27 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/rectangle_sum
+#![allow(non_snake_case)]
+use library::offline_rectangle_sum::OfflineRectangleSum;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        points: [(i64, i64, u64); N],
+        query: [(i64, i64, i64, i64); Q],
+    }
+
+    let mut rs = OfflineRectangleSum::new();
+
+    for (x, y, w) in points {
+        rs.add_point(x, y, w);
+    }
+
+    for (l, d, r, u) in query {
+        rs.add_query(l, r, d, u);
+    }
+
+    for ans in rs.solve() {
+        println!("{}", ans);
+    }
+}
@@ -0,0 +1,28 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/rooted_tree_isomorphism_classification
+#![allow(non_snake_case)]
+use library::graph::UndirectedAdjGraph;
+use library::tree_isomorphism::TreeIsomorphism;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize,
+        P: [u32; N - 1],
+    }
+
+    let edges = (1..N).map(|i| (i as u32, P[i - 1])).collect::<Vec<_>>();
+    let tree = UndirectedAdjGraph::from_edges_no_weight(N as u32, &edges);
+
+    let mut iso = TreeIsomorphism::new();
+    let id = iso.rooted(&tree, 0);
+
+    println!("{}", iso.count());
+    println!(
+        "{}",
+        id.iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+}
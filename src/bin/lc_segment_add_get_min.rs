@@ -0,0 +1,55 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/segment_add_get_min
+#![allow(non_snake_case)]
+use library::li_chao_tree::LiChaoTree;
+use proconio::{fastout, input};
+
+enum Query {
+    Add(i64, i64, i64, i64),
+    Get(i64),
+}
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        segments: [(i64, i64, i64, i64); N],
+    }
+
+    let mut queries = vec![];
+
+    for _ in 0..Q {
+        input! { t: u8 }
+
+        if t == 0 {
+            input! { l: i64, r: i64, a: i64, b: i64 }
+            queries.push(Query::Add(l, r, a, b));
+        } else {
+            input! { p: i64 }
+            queries.push(Query::Get(p));
+        }
+    }
+
+    let xs = queries
+        .iter()
+        .filter_map(|q| match q {
+            Query::Get(p) => Some(*p),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut lct = LiChaoTree::from(&xs);
+
+    for (l, r, a, b) in segments {
+        lct.add_segment(a, b, l, r);
+    }
+
+    for q in queries {
+        match q {
+            Query::Add(l, r, a, b) => lct.add_segment(a, b, l, r),
+            Query::Get(p) => match lct.min(p) {
+                Some(y) => println!("{}", y),
+                None => println!("INFINITY"),
+            },
+        }
+    }
+}
@@ -0,0 +1,17 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/static_range_count_distinct
+#![allow(non_snake_case)]
+use library::range_distinct_count::range_distinct_count;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        A: [u32; N],
+        query: [(usize, usize); Q],
+    }
+
+    for ans in range_distinct_count(&A, &query) {
+        println!("{}", ans);
+    }
+}
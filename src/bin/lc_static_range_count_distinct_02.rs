@@ -0,0 +1,54 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/static_range_count_distinct
+#![allow(non_snake_case)]
+use library::coordinate_compression::CoordinateCompress;
+use library::mo::{mo, MoState};
+use proconio::{fastout, input};
+
+struct Distinct {
+    a: Vec<usize>,
+    count: Vec<u32>,
+    kinds: usize,
+}
+
+impl MoState for Distinct {
+    type Output = usize;
+
+    fn add(&mut self, i: usize) {
+        self.count[self.a[i]] += 1;
+        if self.count[self.a[i]] == 1 {
+            self.kinds += 1;
+        }
+    }
+
+    fn remove(&mut self, i: usize) {
+        self.count[self.a[i]] -= 1;
+        if self.count[self.a[i]] == 0 {
+            self.kinds -= 1;
+        }
+    }
+
+    fn answer(&self) -> usize {
+        self.kinds
+    }
+}
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        A: [u32; N],
+        query: [(usize, usize); Q],
+    }
+
+    let cc = CoordinateCompress::from(&A);
+    let a = A.iter().map(|x| cc.index(*x).unwrap()).collect::<Vec<_>>();
+    let mut state = Distinct {
+        a,
+        count: vec![0; N],
+        kinds: 0,
+    };
+
+    for ans in mo(&mut state, N, &query) {
+        println!("{}", ans);
+    }
+}
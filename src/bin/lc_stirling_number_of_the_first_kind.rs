@@ -0,0 +1,21 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/stirling_number_of_the_first_kind
+#![allow(non_snake_case)]
+use library::stirling::stirling_first_row;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize,
+    }
+
+    let s = stirling_first_row::<998244353>(N);
+
+    println!(
+        "{}",
+        s.iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+}
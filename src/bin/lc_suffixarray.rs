@@ -0,0 +1,22 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/suffixarray
+#![allow(non_snake_case)]
+use library::suffix_array::suffix_array;
+use proconio::marker::Bytes;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        S: Bytes,
+    }
+
+    let sa = suffix_array(&S);
+
+    println!(
+        "{}",
+        sa.iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+}
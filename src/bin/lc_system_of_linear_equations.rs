@@ -0,0 +1,34 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/system_of_linear_equations
+#![allow(non_snake_case)]
+use itertools::Itertools;
+use library::matrix::Matrix;
+use library::modint::ModInt;
+use proconio::{fastout, input};
+
+type Mint = ModInt<998244353>;
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, M: usize,
+        A: [[u32; M]; N],
+        b: [u32; N],
+    }
+
+    let a = A
+        .iter()
+        .map(|row| row.iter().map(|&x| Mint::from(x)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let b = b.iter().map(|&x| Mint::from(x)).collect::<Vec<_>>();
+
+    match Matrix::from(&a).solve(&b) {
+        Some((x, basis)) => {
+            println!("{}", basis.len());
+            println!("{}", x.iter().join(" "));
+            for y in basis {
+                println!("{}", y.iter().join(" "));
+            }
+        }
+        None => println!("-1"),
+    }
+}
@@ -0,0 +1,33 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/unionfind_with_potential
+#![allow(non_snake_case)]
+use library::algebra::Add;
+use library::modint::ModInt;
+use library::unionfind::PotentializedUnionFind;
+use proconio::{fastout, input};
+
+type Mint = ModInt<998244353>;
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+    }
+
+    let mut uf = PotentializedUnionFind::<Add<Mint>>::new(N);
+
+    for _ in 0..Q {
+        input! {
+            t: u8, u: usize, v: usize,
+        }
+
+        if t == 0 {
+            input! { x: u32 }
+            println!("{}", if uf.unite(v, u, Mint::from(x)) { 1 } else { 0 });
+        } else {
+            match uf.diff(v, u) {
+                Some(d) => println!("{}", d),
+                None => println!("-1"),
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/vertex_add_path_sum
+#![allow(non_snake_case)]
+use library::graph::UndirectedAdjGraph;
+use library::subtree_sum::SubtreeSum;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        A: [u64; N],
+        edges: [(u32, u32); N - 1],
+    }
+
+    let tree = UndirectedAdjGraph::from_edges_no_weight(N as u32, &edges);
+    let mut ss = SubtreeSum::from(&tree, 0, &A);
+
+    for _ in 0..Q {
+        input! { t: u8 }
+
+        if t == 0 {
+            input! { p: u32, x: u64 }
+            ss.add_vertex(p, x);
+        } else {
+            input! { u: u32, v: u32 }
+            println!("{}", ss.path_sum(u, v));
+        }
+    }
+}
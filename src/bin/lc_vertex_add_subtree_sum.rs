@@ -0,0 +1,30 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/vertex_add_subtree_sum
+#![allow(non_snake_case)]
+use library::graph::UndirectedAdjGraph;
+use library::subtree_sum::SubtreeSum;
+use proconio::{fastout, input};
+
+#[fastout]
+fn main() {
+    input! {
+        N: usize, Q: usize,
+        A: [u64; N],
+        P: [u32; N - 1],
+    }
+
+    let edges = (1..N).map(|i| (i as u32, P[i - 1])).collect::<Vec<_>>();
+    let tree = UndirectedAdjGraph::from_edges_no_weight(N as u32, &edges);
+    let mut ss = SubtreeSum::from(&tree, 0, &A);
+
+    for _ in 0..Q {
+        input! { t: u8 }
+
+        if t == 0 {
+            input! { u: u32, x: u64 }
+            ss.add_vertex(u, x);
+        } else {
+            input! { u: u32 }
+            println!("{}", ss.subtree_sum(u));
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::algebra::AbelianGroup;
+
 /// [`BinaryIndexedTree`]は一点の値の更新と区間和の計算を高速に行うことができる。
 ///  
 /// ## Examples
@@ -24,6 +26,21 @@
 /// assert_eq!(bit.sum(0..=1), 11);
 /// ```
 ///
+/// `add`, `prefix_sum` には `Default + AddAssign`、`sum` にはさらに `Sub` のみが要求されるので、`ModInt` なども載せられる。
+/// `upper_bound` のみ `Ord` が要求される。
+///
+/// ```
+/// use library::binary_indexed_tree::BinaryIndexedTree;
+/// use library::modint::ModInt;
+///
+/// type Mint = ModInt<998244353>;
+///
+/// let mut bit = BinaryIndexedTree::from(&[Mint::from(998244352u32), Mint::from(3u32)]);
+/// assert_eq!(bit.sum(..), Mint::from(2u32));
+/// bit.add(0, Mint::from(1u32));
+/// assert_eq!(bit.prefix_sum(0), Mint::from(0u32));
+/// ```
+///
 /// ## 計算量
 ///
 /// 区間和を計算したい型 `T` の空間計算量が $`O(1)`$ であり、加法が $`O(1)`$ で行えることを仮定する。
@@ -33,6 +50,7 @@
 /// | `new(size)` | `[0; size]` で初期化する | $`O(\text{size})`$ |
 /// | `self.add(i, w)` | $`i`$ 番目の要素に `w` を足す | $`O(\log(\text{self.size}))`$ |
 /// | `self.sum(range)` | `range` 内の要素の総和を求める | $`O(\log(\text{self.size}))`$ |
+/// | `self.partition_point(pred)` | `pred(prefix_sum(i))` が `false` になる最小の $`i`$ を求める | $`O(\log(\text{self.size}))`$ |
 ///
 /// ## Verified problems
 ///
@@ -46,17 +64,7 @@ pub struct BinaryIndexedTree<T> {
     pub size: usize,
 }
 
-impl<
-        T: Default
-            + Clone
-            + Copy
-            + PartialOrd
-            + Ord
-            + std::ops::AddAssign
-            + std::ops::Sub<Output = T>
-            + std::ops::Add<Output = T>,
-    > BinaryIndexedTree<T>
-{
+impl<T: Default + Clone + Copy + std::ops::AddAssign> BinaryIndexedTree<T> {
     /// 要素数が `size` で各要素が `T::default()` である `BinaruIndexedTree<T>` を生成する。
     pub fn new(size: usize) -> Self {
         return Self {
@@ -77,6 +85,33 @@ impl<
         self._sum(i + 1)
     }
 
+    /// 配列 `array` から `BinaryIndexedTree` を構築する
+    ///
+    /// 参考: <https://qiita.com/ngtkana/items/7d50ff180a4e5c294cb7#%E6%A7%8B%E7%AF%89>
+    pub fn from(array: &[T]) -> Self {
+        array.iter().copied().collect()
+    }
+
+    fn _add(&mut self, mut i: usize, w: T) {
+        while i < self.tree.len() {
+            self.tree[i] += w;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    fn _sum(&self, mut i: usize) -> T {
+        let mut ret = T::default();
+        while i > 0 {
+            ret += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        return ret;
+    }
+}
+
+impl<T: Default + Clone + Copy + std::ops::AddAssign + std::ops::Sub<Output = T>>
+    BinaryIndexedTree<T>
+{
     /// $`\displaystyle \sum_{i \in \text{range}} \text{self} \lbrack i \rbrack`$ を計算する。
     pub fn sum<R: std::ops::RangeBounds<usize>>(&self, range: R) -> T {
         let left = match range.start_bound() {
@@ -97,14 +132,27 @@ impl<
             return self.prefix_sum(right) - self.prefix_sum(left - 1);
         }
     }
+}
 
-    /// `T` の和に単調性がある場合にのみ機能する。($`s + a \geq s`$がすべての$`a \in T`$に対して成り立つ)  
+impl<T: Default + Clone + Copy + std::ops::AddAssign + std::ops::Add<Output = T>>
+    BinaryIndexedTree<T>
+{
+    /// $`\text{pred}(\text{self} \lbrack 0 \rbrack + \dots + \text{self} \lbrack i \rbrack)`$ が `false` になる最小の $`i`$ を返す。
+    /// そのような $`i`$ が存在しなければ `self.size` を返す。
     ///
-    /// $`\displaystyle \text{PrefixSum}(i) = \sum_{j < i} \text{self} \lbrack j \rbrack`$ とする。(値域は$`0, 1, \dots, \text{self.size}`$)  
-    /// $`\text{PrefixSum}(i) \leq w`$ を満たす最大の $`i`$ を返す
+    /// `pred(T::default())` が `true` であり、$`i`$ について `pred` の値が `true, ..., true, false, ..., false` と単調に変化することを仮定する。
+    /// 負の値を足したことがある場合など、この仮定が成り立たないときは、`pred` が `true` から `false` に切り替わる位置のいずれかを返す。
     ///
-    /// 参考: <https://qiita.com/ngtkana/items/7d50ff180a4e5c294cb7#%E6%A7%8B%E7%AF%89>
-    pub fn upper_bound(&self, w: T) -> usize {
+    /// ```
+    /// use library::binary_indexed_tree::BinaryIndexedTree;
+    ///
+    /// let bit = BinaryIndexedTree::from(&[3, 1, 4, 1, 5]);
+    ///
+    /// assert_eq!(bit.partition_point(|s| s < 8), 2);
+    /// assert_eq!(bit.partition_point(|s| s <= 8), 3);
+    /// assert_eq!(bit.partition_point(|s| s < 100), 5);
+    /// ```
+    pub fn partition_point<F: FnMut(T) -> bool>(&self, mut pred: F) -> usize {
         let mut d = self.tree.len().next_power_of_two() / 2;
         let mut j = 0;
         let mut u = T::default();
@@ -113,7 +161,7 @@ impl<
             if j + d < self.tree.len() {
                 let v = u + self.tree[j + d];
 
-                if v <= w {
+                if pred(v) {
                     u = v;
                     j += d;
                 }
@@ -124,16 +172,29 @@ impl<
 
         j
     }
+}
 
-    /// 配列 `array` から `BinaryIndexedTree` を構築する
+/// `upper_bound` のみ、和の比較のために `Ord` を要求する
+impl<T: Default + Clone + Copy + Ord + std::ops::AddAssign + std::ops::Add<Output = T>>
+    BinaryIndexedTree<T>
+{
+    /// `T` の和に単調性がある場合にのみ機能する。($`s + a \geq s`$がすべての$`a \in T`$に対して成り立つ)  
+    ///
+    /// $`\displaystyle \text{PrefixSum}(i) = \sum_{j < i} \text{self} \lbrack j \rbrack`$ とする。(値域は$`0, 1, \dots, \text{self.size}`$)  
+    /// $`\text{PrefixSum}(i) \leq w`$ を満たす最大の $`i`$ を返す
     ///
     /// 参考: <https://qiita.com/ngtkana/items/7d50ff180a4e5c294cb7#%E6%A7%8B%E7%AF%89>
-    pub fn from(array: &[T]) -> Self {
-        let mut tree = vec![T::default(); array.len() + 1];
+    pub fn upper_bound(&self, w: T) -> usize {
+        self.partition_point(|s| s <= w)
+    }
+}
+
+impl<T: Default + Clone + Copy + std::ops::AddAssign> FromIterator<T> for BinaryIndexedTree<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = vec![T::default()];
+        tree.extend(iter);
 
         for i in 1..tree.len() {
-            let x = array[i - 1];
-            tree[i] += x;
             let j = i + (i & i.wrapping_neg());
             if j < tree.len() {
                 let v = tree[i];
@@ -141,26 +202,29 @@ impl<
             }
         }
 
-        Self {
-            tree,
-            size: array.len(),
-        }
-    }
-
-    fn _add(&mut self, mut i: usize, w: T) {
-        while i < self.tree.len() {
-            self.tree[i] += w;
-            i += i & i.wrapping_neg();
-        }
+        let size = tree.len() - 1;
+        Self { tree, size }
     }
+}
 
-    fn _sum(&self, mut i: usize) -> T {
-        let mut ret = T::default();
-        while i > 0 {
-            ret += self.tree[i];
-            i -= i & i.wrapping_neg();
+/// 末尾に要素を追加する  
+/// 追加する要素 $`1`$ つあたり $`O(\log(\text{self.size}))`$ かかる
+impl<
+        T: Default
+            + Clone
+            + Copy
+            + std::ops::AddAssign
+            + std::ops::Sub<Output = T>
+            + std::ops::Add<Output = T>,
+    > Extend<T> for BinaryIndexedTree<T>
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            let i = self.tree.len();
+            let v = x + self._sum(i - 1) - self._sum(i - (i & i.wrapping_neg()));
+            self.tree.push(v);
+            self.size += 1;
         }
-        return ret;
     }
 }
 
@@ -168,12 +232,9 @@ impl<
         T: Default
             + Clone
             + Copy
-            + PartialOrd
-            + Ord
             + std::ops::AddAssign
             + std::ops::Sub<Output = T>
-            + std::fmt::Display
-            + std::ops::Add<Output = T>,
+            + std::fmt::Display,
     > std::fmt::Display for BinaryIndexedTree<T>
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -188,3 +249,246 @@ impl<
         )
     }
 }
+
+/// [`RangeBinaryIndexedTree`] は区間への一様な加算と区間和の計算を高速に行うことができる。
+///
+/// 内部では [`BinaryIndexedTree`] を $`2`$ つ持つ。負の値を足すことがあるので、`T` には `std::ops::Neg` が要求される。
+///
+/// ## Examples
+///
+/// ```
+/// use library::binary_indexed_tree::RangeBinaryIndexedTree;
+///
+/// let mut bit = RangeBinaryIndexedTree::<i64>::from(&[1, 2, 3, 4, 5]);
+/// bit.add(1..4, 10);
+/// assert_eq!(bit.sum(..), 45);
+/// assert_eq!(bit.sum(0..2), 13);
+/// assert_eq!(bit.sum(3..=4), 19);
+///
+/// bit.add(.., -1);
+/// assert_eq!(bit.get(2), 12);
+/// ```
+///
+/// ## 計算量
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `new(size)` | `[0; size]` で初期化する | $`O(\text{size})`$ |
+/// | `from(array)` | `array` で初期化する | $`O(\lvert \text{array} \rvert)`$ |
+/// | `self.add(range, w)` | `range` 内の各要素に `w` を足す | $`O(\log(\text{self.size}))`$ |
+/// | `self.sum(range)` | `range` 内の要素の総和を求める | $`O(\log(\text{self.size}))`$ |
+///
+/// ## Verified problems
+///
+/// * [RSQ and RAQ](../../src/aoj_dsl_2_g/aoj_dsl_2_g.rs.html)
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RangeBinaryIndexedTree<T> {
+    /// 累積和の定数項
+    b0: BinaryIndexedTree<T>,
+    /// 累積和の $`i`$ の係数
+    b1: BinaryIndexedTree<T>,
+    /// 要素数を表す。
+    pub size: usize,
+}
+
+impl<
+        T: Default
+            + Clone
+            + Copy
+            + std::ops::AddAssign
+            + std::ops::Sub<Output = T>
+            + std::ops::Add<Output = T>
+            + std::ops::Mul<Output = T>
+            + std::ops::Neg<Output = T>
+            + TryFrom<usize>,
+    > RangeBinaryIndexedTree<T>
+{
+    /// 要素数が `size` で各要素が `T::default()` である `RangeBinaryIndexedTree<T>` を生成する。
+    pub fn new(size: usize) -> Self {
+        Self {
+            b0: BinaryIndexedTree::new(size + 1),
+            b1: BinaryIndexedTree::new(size + 1),
+            size,
+        }
+    }
+
+    /// 配列 `array` から `RangeBinaryIndexedTree` を構築する
+    pub fn from(array: &[T]) -> Self {
+        Self {
+            b0: array
+                .iter()
+                .copied()
+                .chain(std::iter::once(T::default()))
+                .collect(),
+            b1: BinaryIndexedTree::new(array.len() + 1),
+            size: array.len(),
+        }
+    }
+
+    /// `range` 内の各要素に $`w`$ を加算する。
+    pub fn add<R: std::ops::RangeBounds<usize>>(&mut self, range: R, w: T) {
+        let left = match range.start_bound() {
+            std::ops::Bound::Included(&l) => l,
+            std::ops::Bound::Excluded(&l) => l + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+
+        let right = match range.end_bound() {
+            std::ops::Bound::Included(&r) => r + 1,
+            std::ops::Bound::Excluded(&r) => r,
+            std::ops::Bound::Unbounded => self.size,
+        };
+
+        assert!(left <= right && right <= self.size);
+
+        self.b0.add(left, -(w * Self::cast(left)));
+        self.b0.add(right, w * Self::cast(right));
+        self.b1.add(left, w);
+        self.b1.add(right, -w);
+    }
+
+    /// $`i`$ 番目の要素を取得する。
+    pub fn get(&self, i: usize) -> T {
+        self.sum(i..=i)
+    }
+
+    /// $`\displaystyle \sum_{i \in \text{range}} \text{self} \lbrack i \rbrack`$ を計算する。
+    pub fn sum<R: std::ops::RangeBounds<usize>>(&self, range: R) -> T {
+        let left = match range.start_bound() {
+            std::ops::Bound::Included(&l) => l,
+            std::ops::Bound::Excluded(&l) => l + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+
+        let right = match range.end_bound() {
+            std::ops::Bound::Included(&r) => r + 1,
+            std::ops::Bound::Excluded(&r) => r,
+            std::ops::Bound::Unbounded => self.size,
+        };
+
+        assert!(left <= right && right <= self.size);
+        self.prefix_sum_exclusive(right) - self.prefix_sum_exclusive(left)
+    }
+
+    /// $`\displaystyle \sum_{j < i} \text{self} \lbrack j \rbrack`$ を計算する。
+    fn prefix_sum_exclusive(&self, i: usize) -> T {
+        if i == 0 {
+            return T::default();
+        }
+        self.b0.prefix_sum(i - 1) + self.b1.prefix_sum(i - 1) * Self::cast(i)
+    }
+
+    fn cast(i: usize) -> T {
+        match T::try_from(i) {
+            Ok(v) => v,
+            Err(_) => panic!("index {} cannot be converted", i),
+        }
+    }
+}
+
+/// 可換群 `G` の元を載せる [`BinaryIndexedTree`]
+///
+/// 演算子のトレイト境界の代わりに [`AbelianGroup`] を使うので、xor や `ModInt` の加法などを新しい型で包まずに扱える。
+///
+/// ## Examples
+///
+/// ```
+/// use library::algebra::BitXor;
+/// use library::binary_indexed_tree::GroupBinaryIndexedTree;
+///
+/// let mut bit = GroupBinaryIndexedTree::<BitXor<u32>>::from(&[0b001, 0b010, 0b100]);
+/// assert_eq!(bit.sum(..), 0b111);
+///
+/// bit.add(1, 0b110);
+/// assert_eq!(bit.sum(1..), 0b000);
+/// assert_eq!(bit.prefix_sum(1), 0b101);
+/// ```
+///
+/// ## 計算量
+///
+/// 二項演算・逆元の計算が $`O(1)`$ で行えることを仮定する。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `new(size)` | `[G::E; size]` で初期化する | $`O(\text{size})`$ |
+/// | `from(array)` | `array` で初期化する | $`O(\lvert \text{array} \rvert)`$ |
+/// | `self.add(i, w)` | $`i`$ 番目の要素に `w` を作用させる | $`O(\log(\text{self.size}))`$ |
+/// | `self.sum(range)` | `range` 内の要素の総積を求める | $`O(\log(\text{self.size}))`$ |
+///
+pub struct GroupBinaryIndexedTree<G: AbelianGroup> {
+    tree: Vec<G::S>,
+    /// 要素数を表す。
+    pub size: usize,
+}
+
+impl<G: AbelianGroup> GroupBinaryIndexedTree<G> {
+    /// 要素数が `size` で各要素が `G::E` である `GroupBinaryIndexedTree<G>` を生成する。
+    pub fn new(size: usize) -> Self {
+        Self {
+            tree: vec![G::E; size + 1],
+            size,
+        }
+    }
+
+    /// 配列 `array` から `GroupBinaryIndexedTree` を構築する
+    pub fn from(array: &[G::S]) -> Self {
+        let mut tree = vec![G::E];
+        tree.extend_from_slice(array);
+
+        for i in 1..tree.len() {
+            let j = i + (i & i.wrapping_neg());
+            if j < tree.len() {
+                tree[j] = G::op(&tree[j], &tree[i]);
+            }
+        }
+
+        Self {
+            tree,
+            size: array.len(),
+        }
+    }
+
+    /// $`i`$ 番目の要素を $`\text{self} \lbrack i \rbrack \cdot w`$ で置き換える。
+    pub fn add(&mut self, i: usize, w: G::S) {
+        assert!(i < self.size);
+        let mut i = i + 1;
+        while i < self.tree.len() {
+            self.tree[i] = G::op(&self.tree[i], &w);
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// $`\text{self} \lbrack 0 \rbrack \cdots \text{self} \lbrack i \rbrack`$ を計算する。
+    pub fn prefix_sum(&self, i: usize) -> G::S {
+        assert!(i < self.size, "size = {}, index = {}", self.size, i);
+        self._sum(i + 1)
+    }
+
+    /// `range` 内の要素の総積を計算する。
+    pub fn sum<R: std::ops::RangeBounds<usize>>(&self, range: R) -> G::S {
+        let left = match range.start_bound() {
+            std::ops::Bound::Included(&l) => l,
+            std::ops::Bound::Excluded(&l) => l + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+
+        let right = match range.end_bound() {
+            std::ops::Bound::Included(&r) => r + 1,
+            std::ops::Bound::Excluded(&r) => r,
+            std::ops::Bound::Unbounded => self.size,
+        };
+
+        assert!(left <= right && right <= self.size);
+        G::op(&self._sum(right), &G::inv(&self._sum(left)))
+    }
+
+    fn _sum(&self, mut i: usize) -> G::S {
+        let mut ret = G::E;
+        while i > 0 {
+            ret = G::op(&ret, &self.tree[i]);
+            i -= i & i.wrapping_neg();
+        }
+        ret
+    }
+}
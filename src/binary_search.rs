@@ -0,0 +1,94 @@
+//! 二分探索を行う。
+//!
+//! 述語 `pred` が `ok` で `true`、`ng` で `false` を返し、その間で単調であるとする。このとき、`pred` が `true` を返す値のうち `ng` に最も近いものを求める。
+//!
+//! * [`binary_search()`] は整数に対して、`ok` と `ng` の差が $`1`$ になるまで探索する。
+//! * [`binary_search_f64()`] は `f64` に対して、指定した回数だけ探索する。
+//!
+//! `ok` と `ng` の大小はどちらでもよい。また、`pred(ok)` や `pred(ng)` は呼ばれない。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::binary_search::{binary_search, binary_search_f64};
+//!
+//! let a = [1, 3, 3, 5, 8, 13];
+//!
+//! // a[i] >= 4 を満たす最小の i
+//! assert_eq!(binary_search(a.len(), 0, |i| a[i - 1] >= 4) - 1, 3);
+//!
+//! // x * x <= 1000 を満たす最大の x
+//! assert_eq!(binary_search(0u64, 1 << 32, |x| x * x <= 1000), 31);
+//!
+//! // 負の範囲でもよい
+//! assert_eq!(binary_search(100i64, -100, |x| x > -7), -6);
+//!
+//! // x * x * x <= 2 を満たす最大の x
+//! let x = binary_search_f64(0.0, 2.0, 100, |x| x * x * x <= 2.0);
+//! assert!((x - 2f64.cbrt()).abs() < 1e-12);
+//! ```
+//!
+//! ## 計算量
+//!
+//! `pred` の計算量を $`T`$ とする。[`binary_search()`] は $`O(T \log \lvert \text{ok} - \text{ng} \rvert)`$、[`binary_search_f64()`] は $`O(T \cdot \text{iterations})`$ である。
+//!
+
+/// 整数 `ok`, `ng` に対して二分探索を行い、`pred` が `true` を返す値のうち `ng` に最も近いものを求める
+///
+/// `ok` と `ng` の差がその型で表せることを要求する。
+pub fn binary_search<T>(mut ok: T, mut ng: T, mut pred: impl FnMut(T) -> bool) -> T
+where
+    T: Copy
+        + PartialOrd
+        + From<u8>
+        + std::ops::Add<Output = T>
+        + std::ops::Sub<Output = T>
+        + std::ops::Div<Output = T>,
+{
+    let one = T::from(1);
+    let two = T::from(2);
+
+    loop {
+        let m = if ok < ng {
+            if ng - ok <= one {
+                break;
+            }
+
+            ok + (ng - ok) / two
+        } else {
+            if ok - ng <= one {
+                break;
+            }
+
+            ng + (ok - ng) / two
+        };
+
+        if pred(m) {
+            ok = m;
+        } else {
+            ng = m;
+        }
+    }
+
+    ok
+}
+
+/// 実数 `ok`, `ng` に対して `iterations` 回だけ二分探索を行い、`pred` が `true` を返す値のうち `ng` に最も近いものを求める
+pub fn binary_search_f64(
+    mut ok: f64,
+    mut ng: f64,
+    iterations: usize,
+    mut pred: impl FnMut(f64) -> bool,
+) -> f64 {
+    for _ in 0..iterations {
+        let m = (ok + ng) / 2.0;
+
+        if pred(m) {
+            ok = m;
+        } else {
+            ng = m;
+        }
+    }
+
+    ok
+}
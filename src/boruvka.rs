@@ -0,0 +1,114 @@
+//! Borůvka 法で最小全域木を求める
+//!
+//! 各連結成分から出る最小の辺を求める関数 (オラクル) を与えると、最小全域木を構成する。
+//! 辺が陽に与えられない完全グラフなど、辺の数が多すぎて Kruskal 法が使えない場合に有効である。
+//!
+//! オラクル `cheapest(comp)` には、各頂点 $`v`$ が属する連結成分の番号 `comp[v]` ($`0, 1, \dots, k - 1`$) が渡される。
+//! オラクルは、長さ $`k`$ の列で、連結成分 $`c`$ から出る (他の連結成分へ向かう) 辺のうち重みが最小のもの `(w, u, v)` を返す。
+//! ただし `comp[u] == c` とし、そのような辺がなければ `None` とする。
+//!
+//! 連結成分の数は $`1`$ 回のオラクルの呼び出しごとに半分以下になるので、オラクルは $`O(\log N)`$ 回しか呼ばれない。
+//!
+//! ## Examples
+//!
+//! 頂点 $`i`$ に値 $`a_i`$ が書かれていて、頂点 $`i, j`$ 間の辺の重みが $`a_i \oplus a_j`$ である完全グラフの最小全域木 (XOR-MST) を求める。
+//! 各連結成分について、その成分の値を [`MultiBinaryTrie`](crate::binary_trie::MultiBinaryTrie) から一時的に取り除いて XOR の最小値を求めればよい。
+//!
+//! ```
+//! use library::binary_trie::MultiBinaryTrie;
+//! use library::boruvka::boruvka;
+//!
+//! let a = [1u64, 2, 3, 4, 5];
+//! let index = |x: u64| a.iter().position(|&y| y == x).unwrap() as u32;
+//!
+//! let mut trie: MultiBinaryTrie<3> = MultiBinaryTrie::new();
+//! for &x in &a {
+//!     trie.insert(x);
+//! }
+//!
+//! let (weight, edges) = boruvka(a.len(), |comp| {
+//!     let k = comp.iter().max().unwrap() + 1;
+//!     let mut members = vec![vec![]; k];
+//!     for (v, &c) in comp.iter().enumerate() {
+//!         members[c].push(v);
+//!     }
+//!
+//!     members
+//!         .iter()
+//!         .map(|vs| {
+//!             vs.iter().for_each(|&v| {
+//!                 trie.remove(a[v]);
+//!             });
+//!             let best = vs
+//!                 .iter()
+//!                 .filter_map(|&v| {
+//!                     let w = trie.xor_min(a[v])?;
+//!                     Some((w, v as u32, index(a[v] ^ w)))
+//!                 })
+//!                 .min();
+//!             vs.iter().for_each(|&v| trie.insert(a[v]));
+//!             best
+//!         })
+//!         .collect()
+//! });
+//!
+//! assert_eq!(weight, 8);
+//! assert_eq!(edges.len(), 4);
+//! ```
+//!
+//! ## 計算量
+//!
+//! オラクル $`1`$ 回の計算量を $`T`$ として、$`O((T + N) \log N)`$ である。
+//!
+
+use crate::unionfind::UnionFind;
+
+/// 頂点数 `size` のグラフの最小全域木 (全域森) を求める
+///
+/// 返り値は (重みの総和, 最小全域木の辺 `(u, v, w)` の列) である。
+pub fn boruvka<
+    W: Default + std::ops::Add<Output = W> + Ord + Copy,
+    F: FnMut(&[usize]) -> Vec<Option<(W, u32, u32)>>,
+>(
+    size: usize,
+    mut cheapest: F,
+) -> (W, Vec<(u32, u32, W)>) {
+    let mut uf = UnionFind::new(size);
+    let mut total = W::default();
+    let mut edges = vec![];
+
+    while uf.count() > 1 {
+        let mut label = vec![usize::MAX; size];
+        let mut comp = vec![0; size];
+        let mut k = 0;
+
+        for (v, c) in comp.iter_mut().enumerate() {
+            let r = uf.find(v);
+            if label[r] == usize::MAX {
+                label[r] = k;
+                k += 1;
+            }
+            *c = label[r];
+        }
+
+        let mut candidates = cheapest(&comp).into_iter().flatten().collect::<Vec<_>>();
+        assert!(candidates.len() <= k);
+        candidates.sort();
+
+        let mut merged = false;
+        for (w, u, v) in candidates {
+            if !uf.is_same(u as usize, v as usize) {
+                uf.unite(u as usize, v as usize);
+                total = total + w;
+                edges.push((u, v, w));
+                merged = true;
+            }
+        }
+
+        if !merged {
+            break;
+        }
+    }
+
+    (total, edges)
+}
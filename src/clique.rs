@@ -0,0 +1,130 @@
+//! 頂点数が小さいグラフのクリークを扱う
+//!
+//! 隣接関係を `u64` のビット集合で持つ。
+//!
+//! * [`maximal_cliques()`] は、ピボット選択つきの Bron–Kerbosch 法で極大クリークをすべて列挙する。
+//! * [`max_clique()`] は最大クリークの 1 つを、補グラフの[最大独立集合](crate::maximum_independent_set)として求める。
+//!
+//! 辺の向き・重み・自己ループは無視する。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::clique::{max_clique, maximal_cliques};
+//! use library::graph::UndirectedAdjGraph;
+//!
+//! // 三角形 0 - 1 - 2 と、辺 2 - 3
+//! let graph = UndirectedAdjGraph::from_edges_no_weight(4, &[(0, 1), (1, 2), (2, 0), (2, 3)]);
+//!
+//! let mut cliques = vec![];
+//! maximal_cliques(&graph, |c| cliques.push(c.to_vec()));
+//! cliques.sort();
+//! assert_eq!(cliques, vec![vec![0, 1, 2], vec![2, 3]]);
+//!
+//! assert_eq!(max_clique(&graph), vec![0, 1, 2]);
+//! ```
+//!
+//! ## 計算量
+//!
+//! $`N`$ を頂点数、$`M`$ を辺数とする。$`N \leq 64`$ でなければならない。
+//!
+//! | 関数 | 計算量 |
+//! | --- | --- |
+//! | [`maximal_cliques()`] | $`O(3^{N/3} N + M)`$ |
+//! | [`max_clique()`] | $`O(1.381^N N + M)`$ |
+//!
+
+use crate::graph::{Graph, Index};
+use crate::maximum_independent_set::maximum_independent_set_bits;
+
+/// 極大クリークをすべて列挙し、それぞれについて `f` を呼ぶ
+///
+/// `f` には、クリークに含まれる頂点を昇順に並べたものが渡される。
+pub fn maximal_cliques(graph: &impl Graph, mut f: impl FnMut(&[Index])) {
+    let adj = adjacency(graph);
+    let all = full(adj.len());
+
+    let mut buf = vec![];
+    bron_kerbosch(&adj, 0, all, 0, &mut |r| {
+        buf.clear();
+        buf.extend(ones(r));
+        f(&buf);
+    });
+}
+
+/// 最大クリークの 1 つを、頂点番号の昇順に並べて返す
+pub fn max_clique(graph: &impl Graph) -> Vec<Index> {
+    let adj = adjacency(graph);
+    let all = full(adj.len());
+
+    let complement = adj
+        .iter()
+        .enumerate()
+        .map(|(v, &a)| all & !a & !(1 << v))
+        .collect::<Vec<_>>();
+
+    ones(maximum_independent_set_bits(&complement, all)).collect()
+}
+
+fn adjacency(graph: &impl Graph) -> Vec<u64> {
+    let size = graph.size() as usize;
+    assert!(size <= 64);
+
+    let mut adj = vec![0u64; size];
+    for u in 0..graph.size() {
+        for &(v, _) in graph.adjacent(u) {
+            if u != v {
+                adj[u as usize] |= 1 << v;
+                adj[v as usize] |= 1 << u;
+            }
+        }
+    }
+
+    adj
+}
+
+fn full(size: usize) -> u64 {
+    if size == 64 {
+        !0
+    } else {
+        (1 << size) - 1
+    }
+}
+
+fn ones(mut s: u64) -> impl Iterator<Item = Index> {
+    std::iter::from_fn(move || {
+        if s == 0 {
+            None
+        } else {
+            let v = s.trailing_zeros();
+            s &= s - 1;
+            Some(v)
+        }
+    })
+}
+
+/// P ∪ X のうち、P に含まれる隣接頂点が最も多い頂点を選ぶ
+fn pivot(adj: &[u64], p: u64, x: u64) -> usize {
+    ones(p | x)
+        .max_by_key(|&u| (adj[u as usize] & p).count_ones())
+        .unwrap() as usize
+}
+
+fn bron_kerbosch(adj: &[u64], r: u64, mut p: u64, mut x: u64, f: &mut impl FnMut(u64)) {
+    if p == 0 {
+        if x == 0 {
+            f(r);
+        }
+        return;
+    }
+
+    let mut cand = p & !adj[pivot(adj, p, x)];
+    while cand != 0 {
+        let v = cand.trailing_zeros() as usize;
+        cand &= cand - 1;
+
+        bron_kerbosch(adj, r | 1 << v, p & adj[v], x & adj[v], f);
+        p &= !(1 << v);
+        x |= 1 << v;
+    }
+}
@@ -0,0 +1,125 @@
+use crate::binary_indexed_tree::BinaryIndexedTree;
+use crate::coordinate_compression::CoordinateCompress;
+
+/// 先読みした値を座標圧縮し、各値の個数を [`BinaryIndexedTree`] で管理する順序付き多重集合
+///
+/// 追加される可能性のある値をすべて先に与える必要がある。
+/// その代わりに、$`x`$ 未満の要素の個数や $`k`$ 番目の要素を $`O(\log N)`$ で求められる。
+///
+/// ## Examples
+///
+/// ```
+/// use library::compressed_multiset::CompressedMultiset;
+///
+/// let mut set = CompressedMultiset::from(&[10, 30, 20, 50]);
+///
+/// set.insert(30);
+/// set.insert(10);
+/// set.insert(30);
+/// assert_eq!(set.len(), 3);
+/// assert_eq!(set.count(30), 2);
+///
+/// assert_eq!(set.count_less(30), 1);
+/// assert_eq!(set.count_less(31), 3);
+/// assert_eq!(set.count_less(100), 3);
+///
+/// assert_eq!(set.kth(0), Some(10));
+/// assert_eq!(set.kth(2), Some(30));
+/// assert_eq!(set.kth(3), None);
+///
+/// assert!(set.remove(30));
+/// assert!(!set.remove(20));
+/// assert_eq!(set.kth(1), Some(30));
+/// ```
+///
+/// ## 計算量
+///
+/// 先読みした値の種類数を $`N`$ とする。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `from(values)` | `values` に含まれる値を追加できる、空の多重集合を生成する | $`O(N \log N)`$ |
+/// | `self.insert(x)` | $`x`$ を $`1`$ つ追加する | $`O(\log N)`$ |
+/// | `self.remove(x)` | $`x`$ を $`1`$ つ取り除く | $`O(\log N)`$ |
+/// | `self.count(x)` | $`x`$ の個数を返す | $`O(\log N)`$ |
+/// | `self.count_less(x)` | $`x`$ 未満の要素の個数を返す | $`O(\log N)`$ |
+/// | `self.kth(k)` | 小さい方から $`k`$ 番目 (0-indexed) の要素を返す | $`O(\log N)`$ |
+///
+/// ## Verified problems
+///
+/// * [Ordered Set](../../src/lc_ordered_set_02/lc_ordered_set_02.rs.html)
+///
+pub struct CompressedMultiset<T> {
+    cc: CoordinateCompress<T>,
+    bit: BinaryIndexedTree<i64>,
+    len: usize,
+}
+
+impl<T: Ord + Copy> CompressedMultiset<T> {
+    /// `values` に含まれる値を追加できる、空の多重集合を生成する
+    pub fn from(values: &[T]) -> Self {
+        let cc = CoordinateCompress::from(values);
+        let bit = BinaryIndexedTree::new(cc.len());
+
+        Self { cc, bit, len: 0 }
+    }
+
+    /// 要素数を返す
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 要素数が $`0`$ かどうかを返す
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// $`x`$ を $`1`$ つ追加する  
+    /// $`x`$ は先読みした値でなければならない
+    pub fn insert(&mut self, x: T) {
+        let i = self.cc.index(x).expect("x is not in the given values");
+        self.bit.add(i, 1);
+        self.len += 1;
+    }
+
+    /// $`x`$ を $`1`$ つ取り除く  
+    /// $`x`$ が含まれていなければ何もせず `false` を返す
+    pub fn remove(&mut self, x: T) -> bool {
+        match self.cc.index(x) {
+            Some(i) if self.bit.sum(i..=i) > 0 => {
+                self.bit.add(i, -1);
+                self.len -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// $`x`$ の個数を返す
+    pub fn count(&self, x: T) -> usize {
+        match self.cc.index(x) {
+            Some(i) => self.bit.sum(i..=i) as usize,
+            None => 0,
+        }
+    }
+
+    /// $`x`$ 未満の要素の個数を返す
+    pub fn count_less(&self, x: T) -> usize {
+        let i = self.cc.next_index(x).unwrap_or(self.cc.len());
+
+        if i == 0 {
+            0
+        } else {
+            self.bit.prefix_sum(i - 1) as usize
+        }
+    }
+
+    /// 小さい方から $`k`$ 番目 (0-indexed) の要素を返す
+    pub fn kth(&self, k: usize) -> Option<T> {
+        if k >= self.len {
+            return None;
+        }
+
+        Some(self.cc[self.bit.upper_bound(k as i64)])
+    }
+}
@@ -0,0 +1,154 @@
+use crate::modint::ModInt;
+use crate::prime_factorize::prime_factorize;
+
+/// 数論変換 (NTT) を用いて、2 つの列の畳み込みを計算する
+///
+/// $`c_k = \displaystyle \sum_{i + j = k} a_i b_j`$ を満たす長さ $`\lvert a \rvert + \lvert b \rvert - 1`$ の列 $`c`$ を求める。
+/// $`P`$ は $`P - 1`$ が $`2^{\lceil \log_2 (\lvert a \rvert + \lvert b \rvert - 1) \rceil}`$ で割り切れる素数であることを要求する。
+/// 例えば $`998244353 = 119 \times 2^{23} + 1`$ である。
+///
+/// ## Examples
+///
+/// ```
+/// use library::convolution::convolution;
+/// use library::modint::ModInt;
+///
+/// type Mint = ModInt<998244353>;
+///
+/// let a = [1, 2, 3].map(Mint::from);
+/// let b = [4, 5].map(Mint::from);
+/// assert_eq!(convolution(&a, &b), [4, 13, 22, 15].map(Mint::from));
+///
+/// // (1 + x)^10 の係数
+/// let a = vec![Mint::from(1); 2];
+/// let mut p = vec![Mint::from(1)];
+/// for _ in 0..10 {
+///     p = convolution(&p, &a);
+/// }
+/// assert_eq!(p[5], Mint::from(252));
+/// ```
+///
+/// ## 計算量
+///
+/// $`N = \lvert a \rvert + \lvert b \rvert`$ として、$`O(N \log N)`$ である。
+///
+pub fn convolution<const P: u32>(a: &[ModInt<P>], b: &[ModInt<P>]) -> Vec<ModInt<P>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let n = a.len() + b.len() - 1;
+
+    if std::cmp::min(a.len(), b.len()) <= 32 {
+        let mut c = vec![ModInt::from_raw(0); n];
+
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                c[i + j] += x * y;
+            }
+        }
+
+        return c;
+    }
+
+    let size = n.next_power_of_two();
+
+    let mut fa = a.to_vec();
+    fa.resize(size, ModInt::from_raw(0));
+    let mut fb = b.to_vec();
+    fb.resize(size, ModInt::from_raw(0));
+
+    ntt(&mut fa, false);
+    ntt(&mut fb, false);
+
+    for (x, &y) in fa.iter_mut().zip(fb.iter()) {
+        *x *= y;
+    }
+
+    ntt(&mut fa, true);
+    fa.truncate(n);
+    fa
+}
+
+/// $`\mathbb{Z} / P \mathbb{Z}`$ の原始根のうち最小のものを求める
+pub fn primitive_root<const P: u32>() -> ModInt<P> {
+    if P == 2 {
+        return ModInt::from_raw(1);
+    }
+
+    let factors = prime_factorize(P as u64 - 1);
+
+    for g in 2..P {
+        let g = ModInt::from_raw(g);
+
+        if factors
+            .iter()
+            .all(|&(q, _)| g.pow((P - 1) / q as u32) != ModInt::from_raw(1))
+        {
+            return g;
+        }
+    }
+
+    unreachable!()
+}
+
+/// 長さが 2 冪の列 `a` に対して、数論変換 (`invert` が `true` のときは逆変換) をその場で行う
+pub fn ntt<const P: u32>(a: &mut [ModInt<P>], invert: bool) {
+    let n = a.len();
+    assert!(n.is_power_of_two());
+    assert!((P - 1) % n as u32 == 0, "P - 1 must be divisible by {}", n);
+
+    if n == 1 {
+        return;
+    }
+
+    // ビット反転置換
+    let mut j = 0;
+
+    for i in 1..n {
+        let mut bit = n >> 1;
+
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+
+        j |= bit;
+
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let g = primitive_root::<P>();
+    let g = if invert { g.inv() } else { g };
+
+    let mut len = 2;
+
+    while len <= n {
+        let w = g.pow((P - 1) / len as u32);
+
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            let mut wk = ModInt::from_raw(1);
+
+            for (x, y) in lo.iter_mut().zip(hi.iter_mut()) {
+                let u = *x;
+                let v = *y * wk;
+                *x = u + v;
+                *y = u - v;
+                wk *= w;
+            }
+        }
+
+        len <<= 1;
+    }
+
+    if invert {
+        let inv_n = ModInt::from(n).inv();
+
+        for x in a.iter_mut() {
+            *x *= inv_n;
+        }
+    }
+}
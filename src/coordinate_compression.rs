@@ -1,3 +1,5 @@
+use crate::binary_search::binary_search;
+
 /// 座標圧縮を行う  
 ///
 /// 長さ $`N`$ の列 $`A`$ を座標圧縮したとする。  
@@ -67,18 +69,7 @@ impl<T: std::cmp::Ord + Copy> CoordinateCompress<T> {
         }
 
         // この時点で、0 番目は v 未満で、最後の要素は v 以上であることが確定する
-        let mut ng = 0;
-        let mut ok = self.length - 1;
-
-        while ok - ng > 1 {
-            let m = ng + (ok - ng) / 2;
-
-            if self.values[m] >= v {
-                ok = m;
-            } else {
-                ng = m;
-            }
-        }
+        let ok = binary_search(self.length - 1, 0, |m| self.values[m] >= v);
 
         Some(self.values[ok])
     }
@@ -99,18 +90,7 @@ impl<T: std::cmp::Ord + Copy> CoordinateCompress<T> {
         }
 
         // この時点で、0 番目は v 未満で、最後の要素は v 以上であることが確定する
-        let mut ng = 0;
-        let mut ok = self.length - 1;
-
-        while ok - ng > 1 {
-            let m = ng + (ok - ng) / 2;
-
-            if self.values[m] >= v {
-                ok = m;
-            } else {
-                ng = m;
-            }
-        }
+        let ok = binary_search(self.length - 1, 0, |m| self.values[m] >= v);
 
         Some(ok)
     }
@@ -131,18 +111,7 @@ impl<T: std::cmp::Ord + Copy> CoordinateCompress<T> {
         }
 
         // この時点で、0 番目は v 以下で、最後の要素は v より大きいことが確定する
-        let mut ok = 0;
-        let mut ng = self.length - 1;
-
-        while ng - ok > 1 {
-            let m = ok + (ng - ok) / 2;
-
-            if self.values[m] <= v {
-                ok = m;
-            } else {
-                ng = m;
-            }
-        }
+        let ok = binary_search(0, self.length - 1, |m| self.values[m] <= v);
 
         Some(self.values[ok])
     }
@@ -159,26 +128,11 @@ impl<T: std::cmp::Ord + Copy> CoordinateCompress<T> {
         }
 
         if self.values[self.length - 1] <= v {
-            if self.values[self.length - 1] == v {
-                return Some(self.length - 1);
-            } else {
-                return None;
-            }
+            return Some(self.length - 1);
         }
 
         // この時点で、0 番目は v 以下で、最後の要素は v より大きいことが確定する
-        let mut ok = 0;
-        let mut ng = self.length - 1;
-
-        while ng - ok > 1 {
-            let m = ok + (ng - ok) / 2;
-
-            if self.values[m] <= v {
-                ok = m;
-            } else {
-                ng = m;
-            }
-        }
+        let ok = binary_search(0, self.length - 1, |m| self.values[m] <= v);
 
         Some(ok)
     }
@@ -208,18 +162,7 @@ impl<T: std::cmp::Ord + Copy> CoordinateCompress<T> {
         }
 
         // この時点で、0 番目は v 未満で、最後の要素は v 以上であることが確定する
-        let mut ng = 0;
-        let mut ok = self.length - 1;
-
-        while ok - ng > 1 {
-            let m = ng + (ok - ng) / 2;
-
-            if self.values[m] >= v {
-                ok = m;
-            } else {
-                ng = m;
-            }
-        }
+        let ok = binary_search(self.length - 1, 0, |m| self.values[m] >= v);
 
         if self.values[ok] == v {
             return Some(ok);
@@ -1,3 +1,5 @@
+use crate::algebra::Group;
+
 /// [`CumulativeSum`] は、事前にデータ構造を構築することで、区間和を高速に求めることができる。
 ///
 /// ## Examples
@@ -36,14 +38,7 @@ pub struct CumulativeSum<T> {
 impl<T: std::ops::Add<Output = T> + Default + Clone + Copy> CumulativeSum<T> {
     /// `array` から累積和を構築する
     pub fn from(array: &[T]) -> Self {
-        let size = array.len();
-        let mut prefix_sum = vec![T::default(); size + 1];
-
-        for i in 0..size {
-            prefix_sum[i + 1] = prefix_sum[i] + array[i];
-        }
-
-        Self { size, prefix_sum }
+        array.iter().copied().collect()
     }
 
     /// $`\displaystyle \sum_{j \leq i} \text{self} \lbrack j \rbrack`$ を計算する
@@ -76,3 +71,99 @@ impl<T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + Default + Clone
         }
     }
 }
+
+impl<T: std::ops::Add<Output = T> + Default + Clone + Copy> FromIterator<T> for CumulativeSum<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut cs = Self {
+            size: 0,
+            prefix_sum: vec![T::default()],
+        };
+        cs.extend(iter);
+        cs
+    }
+}
+
+/// 末尾に要素を追加する  
+/// 追加する要素 $`1`$ つあたり $`O(1)`$ かかる
+impl<T: std::ops::Add<Output = T> + Default + Clone + Copy> Extend<T> for CumulativeSum<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            let s = self.prefix_sum[self.size] + x;
+            self.prefix_sum.push(s);
+            self.size += 1;
+        }
+    }
+}
+
+/// 群 `G` の元の列に対する累積和
+///
+/// [`CumulativeSum`] と異なり、`std::ops::Sub` ではなく [`Group`] の逆元を使って区間の総積を求める。
+/// 二項演算は可換でなくてもよい。
+///
+/// ## Examples
+///
+/// ```
+/// use library::algebra::{Add, BitXor};
+/// use library::cumulative_sum::GroupCumulativeSum;
+///
+/// let cs = GroupCumulativeSum::<Add<i64>>::from(&[3, -1, 4, -1, 5]);
+/// assert_eq!(cs.sum(1..4), 2);
+/// assert_eq!(cs.sum(..), 10);
+///
+/// let cs = GroupCumulativeSum::<BitXor<u32>>::from(&[0b101, 0b011, 0b110]);
+/// assert_eq!(cs.sum(1..), 0b101);
+/// ```
+///
+/// ## 計算量
+///
+/// 二項演算・逆元の計算が $`O(1)`$ で行えることを仮定する。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `from(array)` | `array` をもとに累積和を生成する | $`O(\lvert \text{array} \rvert)`$ |
+/// | `self.sum(range)` | `range` 内の要素の総積を求める | $`O(1)`$ |
+///
+pub struct GroupCumulativeSum<G: Group> {
+    size: usize,
+    prefix_sum: Vec<G::S>,
+}
+
+impl<G: Group> GroupCumulativeSum<G> {
+    /// `array` から累積和を構築する
+    pub fn from(array: &[G::S]) -> Self {
+        let mut prefix_sum = Vec::with_capacity(array.len() + 1);
+        prefix_sum.push(G::E);
+        for x in array {
+            let s = G::op(&prefix_sum[prefix_sum.len() - 1], x);
+            prefix_sum.push(s);
+        }
+
+        Self {
+            size: array.len(),
+            prefix_sum,
+        }
+    }
+
+    /// $`\text{self} \lbrack 0 \rbrack \cdots \text{self} \lbrack i \rbrack`$ を計算する
+    pub fn prefix_sum(&self, i: usize) -> G::S {
+        self.prefix_sum[i + 1].clone()
+    }
+
+    /// `range` 内の要素の総積を計算する
+    pub fn sum<R: std::ops::RangeBounds<usize>>(&self, range: R) -> G::S {
+        let left = match range.start_bound() {
+            std::ops::Bound::Included(&l) => l,
+            std::ops::Bound::Excluded(&l) => l + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+
+        let right = match range.end_bound() {
+            std::ops::Bound::Included(&r) => r + 1,
+            std::ops::Bound::Excluded(&r) => r,
+            std::ops::Bound::Unbounded => self.size,
+        };
+
+        assert!(left <= right && right <= self.size);
+        G::op(&G::inv(&self.prefix_sum[left]), &self.prefix_sum[right])
+    }
+}
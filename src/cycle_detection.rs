@@ -1,5 +1,6 @@
-use crate::graph::Graph;
+use crate::graph::{Graph, Index};
 
+/// 有向グラフに閉路が存在するかを判定する
 pub fn cycle_detection(graph: &impl Graph) -> bool {
     struct DFS {
         seen: Vec<bool>,
@@ -59,3 +60,90 @@ pub fn cycle_detection(graph: &impl Graph) -> bool {
     let mut dfs = DFS::new(graph);
     dfs.run(graph)
 }
+
+/// 無向グラフに閉路が存在するかを判定する
+///
+/// 多重辺は長さ $`2`$ の閉路、自己ループは長さ $`1`$ の閉路として扱う。
+///
+/// ```
+/// use library::cycle_detection::undirected_cycle_detection;
+/// use library::graph::UndirectedAdjGraph;
+///
+/// let graph = UndirectedAdjGraph::from_edges_no_weight(4, &[(0, 1), (1, 2), (1, 3)]);
+/// assert!(!undirected_cycle_detection(&graph));
+///
+/// let graph = UndirectedAdjGraph::from_edges_no_weight(4, &[(0, 1), (1, 2), (2, 1)]);
+/// assert!(undirected_cycle_detection(&graph));
+/// ```
+pub fn undirected_cycle_detection(graph: &impl Graph) -> bool {
+    find_undirected_cycle(graph).is_some()
+}
+
+/// 無向グラフの閉路を $`1`$ つ求める
+///
+/// 閉路を $`v_0, v_1, \dots, v_{L-1}`$ の順に並べた列を返す。$`v_i`$ と $`v_{i+1}`$、$`v_{L-1}`$ と $`v_0`$ の間にはそれぞれ辺があり、各頂点は相異なる。
+/// 閉路が存在しない場合は `None` を返す。
+///
+/// 多重辺は長さ $`2`$ の閉路、自己ループは長さ $`1`$ の閉路として扱う。計算量は $`O(|V| + |E|)`$ である。
+///
+/// ```
+/// use library::cycle_detection::find_undirected_cycle;
+/// use library::graph::UndirectedAdjGraph;
+///
+/// let graph = UndirectedAdjGraph::from_edges_no_weight(5, &[(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)]);
+/// let mut cycle = find_undirected_cycle(&graph).unwrap();
+/// cycle.sort();
+/// assert_eq!(cycle, vec![1, 2, 3]);
+///
+/// let graph = UndirectedAdjGraph::from_edges_no_weight(3, &[(0, 1), (2, 2)]);
+/// assert_eq!(find_undirected_cycle(&graph), Some(vec![2]));
+/// ```
+pub fn find_undirected_cycle(graph: &impl Graph) -> Option<Vec<Index>> {
+    assert!(!graph.is_directed_edge());
+
+    let size = graph.size() as usize;
+    let mut seen = vec![false; size];
+    let mut parent = vec![Index::MAX; size];
+
+    for root in 0..size {
+        if seen[root] {
+            continue;
+        }
+        seen[root] = true;
+
+        // (頂点, 次に見る隣接辺の番号, 親への辺をすでに飛ばしたか)
+        let mut stack = vec![(root as Index, 0, false)];
+
+        while let Some((v, i, skipped)) = stack.pop() {
+            let adjacent = graph.adjacent(v);
+            if i == adjacent.len() {
+                continue;
+            }
+
+            let u = adjacent[i].0;
+            if u == parent[v as usize] && !skipped {
+                stack.push((v, i + 1, true));
+                continue;
+            }
+            stack.push((v, i + 1, skipped));
+
+            if seen[u as usize] {
+                // u は v の祖先 (または v 自身) である
+                let mut cycle = vec![v];
+                let mut w = v;
+                while w != u {
+                    w = parent[w as usize];
+                    cycle.push(w);
+                }
+                cycle.reverse();
+                return Some(cycle);
+            }
+
+            seen[u as usize] = true;
+            parent[u as usize] = v;
+            stack.push((u, 0, false));
+        }
+    }
+
+    None
+}
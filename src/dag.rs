@@ -0,0 +1,172 @@
+//! 有向非巡回グラフ (DAG) 上のアルゴリズム
+//!
+//! * [`topological_sort()`] はトポロジカル順序を求める。
+//! * [`dag_longest_path()`] は重みの和が最大となるパスを求める。
+//! * [`count_dag_paths()`] は始点から各頂点へのパスの数を数える。
+//!
+//! いずれもグラフに閉路が存在する場合は `None` を返す。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::dag::{dag_longest_path, topological_sort};
+//! use library::graph::DirectedAdjGraph;
+//!
+//! let graph = DirectedAdjGraph::from_edges(4, &[(0, 1, 2), (1, 3, 2), (0, 2, 1), (2, 3, 5)]);
+//!
+//! let order = topological_sort(&graph).unwrap();
+//! assert_eq!(order[0], 0);
+//! assert_eq!(order[3], 3);
+//!
+//! // 0 -> 2 -> 3 の重みの和 6 が最大
+//! assert_eq!(dag_longest_path(&graph), Some((6, vec![0, 2, 3])));
+//!
+//! let cyclic = DirectedAdjGraph::from_edges(2, &[(0, 1, 1), (1, 0, 1)]);
+//! assert_eq!(topological_sort(&cyclic), None);
+//! assert_eq!(dag_longest_path(&cyclic), None);
+//! ```
+//!
+//! [`count_dag_paths()`] は `ModInt` など、加算ができる型で数えられる。
+//!
+//! ```
+//! use library::dag::count_dag_paths;
+//! use library::graph::DirectedAdjGraph;
+//! use library::modint::ModInt;
+//!
+//! type Mint = ModInt<998244353>;
+//!
+//! // 0 -> 1 -> 2 -> ... -> 60 の各辺が 2 本ずつある
+//! let edges = (0..60).flat_map(|i| [(i, i + 1), (i, i + 1)]).collect::<Vec<_>>();
+//! let graph = DirectedAdjGraph::from_edges_no_weight(61, &edges);
+//!
+//! let count = count_dag_paths::<Mint>(&graph, 0).unwrap();
+//! assert_eq!(count[60], Mint::from(1u64 << 60));
+//!
+//! let count = count_dag_paths::<u64>(&graph, 1).unwrap();
+//! assert_eq!(count[0], 0);
+//! assert_eq!(count[3], 4);
+//! ```
+//!
+//! ## 計算量
+//!
+//! $`N`$ を頂点数、$`M`$ を辺数とする。
+//!
+//! | 関数 | 計算量 |
+//! | --- | --- |
+//! | [`topological_sort()`] | $`O(N + M)`$ |
+//! | [`dag_longest_path()`] | $`O(N + M)`$ |
+//! | [`count_dag_paths()`] | $`O(N + M)`$ |
+//!
+//! ## Verified problems
+//!
+//! * [GRL_4_B](../../src/aoj_grl_4_b/aoj_grl_4_b.rs.html)
+//!
+
+use crate::graph::{Graph, Index};
+
+/// 有向グラフのトポロジカル順序を求める
+/// 閉路が存在する場合は `None` を返す
+pub fn topological_sort(graph: &impl Graph) -> Option<Vec<Index>> {
+    assert!(graph.is_directed_edge());
+
+    let size = graph.size();
+
+    let mut indeg = vec![0; size as usize];
+    for u in 0..size {
+        for &(v, _) in graph.adjacent(u) {
+            indeg[v as usize] += 1;
+        }
+    }
+
+    let mut order = (0..size)
+        .filter(|&v| indeg[v as usize] == 0)
+        .collect::<Vec<_>>();
+    let mut head = 0;
+
+    while head < order.len() {
+        let u = order[head];
+        head += 1;
+
+        for &(v, _) in graph.adjacent(u) {
+            indeg[v as usize] -= 1;
+            if indeg[v as usize] == 0 {
+                order.push(v);
+            }
+        }
+    }
+
+    if order.len() == size as usize {
+        Some(order)
+    } else {
+        None
+    }
+}
+
+/// DAG 上で辺の重みの和が最大となるパスを求め、`(重みの和, パス上の頂点列)` を返す
+/// 閉路が存在する場合は `None` を返す
+///
+/// パスの始点・終点は任意で、辺を 1 本も含まない長さ $`0`$ のパスも考える。
+/// 重みの和が最大となるパスが複数ある場合は、そのうちの 1 つを返す。
+pub fn dag_longest_path<W: Default + Copy + Ord + std::ops::Add<Output = W>>(
+    graph: &impl Graph<Weight = W>,
+) -> Option<(W, Vec<Index>)> {
+    let order = topological_sort(graph)?;
+
+    let size = graph.size() as usize;
+    if size == 0 {
+        return Some((W::default(), vec![]));
+    }
+
+    // dp[v]: v で終わるパスの重みの和の最大値
+    let mut dp = vec![W::default(); size];
+    let mut prev = vec![Index::MAX; size];
+
+    for &u in &order {
+        for &(v, w) in graph.adjacent(u) {
+            let d = dp[u as usize] + w;
+            if dp[v as usize] < d {
+                dp[v as usize] = d;
+                prev[v as usize] = u;
+            }
+        }
+    }
+
+    let mut last = 0;
+    for v in 1..size {
+        if dp[last] < dp[v] {
+            last = v;
+        }
+    }
+
+    let mut path = vec![last as Index];
+    while prev[*path.last().unwrap() as usize] != Index::MAX {
+        path.push(prev[*path.last().unwrap() as usize]);
+    }
+    path.reverse();
+
+    Some((dp[last], path))
+}
+
+/// DAG 上で、`src` から各頂点へのパスの数を求める
+/// 閉路が存在する場合は `None` を返す
+///
+/// 返り値の `[t]` が `src` から `t` へのパスの数である。`src` から `src` へのパスは長さ $`0`$ のもの $`1`$ 通りと数える。
+/// 多重辺はそれぞれ別の辺として数え、辺の重みは無視する。
+pub fn count_dag_paths<T: Default + Copy + std::ops::AddAssign + From<u32>>(
+    graph: &impl Graph,
+    src: Index,
+) -> Option<Vec<T>> {
+    let order = topological_sort(graph)?;
+
+    let mut count = vec![T::default(); graph.size() as usize];
+    count[src as usize] = T::from(1);
+
+    for &u in &order {
+        let c = count[u as usize];
+        for &(v, _) in graph.adjacent(u) {
+            count[v as usize] += c;
+        }
+    }
+
+    Some(count)
+}
@@ -0,0 +1,170 @@
+//! デバッグ出力のためのマクロ・関数を定義する。
+//!
+//! マクロはデバッグビルドのときだけ標準エラー出力に出力し、リリースビルドでは何もしない。
+//! そのため、提出するコードに残したままでも出力結果には影響しない。
+//!
+//! * [`edbg!`](crate::edbg) は、式とその値の組をラベル付きで 1 行に出力する。
+//! * [`dbg2d!`](crate::dbg2d) は、2 次元配列を行番号・列番号付きの表として出力する。
+//! * [`dbg_graph!`](crate::dbg_graph) は、グラフを隣接リストとして出力する。
+//!
+//! 出力される文字列は [`format_2d()`], [`format_graph()`] で得られる。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::debug::{format_2d, format_graph};
+//! use library::graph::{DirectedAdjGraph, UndirectedAdjGraph};
+//! use library::{dbg2d, dbg_graph, edbg};
+//!
+//! let a = vec![3, 1, 4];
+//! let (x, y) = (1, "abc");
+//! // [src/main.rs:8] a = [3, 1, 4], x = 1, y = "abc"
+//! edbg!(a, x, y);
+//!
+//! let grid = vec![vec![1, 20, 3], vec![400, 5, 6]];
+//! dbg2d!(grid);
+//! assert_eq!(
+//!     format_2d(&grid),
+//!     ["  |   0  1 2", "--+---------", "0 |   1 20 3", "1 | 400  5 6"].join("\n")
+//! );
+//!
+//! let graph = UndirectedAdjGraph::from_edges_no_weight(3, &[(0, 1), (1, 2)]);
+//! dbg_graph!(graph);
+//! assert_eq!(format_graph(&graph), "0: [1]\n1: [0, 2]\n2: [1]");
+//!
+//! let graph = DirectedAdjGraph::from_edges(3, &[(0, 1, 10), (0, 2, 5)]);
+//! assert_eq!(format_graph(&graph), "0: [(1, 10), (2, 5)]\n1: []\n2: []");
+//! ```
+//!
+
+use crate::graph::Graph;
+
+/// 式とその値を、ファイル名・行番号とともに標準エラー出力に出力する
+///
+/// デバッグビルドのときだけ出力する。値は [`std::fmt::Debug`] で出力される。
+#[macro_export]
+macro_rules! edbg {
+    ($($e: expr), + $(,)?) => {
+        if cfg!(debug_assertions) {
+            let values: Vec<String> = vec![$(format!("{} = {:?}", stringify!($e), &$e)), +];
+            eprintln!("[{}:{}] {}", file!(), line!(), values.join(", "));
+        }
+    };
+}
+
+/// 2 次元配列を、行番号・列番号付きの表として標準エラー出力に出力する
+///
+/// デバッグビルドのときだけ出力する。
+#[macro_export]
+macro_rules! dbg2d {
+    ($e: expr) => {
+        if cfg!(debug_assertions) {
+            eprintln!(
+                "[{}:{}] {} =\n{}",
+                file!(),
+                line!(),
+                stringify!($e),
+                $crate::debug::format_2d(&$e)
+            );
+        }
+    };
+}
+
+/// グラフを隣接リストとして標準エラー出力に出力する
+///
+/// デバッグビルドのときだけ出力する。
+#[macro_export]
+macro_rules! dbg_graph {
+    ($e: expr) => {
+        if cfg!(debug_assertions) {
+            eprintln!(
+                "[{}:{}] {} =\n{}",
+                file!(),
+                line!(),
+                stringify!($e),
+                $crate::debug::format_graph(&$e)
+            );
+        }
+    };
+}
+
+/// 2 次元配列を、列ごとに右揃えした表の文字列にする
+pub fn format_2d<T: std::fmt::Debug, R: AsRef<[T]>>(a: &[R]) -> String {
+    let cells = a
+        .iter()
+        .map(|row| {
+            row.as_ref()
+                .iter()
+                .map(|x| format!("{:?}", x))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let columns = cells.iter().map(|row| row.len()).max().unwrap_or(0);
+    let label_width = a.len().saturating_sub(1).to_string().len();
+
+    let widths = (0..columns)
+        .map(|j| {
+            cells
+                .iter()
+                .filter_map(|row| row.get(j))
+                .map(|s| s.len())
+                .chain(std::iter::once(j.to_string().len()))
+                .max()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let format_row = |label: String, row: Vec<String>| {
+        let mut line = format!("{:>w$} |", label, w = label_width);
+
+        for (s, &w) in row.iter().zip(widths.iter()) {
+            line += &format!(" {:>w$}", s, w = w);
+        }
+
+        line
+    };
+
+    let mut lines = vec![format_row(
+        String::new(),
+        (0..columns).map(|j| j.to_string()).collect(),
+    )];
+    let total_width = widths.iter().map(|w| w + 1).sum::<usize>();
+    lines.push(format!(
+        "{}-+{}",
+        "-".repeat(label_width),
+        "-".repeat(total_width)
+    ));
+
+    for (i, row) in cells.into_iter().enumerate() {
+        lines.push(format_row(i.to_string(), row));
+    }
+
+    lines.join("\n")
+}
+
+/// グラフを、各頂点から出ている辺を 1 行ずつ並べた文字列にする
+///
+/// 重みが `()` の場合は、行き先の頂点だけを出力する。
+pub fn format_graph<W: std::fmt::Debug>(graph: &dyn Graph<Weight = W>) -> String {
+    (0..graph.size())
+        .map(|v| {
+            let edges = graph
+                .adjacent(v)
+                .iter()
+                .map(|(u, w)| {
+                    let w = format!("{:?}", w);
+
+                    if w == "()" {
+                        u.to_string()
+                    } else {
+                        format!("({}, {})", u, w)
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            format!("{}: [{}]", v, edges.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -52,11 +52,77 @@
 //! assert_eq!(path, vec![0, 1, 3, 4]);
 //! ```
 //!
+//! 辺の重みが浮動小数点数の場合は、[`ordered_float::OrderedFloat`] で包むことで全順序を入れて使う。
+//!
+//! ```
+//! use library::dijkstra::{dijkstras_algorithm, Dist};
+//! use library::graph::UndirectedAdjGraph;
+//! use ordered_float::OrderedFloat;
+//!
+//! let graph = UndirectedAdjGraph::from_edges(
+//!     3,
+//!     &[
+//!         (0, 1, OrderedFloat(0.5)),
+//!         (1, 2, OrderedFloat(0.25)),
+//!         (0, 2, OrderedFloat(1.0)),
+//!     ],
+//! );
+//!
+//! let res = dijkstras_algorithm(&graph, 0);
+//!
+//! assert_eq!(res.get(2), Dist::VALUE(OrderedFloat(0.75)));
+//! ```
+//!
+//! 辺の重みが $`0`$ か $`1`$ のみの場合は、[`zero_one_bfs`] を使うと両端キューで $`O(|V| + |E|)`$ で計算できる。
+//!
+//! ```
+//! use library::dijkstra::{zero_one_bfs, Dist};
+//! use library::graph::DirectedAdjGraph;
+//!
+//! let graph = DirectedAdjGraph::from_edges(4, &[(0, 1, 1u8), (1, 2, 0), (0, 2, 1), (2, 0, 0)]);
+//!
+//! let dist = zero_one_bfs(&graph, 1);
+//! assert_eq!(dist, vec![Dist::VALUE(0), Dist::VALUE(0), Dist::VALUE(0), Dist::UNREACHABLE]);
+//! ```
+//!
+//! 終点 $`t`$ までの距離の下界 $`h(v)`$ (許容的なヒューリスティック) が分かっている場合は、[`a_star`] で探索する頂点を減らせる。
+//!
+//! ```
+//! use library::dijkstra::{a_star, Dist};
+//! use library::graph::UndirectedAdjGraph;
+//!
+//! // 3 x 3 のグリッドで、頂点 3 * i + j はマス (i, j) を表す
+//! let mut edges = vec![];
+//! for i in 0..3u32 {
+//!     for j in 0..3u32 {
+//!         if i + 1 < 3 {
+//!             edges.push((3 * i + j, 3 * (i + 1) + j, 1u32));
+//!         }
+//!         if j + 1 < 3 {
+//!             edges.push((3 * i + j, 3 * i + j + 1, 1));
+//!         }
+//!     }
+//! }
+//! let graph = UndirectedAdjGraph::from_edges(9, &edges);
+//!
+//! // マンハッタン距離は許容的なヒューリスティックである
+//! let (dist, path) = a_star(&graph, 0, 8, |v| (2 - v / 3) + (2 - v % 3));
+//! assert_eq!(dist, Dist::VALUE(4));
+//! assert_eq!(path.len(), 5);
+//! ```
+//!
+//! ## オーバーフローについて
+//!
+//! 距離の計算には [`SaturatingAdd`] による飽和加算を使うので、距離がオーバーフローして小さな値になることはない。
+//! 最短距離が `W::MAX` 以上になる頂点は、到達不可能 (`Dist::UNREACHABLE`) として扱われる。
+//!
 //! ## 計算量
 //!
 //! グラフの辺の重みの型 `W` の空間計算量が $`O(1)`$ で、加法が $`O(1)`$ で行えることを仮定する。
 //!
 //! [`dijkstras_algorithm`], [`dijkstras_algorithm_restore_path`] いずれも引数の `graph` が $`G = (V, E)`$ であるとして、$`O((|V| + |E|) \log{|V|})`$ である。
+//! [`zero_one_bfs`] は $`O(|V| + |E|)`$ である。
+//! [`a_star`] は最悪の場合 Dijkstra 法と同じだが、ヒューリスティックが良ければ探索する頂点が少なくなる。
 //!
 //! ## Verified problems
 //!
@@ -65,7 +131,7 @@
 //!
 
 use crate::graph::Graph;
-use crate::integer_traits::HasMaxValue;
+use crate::integer_traits::{HasMaxValue, SaturatingAdd};
 
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum Dist<W> {
@@ -92,7 +158,9 @@ impl<W: Copy> DijkstraResult<W> {
 }
 
 /// `graph` 上で始点 `src` から各頂点への最短距離を計算する
-pub fn dijkstras_algorithm<W: Default + std::ops::Add<Output = W> + Ord + Copy + HasMaxValue>(
+pub fn dijkstras_algorithm<
+    W: Default + std::ops::Add<Output = W> + Ord + Copy + HasMaxValue + SaturatingAdd,
+>(
     graph: &impl Graph<Weight = W>,
     src: u32,
 ) -> DijkstraResult<W> {
@@ -119,7 +187,7 @@ pub fn dijkstras_algorithm<W: Default + std::ops::Add<Output = W> + Ord + Copy +
 
         for &(v, w) in graph.adjacent(u) {
             if !seen[v as usize] {
-                let dv = dist[u as usize] + w;
+                let dv = dist[u as usize].saturating_add(w);
 
                 if dv < dist[v as usize] {
                     dist[v as usize] = dv;
@@ -134,7 +202,7 @@ pub fn dijkstras_algorithm<W: Default + std::ops::Add<Output = W> + Ord + Copy +
 
 /// `graph` 上で始点 `src` から終点 `dst` への最短経路を計算する
 pub fn dijkstras_algorithm_restore_path<
-    W: Default + std::ops::Add<Output = W> + Ord + Copy + HasMaxValue,
+    W: Default + std::ops::Add<Output = W> + Ord + Copy + HasMaxValue + SaturatingAdd,
 >(
     graph: &impl Graph<Weight = W>,
     src: u32,
@@ -162,7 +230,7 @@ pub fn dijkstras_algorithm_restore_path<
 
         for &(v, w) in graph.adjacent(u) {
             if !seen[v as usize] {
-                let dv = dist[u as usize] + w;
+                let dv = dist[u as usize].saturating_add(w);
 
                 if dv < dist[v as usize] {
                     dist[v as usize] = dv;
@@ -188,3 +256,109 @@ pub fn dijkstras_algorithm_restore_path<
 
     return (Dist::VALUE(dist[dst as usize]), path);
 }
+
+/// 辺の重みが $`0`$ か $`1`$ のみの `graph` 上で、始点 `src` から各頂点への最短距離を計算する
+///
+/// 重みが `W::default()` の辺を重み $`0`$、それ以外の辺を重み $`1`$ として扱う。
+pub fn zero_one_bfs<W: Default + PartialEq + Copy>(
+    graph: &impl Graph<Weight = W>,
+    src: u32,
+) -> Vec<Dist<u32>> {
+    let size = graph.size();
+
+    let mut dq = std::collections::VecDeque::new();
+    let mut seen = vec![false; size as usize];
+    let mut dist = vec![u32::MAX; size as usize];
+
+    dq.push_back(src);
+    dist[src as usize] = 0;
+
+    while let Some(u) = dq.pop_front() {
+        if seen[u as usize] {
+            continue;
+        }
+        seen[u as usize] = true;
+
+        for &(v, w) in graph.adjacent(u) {
+            if w == W::default() {
+                if dist[u as usize] < dist[v as usize] {
+                    dist[v as usize] = dist[u as usize];
+                    dq.push_front(v);
+                }
+            } else if dist[u as usize] + 1 < dist[v as usize] {
+                dist[v as usize] = dist[u as usize] + 1;
+                dq.push_back(v);
+            }
+        }
+    }
+
+    dist.into_iter()
+        .map(|d| {
+            if d == u32::MAX {
+                Dist::UNREACHABLE
+            } else {
+                Dist::VALUE(d)
+            }
+        })
+        .collect()
+}
+
+/// `graph` 上で始点 `src` から終点 `dst` への最短経路を A* 探索で計算する
+///
+/// `h(v)` は $`v`$ から `dst` への最短距離以下である (許容的である) 必要がある。`h` が常に `W::default()` なら Dijkstra 法と同じになる。
+/// 返り値は [`dijkstras_algorithm_restore_path`] と同じ形式である。
+pub fn a_star<
+    W: Default + std::ops::Add<Output = W> + Ord + Copy + HasMaxValue + SaturatingAdd,
+    H: FnMut(u32) -> W,
+>(
+    graph: &impl Graph<Weight = W>,
+    src: u32,
+    dst: u32,
+    mut h: H,
+) -> (Dist<W>, Vec<u32>) {
+    let size = graph.size();
+
+    let mut hq = std::collections::BinaryHeap::new();
+    let mut dist = vec![W::MAX; size as usize];
+    let mut prev = vec![u32::MAX; size as usize];
+    let mut reached = false;
+
+    hq.push((std::cmp::Reverse(h(src)), W::default(), src));
+    dist[src as usize] = W::default();
+
+    while let Some((_, d, u)) = hq.pop() {
+        if d > dist[u as usize] {
+            continue;
+        }
+
+        if u == dst {
+            reached = true;
+            break;
+        }
+
+        for &(v, w) in graph.adjacent(u) {
+            let dv = d.saturating_add(w);
+
+            if dv < dist[v as usize] {
+                dist[v as usize] = dv;
+                prev[v as usize] = u;
+                hq.push((std::cmp::Reverse(dv.saturating_add(h(v))), dv, v));
+            }
+        }
+    }
+
+    if !reached {
+        return (Dist::UNREACHABLE, vec![]);
+    }
+
+    let mut path = vec![dst];
+    let mut v = dst;
+
+    while v != src {
+        v = prev[v as usize];
+        path.push(v);
+    }
+    path.reverse();
+
+    (Dist::VALUE(dist[dst as usize]), path)
+}
@@ -0,0 +1,135 @@
+//! 有向グラフの支配木を Lengauer–Tarjan 法で求める
+//!
+//! 始点 $`s`$ から頂点 $`v`$ へのどのパスも頂点 $`d`$ を通るとき、$`d`$ は $`v`$ を支配するという。
+//! $`v \neq s`$ を支配する $`v`$ 以外の頂点のうち、$`v`$ に最も近いものを $`v`$ の直接支配頂点 (immediate dominator) といい、各頂点から直接支配頂点へ辺を張ると $`s`$ を根とする木になる。
+//!
+//! 深さ優先探索と経路圧縮を再帰なしで行うので、深いグラフでもスタックオーバーフローしない。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::dominator_tree::dominator_tree;
+//! use library::graph::DirectedAdjGraph;
+//!
+//! // 0 -> 1 -> 3, 0 -> 2 -> 3, 3 -> 4
+//! let graph = DirectedAdjGraph::from_edges_no_weight(6, &[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+//! let idom = dominator_tree(&graph, 0);
+//!
+//! assert_eq!(idom, [0, 0, 0, 0, 3, u32::MAX]);
+//! ```
+//!
+//! ## 計算量
+//!
+//! $`N`$ を頂点数、$`M`$ を辺数として、$`O((N + M) \log N)`$ である。
+//!
+//! ## Verified problems
+//!
+//! * [Dominator Tree](../../src/lc_dominatortree/lc_dominatortree.rs.html)
+//!
+
+use crate::graph::{Graph, Index};
+
+/// `src` を始点としたときの、各頂点の直接支配頂点を求める
+///
+/// `src` 自身は `src`、`src` から到達できない頂点は `Index::MAX` とする。
+pub fn dominator_tree(graph: &impl Graph, src: Index) -> Vec<Index> {
+    const NONE: usize = usize::MAX;
+
+    let size = graph.size() as usize;
+
+    // 行きがけ順の番号を振り、以降は番号で計算する
+    let mut ord = vec![NONE; size];
+    let mut vertex = vec![];
+    let mut par = vec![];
+
+    let mut stack = vec![(src, NONE)];
+    while let Some((v, p)) = stack.pop() {
+        if ord[v as usize] != NONE {
+            continue;
+        }
+
+        ord[v as usize] = vertex.len();
+        vertex.push(v);
+        par.push(p);
+
+        for &(u, _) in graph.adjacent(v).iter().rev() {
+            if ord[u as usize] == NONE {
+                stack.push((u, ord[v as usize]));
+            }
+        }
+    }
+
+    let n = vertex.len();
+
+    let mut pred = vec![vec![]; n];
+    for (i, &v) in vertex.iter().enumerate() {
+        for &(u, _) in graph.adjacent(v) {
+            pred[ord[u as usize]].push(i);
+        }
+    }
+
+    let mut semi = (0..n).collect::<Vec<_>>();
+    let mut idom = vec![0; n];
+    let mut label = (0..n).collect::<Vec<_>>();
+    let mut anc = vec![NONE; n];
+    let mut bucket = vec![vec![]; n];
+    let mut path = vec![];
+
+    for w in (1..n).rev() {
+        for &v in &pred[w] {
+            let u = eval(v, &mut anc, &mut label, &semi, &mut path);
+            semi[w] = semi[w].min(semi[u]);
+        }
+
+        bucket[semi[w]].push(w);
+        anc[w] = par[w];
+
+        for v in std::mem::take(&mut bucket[par[w]]) {
+            let u = eval(v, &mut anc, &mut label, &semi, &mut path);
+            idom[v] = if semi[u] < semi[v] { u } else { par[w] };
+        }
+    }
+
+    for w in 1..n {
+        if idom[w] != semi[w] {
+            idom[w] = idom[idom[w]];
+        }
+    }
+
+    let mut res = vec![Index::MAX; size];
+    for (i, &v) in vertex.iter().enumerate() {
+        res[v as usize] = vertex[idom[i]];
+    }
+
+    res
+}
+
+/// 森の根から `v` までのパス上で、半支配頂点の番号が最小の頂点を求める
+fn eval(
+    v: usize,
+    anc: &mut [usize],
+    label: &mut [usize],
+    semi: &[usize],
+    path: &mut Vec<usize>,
+) -> usize {
+    if anc[v] == usize::MAX {
+        return v;
+    }
+
+    // 経路圧縮を、根に近い方から順に行う
+    let mut x = v;
+    while anc[anc[x]] != usize::MAX {
+        path.push(x);
+        x = anc[x];
+    }
+
+    while let Some(x) = path.pop() {
+        let a = anc[x];
+        if semi[label[a]] < semi[label[x]] {
+            label[x] = label[a];
+        }
+        anc[x] = anc[a];
+    }
+
+    label[v]
+}
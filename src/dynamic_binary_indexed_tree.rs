@@ -104,3 +104,127 @@ impl<T: Default + std::ops::AddAssign + std::ops::Sub<Output = T> + Copy>
         }
     }
 }
+
+/// 必要なところだけ値を持つようにした $`2`$ 次元 BinaryIndexedTree
+///
+/// 座標が $`10^9`$ 程度まである場合の一点加算・長方形和に使える。
+///
+/// ## Examples
+///
+/// ```
+/// use library::dynamic_binary_indexed_tree::DynamicBinaryIndexedTree2D;
+///
+/// let mut bit = DynamicBinaryIndexedTree2D::new(1_000_000_000, 1_000_000_000);
+///
+/// bit.add(1, 1, 1);
+/// bit.add(500_000_000, 2, 10);
+/// bit.add(999_999_999, 999_999_999, 100);
+///
+/// assert_eq!(bit.sum(.., ..), 111);
+/// assert_eq!(bit.sum(1..=500_000_000, ..3), 11);
+/// assert_eq!(bit.sum(2.., 2..), 110);
+/// assert_eq!(bit.sum(2..500_000_000, ..), 0);
+/// ```
+///
+/// ## 計算量
+///
+/// 区間和を計算したい型 `T` の空間計算量が $`O(1)`$ であり、加法が $`O(1)`$ で行えることを仮定する。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `new(height, width)` | `[[0; width]; height]` で初期化する | $`O(1)`$ |
+/// | `self.add(x, y, w)` | $`(x, y)`$ の要素に `w` を足す | $`O(\log(\text{self.height}) \log(\text{self.width}))`$ |
+/// | `self.sum(xrange, yrange)` | `xrange` $`\times`$ `yrange` 内の要素の総和を求める | $`O(\log(\text{self.height}) \log(\text{self.width}))`$ |
+///
+/// 空間計算量は `add` $`1`$ 回あたり $`O(\log(\text{self.height}) \log(\text{self.width}))`$ である。
+///
+/// ## Verified problems
+///
+/// * [Point Add Rectangle Sum](../../src/lc_point_add_rectangle_sum_02/lc_point_add_rectangle_sum_02.rs.html)
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DynamicBinaryIndexedTree2D<T> {
+    tree: Map<(usize, usize), T>,
+    pub height: usize,
+    pub width: usize,
+}
+
+impl<T: Default + std::ops::AddAssign + Copy> DynamicBinaryIndexedTree2D<T> {
+    /// `[[0; width]; height]` で初期化する
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            tree: Map::default(),
+            height,
+            width,
+        }
+    }
+
+    /// $`(x, y)`$ の要素に `w` を足す
+    pub fn add(&mut self, x: usize, y: usize, w: T) {
+        assert!(x < self.height && y < self.width);
+
+        let mut i = x + 1;
+        while i <= self.height {
+            let mut j = y + 1;
+            while j <= self.width {
+                *self.tree.entry((i, j)).or_insert(T::default()) += w;
+                j += j & j.wrapping_neg();
+            }
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// $`\lbrack 0, x) \times \lbrack 0, y)`$ 内の要素の総和を求める
+    fn _sum(&self, x: usize, y: usize) -> T {
+        let mut r = T::default();
+        let mut i = x;
+        while i > 0 {
+            let mut j = y;
+            while j > 0 {
+                if let Some(&v) = self.tree.get(&(i, j)) {
+                    r += v;
+                }
+                j -= j & j.wrapping_neg();
+            }
+            i -= i & i.wrapping_neg();
+        }
+        r
+    }
+}
+
+impl<T: Default + std::ops::AddAssign + std::ops::Sub<Output = T> + Copy>
+    DynamicBinaryIndexedTree2D<T>
+{
+    /// `xrange` $`\times`$ `yrange` 内の要素の総和を求める
+    pub fn sum<R1: std::ops::RangeBounds<usize>, R2: std::ops::RangeBounds<usize>>(
+        &self,
+        xrange: R1,
+        yrange: R2,
+    ) -> T {
+        let (xl, xr) = Self::to_half_open(xrange, self.height);
+        let (yl, yr) = Self::to_half_open(yrange, self.width);
+
+        let mut pos = self._sum(xr, yr);
+        pos += self._sum(xl, yl);
+        let mut neg = self._sum(xl, yr);
+        neg += self._sum(xr, yl);
+        pos - neg
+    }
+
+    fn to_half_open<R: std::ops::RangeBounds<usize>>(range: R, size: usize) -> (usize, usize) {
+        let left = match range.start_bound() {
+            std::ops::Bound::Included(&l) => l,
+            std::ops::Bound::Excluded(&l) => l + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+
+        let right = match range.end_bound() {
+            std::ops::Bound::Included(&r) => r + 1,
+            std::ops::Bound::Excluded(&r) => r,
+            std::ops::Bound::Unbounded => size,
+        };
+
+        assert!(left <= right && right <= size);
+        (left, right)
+    }
+}
@@ -0,0 +1,134 @@
+//! 2 つの列の編集距離(Levenshtein 距離)を計算する。
+//!
+//! 列 $`A`$ に対して、1 要素の挿入・削除・置換を繰り返して列 $`B`$ にするとき、必要な操作の回数の最小値を編集距離という。
+//!
+//! * [`edit_distance()`] は編集距離だけを計算する。
+//! * [`edit_distance_with_operations()`] は編集距離と、それを達成する操作列を計算する。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::edit_distance::{edit_distance, edit_distance_with_operations, EditOperation};
+//!
+//! let a = b"kitten";
+//! let b = b"sitting";
+//!
+//! assert_eq!(edit_distance(a, b), 3);
+//!
+//! let (dist, ops) = edit_distance_with_operations(a, b);
+//! assert_eq!(dist, 3);
+//!
+//! // 操作列を a に順に適用すると b になる
+//! let mut c = vec![];
+//! for op in ops {
+//!     match op {
+//!         EditOperation::Keep(i, _) => c.push(a[i]),
+//!         EditOperation::Replace(_, j) | EditOperation::Insert(j) => c.push(b[j]),
+//!         EditOperation::Delete(_) => {}
+//!     }
+//! }
+//! assert_eq!(&c, b);
+//! ```
+//!
+//! ## 計算量
+//!
+//! 列の長さをそれぞれ $`N, M`$ とし、要素の比較が $`O(1)`$ で行えることを仮定する。
+//!
+//! | 関数 | 時間計算量 | 空間計算量 |
+//! | --- | --- | --- |
+//! | [`edit_distance()`] | $`O(NM)`$ | $`O(\min(N, M))`$ |
+//! | [`edit_distance_with_operations()`] | $`O(NM)`$ | $`O(NM)`$ |
+//!
+
+/// 編集距離を達成する操作
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum EditOperation {
+    /// $`A_i`$ をそのまま $`B_j`$ として残す
+    Keep(usize, usize),
+    /// $`A_i`$ を $`B_j`$ に置き換える
+    Replace(usize, usize),
+    /// $`B_j`$ を挿入する
+    Insert(usize),
+    /// $`A_i`$ を削除する
+    Delete(usize),
+}
+
+/// 列 `a` と列 `b` の編集距離を計算する
+pub fn edit_distance<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    // 短い方を b にして、長さ |b| + 1 の dp を 1 行分だけ持つ
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+
+    let mut dp = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, x) in a.iter().enumerate() {
+        let mut diag = dp[0];
+        dp[0] = i + 1;
+
+        for (j, y) in b.iter().enumerate() {
+            let up = dp[j + 1];
+            let cost = if x == y { 0 } else { 1 };
+            dp[j + 1] = std::cmp::min(std::cmp::min(up, dp[j]) + 1, diag + cost);
+            diag = up;
+        }
+    }
+
+    dp[b.len()]
+}
+
+/// 列 `a` と列 `b` の編集距離と、`a` を `b` にする操作列のうちの一つを計算する
+///
+/// 操作列は `a` の先頭から順に適用するものとして並んでいる。
+pub fn edit_distance_with_operations<T: PartialEq>(
+    a: &[T],
+    b: &[T],
+) -> (usize, Vec<EditOperation>) {
+    let (n, m) = (a.len(), b.len());
+    let flatten = |i: usize, j: usize| i * (m + 1) + j;
+
+    let mut dp = vec![0; (n + 1) * (m + 1)];
+
+    for i in 0..=n {
+        dp[flatten(i, 0)] = i;
+    }
+
+    for j in 0..=m {
+        dp[flatten(0, j)] = j;
+    }
+
+    for i in 0..n {
+        for j in 0..m {
+            let cost = if a[i] == b[j] { 0 } else { 1 };
+            dp[flatten(i + 1, j + 1)] = std::cmp::min(
+                std::cmp::min(dp[flatten(i, j + 1)], dp[flatten(i + 1, j)]) + 1,
+                dp[flatten(i, j)] + cost,
+            );
+        }
+    }
+
+    let mut ops = vec![];
+    let (mut i, mut j) = (n, m);
+
+    while i > 0 || j > 0 {
+        let d = dp[flatten(i, j)];
+
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] && d == dp[flatten(i - 1, j - 1)] {
+            ops.push(EditOperation::Keep(i - 1, j - 1));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && d == dp[flatten(i - 1, j - 1)] + 1 {
+            ops.push(EditOperation::Replace(i - 1, j - 1));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && d == dp[flatten(i - 1, j)] + 1 {
+            ops.push(EditOperation::Delete(i - 1));
+            i -= 1;
+        } else {
+            ops.push(EditOperation::Insert(j - 1));
+            j -= 1;
+        }
+    }
+
+    ops.reverse();
+
+    (dp[flatten(n, m)], ops)
+}
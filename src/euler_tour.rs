@@ -0,0 +1,203 @@
+use crate::graph::{Index, Tree};
+
+/// 根付き木のオイラーツアー
+///
+/// 根から深さ優先探索を行い、以下の列を計算する。
+///
+/// * `order()`: 行きがけ順に頂点を並べた列 (長さ $`N`$)。頂点 $`v`$ の部分木は `order()[tin(v)..tout(v)]` になる。
+/// * `tour()`: 頂点に入るときと子から戻るときに頂点を記録した列 (長さ $`2N - 1`$)。`depth_tour()` はその深さの列である。
+///   $`u, v`$ の LCA は `tour()[first(u)..=first(v)]` の中で深さが最小の頂点になる。
+/// * 辺の列 (長さ $`2N`$): 頂点 $`v`$ に入る時刻を `edge_in(v)`、出る時刻を `edge_out(v)` とする。
+///   `edge_in(v)` に $`+x`$、`edge_out(v)` に $`-x`$ を置くと、根から $`v`$ へのパス上の頂点の値の総和が `..=edge_in(v)` の総和になる。
+///
+/// ## Examples
+///
+/// 部分木の総和・根からのパスの総和を [`BinaryIndexedTree`](crate::binary_indexed_tree::BinaryIndexedTree) で求める。
+///
+/// ```
+/// use library::binary_indexed_tree::BinaryIndexedTree;
+/// use library::euler_tour::EulerTour;
+/// use library::graph::UndirectedAdjGraph;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let tree = UndirectedAdjGraph::from_edges_no_weight(5, &[(0, 1), (0, 2), (1, 3), (1, 4)]);
+/// let et = EulerTour::from(&tree, 0);
+///
+/// assert_eq!(et.order(), &[0, 1, 3, 4, 2]);
+/// assert_eq!(et.tour(), &[0, 1, 3, 1, 4, 1, 0, 2, 0]);
+/// assert_eq!(et.depth_tour(), &[0, 1, 2, 1, 2, 1, 0, 1, 0]);
+/// assert_eq!(et.subtree(1), 1..4);
+///
+/// let a = [1i64, 10, 100, 1000, 10000];
+///
+/// let mut bit = BinaryIndexedTree::new(5);
+/// for v in 0..5 {
+///     bit.add(et.tin(v), a[v as usize]);
+/// }
+/// assert_eq!(bit.sum(et.subtree(1)), 11010);
+///
+/// let mut path = BinaryIndexedTree::new(10);
+/// for v in 0..5 {
+///     path.add(et.edge_in(v), a[v as usize]);
+///     path.add(et.edge_out(v), -a[v as usize]);
+/// }
+/// assert_eq!(path.sum(..=et.edge_in(4)), 10011);
+/// ```
+///
+/// ## 計算量
+///
+/// 木の頂点数を $`N`$ とする。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `from(tree, root)` | `root` を根としてオイラーツアーを計算する | $`O(N)`$ |
+/// | その他 | | $`O(1)`$ |
+///
+/// ## Verified problems
+///
+/// * [Vertex Add Subtree Sum](../../src/lc_vertex_add_subtree_sum_02/lc_vertex_add_subtree_sum_02.rs.html)
+///
+pub struct EulerTour {
+    tin: Vec<usize>,
+    tout: Vec<usize>,
+    order: Vec<Index>,
+    tour: Vec<Index>,
+    depth_tour: Vec<u32>,
+    first: Vec<usize>,
+    edge_in: Vec<usize>,
+    edge_out: Vec<usize>,
+    parent: Vec<Index>,
+    depth: Vec<u32>,
+}
+
+impl EulerTour {
+    /// `root` を根としてオイラーツアーを計算する
+    pub fn from<W>(tree: &dyn Tree<Weight = W>, root: Index) -> Self {
+        let size = tree.size() as usize;
+
+        let mut tin = vec![0; size];
+        let mut tout = vec![0; size];
+        let mut order = Vec::with_capacity(size);
+        let mut tour = Vec::with_capacity(2 * size);
+        let mut depth_tour = Vec::with_capacity(2 * size);
+        let mut first = vec![0; size];
+        let mut edge_in = vec![0; size];
+        let mut edge_out = vec![0; size];
+        let mut parent = vec![Index::MAX; size];
+        let mut depth = vec![0; size];
+        let mut time = 0;
+
+        // (頂点, 次に見る隣接辺の番号)
+        let mut stack = vec![(root, 0)];
+
+        while let Some((u, i)) = stack.pop() {
+            if i == 0 {
+                tin[u as usize] = order.len();
+                order.push(u);
+                first[u as usize] = tour.len();
+                edge_in[u as usize] = time;
+                time += 1;
+
+                tour.push(u);
+                depth_tour.push(depth[u as usize]);
+            }
+
+            let adjacent = tree.adjacent(u);
+            let mut i = i;
+
+            while i < adjacent.len() && adjacent[i].0 == parent[u as usize] {
+                i += 1;
+            }
+
+            if i < adjacent.len() {
+                let v = adjacent[i].0;
+                parent[v as usize] = u;
+                depth[v as usize] = depth[u as usize] + 1;
+
+                stack.push((u, i + 1));
+                stack.push((v, 0));
+            } else {
+                tout[u as usize] = order.len();
+                edge_out[u as usize] = time;
+                time += 1;
+
+                if let Some(&(p, _)) = stack.last() {
+                    tour.push(p);
+                    depth_tour.push(depth[p as usize]);
+                }
+            }
+        }
+
+        Self {
+            tin,
+            tout,
+            order,
+            tour,
+            depth_tour,
+            first,
+            edge_in,
+            edge_out,
+            parent,
+            depth,
+        }
+    }
+
+    /// 行きがけ順で $`v`$ が何番目か
+    pub fn tin(&self, v: Index) -> usize {
+        self.tin[v as usize]
+    }
+
+    /// 行きがけ順で $`v`$ の部分木の直後が何番目か
+    pub fn tout(&self, v: Index) -> usize {
+        self.tout[v as usize]
+    }
+
+    /// $`v`$ の部分木に対応する `order()` の区間
+    pub fn subtree(&self, v: Index) -> std::ops::Range<usize> {
+        self.tin(v)..self.tout(v)
+    }
+
+    /// 行きがけ順に頂点を並べた列
+    pub fn order(&self) -> &[Index] {
+        &self.order
+    }
+
+    /// 頂点に入るときと子から戻るときに頂点を記録した列
+    pub fn tour(&self) -> &[Index] {
+        &self.tour
+    }
+
+    /// `tour()` の各頂点の深さの列
+    pub fn depth_tour(&self) -> &[u32] {
+        &self.depth_tour
+    }
+
+    /// `tour()` で $`v`$ が初めて現れる位置
+    pub fn first(&self, v: Index) -> usize {
+        self.first[v as usize]
+    }
+
+    /// 辺の列で $`v`$ に入る時刻
+    pub fn edge_in(&self, v: Index) -> usize {
+        self.edge_in[v as usize]
+    }
+
+    /// 辺の列で $`v`$ から出る時刻
+    pub fn edge_out(&self, v: Index) -> usize {
+        self.edge_out[v as usize]
+    }
+
+    /// $`v`$ の親 (根の場合は `Index::MAX`)
+    pub fn parent(&self, v: Index) -> Index {
+        self.parent[v as usize]
+    }
+
+    /// $`v`$ の深さ
+    pub fn depth(&self, v: Index) -> u32 {
+        self.depth[v as usize]
+    }
+}
@@ -0,0 +1,149 @@
+//! 全点対最短経路問題を解くことができる
+//!
+//! ## Examples
+//!
+//! 計算した結果は [`FloydWarshall`] 型の変数に格納される。頂点 $`u`$ から頂点 $`v`$ への最短距離は `result.get(u, v)` で取得できる。
+//! 到達できない頂点の組の距離は [`Dist::UNREACHABLE`] になり、$`\infty`$ 同士の加算によるオーバーフローは起きない。
+//!
+//! ```
+//! use library::dijkstra::Dist;
+//! use library::floyd_warshall::floyd_warshall;
+//! use library::graph::DirectedAdjGraph;
+//!
+//! let graph = DirectedAdjGraph::from_edges(4, &[(0, 1, 1i64), (1, 2, 5), (0, 2, 7), (2, 0, -2)]);
+//!
+//! let mut res = floyd_warshall(&graph);
+//!
+//! assert!(!res.has_negative_cycle());
+//! assert_eq!(res.get(0, 2), Dist::VALUE(6));
+//! assert_eq!(res.get(2, 1), Dist::VALUE(-1));
+//! assert_eq!(res.get(0, 3), Dist::UNREACHABLE);
+//!
+//! // 辺を追加して距離を更新する
+//! res.add_edge(1, 3, 1);
+//! assert_eq!(res.get(2, 3), Dist::VALUE(0));
+//!
+//! res.add_edge(3, 0, -3);
+//! assert!(res.has_negative_cycle());
+//! ```
+//!
+//! ## 負閉路について
+//!
+//! 負閉路が存在する場合、`has_negative_cycle()` が `true` になる。このとき、各距離の値に意味はない。
+//! 負閉路を見つけた時点で計算を打ち切るので、距離が負の方向にオーバーフローすることもない。
+//!
+//! ## 計算量
+//!
+//! グラフの辺の重みの型 `W` の空間計算量が $`O(1)`$ で、加法が $`O(1)`$ で行えることを仮定する。
+//!
+//! | 関数 | 計算量 |
+//! | --- | --- |
+//! | [`floyd_warshall()`] | $`O(\lvert V \rvert^3)`$ |
+//! | [`FloydWarshall::get()`] | $`O(1)`$ |
+//! | [`FloydWarshall::add_edge()`] | $`O(\lvert V \rvert^2)`$ |
+//!
+//! ## Verified problems
+//!
+//! * [All Pairs Shortest Path](../../src/aoj_grl_1_c/aoj_grl_1_c.rs.html)
+//!
+
+use crate::dijkstra::Dist;
+use crate::graph::Graph;
+
+/// [`floyd_warshall`] の結果を格納するデータ構造
+pub struct FloydWarshall<W> {
+    dist: Vec<Vec<Option<W>>>,
+    negative_cycle: bool,
+}
+
+impl<W: Default + std::ops::Add<Output = W> + Ord + Copy> FloydWarshall<W> {
+    /// 頂点 $`u`$ から頂点 $`v`$ への最短距離を返す
+    pub fn get(&self, u: u32, v: u32) -> Dist<W> {
+        match self.dist[u as usize][v as usize] {
+            Some(d) => Dist::VALUE(d),
+            None => Dist::UNREACHABLE,
+        }
+    }
+
+    /// 負閉路が存在するかを返す
+    pub fn has_negative_cycle(&self) -> bool {
+        self.negative_cycle
+    }
+
+    /// $`u`$ から $`v`$ へ重み $`w`$ の辺を追加して、最短距離を更新する
+    ///
+    /// 無向辺を追加する場合は、`add_edge(u, v, w)` と `add_edge(v, u, w)` の両方を呼ぶ。
+    pub fn add_edge(&mut self, u: u32, v: u32, w: W) {
+        if self.negative_cycle {
+            return;
+        }
+
+        let (u, v) = (u as usize, v as usize);
+        let size = self.dist.len();
+
+        for i in 0..size {
+            let diu = match self.dist[i][u] {
+                Some(d) => d,
+                None => continue,
+            };
+
+            for j in 0..size {
+                if let Some(dvj) = self.dist[v][j] {
+                    let d = diu + w + dvj;
+                    if self.dist[i][j].map_or(true, |x| d < x) {
+                        self.dist[i][j] = Some(d);
+                    }
+                }
+            }
+        }
+
+        self.negative_cycle = (0..size).any(|i| self.dist[i][i] < Some(W::default()));
+    }
+}
+
+/// `graph` 上で全点対の最短距離を計算する
+pub fn floyd_warshall<W: Default + std::ops::Add<Output = W> + Ord + Copy>(
+    graph: &impl Graph<Weight = W>,
+) -> FloydWarshall<W> {
+    let size = graph.size() as usize;
+    let mut dist: Vec<Vec<Option<W>>> = vec![vec![None; size]; size];
+
+    for (u, row) in dist.iter_mut().enumerate() {
+        row[u] = Some(W::default());
+        for &(v, w) in graph.adjacent(u as u32) {
+            if row[v as usize].map_or(true, |x| w < x) {
+                row[v as usize] = Some(w);
+            }
+        }
+    }
+
+    for k in 0..size {
+        for i in 0..size {
+            let dik = match dist[i][k] {
+                Some(d) => d,
+                None => continue,
+            };
+
+            for j in 0..size {
+                if let Some(dkj) = dist[k][j] {
+                    let d = dik + dkj;
+                    if dist[i][j].map_or(true, |x| d < x) {
+                        dist[i][j] = Some(d);
+                    }
+                }
+            }
+        }
+
+        if (0..size).any(|i| dist[i][i] < Some(W::default())) {
+            return FloydWarshall {
+                dist,
+                negative_cycle: true,
+            };
+        }
+    }
+
+    FloydWarshall {
+        dist,
+        negative_cycle: false,
+    }
+}
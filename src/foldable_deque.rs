@@ -0,0 +1,174 @@
+use crate::algebra::Monoid;
+
+/// 両端への要素の追加・削除と、全要素の総積の計算ができる両端キュー
+///
+/// 先頭側と末尾側の 2 つのスタックに分けて、それぞれ境界までの累積積を持つ。
+/// 一方のスタックが空のときにそちらから削除しようとした場合は、もう一方のスタックの要素を半分ずつに分け直す。
+/// 先頭から削除しない場合は Sliding Window Aggregation (SWAG) と同じものになる。
+///
+/// 総積は先頭から順に積をとったものである。モノイドは可換でなくてもよい。
+///
+/// ## Examples
+///
+/// ```
+/// use library::algebra::{Max, Monoid};
+/// use library::foldable_deque::FoldableDeque;
+///
+/// let mut deque: FoldableDeque<Max<i32>> = FoldableDeque::new();
+/// deque.push_back(3);
+/// deque.push_back(1);
+/// deque.push_front(4);
+/// assert_eq!(deque.fold(), 4);
+///
+/// assert_eq!(deque.pop_front(), Some(4));
+/// assert_eq!(deque.fold(), 3);
+/// assert_eq!(deque.pop_front(), Some(3));
+/// assert_eq!(deque.pop_back(), Some(1));
+/// assert_eq!(deque.pop_back(), None);
+/// assert_eq!(deque.fold(), i32::MIN);
+///
+/// // 可換でないモノイド: 1 次関数の合成 f(x) = ax + b を (a, b) で表す
+/// enum Affine {}
+/// impl Monoid for Affine {
+///     type S = (i64, i64);
+///     // 先に lhs を適用してから rhs を適用する
+///     fn op(lhs: &(i64, i64), rhs: &(i64, i64)) -> (i64, i64) {
+///         (lhs.0 * rhs.0, lhs.1 * rhs.0 + rhs.1)
+///     }
+///     const E: (i64, i64) = (1, 0);
+/// }
+///
+/// let mut deque: FoldableDeque<Affine> = FoldableDeque::new();
+/// deque.push_back((2, 1));
+/// deque.push_back((3, 0));
+/// deque.push_front((1, 5));
+/// // x -> x + 5 -> 2x + 11 -> 6x + 33
+/// assert_eq!(deque.fold(), (6, 33));
+/// ```
+///
+/// ## 計算量
+///
+/// 二項演算が $`O(1)`$ で行えることを仮定する。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `new()` | 空の両端キューを生成する | $`O(1)`$ |
+/// | `self.push_front(x)`, `self.push_back(x)` | 先頭・末尾に要素を追加する | $`O(1)`$ |
+/// | `self.pop_front()`, `self.pop_back()` | 先頭・末尾の要素を削除する | ならし $`O(1)`$ |
+/// | `self.fold()` | 全要素の総積を求める | $`O(1)`$ |
+///
+pub struct FoldableDeque<M: Monoid> {
+    // (要素, その要素から境界までの総積) を、先頭の要素がスタックの一番上になるように持つ
+    front: Vec<(M::S, M::S)>,
+    // (要素, 境界からその要素までの総積) を、末尾の要素がスタックの一番上になるように持つ
+    back: Vec<(M::S, M::S)>,
+}
+
+impl<M: Monoid> FoldableDeque<M> {
+    /// 空の両端キューを生成する
+    pub fn new() -> Self {
+        Self {
+            front: vec![],
+            back: vec![],
+        }
+    }
+
+    /// 要素数を返す
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    /// 空であるかを返す
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 全要素の総積を求める。空の場合は単位元を返す
+    pub fn fold(&self) -> M::S {
+        M::op(&self._front_fold(), &self._back_fold())
+    }
+
+    /// 先頭に `x` を追加する
+    pub fn push_front(&mut self, x: M::S) {
+        let fold = M::op(&x, &self._front_fold());
+        self.front.push((x, fold));
+    }
+
+    /// 末尾に `x` を追加する
+    pub fn push_back(&mut self, x: M::S) {
+        let fold = M::op(&self._back_fold(), &x);
+        self.back.push((x, fold));
+    }
+
+    /// 先頭の要素を削除して返す。空の場合は `None` を返す
+    pub fn pop_front(&mut self) -> Option<M::S> {
+        if self.front.is_empty() {
+            // 末尾側の前半を先頭側に移す
+            let k = (self.back.len() + 1) / 2;
+            self._rebuild(k);
+        }
+
+        self.front.pop().map(|(x, _)| x)
+    }
+
+    /// 末尾の要素を削除して返す。空の場合は `None` を返す
+    pub fn pop_back(&mut self) -> Option<M::S> {
+        if self.back.is_empty() {
+            // 先頭側の後半を末尾側に移す
+            let k = self.front.len() / 2;
+            self._rebuild(k);
+        }
+
+        self.back.pop().map(|(x, _)| x)
+    }
+
+    /// 先頭の要素を返す
+    pub fn front(&self) -> Option<&M::S> {
+        match self.front.last() {
+            Some((x, _)) => Some(x),
+            None => self.back.first().map(|(x, _)| x),
+        }
+    }
+
+    /// 末尾の要素を返す
+    pub fn back(&self) -> Option<&M::S> {
+        match self.back.last() {
+            Some((x, _)) => Some(x),
+            None => self.front.first().map(|(x, _)| x),
+        }
+    }
+
+    fn _front_fold(&self) -> M::S {
+        self.front.last().map_or(M::E, |(_, f)| f.clone())
+    }
+
+    fn _back_fold(&self) -> M::S {
+        self.back.last().map_or(M::E, |(_, f)| f.clone())
+    }
+
+    // 全要素を先頭から順に並べ、先頭 k 個を先頭側、残りを末尾側のスタックに積み直す
+    fn _rebuild(&mut self, k: usize) {
+        let mut elements = std::mem::take(&mut self.front)
+            .into_iter()
+            .rev()
+            .map(|(x, _)| x)
+            .collect::<Vec<_>>();
+        elements.extend(std::mem::take(&mut self.back).into_iter().map(|(x, _)| x));
+
+        let back = elements.split_off(k);
+
+        for x in elements.into_iter().rev() {
+            self.push_front(x);
+        }
+
+        for x in back {
+            self.push_back(x);
+        }
+    }
+}
+
+impl<M: Monoid> Default for FoldableDeque<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
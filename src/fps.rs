@@ -0,0 +1,204 @@
+//! 形式的冪級数 (FPS) の演算を $`\mathbb{Z} / P \mathbb{Z}`$ 上で行う。
+//!
+//! 形式的冪級数 $`f(x) = \displaystyle \sum_{i} f_i x^i`$ を、係数の列 $`(f_0, f_1, \dots)`$ で表す。
+//! $`P`$ は [`convolution()`] が使える素数であることを要求する。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::fps::fps_inv;
+//! use library::modint::ModInt;
+//!
+//! type Mint = ModInt<998244353>;
+//!
+//! // 1 / (1 - x - x^2) はフィボナッチ数列の母関数
+//! let f = [1, -1, -1].map(Mint::from);
+//! assert_eq!(fps_inv(&f, 8), [1, 1, 2, 3, 5, 8, 13, 21].map(Mint::from));
+//! ```
+//!
+//! 有理式 $`P(x) / Q(x)`$ の $`N`$ 次の係数は、Bostan–Mori のアルゴリズムで $`N`$ が大きくても求められる。
+//! 線形漸化式を満たす数列の $`N`$ 項目を求めるのに使う。
+//!
+//! ```
+//! use library::fps::{coefficient_of_rational, kth_term_of_linear_recurrence};
+//! use library::modint::ModInt;
+//!
+//! type Mint = ModInt<998244353>;
+//!
+//! // x / (1 - x - x^2) の 90 次の係数はフィボナッチ数 F_90
+//! let p = [0, 1].map(Mint::from);
+//! let q = [1, -1, -1].map(Mint::from);
+//! assert_eq!(coefficient_of_rational(&p, &q, 90), Mint::from(2880067194370816120u64));
+//!
+//! // a_i = a_{i-1} + a_{i-2}, a_0 = 0, a_1 = 1
+//! let a = [0, 1].map(Mint::from);
+//! let c = [1, 1].map(Mint::from);
+//! assert_eq!(kth_term_of_linear_recurrence(&a, &c, 90), Mint::from(2880067194370816120u64));
+//! ```
+//!
+//! 数列の先頭の項から、それを満たす最短の線形漸化式を Berlekamp–Massey のアルゴリズムで求められる。
+//! DP の値を先頭のいくつかだけ愚直に計算し、漸化式を推測して遠くの項を求めるのに使う。
+//!
+//! ```
+//! use library::fps::{berlekamp_massey, kth_term_of_linear_recurrence};
+//! use library::modint::ModInt;
+//!
+//! type Mint = ModInt<998244353>;
+//!
+//! // 1 x 2 のドミノで 3 x 2n の盤面を敷き詰める方法の数 (n = 0, 1, ...)
+//! let a = [1, 3, 11, 41, 153, 571, 2131, 7953].map(Mint::from);
+//! let c = berlekamp_massey(&a);
+//! assert_eq!(c, [4, -1].map(Mint::from));
+//!
+//! assert_eq!(kth_term_of_linear_recurrence(&a, &c, 10), Mint::from(413403));
+//! ```
+//!
+//! ## 計算量
+//!
+//! | 関数 | 計算量 |
+//! | --- | --- |
+//! | [`fps_inv()`] | $`O(N \log N)`$ |
+//! | [`coefficient_of_rational()`] | $`O(D \log D \log N)`$ ($`D = \deg Q`$) |
+//! | [`kth_term_of_linear_recurrence()`] | $`O(D \log D \log K)`$ ($`D`$ は漸化式の次数) |
+//! | [`berlekamp_massey()`] | $`O(N^2)`$ |
+//!
+//! ## Verified problems
+//!
+//! * [Find Linear Recurrence](../../src/lc_find_linear_recurrence/lc_find_linear_recurrence.rs.html)
+//! * [Kth term of Linearly Recurrent Sequence](../../src/lc_kth_term_of_linearly_recurrent_sequence/lc_kth_term_of_linearly_recurrent_sequence.rs.html)
+//!
+
+use crate::convolution::convolution;
+use crate::modint::ModInt;
+
+/// $`f(x) g(x) \equiv 1 \pmod{x^n}`$ を満たす $`g`$ の先頭 $`n`$ 項を求める
+///
+/// $`f_0 \neq 0`$ であることを要求する。Newton 法で $`g \leftarrow g (2 - f g)`$ と精度を倍々にしていく。
+pub fn fps_inv<const P: u32>(f: &[ModInt<P>], n: usize) -> Vec<ModInt<P>> {
+    assert!(!f.is_empty() && f[0] != ModInt::from_raw(0));
+
+    let mut g = vec![f[0].inv()];
+    let mut m = 1;
+
+    while m < n {
+        m *= 2;
+
+        let fg = convolution(&f[..std::cmp::min(f.len(), m)], &g);
+        let mut h = fg.into_iter().take(m).map(|x| -x).collect::<Vec<_>>();
+        h[0] += ModInt::from_raw(2);
+
+        g = convolution(&g, &h);
+        g.truncate(m);
+    }
+
+    g.resize(n, ModInt::from_raw(0));
+    g
+}
+
+/// $`\displaystyle \frac{P(x)}{Q(x)}`$ の $`x^n`$ の係数を求める
+///
+/// $`Q_0 \neq 0`$ であることを要求する。Bostan–Mori のアルゴリズムで、
+/// $`\displaystyle \frac{P(x)}{Q(x)} = \frac{P(x) Q(-x)}{Q(x) Q(-x)}`$ の分母が $`x^2`$ の多項式になることを使い、$`n`$ を半分にしていく。
+pub fn coefficient_of_rational<const P: u32>(
+    p: &[ModInt<P>],
+    q: &[ModInt<P>],
+    mut n: u64,
+) -> ModInt<P> {
+    assert!(!q.is_empty() && q[0] != ModInt::from_raw(0));
+
+    let mut p = p.to_vec();
+    let mut q = q.to_vec();
+
+    while n > 0 {
+        let q_neg = q
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| if i % 2 == 0 { c } else { -c })
+            .collect::<Vec<_>>();
+
+        let u = convolution(&p, &q_neg);
+        let v = convolution(&q, &q_neg);
+
+        // U(x) の n と偶奇が同じ次数の項と、V(x) の偶数次の項だけを残す
+        p = u.into_iter().skip((n % 2) as usize).step_by(2).collect();
+        q = v.into_iter().step_by(2).collect();
+        n >>= 1;
+    }
+
+    match p.first() {
+        Some(&p0) => p0 / q[0],
+        None => ModInt::from_raw(0),
+    }
+}
+
+/// 線形漸化式 $`a_i = \displaystyle \sum_{j = 1}^{d} c_j a_{i - j}`$ $`(i \geq d)`$ を満たす数列の第 $`k`$ 項 (0-indexed) を求める
+///
+/// `a` は初項 $`a_0, \dots, a_{d - 1}`$、`c` は係数 $`c_1, \dots, c_d`$ である。
+/// 母関数は $`A(x) = P(x) / Q(x)`$ $`(Q(x) = 1 - \sum_j c_j x^j, P(x) = A(x) Q(x) \bmod x^d)`$ と表せる。
+pub fn kth_term_of_linear_recurrence<const P: u32>(
+    a: &[ModInt<P>],
+    c: &[ModInt<P>],
+    k: u64,
+) -> ModInt<P> {
+    let d = c.len();
+    assert!(a.len() >= d);
+
+    if k < a.len() as u64 {
+        return a[k as usize];
+    }
+
+    let mut q = vec![ModInt::from_raw(1)];
+    q.extend(c.iter().map(|&x| -x));
+
+    let mut p = convolution(&a[..d], &q);
+    p.truncate(d);
+
+    coefficient_of_rational(&p, &q, k)
+}
+
+/// 数列 `a` を満たす最短の線形漸化式 $`a_i = \displaystyle \sum_{j = 1}^{d} c_j a_{i - j}`$ $`(d \leq i < N)`$ の係数 $`c_1, \dots, c_d`$ を求める
+///
+/// Berlekamp–Massey のアルゴリズムで、先頭から $`1`$ 項ずつ見て、漸化式が合わなくなったら以前の漸化式を使って修正する。
+/// $`P`$ は素数であることを要求する。求めた漸化式が正しいと言えるのは、$`N \geq 2d`$ のときである。
+pub fn berlekamp_massey<const P: u32>(a: &[ModInt<P>]) -> Vec<ModInt<P>> {
+    let zero = ModInt::from_raw(0);
+
+    // 現在の漸化式 C(x) と、最後に次数が増える直前の漸化式 B(x) (どちらも定数項は 1)
+    let mut c = vec![ModInt::from_raw(1)];
+    let mut b = vec![ModInt::from_raw(1)];
+    let mut len = 0;
+    // B(x) が最後に合わなくなった位置からの距離と、そのときのずれ
+    let mut shift = 1;
+    let mut b_diff = ModInt::from_raw(1);
+
+    for n in 0..a.len() {
+        let diff = (1..=len).fold(a[n], |acc, i| acc + c[i] * a[n - i]);
+
+        if diff == zero {
+            shift += 1;
+            continue;
+        }
+
+        let coef = diff / b_diff;
+        let prev = c.clone();
+
+        if c.len() < b.len() + shift {
+            c.resize(b.len() + shift, zero);
+        }
+        for (i, &x) in b.iter().enumerate() {
+            c[i + shift] -= coef * x;
+        }
+
+        if 2 * len <= n {
+            len = n + 1 - len;
+            b = prev;
+            b_diff = diff;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+    }
+
+    c.resize(len + 1, zero);
+    c[1..].iter().map(|&x| -x).collect()
+}
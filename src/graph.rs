@@ -90,7 +90,7 @@ pub trait UndirectedGraph: Graph {}
 /// (無向)木であることを示すトレイト
 pub trait Tree: Graph {}
 
-impl<W: Default + std::ops::Add<Output = W> + Copy> dyn Tree<Weight = W> {
+impl<W: Default + std::ops::Add<Output = W> + Copy> dyn Tree<Weight = W> + '_ {
     /// 木上で幅優先探索を行って、始点 `src` から他の頂点への最短距離を計算する。
     ///
     /// ## Example
@@ -144,9 +144,44 @@ pub trait Graph {
     fn add_edge(&mut self, u: Index, v: Index, w: Self::Weight);
     /// `v` から出ている辺を列挙する
     fn adjacent(&self, v: Index) -> &[(Index, Self::Weight)];
+    /// すべての辺を `(u, v, &w)` の形で列挙する
+    ///
+    /// 無向グラフの場合、各辺は $`u \leq v`$ の向きで $`1`$ 回ずつ列挙される。
+    ///
+    /// ```
+    /// use library::graph::{Graph, UndirectedAdjGraph};
+    ///
+    /// let graph = UndirectedAdjGraph::from_edges(3, &[(0, 1, 5), (2, 1, 3), (2, 2, 1)]);
+    /// let edges = graph.edges().collect::<Vec<_>>();
+    /// assert_eq!(edges, vec![(0, 1, &5), (1, 2, &3), (2, 2, &1)]);
+    /// ```
+    fn edges(&self) -> Box<dyn Iterator<Item = (Index, Index, &Self::Weight)> + '_> {
+        let directed = self.is_directed_edge();
+
+        Box::new((0..self.size()).flat_map(move |u| {
+            // 無向グラフの自己ループは隣接リストに 2 回現れるので、1 回おきに列挙する
+            let mut parity = false;
+
+            self.adjacent(u)
+                .iter()
+                .filter(move |&&(v, _)| {
+                    if directed {
+                        true
+                    } else if u == v {
+                        parity = !parity;
+                        parity
+                    } else {
+                        u < v
+                    }
+                })
+                .map(move |(v, w)| (u, *v, w))
+        }))
+    }
 }
 
 impl dyn Graph<Weight = ()> {
+    /// `src` から各頂点への最短距離 (辺の本数) を求める  
+    /// 到達不可能な頂点の距離は `Index::MAX` になる。距離は到達済みの頂点からしか計算しないので、オーバーフローすることはない。
     pub fn bfs(&self, src: Index) -> Vec<Index> {
         let size = self.size();
         let mut seen = vec![false; size as usize];
@@ -273,6 +308,31 @@ impl<O: Orientation> AdjGraph<O, ()> {
     }
 }
 
+impl<W: Clone + Copy> AdjGraph<Directed, W> {
+    /// すべての辺の向きを逆にしたグラフを返す
+    ///
+    /// ```
+    /// use library::graph::DirectedAdjGraph;
+    ///
+    /// let graph = DirectedAdjGraph::from_edges(3, &[(0, 1, 10), (0, 2, 20), (1, 2, 30)]);
+    /// let rev = graph.reverse();
+    ///
+    /// assert_eq!(rev.adjacent(0), &[]);
+    /// assert_eq!(rev.adjacent(2), &[(0, 20), (1, 30)]);
+    /// ```
+    pub fn reverse(&self) -> Self {
+        let mut graph = Self::new(self.size);
+
+        for u in 0..self.size {
+            for &(v, w) in self.adjacent(u) {
+                graph.add_edge(v, u, w);
+            }
+        }
+
+        graph
+    }
+}
+
 impl<O: Orientation, W> std::ops::Index<Index> for AdjGraph<O, W> {
     type Output = [(Index, W)];
     fn index(&self, index: Index) -> &Self::Output {
@@ -341,6 +401,48 @@ impl<O: Orientation, W: Clone> CRSGraph<O, W> {
     }
 }
 
+impl<W: Clone + Copy> CRSGraph<Directed, W> {
+    /// すべての辺の向きを逆にしたグラフを返す
+    ///
+    /// ```
+    /// use library::graph::DirectedAdjGraph;
+    ///
+    /// let graph = DirectedAdjGraph::from_edges(3, &[(0, 1, 10), (0, 2, 20), (1, 2, 30)]).to_crs();
+    /// let rev = graph.reverse();
+    ///
+    /// assert_eq!(rev.adjacent(0), &[]);
+    /// assert_eq!(rev.adjacent(2), &[(0, 20), (1, 30)]);
+    /// ```
+    pub fn reverse(&self) -> Self {
+        let size = self.size as usize;
+        let mut ptr = vec![0; size + 1];
+
+        for &(v, _) in &self.crs {
+            ptr[v as usize + 1] += 1;
+        }
+        for i in 0..size {
+            ptr[i + 1] += ptr[i];
+        }
+
+        let mut pos = ptr.clone();
+        let mut crs = self.crs.clone();
+
+        for u in 0..self.size {
+            for &(v, w) in self.adjacent(u) {
+                crs[pos[v as usize] as usize] = (u, w);
+                pos[v as usize] += 1;
+            }
+        }
+
+        Self {
+            size: self.size,
+            crs,
+            ptr,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
 impl<O: Orientation, W> std::ops::Index<Index> for CRSGraph<O, W> {
     type Output = [(Index, W)];
     fn index(&self, index: Index) -> &Self::Output {
@@ -0,0 +1,190 @@
+//! 二次元グリッドをグラフに変換する
+//!
+//! [`Grid`] はマス $`(i, j)`$ と頂点番号 $`iW + j`$ の対応を管理し、通行可能なマスを頂点とするグラフを構築する。
+//! 隣接関係は [`Neighborhood::Four`] (上下左右) か [`Neighborhood::Eight`] (斜めを含む) から選ぶ。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::graph::Graph;
+//! use library::grid::{Grid, Neighborhood};
+//!
+//! let s = ["..#", "#..", "..."].map(|r| r.chars().collect::<Vec<_>>());
+//! let grid = Grid::new(3, 3);
+//!
+//! let graph = grid.build_graph(|i, j| s[i][j] != '#', Neighborhood::Four);
+//! let dist = <dyn Graph<Weight = ()>>::bfs(&graph, grid.index(0, 0));
+//! assert_eq!(dist[grid.index(2, 0) as usize], 4);
+//! assert_eq!(dist[grid.index(0, 2) as usize], u32::MAX);
+//! assert_eq!(grid.position(5), (1, 2));
+//!
+//! let graph = grid.build_graph(|i, j| s[i][j] != '#', Neighborhood::Eight);
+//! let dist = <dyn Graph<Weight = ()>>::bfs(&graph, grid.index(0, 0));
+//! assert_eq!(dist[grid.index(2, 0) as usize], 2);
+//! ```
+//!
+//! マスごとに入るコストがある場合は [`Grid::build_weighted_graph`] を使う。
+//!
+//! ```
+//! use library::dijkstra::{dijkstras_algorithm, Dist};
+//! use library::grid::{Grid, Neighborhood};
+//!
+//! let cost = [[1u32, 9, 1], [1, 9, 1], [1, 1, 1]];
+//! let grid = Grid::new(3, 3);
+//!
+//! let graph = grid.build_weighted_graph(|i, j| Some(cost[i][j]), Neighborhood::Four);
+//! let res = dijkstras_algorithm(&graph, grid.index(0, 0));
+//! assert_eq!(res.get(grid.index(0, 2)), Dist::VALUE(6));
+//! ```
+//!
+//! 構築したグラフは [`AdjGraph::to_crs()`](crate::graph::AdjGraph::to_crs) で [`CRSGraph`](crate::graph::CRSGraph) に変換できる。
+//!
+//! ## 計算量
+//!
+//! グリッドの大きさを $`H \times W`$ として、グラフの構築は $`O(HW)`$ である。
+//!
+
+use crate::graph::{DirectedAdjGraph, Index, UndirectedAdjGraph};
+
+/// グリッドの隣接関係
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Neighborhood {
+    /// 上下左右の $`4`$ 近傍
+    Four,
+    /// 斜めを含む $`8`$ 近傍
+    Eight,
+}
+
+impl Neighborhood {
+    fn directions(&self) -> &'static [(isize, isize)] {
+        match self {
+            Neighborhood::Four => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Neighborhood::Eight => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+/// $`H \times W`$ のグリッドのマスと頂点番号の対応
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Grid {
+    pub height: usize,
+    pub width: usize,
+}
+
+impl Grid {
+    /// $`H \times W`$ のグリッドを生成する
+    pub fn new(height: usize, width: usize) -> Self {
+        Self { height, width }
+    }
+
+    /// 頂点数 ($`HW`$) を返す
+    pub fn size(&self) -> usize {
+        self.height * self.width
+    }
+
+    /// マス $`(i, j)`$ に対応する頂点番号を返す
+    pub fn index(&self, i: usize, j: usize) -> Index {
+        assert!(i < self.height && j < self.width);
+        (i * self.width + j) as Index
+    }
+
+    /// 頂点 $`v`$ に対応するマスを返す
+    pub fn position(&self, v: Index) -> (usize, usize) {
+        assert!((v as usize) < self.size());
+        (v as usize / self.width, v as usize % self.width)
+    }
+
+    /// マス $`(i, j)`$ に隣接するマスを列挙する
+    pub fn neighbors(
+        &self,
+        i: usize,
+        j: usize,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        neighborhood
+            .directions()
+            .iter()
+            .filter_map(move |&(di, dj)| {
+                let ni = i.checked_add_signed(di)?;
+                let nj = j.checked_add_signed(dj)?;
+                if ni < self.height && nj < self.width {
+                    Some((ni, nj))
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// `passable(i, j)` が `true` のマス同士を隣接関係に従って結んだ無向グラフを構築する
+    ///
+    /// 通行できないマスに対応する頂点は孤立点になる。
+    pub fn build_graph<F: FnMut(usize, usize) -> bool>(
+        &self,
+        mut passable: F,
+        neighborhood: Neighborhood,
+    ) -> UndirectedAdjGraph<()> {
+        let ok = (0..self.size())
+            .map(|v| passable(v / self.width, v % self.width))
+            .collect::<Vec<_>>();
+        let mut graph = UndirectedAdjGraph::new(self.size() as Index);
+
+        for i in 0..self.height {
+            for j in 0..self.width {
+                let u = self.index(i, j);
+                if !ok[u as usize] {
+                    continue;
+                }
+
+                for (ni, nj) in self.neighbors(i, j, neighborhood) {
+                    let v = self.index(ni, nj);
+                    if u < v && ok[v as usize] {
+                        graph.add_edge(u, v, ());
+                    }
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// 隣接するマス $`a`$ から $`b`$ へ、重み `weight(b)` の辺を張った有向グラフを構築する
+    ///
+    /// `weight(i, j)` が `None` のマスは通行できないものとする。
+    pub fn build_weighted_graph<W: Copy, F: FnMut(usize, usize) -> Option<W>>(
+        &self,
+        mut weight: F,
+        neighborhood: Neighborhood,
+    ) -> DirectedAdjGraph<W> {
+        let cost = (0..self.size())
+            .map(|v| weight(v / self.width, v % self.width))
+            .collect::<Vec<_>>();
+        let mut graph = DirectedAdjGraph::new(self.size() as Index);
+
+        for i in 0..self.height {
+            for j in 0..self.width {
+                let u = self.index(i, j);
+                if cost[u as usize].is_none() {
+                    continue;
+                }
+
+                for (ni, nj) in self.neighbors(i, j, neighborhood) {
+                    let v = self.index(ni, nj);
+                    if let Some(w) = cost[v as usize] {
+                        graph.add_edge(u, v, w);
+                    }
+                }
+            }
+        }
+
+        graph
+    }
+}
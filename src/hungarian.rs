@@ -0,0 +1,126 @@
+//! ハンガリー法で割当問題を解く
+//!
+//! $`N \times M`$ $`(N \leq M)`$ のコスト行列 $`A`$ が与えられたとき、各行に相異なる列を $`1`$ つずつ割り当てて、コストの和 $`\sum_i A_{i, p_i}`$ を最小化する。
+//! 重み付き二部グラフの最小重み完全マッチングに相当する。
+//!
+//! 行を $`1`$ つずつ追加し、双対変数(ポテンシャル)を更新しながら最短の増加路を探す。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::hungarian::assignment;
+//!
+//! let cost = vec![vec![4, 1, 3], vec![2, 0, 5], vec![3, 2, 2]];
+//! assert_eq!(assignment(&cost), (5, vec![1, 0, 2]));
+//!
+//! // 行より列が多い場合
+//! let cost = vec![vec![10, 1, 7], vec![1, 10, 7]];
+//! assert_eq!(assignment(&cost), (2, vec![1, 0]));
+//! ```
+//!
+//! ## 計算量
+//!
+//! $`O(N^2 M)`$ である。
+//!
+//! ## Verified problems
+//!
+//! * [Assignment Problem](../../src/lc_assignment/lc_assignment.rs.html)
+//!
+
+use crate::integer_traits::HasMaxValue;
+
+/// コスト行列 `cost_matrix` の割当問題を解き、`(コストの和の最小値, 各行に割り当てた列)` を返す
+///
+/// 行数は列数以下でなければならない。ポテンシャルが負になりうるので、`W` は符号付き整数とする。
+pub fn assignment<
+    W: Default
+        + Copy
+        + Ord
+        + std::ops::Add<Output = W>
+        + std::ops::Sub<Output = W>
+        + std::ops::AddAssign
+        + std::ops::SubAssign
+        + HasMaxValue,
+>(
+    cost_matrix: &[Vec<W>],
+) -> (W, Vec<usize>) {
+    let n = cost_matrix.len();
+    if n == 0 {
+        return (W::default(), vec![]);
+    }
+
+    let m = cost_matrix[0].len();
+    assert!(n <= m);
+
+    // 行・列とも 1-indexed で持ち、列 0 を番兵にする
+    // p[j]: 列 j に割り当てた行
+    let mut u = vec![W::default(); n + 1];
+    let mut v = vec![W::default(); m + 1];
+    let mut p = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+
+    for i in 1..=n {
+        p[0] = i;
+        let mut j0 = 0;
+        let mut minv = vec![W::MAX; m + 1];
+        let mut used = vec![false; m + 1];
+
+        loop {
+            used[j0] = true;
+            let i0 = p[j0];
+            let mut delta = W::MAX;
+            let mut j1 = 0;
+
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+
+                let cur = cost_matrix[i0 - 1][j - 1] - u[i0] - v[j];
+                if cur < minv[j] {
+                    minv[j] = cur;
+                    way[j] = j0;
+                }
+                if minv[j] < delta {
+                    delta = minv[j];
+                    j1 = j;
+                }
+            }
+
+            for j in 0..=m {
+                if used[j] {
+                    u[p[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    minv[j] -= delta;
+                }
+            }
+
+            j0 = j1;
+            if p[j0] == 0 {
+                break;
+            }
+        }
+
+        // 増加路に沿って割当を更新する
+        while j0 != 0 {
+            let j1 = way[j0];
+            p[j0] = p[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut assign = vec![0; n];
+    for j in 1..=m {
+        if p[j] != 0 {
+            assign[p[j] - 1] = j - 1;
+        }
+    }
+
+    let total = assign
+        .iter()
+        .enumerate()
+        .fold(W::default(), |acc, (i, &j)| acc + cost_matrix[i][j]);
+
+    (total, assign)
+}
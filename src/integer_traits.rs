@@ -6,6 +6,12 @@ pub trait HasMinValue {
     const MIN: Self;
 }
 
+/// 飽和加算  
+/// 結果が表現できる範囲を超える場合、最大値 (最小値) に丸める。
+pub trait SaturatingAdd {
+    fn saturating_add(self, rhs: Self) -> Self;
+}
+
 macro_rules! impl_to_integers {
     ($($t: ty), *) => {
         $(
@@ -16,8 +22,38 @@ macro_rules! impl_to_integers {
             impl HasMinValue for $t {
                 const MIN: $t = <$t>::MIN;
             }
+
+            impl SaturatingAdd for $t {
+                fn saturating_add(self, rhs: $t) -> $t {
+                    <$t>::saturating_add(self, rhs)
+                }
+            }
         )*
     };
 }
 
 impl_to_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// 浮動小数点数は `Ord` を実装していないので、[`ordered_float::OrderedFloat`] で包んで使う。
+/// 最大値・最小値はそれぞれ $`+\infty`$, $`-\infty`$ とする。
+macro_rules! impl_to_floats {
+    ($($t: ty), *) => {
+        $(
+            impl HasMaxValue for ordered_float::OrderedFloat<$t> {
+                const MAX: Self = ordered_float::OrderedFloat(<$t>::INFINITY);
+            }
+
+            impl HasMinValue for ordered_float::OrderedFloat<$t> {
+                const MIN: Self = ordered_float::OrderedFloat(<$t>::NEG_INFINITY);
+            }
+
+            impl SaturatingAdd for ordered_float::OrderedFloat<$t> {
+                fn saturating_add(self, rhs: Self) -> Self {
+                    self + rhs
+                }
+            }
+        )*
+    };
+}
+
+impl_to_floats!(f32, f64);
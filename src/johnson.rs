@@ -0,0 +1,92 @@
+//! Johnson のアルゴリズムで、負の重みの辺を含む疎なグラフの全点対最短経路を求める
+//!
+//! 頂点数を $`N`$ として、仮想的な頂点 $`N`$ から全頂点へ重み $`0`$ の辺を張ったグラフで [`bellman_ford`] を解き、ポテンシャル $`h`$ を求める。
+//! 辺 $`u \to v`$ の重みを $`w + h(u) - h(v) \geq 0`$ に置き換えると、各頂点を始点として [`dijkstras_algorithm`] を使えるようになる。
+//!
+//! 負閉路が存在する場合は `None` を返す。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::dijkstra::Dist;
+//! use library::graph::DirectedAdjGraph;
+//! use library::johnson::johnson;
+//!
+//! let graph = DirectedAdjGraph::from_edges(4, &[(0, 1, 3i64), (1, 2, -2), (0, 2, 2), (2, 0, 1)]);
+//!
+//! let dist = johnson(&graph).unwrap();
+//! assert_eq!(dist[0][2], Dist::VALUE(1));
+//! assert_eq!(dist[2][1], Dist::VALUE(4));
+//! assert_eq!(dist[1][0], Dist::VALUE(-1));
+//! assert_eq!(dist[0][3], Dist::UNREACHABLE);
+//!
+//! let negative = DirectedAdjGraph::from_edges(2, &[(0, 1, 1i64), (1, 0, -2)]);
+//! assert_eq!(johnson(&negative), None);
+//! ```
+//!
+//! ## 計算量
+//!
+//! グラフを $`G = (V, E)`$ として、$`O(|V| |E| \log |V|)`$ である。
+//!
+
+use crate::bellman_ford::bellman_ford;
+use crate::dijkstra::{dijkstras_algorithm, Dist};
+use crate::graph::{DirectedAdjGraph, Graph};
+use crate::integer_traits::{HasMaxValue, SaturatingAdd};
+
+/// `graph` 上で全点対の最短距離を計算する  
+/// 負閉路が存在する場合は `None` を返す
+pub fn johnson<
+    W: Default
+        + std::ops::Add<Output = W>
+        + std::ops::Sub<Output = W>
+        + Ord
+        + Copy
+        + HasMaxValue
+        + SaturatingAdd,
+>(
+    graph: &impl Graph<Weight = W>,
+) -> Option<Vec<Vec<Dist<W>>>> {
+    let size = graph.size();
+
+    let mut augmented = DirectedAdjGraph::new(size + 1);
+    for u in 0..size {
+        for &(v, w) in graph.adjacent(u) {
+            augmented.add_edge(u, v, w);
+        }
+        augmented.add_edge(size, u, W::default());
+    }
+
+    let potential = bellman_ford(&augmented, size);
+    if potential.has_negative_cycle() {
+        return None;
+    }
+
+    let h = (0..size)
+        .map(|v| match potential.get(v) {
+            Dist::VALUE(d) => d,
+            Dist::UNREACHABLE => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+
+    let mut reweighted = DirectedAdjGraph::new(size);
+    for u in 0..size {
+        for &(v, w) in graph.adjacent(u) {
+            reweighted.add_edge(u, v, w + h[u as usize] - h[v as usize]);
+        }
+    }
+
+    let dist = (0..size)
+        .map(|s| {
+            let res = dijkstras_algorithm(&reweighted, s);
+            (0..size)
+                .map(|t| match res.get(t) {
+                    Dist::VALUE(d) => Dist::VALUE(d - h[s as usize] + h[t as usize]),
+                    Dist::UNREACHABLE => Dist::UNREACHABLE,
+                })
+                .collect()
+        })
+        .collect();
+
+    Some(dist)
+}
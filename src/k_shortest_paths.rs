@@ -0,0 +1,127 @@
+//! Yen のアルゴリズムで、$`s`$ から $`t`$ への単純パス (同じ頂点を $`2`$ 回以上通らないパス) を短い順に $`k`$ 個求める
+//!
+//! パスは頂点の列として区別する。多重辺がある場合、同じ頂点の列は $`1`$ つのパスとみなし、その重みは最も軽い辺を使ったときの重みとする。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::graph::DirectedAdjGraph;
+//! use library::k_shortest_paths::k_shortest_paths;
+//!
+//! let graph = DirectedAdjGraph::from_edges(
+//!     4,
+//!     &[(0, 1, 1u32), (1, 3, 1), (0, 2, 2), (2, 3, 2), (1, 2, 1), (0, 3, 5)],
+//! );
+//!
+//! let paths = k_shortest_paths(&graph, 0, 3, 10);
+//! assert_eq!(
+//!     paths,
+//!     vec![
+//!         (2, vec![0, 1, 3]),
+//!         (4, vec![0, 1, 2, 3]),
+//!         (4, vec![0, 2, 3]),
+//!         (5, vec![0, 3]),
+//!     ]
+//! );
+//! ```
+//!
+//! ## 計算量
+//!
+//! グラフを $`G = (V, E)`$ として、各 spur node について [`dijkstras_algorithm_restore_path`] を呼ぶので $`O(k |V| (|V| + |E|) \log |V|)`$ である。
+//!
+
+use crate::dijkstra::{dijkstras_algorithm_restore_path, Dist};
+use crate::graph::{DirectedAdjGraph, Graph, Index};
+use crate::integer_traits::{HasMaxValue, SaturatingAdd};
+
+/// `graph` 上で `src` から `dst` への単純パスを短い順に最大 $`k`$ 個求める
+///
+/// 返り値は (パスの重み, パスの頂点の列) の列で、重みの昇順に並ぶ。
+pub fn k_shortest_paths<
+    W: Default + std::ops::Add<Output = W> + Ord + Copy + HasMaxValue + SaturatingAdd,
+>(
+    graph: &impl Graph<Weight = W>,
+    src: Index,
+    dst: Index,
+    k: usize,
+) -> Vec<(W, Vec<Index>)> {
+    let size = graph.size() as usize;
+    let mut result: Vec<(W, Vec<Index>)> = vec![];
+
+    if k == 0 {
+        return result;
+    }
+
+    match dijkstras_algorithm_restore_path(graph, src, dst) {
+        (Dist::VALUE(d), path) => result.push((d, path)),
+        (Dist::UNREACHABLE, _) => return result,
+    }
+
+    let mut candidates = std::collections::BinaryHeap::new();
+    let mut found = std::collections::HashSet::new();
+    found.insert(result[0].1.clone());
+
+    while result.len() < k {
+        let last = result[result.len() - 1].1.clone();
+
+        // root path の重み
+        let mut root_cost = vec![W::default()];
+        for j in 1..last.len() {
+            let w = graph
+                .adjacent(last[j - 1])
+                .iter()
+                .filter(|e| e.0 == last[j])
+                .map(|e| e.1)
+                .min()
+                .unwrap();
+            root_cost.push(root_cost[j - 1] + w);
+        }
+
+        for j in 0..last.len() - 1 {
+            let spur = last[j];
+            let root = &last[..=j];
+
+            let mut removed_vertex = vec![false; size];
+            for &v in &last[..j] {
+                removed_vertex[v as usize] = true;
+            }
+
+            let mut removed_next = vec![false; size];
+            for (_, path) in &result {
+                if path.len() > j + 1 && &path[..=j] == root {
+                    removed_next[path[j + 1] as usize] = true;
+                }
+            }
+
+            let mut spur_graph = DirectedAdjGraph::new(size as Index);
+            for u in (0..size).filter(|&u| !removed_vertex[u]) {
+                for &(v, w) in graph.adjacent(u as Index) {
+                    if removed_vertex[v as usize]
+                        || (u == spur as usize && removed_next[v as usize])
+                    {
+                        continue;
+                    }
+                    spur_graph.add_edge(u as Index, v, w);
+                }
+            }
+
+            if let (Dist::VALUE(d), spur_path) =
+                dijkstras_algorithm_restore_path(&spur_graph, spur, dst)
+            {
+                let mut path = last[..j].to_vec();
+                path.extend(spur_path);
+
+                if found.insert(path.clone()) {
+                    candidates.push(std::cmp::Reverse((root_cost[j] + d, path)));
+                }
+            }
+        }
+
+        match candidates.pop() {
+            Some(std::cmp::Reverse(p)) => result.push(p),
+            None => break,
+        }
+    }
+
+    result
+}
@@ -0,0 +1,189 @@
+/// 平面上の点集合に対して、最近傍点の探索や長方形領域内の点の列挙を行う静的な kD-tree
+///
+/// 点は整数座標 $`(x, y)`$ で与え、構築時の添字で識別する。距離はユークリッド距離の 2 乗で扱う。
+/// 座標の絶対値は $`10^9`$ 以下であることを要求する。
+///
+/// ## Examples
+///
+/// ```
+/// use library::kd_tree::KdTree;
+///
+/// let points = [(0, 0), (5, 4), (9, 6), (4, 7), (8, 1), (7, 2)];
+/// let tree = KdTree::from(&points);
+///
+/// // (6, 3) に最も近い点は (7, 2) で、距離の 2 乗は 2
+/// assert_eq!(tree.nearest((6, 3)), Some((5, 2)));
+/// assert_eq!(tree.nearest((1, 1)), Some((0, 2)));
+///
+/// // 4 <= x < 9, 0 <= y < 5 を満たす点
+/// let mut inside = tree.range(4, 9, 0, 5);
+/// inside.sort();
+/// assert_eq!(inside, [1, 4, 5]);
+///
+/// assert_eq!(KdTree::from(&[]).nearest((0, 0)), None);
+/// ```
+///
+/// ## 計算量
+///
+/// 点の数を $`N`$ とする。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `from(points)` | kD-tree を構築する | $`O(N \log N)`$ |
+/// | `self.nearest(p)` | $`p`$ に最も近い点を求める | 最悪 $`O(N)`$、点がランダムに分布していれば期待 $`O(\log N)`$ |
+/// | `self.range(xl, xr, yl, yr)` | 長方形領域内の点を列挙する | 列挙する点の数を $`K`$ として $`O(\sqrt{N} + K)`$ |
+///
+pub struct KdTree {
+    // 各部分木を配列上の区間 [l, r) で表し、その中央 m = (l + r) / 2 を部分木の根とする
+    points: Vec<(i64, i64)>,
+    index: Vec<usize>,
+    // bbox[m] = m を根とする部分木に含まれる点の (x の最小値, x の最大値, y の最小値, y の最大値)
+    bbox: Vec<(i64, i64, i64, i64)>,
+}
+
+impl KdTree {
+    /// 点集合 `points` から kD-tree を構築する
+    pub fn from(points: &[(i64, i64)]) -> Self {
+        let n = points.len();
+        let mut order = (0..n).collect::<Vec<_>>();
+        let mut bbox = vec![(0, 0, 0, 0); n];
+
+        // (l, r, x 座標で分割するか)
+        let mut stack = vec![(0, n, true)];
+
+        while let Some((l, r, divide_x)) = stack.pop() {
+            if l >= r {
+                continue;
+            }
+
+            let m = (l + r) / 2;
+
+            let mut b = (i64::MAX, i64::MIN, i64::MAX, i64::MIN);
+            for &i in order[l..r].iter() {
+                let (x, y) = points[i];
+                b = (
+                    std::cmp::min(b.0, x),
+                    std::cmp::max(b.1, x),
+                    std::cmp::min(b.2, y),
+                    std::cmp::max(b.3, y),
+                );
+            }
+            bbox[m] = b;
+
+            if divide_x {
+                order[l..r].select_nth_unstable_by_key(m - l, |&i| points[i].0);
+            } else {
+                order[l..r].select_nth_unstable_by_key(m - l, |&i| points[i].1);
+            }
+
+            stack.push((l, m, !divide_x));
+            stack.push((m + 1, r, !divide_x));
+        }
+
+        Self {
+            points: order.iter().map(|&i| points[i]).collect(),
+            index: order,
+            bbox,
+        }
+    }
+
+    /// 点の数を返す
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// 点集合が空であるかを返す
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// `p` に最も近い点の添字と、その点との距離の 2 乗を返す
+    ///
+    /// 最も近い点が複数ある場合は、そのうちのいずれかを返す。点集合が空の場合は `None` を返す。
+    pub fn nearest(&self, p: (i64, i64)) -> Option<(usize, i64)> {
+        let mut best = None;
+        self._nearest(0, self.len(), true, p, &mut best);
+        best.map(|(m, d)| (self.index[m], d))
+    }
+
+    fn _nearest(
+        &self,
+        l: usize,
+        r: usize,
+        divide_x: bool,
+        p: (i64, i64),
+        best: &mut Option<(usize, i64)>,
+    ) {
+        if l >= r {
+            return;
+        }
+
+        let m = (l + r) / 2;
+
+        // 部分木の点を囲む長方形までの距離が、これまでの最小値以上なら探索しない
+        let (x_min, x_max, y_min, y_max) = self.bbox[m];
+        let dx = std::cmp::max(0, std::cmp::max(x_min - p.0, p.0 - x_max));
+        let dy = std::cmp::max(0, std::cmp::max(y_min - p.1, p.1 - y_max));
+
+        if let Some((_, d)) = *best {
+            if dx * dx + dy * dy >= d {
+                return;
+            }
+        }
+
+        let (x, y) = self.points[m];
+        let d = (x - p.0) * (x - p.0) + (y - p.1) * (y - p.1);
+
+        if best.map_or(true, |(_, e)| d < e) {
+            *best = Some((m, d));
+        }
+
+        // p がある側から先に探索する
+        let left_first = if divide_x { p.0 < x } else { p.1 < y };
+
+        if left_first {
+            self._nearest(l, m, !divide_x, p, best);
+            self._nearest(m + 1, r, !divide_x, p, best);
+        } else {
+            self._nearest(m + 1, r, !divide_x, p, best);
+            self._nearest(l, m, !divide_x, p, best);
+        }
+    }
+
+    /// $`\text{xl} \leq x < \text{xr}`$ かつ $`\text{yl} \leq y < \text{yr}`$ を満たす点の添字を列挙する
+    ///
+    /// 添字の順番は不定である。
+    pub fn range(&self, xl: i64, xr: i64, yl: i64, yr: i64) -> Vec<usize> {
+        let mut ret = vec![];
+        let mut stack = vec![(0, self.len())];
+
+        while let Some((l, r)) = stack.pop() {
+            if l >= r {
+                continue;
+            }
+
+            let m = (l + r) / 2;
+            let (x_min, x_max, y_min, y_max) = self.bbox[m];
+
+            if x_max < xl || xr <= x_min || y_max < yl || yr <= y_min {
+                continue;
+            }
+
+            if xl <= x_min && x_max < xr && yl <= y_min && y_max < yr {
+                ret.extend_from_slice(&self.index[l..r]);
+                continue;
+            }
+
+            let (x, y) = self.points[m];
+
+            if xl <= x && x < xr && yl <= y && y < yr {
+                ret.push(self.index[m]);
+            }
+
+            stack.push((l, m));
+            stack.push((m + 1, r));
+        }
+
+        ret
+    }
+}
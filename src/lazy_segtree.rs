@@ -0,0 +1,246 @@
+use crate::algebra::{ActedMonoid, Monoid};
+
+/// 区間作用と区間積の計算を行える遅延評価セグメント木
+///
+/// よく使う作用付きモノイドは [`algebra`](crate::algebra) にある。
+///
+/// ## Examples
+///
+/// ```
+/// use library::algebra::{RangeAddRangeMin, RangeAddRangeSum};
+/// use library::lazy_segtree::LazySegmentTree;
+///
+/// let a = [3, 1, 4, 1, 5].map(|x| (x, 1));
+/// let mut stree: LazySegmentTree<RangeAddRangeSum<i64>> = LazySegmentTree::from(&a);
+///
+/// assert_eq!(stree.prod(1..4).0, 6);
+/// stree.apply(0..3, 10);
+/// assert_eq!(stree.prod(1..4).0, 26);
+/// assert_eq!(stree.get(2), (14, 1));
+///
+/// let mut stree: LazySegmentTree<RangeAddRangeMin<i64>> = LazySegmentTree::from(&[3, 1, 4, 1, 5]);
+///
+/// stree.apply(1..=3, 5);
+/// assert_eq!(stree.prod(..), 3);
+/// stree.insert(4, -2);
+/// assert_eq!(stree.prod(2..), -2);
+/// ```
+///
+/// ## 計算量
+///
+/// 作用付きモノイド `A` の各演算が $`O(1)`$ で行えるとする。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `new(size)` | 大きさ `size` で各要素が単位元の `LazySegmentTree` を生成する | $`O(\text{size})`$ |
+/// | `from(array)` | `array` から `LazySegmentTree` を生成する | $`O(\lvert \text{array} \rvert)`$ |
+/// | `self.insert(i, s)` | $`i`$ 番目の要素を $`s`$ に更新する | $`O(\log(\text{self.size}))`$ |
+/// | `self.get(i)` | $`i`$ 番目の要素を返す | $`O(\log(\text{self.size}))`$ |
+/// | `self.prod(range)` | `range` 内の要素の総積を求める | $`O(\log(\text{self.size}))`$ |
+/// | `self.apply(range, f)` | `range` 内の要素に $`f`$ を作用させる | $`O(\log(\text{self.size}))`$ |
+///
+/// ## Verified problems
+///
+/// * [Range Affine Range Sum](../../src/lc_range_affine_range_sum/lc_range_affine_range_sum.rs.html)
+///
+pub struct LazySegmentTree<A: ActedMonoid> {
+    len: usize,
+    size: usize,
+    log: u32,
+    tree: Vec<<A::M as Monoid>::S>,
+    lazy: Vec<A::F>,
+}
+
+impl<A: ActedMonoid> LazySegmentTree<A> {
+    /// 大きさ `size` で、すべての要素が単位元である `LazySegmentTree<A>` を生成する
+    pub fn new(size: usize) -> Self {
+        Self::from(&vec![<A::M as Monoid>::E; size])
+    }
+
+    /// `array` から `LazySegmentTree` を生成する
+    pub fn from(array: &[<A::M as Monoid>::S]) -> Self {
+        let log = array.len().next_power_of_two().trailing_zeros();
+        let size = 1 << log;
+
+        let mut tree = vec![<A::M as Monoid>::E; size << 1];
+        tree[size..size + array.len()].clone_from_slice(array);
+
+        for i in (1..size).rev() {
+            tree[i] = A::M::op(&tree[i << 1], &tree[i << 1 | 1]);
+        }
+
+        Self {
+            len: array.len(),
+            size,
+            log,
+            tree,
+            lazy: vec![A::ID; size],
+        }
+    }
+
+    /// 要素数を返す
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 要素数が $`0`$ かどうかを返す
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// $`i`$ 番目の要素を `s` に変更する
+    pub fn insert(&mut self, i: usize, s: <A::M as Monoid>::S) {
+        assert!(i < self.len);
+        let i = i + self.size;
+
+        for k in (1..=self.log).rev() {
+            self.push(i >> k);
+        }
+
+        self.tree[i] = s;
+
+        for k in 1..=self.log {
+            self.update(i >> k);
+        }
+    }
+
+    /// $`i`$ 番目の要素を返す
+    pub fn get(&mut self, i: usize) -> <A::M as Monoid>::S {
+        assert!(i < self.len);
+        let i = i + self.size;
+
+        for k in (1..=self.log).rev() {
+            self.push(i >> k);
+        }
+
+        self.tree[i].clone()
+    }
+
+    /// $`\displaystyle \prod_{i \in \text{range}} \text{self} \lbrack i \rbrack`$ を返す
+    pub fn prod<R: std::ops::RangeBounds<usize>>(&mut self, range: R) -> <A::M as Monoid>::S {
+        let (mut left, mut right) = self.bounds(range);
+
+        if left == right {
+            return <A::M as Monoid>::E;
+        }
+
+        left += self.size;
+        right += self.size;
+
+        for k in (1..=self.log).rev() {
+            if ((left >> k) << k) != left {
+                self.push(left >> k);
+            }
+
+            if ((right >> k) << k) != right {
+                self.push((right - 1) >> k);
+            }
+        }
+
+        let (mut sl, mut sr) = (<A::M as Monoid>::E, <A::M as Monoid>::E);
+
+        while left < right {
+            if left & 1 == 1 {
+                sl = A::M::op(&sl, &self.tree[left]);
+                left += 1;
+            }
+
+            if right & 1 == 1 {
+                right ^= 1;
+                sr = A::M::op(&self.tree[right], &sr);
+            }
+
+            left >>= 1;
+            right >>= 1;
+        }
+
+        A::M::op(&sl, &sr)
+    }
+
+    /// `range` 内の要素に $`f`$ を作用させる
+    pub fn apply<R: std::ops::RangeBounds<usize>>(&mut self, range: R, f: A::F) {
+        let (mut left, mut right) = self.bounds(range);
+
+        if left == right {
+            return;
+        }
+
+        left += self.size;
+        right += self.size;
+
+        for k in (1..=self.log).rev() {
+            if ((left >> k) << k) != left {
+                self.push(left >> k);
+            }
+
+            if ((right >> k) << k) != right {
+                self.push((right - 1) >> k);
+            }
+        }
+
+        {
+            let (mut left, mut right) = (left, right);
+
+            while left < right {
+                if left & 1 == 1 {
+                    self.apply_node(left, &f);
+                    left += 1;
+                }
+
+                if right & 1 == 1 {
+                    right ^= 1;
+                    self.apply_node(right, &f);
+                }
+
+                left >>= 1;
+                right >>= 1;
+            }
+        }
+
+        for k in 1..=self.log {
+            if ((left >> k) << k) != left {
+                self.update(left >> k);
+            }
+
+            if ((right >> k) << k) != right {
+                self.update((right - 1) >> k);
+            }
+        }
+    }
+
+    fn bounds<R: std::ops::RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let left = match range.start_bound() {
+            std::ops::Bound::Included(&l) => l,
+            std::ops::Bound::Excluded(&l) => l + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+
+        let right = match range.end_bound() {
+            std::ops::Bound::Included(&r) => r + 1,
+            std::ops::Bound::Excluded(&r) => r,
+            std::ops::Bound::Unbounded => self.len,
+        };
+
+        assert!(left <= right && right <= self.len);
+
+        (left, right)
+    }
+
+    fn update(&mut self, k: usize) {
+        self.tree[k] = A::M::op(&self.tree[k << 1], &self.tree[k << 1 | 1]);
+    }
+
+    fn apply_node(&mut self, k: usize, f: &A::F) {
+        self.tree[k] = A::mapping(f, &self.tree[k]);
+
+        if k < self.size {
+            self.lazy[k] = A::composition(f, &self.lazy[k]);
+        }
+    }
+
+    fn push(&mut self, k: usize) {
+        let f = std::mem::replace(&mut self.lazy[k], A::ID);
+        self.apply_node(k << 1, &f);
+        self.apply_node(k << 1 | 1, &f);
+    }
+}
@@ -0,0 +1,182 @@
+/// 直線・線分の追加と、ある点での最小値を求めるクエリを処理する Li Chao Tree
+///
+/// クエリで使う $`x`$ 座標をすべて先読みして、それらを葉に持つ完全二分木を作る。
+/// 各ノードには、そのノードの区間の中央で最小となる直線を $`1`$ 本だけ持たせる。
+///
+/// 値の計算は `i64` で行うので、$`ax + b`$ がオーバーフローしないことを要求する。
+///
+/// ## Examples
+///
+/// ```
+/// use library::li_chao_tree::LiChaoTree;
+///
+/// let mut lct = LiChaoTree::from(&[-2, 0, 1, 3, 5]);
+///
+/// assert_eq!(lct.min(0), None);
+///
+/// // y = x + 1
+/// lct.add_line(1, 1);
+/// // y = -x + 2
+/// lct.add_line(-1, 2);
+/// assert_eq!(lct.min(-2), Some(-1));
+/// assert_eq!(lct.min(1), Some(1));
+/// assert_eq!(lct.min(5), Some(-3));
+///
+/// // x が [0, 3) の範囲に y = -5 を追加する
+/// lct.add_segment(0, -5, 0, 3);
+/// assert_eq!(lct.min(0), Some(-5));
+/// assert_eq!(lct.min(1), Some(-5));
+/// assert_eq!(lct.min(3), Some(-1));
+/// ```
+///
+/// ## 計算量
+///
+/// 先読みした $`x`$ 座標の数を $`N`$ とする。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `from(xs)` | $`x`$ 座標の集合 `xs` から空の `LiChaoTree` を構築する | $`O(N \log N)`$ |
+/// | `self.add_line(a, b)` | 直線 $`y = ax + b`$ を追加する | $`O(\log N)`$ |
+/// | `self.add_segment(a, b, l, r)` | $`l \leq x < r`$ の範囲に線分 $`y = ax + b`$ を追加する | $`O(\log^2 N)`$ |
+/// | `self.min(x)` | $`x`$ での最小値を求める | $`O(\log N)`$ |
+///
+/// ## Verified problems
+///
+/// * [Line Add Get Min](../../src/lc_line_add_get_min/lc_line_add_get_min.rs.html)
+/// * [Segment Add Get Min](../../src/lc_segment_add_get_min/lc_segment_add_get_min.rs.html)
+///
+pub struct LiChaoTree {
+    xs: Vec<i64>,
+    size: usize,
+    lines: Vec<Option<(i64, i64)>>,
+}
+
+impl LiChaoTree {
+    /// $`x`$ 座標の集合 `xs` から、直線を $`1`$ 本も持たない `LiChaoTree` を構築する
+    pub fn from(xs: &[i64]) -> Self {
+        let mut xs = xs.to_vec();
+        xs.sort();
+        xs.dedup();
+
+        let size = xs.len().next_power_of_two();
+
+        Self {
+            xs,
+            size,
+            lines: vec![None; size << 1],
+        }
+    }
+
+    /// 直線 $`y = ax + b`$ を追加する
+    pub fn add_line(&mut self, a: i64, b: i64) {
+        if !self.xs.is_empty() {
+            self.insert(1, 0, self.size, (a, b));
+        }
+    }
+
+    /// $`l \leq x < r`$ の範囲に線分 $`y = ax + b`$ を追加する
+    pub fn add_segment(&mut self, a: i64, b: i64, l: i64, r: i64) {
+        let li = self.xs.partition_point(|&x| x < l);
+        let ri = self.xs.partition_point(|&x| x < r);
+
+        if li < ri {
+            self.insert_range(1, 0, self.size, li, ri, (a, b));
+        }
+    }
+
+    /// $`x`$ での最小値を求める  
+    /// 直線が $`1`$ 本もなければ `None` を返す。$`x`$ は構築時に与えた座標でなければならない
+    pub fn min(&self, x: i64) -> Option<i64> {
+        let i = self
+            .xs
+            .binary_search(&x)
+            .expect("x is not in the given coordinates");
+
+        let mut node = i + self.size;
+        let mut ret = None;
+
+        while node > 0 {
+            if let Some(line) = self.lines[node] {
+                let y = Self::eval(line, x);
+                ret = Some(ret.map_or(y, |r: i64| r.min(y)));
+            }
+
+            node >>= 1;
+        }
+
+        ret
+    }
+
+    fn eval((a, b): (i64, i64), x: i64) -> i64 {
+        a * x + b
+    }
+
+    fn x(&self, i: usize) -> i64 {
+        self.xs[i.min(self.xs.len() - 1)]
+    }
+
+    /// ノード `node` (添字の区間 $`\lbrack l, r)`$ に対応する) 以下に直線を追加する
+    fn insert(&mut self, mut node: usize, mut l: usize, mut r: usize, mut line: (i64, i64)) {
+        loop {
+            let cur = match self.lines[node] {
+                Some(cur) => cur,
+                None => {
+                    self.lines[node] = Some(line);
+                    return;
+                }
+            };
+
+            let (xl, xr) = (self.x(l), self.x(r - 1));
+            let left = Self::eval(line, xl) < Self::eval(cur, xl);
+            let right = Self::eval(line, xr) < Self::eval(cur, xr);
+
+            if left && right {
+                self.lines[node] = Some(line);
+                return;
+            }
+
+            if !left && !right {
+                return;
+            }
+
+            let m = (l + r) / 2;
+            let xm = self.x(m);
+
+            if Self::eval(line, xm) < Self::eval(cur, xm) {
+                self.lines[node] = Some(line);
+                line = cur;
+            }
+
+            if Self::eval(line, xl) < Self::eval(self.lines[node].unwrap(), xl) {
+                node <<= 1;
+                r = m;
+            } else {
+                node = node << 1 | 1;
+                l = m;
+            }
+        }
+    }
+
+    fn insert_range(
+        &mut self,
+        node: usize,
+        l: usize,
+        r: usize,
+        ql: usize,
+        qr: usize,
+        line: (i64, i64),
+    ) {
+        if qr <= l || r <= ql {
+            return;
+        }
+
+        if ql <= l && r <= qr {
+            self.insert(node, l, r, line);
+            return;
+        }
+
+        let m = (l + r) / 2;
+        self.insert_range(node << 1, l, m, ql, qr, line);
+        self.insert_range(node << 1 | 1, m, r, ql, qr, line);
+    }
+}
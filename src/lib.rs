@@ -4,61 +4,185 @@
 //!
 //! * [累積和](cumulative_sum/struct.CumulativeSum.html)
 //! * [Binary Indexed Tree](binary_indexed_tree/struct.BinaryIndexedTree.html)
+//! * [区間加算ができる Binary Indexed Tree](binary_indexed_tree/struct.RangeBinaryIndexedTree.html)
+//! * [可換群を載せる Binary Indexed Tree](binary_indexed_tree/struct.GroupBinaryIndexedTree.html)
 //! * [動的Binary Indexed Tree](dynamic_binary_indexed_tree/struct.DynamicBinaryIndexedTree.html)
+//! * [動的 2 次元 Binary Indexed Tree](dynamic_binary_indexed_tree/struct.DynamicBinaryIndexedTree2D.html)
 //! * [Segment Tree](segtree/struct.SegmentTree.html)
+//! * [遅延評価 Segment Tree](lazy_segtree/struct.LazySegmentTree.html)
 //! * [Wavelet Matrix](wavelet_matrix/struct.WaveletMatrix.html)
+//! * [一点更新ができる Wavelet Matrix](wavelet_matrix/struct.DynamicWaveletMatrix.html)
 //! * [Sparse Table](sparse_table/struct.SparseTable.html)
+//! * [区間の種類数](range_distinct_count/fn.range_distinct_count.html)
+//! * [区間の最頻値](range_mode/struct.RangeMode.html)
+//! * [オフライン長方形和](offline_rectangle_sum/struct.OfflineRectangleSum.html)
+//! * [Mo's algorithm・一点更新つき Mo's algorithm](mo/index.html)
 //!  
 //! ## グラフ
 //!
 //! * [グラフのための構造体・トレイト](graph/index.html)
+//! * [グリッドからグラフへの変換](grid/index.html)
 //! * [Dijkstra法](dijkstra/index.html)
+//! * [Bellman-Ford 法](bellman_ford/index.html)
+//! * [Floyd-Warshall 法](floyd_warshall/index.html)
+//! * [Johnson のアルゴリズム](johnson/index.html)
+//! * [k 番目までの最短単純パス (Yen のアルゴリズム)](k_shortest_paths/index.html)
+//! * [辺の本数を指定した最短経路 ((min, +) 行列累乗)](min_plus/index.html)
+//! * [ウォークの数え上げ](walk_count/index.html)
 //! * [LowLink(橋・関節点)](lowlink/struct.LowLink.html)
 //! * [強連結成分分解](scc/fn.strongly_connected_components.html)
+//! * [支配木](dominator_tree/fn.dominator_tree.html)
+//! * [トポロジカルソート・DAG 上の最長路・パスの数え上げ](dag/index.html)
+//! * [Borůvka 法 (最小全域木)](boruvka/index.html)
+//! * [最小全域有向木](arborescence/fn.minimum_spanning_arborescence.html)
+//! * [最大独立集合](maximum_independent_set/fn.maximum_independent_set.html)
+//! * [極大クリークの列挙・最大クリーク](clique/index.html)
+//! * [三角形の列挙](triangle/index.html)
+//! * [割当問題 (ハンガリー法)](hungarian/fn.assignment.html)
+//! * [最大流 (Dinic 法)](max_flow/struct.MaxFlow.html)
+//! * [燃やす埋める問題](project_selection/struct.ProjectSelection.html)
+//! * [巡回セールスマン問題](tsp/index.html)
 //!
 //! ## 木
 //!
+//! * [根付き木](rooted_tree/struct.RootedTree.html)
 //! * [木の直径](tree_diameter/fn.tree_diameter.html)
+//! * [オフライン LCA](offline_lca/fn.offline_lca.html)
+//! * [LCA・2 頂点間の距離](tree_dist/struct.TreeDist.html)
+//! * [部分木・パスの総和](subtree_sum/struct.SubtreeSum.html)
+//! * [オイラーツアー](euler_tour/struct.EulerTour.html)
+//! * [木の同型性判定](tree_isomorphism/struct.TreeIsomorphism.html)
 //!
 //! ## 整数
 //!
 //! * [素因数分解](prime_factorize/fn.prime_factorize.html)
+//! * [畳み込み (NTT)](convolution/fn.convolution.html)
+//! * [形式的冪級数](fps/index.html)
+//! * [線形漸化式の第 K 項 (Bostan–Mori)](fps/fn.kth_term_of_linear_recurrence.html)
+//! * [スターリング数・ベル数](stirling/index.html)
+//! * [ベルヌーイ数・冪乗の和](bernoulli/index.html)
+//! * [行列・掃き出し法](matrix/struct.Matrix.html)
+//! * [$`\mathbb{F}_2`$ 上の行列](matrix/struct.BitMatrix.html)
 //!
 //! ## 文字列
 //!
 //! * [Rolling Hash](rolling_hash/struct.RollingHash.html)
+//! * [一点更新ができる Rolling Hash](rolling_hash/struct.UpdatableRollingHash.html)
+//! * [Trie](trie/struct.Trie.html)
+//! * [接尾辞配列 (SA-IS)・LCP 配列](suffix_array/index.html)
+//! * [回文木](palindromic_tree/struct.PalindromicTree.html)
+//! * [Lyndon 分解](lyndon/index.html)
+//!
+//! ## 動的計画法
+//!
+//! * [編集距離](edit_distance/index.html)
+//! * [最長共通部分列](longest_common_subsequence/fn.longest_common_subsequence.html)
+//! * [最長増加部分列](longest_increasing_subsequence/fn.longest_increasing_subsequence.html)
+//! * [Monotone minima・SMAWK・分割統治 DP 高速化](monotone_minima/index.html)
+//! * [Aliens DP](aliens_trick/fn.aliens_trick.html)
 //!
 //! ## それ以外のアルゴリズム・データ構造
 //!
 //! * [代数的構造の構造体・トレイト](algebra/index.html)
 //! * [ModInt](modint/struct.ModInt.html)
+//! * [二分探索](binary_search/index.html)
 //! * [座標圧縮](coordinate_compression/struct.CoordinateCompress.html)
 //! * [Union-Find](unionfind/struct.UnionFind.html)
+//! * [ポテンシャル付き Union-Find](unionfind/struct.PotentializedUnionFind.html)
+//! * [部分永続 Union-Find](unionfind/struct.PartiallyPersistentUnionFind.html)
+//! * [データ付き Union-Find](unionfind/struct.UnionFindWithData.html)
 //! * [ダブリング](doubling/struct.Doubling.html)
+//! * [置換](permutation/struct.Permutation.html)
 //! * [Binary Trie](binary_trie/struct.MultiBinaryTrie.html)
 //! * [Fast Set](fastset/struct.FastSet.html)
+//! * [総積を計算できる両端キュー](foldable_deque/struct.FoldableDeque.html)
+//! * [Li Chao Tree](li_chao_tree/struct.LiChaoTree.html)
+//! * [kD-tree](kd_tree/struct.KdTree.html)
+//! * [SortedList (平方分割による順序付き多重集合)](sorted_list/struct.SortedList.html)
+//! * [座標圧縮と Binary Indexed Tree による順序付き多重集合](compressed_multiset/struct.CompressedMultiset.html)
+//! * [永続平衡二分探索木](persistent_treap/struct.PersistentTreap.html)
+//! * [線分の交差判定](segment_intersection/index.html)
+//! * [ランレングス圧縮](run_length_encoding/index.html)
+//! * [デバッグ出力](debug/index.html)
+//! * [ランダムなテストケースの生成](testgen/index.html)
+//! * [愚直な実装](naive/index.html)
 //!
 
 pub mod algebra;
+pub mod aliens_trick;
+pub mod arborescence;
+pub mod bellman_ford;
+pub mod bernoulli;
 pub mod binary_indexed_tree;
+pub mod binary_search;
 pub mod binary_trie;
+pub mod boruvka;
+pub mod clique;
+pub mod compressed_multiset;
+pub mod convolution;
 pub mod coordinate_compression;
 pub mod cumulative_sum;
 pub mod cycle_detection;
+pub mod dag;
+pub mod debug;
 pub mod dijkstra;
+pub mod dominator_tree;
 pub mod doubling;
 pub mod dynamic_binary_indexed_tree;
+pub mod edit_distance;
+pub mod euler_tour;
 pub mod fastset;
+pub mod floyd_warshall;
+pub mod foldable_deque;
+pub mod fps;
 pub mod graph;
+pub mod grid;
+pub mod hungarian;
 pub mod integer_traits;
+pub mod johnson;
+pub mod k_shortest_paths;
+pub mod kd_tree;
+pub mod lazy_segtree;
+pub mod li_chao_tree;
+pub mod longest_common_subsequence;
+pub mod longest_increasing_subsequence;
 pub mod lowlink;
+pub mod lyndon;
+pub mod matrix;
+pub mod max_flow;
+pub mod maximum_independent_set;
+pub mod min_plus;
+pub mod mo;
 pub mod modint;
+pub mod monotone_minima;
+pub mod naive;
+pub mod offline_lca;
+pub mod offline_rectangle_sum;
+pub mod palindromic_tree;
+pub mod permutation;
+pub mod persistent_treap;
 pub mod prime_factorize;
+pub mod project_selection;
+pub mod range_distinct_count;
+pub mod range_mode;
 pub mod rolling_hash;
+pub mod rooted_tree;
+pub mod run_length_encoding;
 pub mod scc;
+pub mod segment_intersection;
 pub mod segtree;
+pub mod sorted_list;
 pub mod sparse_table;
+pub mod stirling;
+pub mod subtree_sum;
+pub mod suffix_array;
+pub mod testgen;
 pub mod tree_diameter;
+pub mod tree_dist;
+pub mod tree_isomorphism;
+pub mod triangle;
+pub mod trie;
 pub mod tsp;
 pub mod unionfind;
+pub mod walk_count;
 pub mod wavelet_matrix;
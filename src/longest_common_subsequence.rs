@@ -0,0 +1,62 @@
+/// 最長共通部分列を求める
+///
+/// 列 `a` と列 `b` の最長共通部分列のうちの一つを、添字の組 $`(i_0, j_0), (i_1, j_1), \dots`$ の列として返す。
+/// $`i_0 < i_1 < \dots`$ かつ $`j_0 < j_1 < \dots`$ であり、$`a_{i_k} = b_{j_k}`$ を満たす。返り値の長さが最長共通部分列の長さである。
+///
+/// ## Examples
+///
+/// ```
+/// use library::longest_common_subsequence::longest_common_subsequence;
+///
+/// let a = "axyb".chars().collect::<Vec<_>>();
+/// let b = "abyxb".chars().collect::<Vec<_>>();
+///
+/// let lcs = longest_common_subsequence(&a, &b);
+/// assert_eq!(lcs.len(), 3);
+///
+/// let s = lcs.iter().map(|&(i, _)| a[i]).collect::<String>();
+/// assert!(s == "axb" || s == "ayb");
+///
+/// for &(i, j) in lcs.iter() {
+///     assert_eq!(a[i], b[j]);
+/// }
+/// ```
+///
+/// ## 計算量
+///
+/// 列の長さをそれぞれ $`N, M`$ とし、要素の比較が $`O(1)`$ で行えることを仮定する。このとき、$`O(NM)`$ である。
+///
+pub fn longest_common_subsequence<T: PartialEq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    let (n, m) = (a.len(), b.len());
+    let flatten = |i: usize, j: usize| i * (m + 1) + j;
+
+    let mut dp = vec![0u32; (n + 1) * (m + 1)];
+
+    for i in 0..n {
+        for j in 0..m {
+            dp[flatten(i + 1, j + 1)] = if a[i] == b[j] {
+                dp[flatten(i, j)] + 1
+            } else {
+                std::cmp::max(dp[flatten(i, j + 1)], dp[flatten(i + 1, j)])
+            };
+        }
+    }
+
+    let mut lcs = vec![];
+    let (mut i, mut j) = (n, m);
+
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            lcs.push((i - 1, j - 1));
+            i -= 1;
+            j -= 1;
+        } else if dp[flatten(i - 1, j)] >= dp[flatten(i, j - 1)] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    lcs.reverse();
+    lcs
+}
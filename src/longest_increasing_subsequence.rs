@@ -0,0 +1,75 @@
+/// 最長増加部分列を求める
+///
+/// 列 `a` の最長増加部分列のうちの一つを、添字の列 $`i_0 < i_1 < \dots < i_{L - 1}`$ として返す。返り値の長さ $`L`$ が最長増加部分列の長さである。
+///
+/// * `strict` が `true` のとき、$`a_{i_0} < a_{i_1} < \dots`$ を満たす(狭義単調増加)。
+/// * `strict` が `false` のとき、$`a_{i_0} \leq a_{i_1} \leq \dots`$ を満たす(広義単調増加)。
+///
+/// ## Examples
+///
+/// ```
+/// use library::longest_increasing_subsequence::longest_increasing_subsequence;
+///
+/// let a = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+///
+/// let lis = longest_increasing_subsequence(&a, true);
+/// assert_eq!(lis.len(), 4);
+/// assert!(lis.windows(2).all(|w| w[0] < w[1] && a[w[0]] < a[w[1]]));
+///
+/// // 広義単調増加なら 1, 1, 2, 3 などがとれる
+/// let lis = longest_increasing_subsequence(&a, false);
+/// assert_eq!(lis.len(), 4);
+/// assert!(lis.windows(2).all(|w| w[0] < w[1] && a[w[0]] <= a[w[1]]));
+///
+/// let lis = longest_increasing_subsequence(&[1, 1, 1], false);
+/// assert_eq!(lis, [0, 1, 2]);
+/// ```
+///
+/// ## 計算量
+///
+/// 列 `a` の長さを $`N`$ とし、要素の比較が $`O(1)`$ で行えることを仮定する。このとき、$`O(N \log N)`$ である。
+///
+pub fn longest_increasing_subsequence<T: Ord>(a: &[T], strict: bool) -> Vec<usize> {
+    const NONE: usize = usize::MAX;
+
+    // tails[k] = 長さ k + 1 の増加部分列の末尾としてありうるもので、値が最小のものの添字
+    let mut tails: Vec<usize> = vec![];
+    let mut prev = vec![NONE; a.len()];
+
+    for (i, x) in a.iter().enumerate() {
+        let k = if strict {
+            tails.partition_point(|&t| a[t] < *x)
+        } else {
+            tails.partition_point(|&t| a[t] <= *x)
+        };
+
+        if k > 0 {
+            prev[i] = tails[k - 1];
+        }
+
+        if k == tails.len() {
+            tails.push(i);
+        } else {
+            tails[k] = i;
+        }
+    }
+
+    let mut lis = vec![];
+    let mut v = match tails.last() {
+        Some(&v) => v,
+        None => return lis,
+    };
+
+    loop {
+        lis.push(v);
+
+        if prev[v] == NONE {
+            break;
+        }
+
+        v = prev[v];
+    }
+
+    lis.reverse();
+    lis
+}
@@ -48,58 +48,73 @@ impl LowLink {
         };
 
         for i in 0..size {
-            lowlink.dfs(graph, i, Self::ROOT, 0);
+            lowlink.dfs(graph, i);
         }
 
         lowlink
     }
 
-    fn dfs(&mut self, graph: &impl UndirectedGraph, v: u32, parent: u32, mut cnt: u32) {
-        if self.seen[v as usize] {
+    fn dfs(&mut self, graph: &impl UndirectedGraph, root: u32) {
+        if self.seen[root as usize] {
             return;
         }
 
-        self.seen[v as usize] = true;
-        self.ord[v as usize] = cnt;
-        self.low[v as usize] = cnt;
-        cnt += 1;
+        // 再帰の代わりに、(頂点, 親, 次に見る辺の番号, 子の数, 関節点か) を積んだスタックで DFS する
+        let mut stack = vec![(root, Self::ROOT, 0, 0, false)];
+        self.seen[root as usize] = true;
+        self.ord[root as usize] = 0;
+        self.low[root as usize] = 0;
+
+        while let Some(&mut (v, parent, ref mut idx, ref mut child_cnt, _)) = stack.last_mut() {
+            let adjacent = graph.adjacent(v);
+
+            if *idx < adjacent.len() {
+                let u = adjacent[*idx].0;
+                *idx += 1;
+
+                if self.seen[u as usize] {
+                    if u != parent {
+                        self.low[v as usize] =
+                            std::cmp::min(self.low[v as usize], self.ord[u as usize]);
+                    }
+                } else {
+                    *child_cnt += 1;
+
+                    self.seen[u as usize] = true;
+                    self.ord[u as usize] = self.ord[v as usize] + 1;
+                    self.low[u as usize] = self.ord[v as usize] + 1;
+                    stack.push((u, v, 0, 0, false));
+                }
 
-        let mut child_cnt = 0;
-        let mut is_articulation_point = false;
+                continue;
+            }
 
-        for &(u, _) in graph.adjacent(v) {
-            if self.seen[u as usize] {
-                if u != parent {
-                    self.low[v as usize] =
-                        std::cmp::min(self.low[v as usize], self.ord[u as usize]);
-                }
-            } else {
-                child_cnt += 1;
-                self.dfs(graph, u, v, cnt);
+            let (_, _, _, child_cnt, mut is_articulation_point) = stack.pop().unwrap();
 
-                if u != parent {
-                    self.low[v as usize] =
-                        std::cmp::min(self.low[v as usize], self.low[u as usize]);
-                }
+            if parent == Self::ROOT && child_cnt >= 2 {
+                is_articulation_point = true;
+            }
+
+            if is_articulation_point {
+                self.articulation_points.push(v);
+            }
+
+            // 親に戻って、子 v の結果を反映する
+            if let Some(&mut (p, grandparent, _, _, ref mut is_articulation_point)) =
+                stack.last_mut()
+            {
+                self.low[p as usize] = std::cmp::min(self.low[p as usize], self.low[v as usize]);
 
-                if parent != Self::ROOT && self.ord[v as usize] <= self.low[u as usize] {
-                    is_articulation_point = true;
+                if grandparent != Self::ROOT && self.ord[p as usize] <= self.low[v as usize] {
+                    *is_articulation_point = true;
                 }
 
-                if self.ord[v as usize] < self.low[u as usize] {
-                    let (a, b) = (std::cmp::min(u, v), std::cmp::max(u, v));
+                if self.ord[p as usize] < self.low[v as usize] {
+                    let (a, b) = (std::cmp::min(p, v), std::cmp::max(p, v));
                     self.bridges.push((a, b));
                 }
             }
         }
-
-        if parent == Self::ROOT && child_cnt >= 2 {
-            is_articulation_point = true;
-        }
-
-        if is_articulation_point {
-            self.articulation_points.push(v);
-        }
     }
 
     /// 求めた関節点を列挙する。
@@ -0,0 +1,95 @@
+//! Lyndon 分解
+//!
+//! 列 $`s`$ が、その真の接尾辞のいずれよりも辞書順で真に小さいとき、$`s`$ を Lyndon 語という。
+//! 任意の列は、辞書順で広義単調減少な Lyndon 語の列 $`s = w_1 w_2 \cdots w_k`$ $`(w_1 \geq w_2 \geq \cdots \geq w_k)`$ に一意に分解できる。
+//!
+//! * [`lyndon_factorization()`] は Duval のアルゴリズムで Lyndon 分解を求める。
+//! * [`minimal_rotation()`] は、列を巡回させたもののうち辞書順最小のものの開始位置を求める。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::lyndon::{lyndon_factorization, minimal_rotation};
+//!
+//! // "abracadabra" = "abracad" + "abr" + "a"
+//! let s = "abracadabra".chars().collect::<Vec<_>>();
+//! assert_eq!(lyndon_factorization(&s), [0, 7, 10, 11]);
+//!
+//! // "cabab" を巡回させたもので辞書順最小なのは "ababc"
+//! let s = b"cabab";
+//! assert_eq!(minimal_rotation(s), 1);
+//! ```
+//!
+//! ## 計算量
+//!
+//! 列の長さを $`N`$ として、いずれも $`O(N)`$ である。
+//!
+//! ## Verified problems
+//!
+//! * [Lyndon Factorization](../../src/lc_lyndon_factorization/lc_lyndon_factorization.rs.html)
+//!
+
+/// 列 `s` の Lyndon 分解を求め、各 Lyndon 語の境界を返す
+///
+/// 返り値 `a` は $`0 = a_0 < a_1 < \cdots < a_k = N`$ を満たし、`s[a[i]..a[i + 1]]` が $`i`$ 番目の Lyndon 語である。
+pub fn lyndon_factorization<T: Ord>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut res = vec![0];
+    let mut i = 0;
+
+    while i < n {
+        // s[i..j] は、ある Lyndon 語 (長さ j - k) の繰り返しとその接頭辞になっている
+        let mut j = i + 1;
+        let mut k = i;
+
+        while j < n && s[k] <= s[j] {
+            if s[k] < s[j] {
+                k = i;
+            } else {
+                k += 1;
+            }
+            j += 1;
+        }
+
+        while i <= k {
+            i += j - k;
+            res.push(i);
+        }
+    }
+
+    res
+}
+
+/// 列 `s` を巡回させたもののうち辞書順最小のものの開始位置を求める
+///
+/// 最小のものが複数の位置から得られる場合、そのうち最小の位置を返す。
+pub fn minimal_rotation<T: Ord>(s: &[T]) -> usize {
+    let n = s.len();
+    let at = |i: usize| &s[i % n];
+
+    // s + s を Lyndon 分解したとき、位置 n より前で始まる最後の Lyndon 語の開始位置が答え
+    let mut res = 0;
+    let mut i = 0;
+
+    while i < n {
+        res = i;
+
+        let mut j = i + 1;
+        let mut k = i;
+
+        while j < 2 * n && at(k) <= at(j) {
+            if at(k) < at(j) {
+                k = i;
+            } else {
+                k += 1;
+            }
+            j += 1;
+        }
+
+        while i <= k {
+            i += j - k;
+        }
+    }
+
+    res
+}
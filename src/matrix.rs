@@ -0,0 +1,678 @@
+use crate::algebra::{PlusTimes, Semiring};
+use crate::modint::ModInt;
+
+/// 行列
+///
+/// $`R \times C`$ の行列を、要素を行優先で並べた `Vec` で持つ。
+/// 積と累乗は [`Semiring`] を使って、任意の半環上で計算できる。
+/// `*` や `pow(k)` は通常の $`(+, \times)`$ ([`PlusTimes`]) 上の演算で、[`ModInt`](crate::modint::ModInt) や整数型の行列に使える。
+/// `semiring_mul::<R>()` や `semiring_pow::<R>()` で半環 `R` を指定すると、例えば [`MinPlus`](crate::algebra::MinPlus) 上の行列積で最短経路を求められる。
+///
+/// 線形漸化式の $`N`$ 項目や、グラフ上の長さ $`L`$ のウォークの数を、行列累乗で計算するのに使う。
+///
+/// 要素が `ModInt<P>` のときは、掃き出し法で階数・行列式・逆行列・連立一次方程式の解を求められる。
+/// $`\mathbb{F}_2`$ 上で扱う場合は、bit 列で持つ [`BitMatrix`] を使う。
+///
+/// ## Examples
+///
+/// ```
+/// use library::matrix::Matrix;
+/// use library::modint::ModInt;
+///
+/// type Mint = ModInt<998244353>;
+///
+/// let a = Matrix::from(&[vec![1, 2], vec![3, 4]]);
+/// let b = Matrix::from(&[vec![5, 6], vec![7, 8]]);
+///
+/// assert_eq!(&a + &b, Matrix::from(&[vec![6, 8], vec![10, 12]]));
+/// assert_eq!(&a * &b, Matrix::from(&[vec![19, 22], vec![43, 50]]));
+/// assert_eq!(a[(1, 0)], 3);
+///
+/// // フィボナッチ数列: (F_{n+1}, F_n) = A^n (F_1, F_0)
+/// let a = Matrix::from(&[vec![Mint::from(1), Mint::from(1)], vec![Mint::from(1), Mint::from(0)]]);
+/// let f = a.pow(90).apply(&[Mint::from(1), Mint::from(0)]);
+/// assert_eq!(f[1], Mint::from(2880067194370816120u64));
+/// ```
+///
+/// 頂点 $`i`$ から頂点 $`j`$ への辺の本数を $`A_{i, j}`$ とすると、$`(A^L)_{i, j}`$ は $`i`$ から $`j`$ への長さ $`L`$ のウォークの数になる。
+///
+/// ```
+/// use library::matrix::Matrix;
+///
+/// // 三角形 0 - 1 - 2 - 0
+/// let a = Matrix::from(&[vec![0u64, 1, 1], vec![1, 0, 1], vec![1, 1, 0]]);
+///
+/// // 0 -> 1 -> 2 -> 0 と 0 -> 2 -> 1 -> 0
+/// assert_eq!(a.pow(3)[(0, 0)], 2);
+/// assert_eq!(a.pow(0), Matrix::identity(3));
+/// ```
+///
+/// $`(\min, +)`$ 半環上の累乗では、$`(A^k)_{i, j}`$ はちょうど $`k`$ 本の辺を通るときの最短距離になる。
+///
+/// ```
+/// use library::algebra::MinPlus;
+/// use library::matrix::Matrix;
+///
+/// let inf = i64::MAX;
+/// let a = Matrix::from(&[vec![inf, 1, 5], vec![inf, inf, 1], vec![-1, inf, inf]]);
+///
+/// // 0 -> 1 -> 2
+/// assert_eq!(a.semiring_mul::<MinPlus<i64>>(&a)[(0, 2)], 2);
+/// // 0 -> 1 -> 2 -> 0 -> 1 -> 2 -> 0
+/// assert_eq!(a.semiring_pow::<MinPlus<i64>>(6)[(0, 0)], 2);
+/// assert_eq!(a.semiring_pow::<MinPlus<i64>>(2)[(0, 1)], inf);
+/// ```
+///
+/// 掃き出し法を使う例。
+///
+/// ```
+/// use library::matrix::Matrix;
+/// use library::modint::ModInt;
+///
+/// type Mint = ModInt<998244353>;
+///
+/// let to_mint = |a: &[Vec<i64>]| {
+///     let a = a.iter().map(|row| row.iter().map(|&x| Mint::from(x)).collect()).collect::<Vec<_>>();
+///     Matrix::from(&a)
+/// };
+///
+/// let a = to_mint(&[vec![2, 1], vec![1, 3]]);
+/// assert_eq!(a.rank(), 2);
+/// assert_eq!(a.det(), Mint::from(5));
+/// assert_eq!(&a * &a.inv().unwrap(), Matrix::identity(2));
+///
+/// // x + y + z = 6, x - y = -1 の解は (1, 2, 3) + t (-1, -1, 2)
+/// let a = to_mint(&[vec![1, 1, 1], vec![1, -1, 0]]);
+/// let (x, basis) = a.solve(&[Mint::from(6), Mint::from(-1)]).unwrap();
+/// assert_eq!(a.apply(&x), [Mint::from(6), Mint::from(-1)]);
+/// assert_eq!(basis.len(), 1);
+/// assert_eq!(a.apply(&basis[0]), [Mint::from(0), Mint::from(0)]);
+///
+/// // 解なし
+/// let a = to_mint(&[vec![1, 1], vec![2, 2]]);
+/// assert!(a.solve(&[Mint::from(1), Mint::from(3)]).is_none());
+/// assert_eq!(a.det(), Mint::from(0));
+/// assert!(a.inv().is_none());
+/// ```
+///
+/// ## 計算量
+///
+/// 行列の大きさを $`N \times N`$ とする。
+///
+/// | 関数 | 計算量 |
+/// | --- | --- |
+/// | `new(rows, cols)`, `identity(n)`, `from(a)` | $`O(N^2)`$ |
+/// | `&a + &b`, `&a - &b` | $`O(N^2)`$ |
+/// | `&a * &b`, `self.semiring_mul::<R>(rhs)` | $`O(N^3)`$ |
+/// | `self.apply(v)` | $`O(N^2)`$ |
+/// | `self.pow(k)`, `self.semiring_pow::<R>(k)` | $`O(N^3 \log k)`$ |
+/// | `self.rank()`, `self.det()`, `self.inv()`, `self.solve(b)` | $`O(N^3)`$ |
+///
+/// ## Verified problems
+///
+/// * [Pow of Matrix](../../src/lc_pow_of_matrix/lc_pow_of_matrix.rs.html)
+/// * [Determinant of Matrix](../../src/lc_matrix_det/lc_matrix_det.rs.html)
+/// * [Inverse Matrix](../../src/lc_inverse_matrix/lc_inverse_matrix.rs.html)
+/// * [System of Linear Equations](../../src/lc_system_of_linear_equations/lc_system_of_linear_equations.rs.html)
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: Clone> Matrix<T> {
+    /// 二次元配列 `a` から行列を作る
+    pub fn from(a: &[Vec<T>]) -> Self {
+        let rows = a.len();
+        let cols = a.first().map_or(0, |row| row.len());
+        assert!(a.iter().all(|row| row.len() == cols));
+
+        Self {
+            rows,
+            cols,
+            data: a.concat(),
+        }
+    }
+
+    /// 行数
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// 列数
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// `i` 行目
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// 二次元配列に変換する
+    pub fn to_vec(&self) -> Vec<Vec<T>> {
+        (0..self.rows).map(|i| self.row(i).to_vec()).collect()
+    }
+
+    /// 転置行列
+    pub fn transpose(&self) -> Self {
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            data: (0..self.cols)
+                .flat_map(|j| (0..self.rows).map(move |i| self[(i, j)].clone()))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Clone + Eq> Matrix<T> {
+    /// 半環 `R` 上の `n` $`\times`$ `n` の単位行列を作る
+    pub fn semiring_identity<R: Semiring<S = T>>(n: usize) -> Self {
+        Self {
+            rows: n,
+            cols: n,
+            data: (0..n * n)
+                .map(|i| if i % (n + 1) == 0 { R::ONE } else { R::ZERO })
+                .collect(),
+        }
+    }
+
+    /// 半環 `R` 上の行列積 $`(AB)_{i, j} = \bigoplus_k A_{i, k} \otimes B_{k, j}`$ を求める
+    pub fn semiring_mul<R: Semiring<S = T>>(&self, rhs: &Self) -> Self {
+        assert_eq!(self.cols, rhs.rows);
+
+        let mut res = Self {
+            rows: self.rows,
+            cols: rhs.cols,
+            data: vec![R::ZERO; self.rows * rhs.cols],
+        };
+
+        for i in 0..self.rows {
+            let row = &mut res.data[i * rhs.cols..(i + 1) * rhs.cols];
+
+            for (a, b_row) in self.row(i).iter().zip(rhs.data.chunks(rhs.cols.max(1))) {
+                // 加法の単位元は乗法の零元なので、足しても変わらない
+                if *a == R::ZERO {
+                    continue;
+                }
+
+                for (c, b) in row.iter_mut().zip(b_row) {
+                    *c = R::add(c, &R::mul(a, b));
+                }
+            }
+        }
+
+        res
+    }
+
+    /// 半環 `R` 上で、正方行列の `k` 乗を求める
+    pub fn semiring_pow<R: Semiring<S = T>>(&self, mut k: u64) -> Self {
+        assert_eq!(self.rows, self.cols);
+
+        let mut a = self.clone();
+        let mut r = Self::semiring_identity::<R>(self.rows);
+
+        while k > 0 {
+            if k & 1 == 1 {
+                r = r.semiring_mul::<R>(&a);
+            }
+
+            k >>= 1;
+
+            if k > 0 {
+                a = a.semiring_mul::<R>(&a);
+            }
+        }
+
+        r
+    }
+}
+
+impl<T: Copy + Default + std::ops::Add<Output = T> + std::ops::Mul<Output = T>> Matrix<T> {
+    /// 要素がすべて $`0`$ の `rows` $`\times`$ `cols` 行列を作る
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![T::default(); rows * cols],
+        }
+    }
+
+    /// 行列とベクトル `v` の積 $`A v`$ を求める
+    pub fn apply(&self, v: &[T]) -> Vec<T> {
+        assert_eq!(self.cols, v.len());
+
+        (0..self.rows)
+            .map(|i| {
+                self.row(i)
+                    .iter()
+                    .zip(v)
+                    .fold(T::default(), |acc, (&a, &x)| acc + a * x)
+            })
+            .collect()
+    }
+}
+
+impl<T: Clone + Eq> Matrix<T>
+where
+    PlusTimes<T>: Semiring<S = T>,
+{
+    /// `n` $`\times`$ `n` の単位行列を作る
+    pub fn identity(n: usize) -> Self {
+        Self::semiring_identity::<PlusTimes<T>>(n)
+    }
+
+    /// 正方行列の `k` 乗を求める
+    pub fn pow(&self, k: u64) -> Self {
+        self.semiring_pow::<PlusTimes<T>>(k)
+    }
+}
+
+impl<T> Matrix<T> {
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        if i != j {
+            for k in 0..self.cols {
+                self.data.swap(i * self.cols + k, j * self.cols + k);
+            }
+        }
+    }
+}
+
+impl<const P: u32> Matrix<ModInt<P>> {
+    /// 先頭の `cols` 列について掃き出して簡約化された行階段形にし、ピボットの列と、ピボットの値と行の交換から求めた行列式への寄与を返す
+    fn gauss(&mut self, cols: usize) -> (Vec<usize>, ModInt<P>) {
+        let mut pivots = vec![];
+        let mut det = ModInt::ONE;
+
+        for j in 0..cols {
+            let r = pivots.len();
+            if r == self.rows {
+                break;
+            }
+
+            let p = match (r..self.rows).find(|&i| self[(i, j)] != ModInt::ZERO) {
+                Some(p) => p,
+                None => {
+                    det = ModInt::ZERO;
+                    continue;
+                }
+            };
+
+            if p != r {
+                self.swap_rows(p, r);
+                det = -det;
+            }
+
+            det *= self[(r, j)];
+            let inv = self[(r, j)].inv();
+            for k in j..self.cols {
+                self[(r, k)] *= inv;
+            }
+
+            let pivot_row = self.row(r).to_vec();
+            for i in 0..self.rows {
+                let c = self[(i, j)];
+                if i == r || c == ModInt::ZERO {
+                    continue;
+                }
+
+                let row = &mut self.data[i * self.cols..(i + 1) * self.cols];
+                for (x, &y) in row.iter_mut().zip(&pivot_row).skip(j) {
+                    *x -= c * y;
+                }
+            }
+
+            pivots.push(j);
+        }
+
+        (pivots, det)
+    }
+
+    /// 階数
+    pub fn rank(&self) -> usize {
+        let mut a = self.clone();
+        a.gauss(self.cols).0.len()
+    }
+
+    /// 正方行列の行列式
+    pub fn det(&self) -> ModInt<P> {
+        assert_eq!(self.rows, self.cols);
+
+        let mut a = self.clone();
+        let (pivots, det) = a.gauss(self.cols);
+
+        if pivots.len() == self.rows {
+            det
+        } else {
+            ModInt::ZERO
+        }
+    }
+
+    /// 正方行列の逆行列
+    /// 正則でない場合 `None` を返す
+    pub fn inv(&self) -> Option<Self> {
+        assert_eq!(self.rows, self.cols);
+        let n = self.rows;
+
+        // (A | I) を掃き出すと (I | A^{-1}) になる
+        let mut a = Self::new(n, 2 * n);
+        for i in 0..n {
+            for j in 0..n {
+                a[(i, j)] = self[(i, j)];
+            }
+            a[(i, n + i)] = ModInt::ONE;
+        }
+
+        if a.gauss(n).0.len() < n {
+            return None;
+        }
+
+        let mut res = Self::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                res[(i, j)] = a[(i, n + j)];
+            }
+        }
+
+        Some(res)
+    }
+
+    /// 連立一次方程式 $`A x = b`$ を解く
+    ///
+    /// 解が存在する場合、解の $`1`$ つ $`x`$ と、$`A y = 0`$ の解空間の基底を返す。解全体は $`x`$ に基底の線形結合を足したものである。
+    /// 解が存在しない場合 `None` を返す。
+    pub fn solve(&self, b: &[ModInt<P>]) -> Option<(Vec<ModInt<P>>, Vec<Vec<ModInt<P>>>)> {
+        assert_eq!(self.rows, b.len());
+        let m = self.cols;
+
+        let mut a = Self::new(self.rows, m + 1);
+        for (i, &bi) in b.iter().enumerate() {
+            for j in 0..m {
+                a[(i, j)] = self[(i, j)];
+            }
+            a[(i, m)] = bi;
+        }
+
+        let (pivots, _) = a.gauss(m);
+
+        if (pivots.len()..self.rows).any(|i| a[(i, m)] != ModInt::ZERO) {
+            return None;
+        }
+
+        let mut x = vec![ModInt::ZERO; m];
+        for (r, &j) in pivots.iter().enumerate() {
+            x[j] = a[(r, m)];
+        }
+
+        let mut is_pivot = vec![false; m];
+        for &j in &pivots {
+            is_pivot[j] = true;
+        }
+
+        let basis = (0..m)
+            .filter(|&f| !is_pivot[f])
+            .map(|f| {
+                let mut y = vec![ModInt::ZERO; m];
+                y[f] = ModInt::ONE;
+                for (r, &j) in pivots.iter().enumerate() {
+                    y[j] = -a[(r, f)];
+                }
+                y
+            })
+            .collect();
+
+        Some((x, basis))
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.rows && j < self.cols);
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.rows && j < self.cols);
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl<T: Copy + Default + std::ops::Add<Output = T> + std::ops::Mul<Output = T>> std::ops::Add
+    for &Matrix<T>
+{
+    type Output = Matrix<T>;
+    fn add(self, rhs: Self) -> Matrix<T> {
+        assert!(self.rows == rhs.rows && self.cols == rhs.cols);
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(&rhs.data)
+                .map(|(&a, &b)| a + b)
+                .collect(),
+        }
+    }
+}
+
+impl<
+        T: Copy
+            + Default
+            + std::ops::Add<Output = T>
+            + std::ops::Sub<Output = T>
+            + std::ops::Mul<Output = T>,
+    > std::ops::Sub for &Matrix<T>
+{
+    type Output = Matrix<T>;
+    fn sub(self, rhs: Self) -> Matrix<T> {
+        assert!(self.rows == rhs.rows && self.cols == rhs.cols);
+
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(&rhs.data)
+                .map(|(&a, &b)| a - b)
+                .collect(),
+        }
+    }
+}
+
+impl<T: Clone + Eq> std::ops::Mul for &Matrix<T>
+where
+    PlusTimes<T>: Semiring<S = T>,
+{
+    type Output = Matrix<T>;
+    fn mul(self, rhs: Self) -> Matrix<T> {
+        self.semiring_mul::<PlusTimes<T>>(rhs)
+    }
+}
+
+/// $`\mathbb{F}_2`$ 上の行列
+///
+/// 各行を $`64`$ bit ごとに区切った `u64` の列で持ち、掃き出し法の行の足し算を bit 演算でまとめて行う。
+///
+/// ## Examples
+///
+/// ```
+/// use library::matrix::BitMatrix;
+///
+/// // x0 + x1 = 1, x1 + x2 = 0 (mod 2)
+/// let mut a = BitMatrix::new(2, 3);
+/// a.set(0, 0, true);
+/// a.set(0, 1, true);
+/// a.set(1, 1, true);
+/// a.set(1, 2, true);
+///
+/// assert_eq!(a.rank(), 2);
+///
+/// let (x, basis) = a.solve(&[true, false]).unwrap();
+/// assert_eq!(a.apply(&x), [true, false]);
+/// assert_eq!(basis, [vec![true, true, true]]);
+///
+/// // x0 + x1 = 1 と x0 + x1 = 0 は同時に満たせない
+/// let mut a = BitMatrix::new(2, 2);
+/// for i in 0..2 {
+///     for j in 0..2 {
+///         a.set(i, j, true);
+///     }
+/// }
+/// assert!(a.solve(&[true, false]).is_none());
+/// ```
+///
+/// ## 計算量
+///
+/// 行列の大きさを $`N \times M`$、ワードサイズを $`w = 64`$ とする。
+///
+/// | 関数 | 計算量 |
+/// | --- | --- |
+/// | `new(rows, cols)` | $`O(NM / w)`$ |
+/// | `self.get(i, j)`, `self.set(i, j, x)` | $`O(1)`$ |
+/// | `self.apply(v)` | $`O(NM)`$ |
+/// | `self.rank()`, `self.solve(b)` | $`O(N M \min(N, M) / w)`$ |
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BitMatrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<Vec<u64>>,
+}
+
+impl BitMatrix {
+    /// 要素がすべて $`0`$ の `rows` $`\times`$ `cols` 行列を作る
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![vec![0; (cols + 63) / 64]; rows],
+        }
+    }
+
+    /// 行数
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// 列数
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// `(i, j)` 成分
+    pub fn get(&self, i: usize, j: usize) -> bool {
+        assert!(i < self.rows && j < self.cols);
+        self.data[i][j / 64] >> (j % 64) & 1 == 1
+    }
+
+    /// `(i, j)` 成分を `x` にする
+    pub fn set(&mut self, i: usize, j: usize, x: bool) {
+        assert!(i < self.rows && j < self.cols);
+        if x {
+            self.data[i][j / 64] |= 1 << (j % 64);
+        } else {
+            self.data[i][j / 64] &= !(1 << (j % 64));
+        }
+    }
+
+    /// 行列とベクトル `v` の積 $`A v`$ を求める
+    pub fn apply(&self, v: &[bool]) -> Vec<bool> {
+        assert_eq!(self.cols, v.len());
+
+        (0..self.rows)
+            .map(|i| (0..self.cols).filter(|&j| v[j] && self.get(i, j)).count() % 2 == 1)
+            .collect()
+    }
+
+    /// 先頭の `cols` 列について掃き出して簡約化された行階段形にし、ピボットの列を返す
+    fn gauss(&mut self, cols: usize) -> Vec<usize> {
+        let mut pivots = vec![];
+
+        for j in 0..cols {
+            let r = pivots.len();
+            if r == self.rows {
+                break;
+            }
+
+            let p = match (r..self.rows).find(|&i| self.get(i, j)) {
+                Some(p) => p,
+                None => continue,
+            };
+            self.data.swap(p, r);
+
+            let pivot_row = self.data[r].clone();
+            for i in 0..self.rows {
+                if i != r && self.get(i, j) {
+                    for (x, &y) in self.data[i].iter_mut().zip(&pivot_row).skip(j / 64) {
+                        *x ^= y;
+                    }
+                }
+            }
+
+            pivots.push(j);
+        }
+
+        pivots
+    }
+
+    /// 階数
+    pub fn rank(&self) -> usize {
+        let mut a = self.clone();
+        a.gauss(self.cols).len()
+    }
+
+    /// 連立一次方程式 $`A x = b`$ を解く
+    ///
+    /// 解が存在する場合、解の $`1`$ つ $`x`$ と、$`A y = 0`$ の解空間の基底を返す。解が存在しない場合 `None` を返す。
+    pub fn solve(&self, b: &[bool]) -> Option<(Vec<bool>, Vec<Vec<bool>>)> {
+        assert_eq!(self.rows, b.len());
+        let m = self.cols;
+
+        let mut a = Self::new(self.rows, m + 1);
+        for (i, &bi) in b.iter().enumerate() {
+            for j in 0..m {
+                a.set(i, j, self.get(i, j));
+            }
+            a.set(i, m, bi);
+        }
+
+        let pivots = a.gauss(m);
+
+        if (pivots.len()..self.rows).any(|i| a.get(i, m)) {
+            return None;
+        }
+
+        let mut x = vec![false; m];
+        for (r, &j) in pivots.iter().enumerate() {
+            x[j] = a.get(r, m);
+        }
+
+        let mut is_pivot = vec![false; m];
+        for &j in &pivots {
+            is_pivot[j] = true;
+        }
+
+        let basis = (0..m)
+            .filter(|&f| !is_pivot[f])
+            .map(|f| {
+                let mut y = vec![false; m];
+                y[f] = true;
+                for (r, &j) in pivots.iter().enumerate() {
+                    y[j] = a.get(r, f);
+                }
+                y
+            })
+            .collect();
+
+        Some((x, basis))
+    }
+}
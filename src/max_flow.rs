@@ -0,0 +1,180 @@
+use crate::integer_traits::HasMaxValue;
+
+/// Dinic 法で最大流を求める
+///
+/// 残余グラフ上で幅優先探索により始点からの距離を求め、距離が $`1`$ ずつ増える辺だけを使って深さ優先探索でフローを流すことを繰り返す。
+///
+/// ## Examples
+///
+/// ```
+/// use library::max_flow::MaxFlow;
+///
+/// let mut mf = MaxFlow::new(4);
+/// mf.add_edge(0, 1, 2);
+/// mf.add_edge(0, 2, 1);
+/// let e = mf.add_edge(1, 2, 1);
+/// mf.add_edge(1, 3, 1);
+/// mf.add_edge(2, 3, 2);
+///
+/// assert_eq!(mf.flow(0, 3), 3);
+/// assert_eq!(mf.get_edge(e), (1, 2, 1, 1));
+///
+/// // 最小カットで始点側に残る頂点
+/// assert_eq!(mf.min_cut(0), [true, false, false, false]);
+/// ```
+///
+/// ## 計算量
+///
+/// 頂点数を $`N`$、辺数を $`M`$ とする。
+///
+/// | 関数 | 計算量 |
+/// | --- | --- |
+/// | `new(size)` | $`O(N)`$ |
+/// | `add_edge(from, to, cap)` | $`O(1)`$ |
+/// | `flow(s, t)` | $`O(N^2 M)`$ |
+/// | `min_cut(s)` | $`O(N + M)`$ |
+///
+/// ## Verified problems
+///
+/// * [Maximum Flow](../../src/aoj_grl_6_a/aoj_grl_6_a.rs.html)
+///
+pub struct MaxFlow<C> {
+    size: usize,
+    // (行き先, 容量, 逆辺の番号)
+    graph: Vec<Vec<(usize, C, usize)>>,
+    // 追加した辺の (始点, 隣接リスト内の番号, 元の容量)
+    edges: Vec<(usize, usize, C)>,
+}
+
+impl<
+        C: Default
+            + Copy
+            + Ord
+            + std::ops::Add<Output = C>
+            + std::ops::Sub<Output = C>
+            + std::ops::AddAssign
+            + std::ops::SubAssign
+            + HasMaxValue,
+    > MaxFlow<C>
+{
+    /// 頂点数 `size` の、辺のないグラフを作る
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            graph: vec![vec![]; size],
+            edges: vec![],
+        }
+    }
+
+    /// `from` から `to` へ容量 `cap` の辺を追加し、その辺の番号を返す
+    pub fn add_edge(&mut self, from: usize, to: usize, cap: C) -> usize {
+        assert!(from < self.size && to < self.size);
+
+        let i = self.graph[from].len();
+        let j = self.graph[to].len() + (from == to) as usize;
+
+        self.graph[from].push((to, cap, j));
+        self.graph[to].push((from, C::default(), i));
+        self.edges.push((from, i, cap));
+
+        self.edges.len() - 1
+    }
+
+    /// `i` 番目に追加した辺の `(始点, 終点, 容量, 流量)` を返す
+    pub fn get_edge(&self, i: usize) -> (usize, usize, C, C) {
+        let (from, idx, cap) = self.edges[i];
+        let (to, rest, _) = self.graph[from][idx];
+
+        (from, to, cap, cap - rest)
+    }
+
+    /// `s` から `t` へ流せるだけ流し、流した量を返す
+    pub fn flow(&mut self, s: usize, t: usize) -> C {
+        assert!(s != t);
+
+        let mut total = C::default();
+
+        loop {
+            let level = self.levels(s);
+            if level[t] == usize::MAX {
+                return total;
+            }
+
+            let mut iter = vec![0; self.size];
+            loop {
+                let f = self.augment(s, t, &level, &mut iter);
+                if f == C::default() {
+                    break;
+                }
+                total += f;
+            }
+        }
+    }
+
+    /// 残余グラフで `s` から到達できる頂点を求める
+    ///
+    /// `flow(s, t)` の後に呼ぶと、`true` の頂点が最小カットの `s` 側になる。
+    pub fn min_cut(&self, s: usize) -> Vec<bool> {
+        self.levels(s).iter().map(|&d| d != usize::MAX).collect()
+    }
+
+    fn levels(&self, s: usize) -> Vec<usize> {
+        let mut level = vec![usize::MAX; self.size];
+        let mut q = std::collections::VecDeque::new();
+
+        level[s] = 0;
+        q.push_back(s);
+
+        while let Some(u) = q.pop_front() {
+            for &(v, cap, _) in &self.graph[u] {
+                if cap > C::default() && level[v] == usize::MAX {
+                    level[v] = level[u] + 1;
+                    q.push_back(v);
+                }
+            }
+        }
+
+        level
+    }
+
+    fn augment(&mut self, s: usize, t: usize, level: &[usize], iter: &mut [usize]) -> C {
+        // 再帰の代わりに、通った (頂点, 辺の番号) をスタックに積む
+        let mut path: Vec<(usize, usize)> = vec![];
+        let mut u = s;
+
+        while u != t {
+            if iter[u] < self.graph[u].len() {
+                let (v, cap, _) = self.graph[u][iter[u]];
+
+                if cap > C::default() && level[u] < level[v] {
+                    path.push((u, iter[u]));
+                    u = v;
+                } else {
+                    iter[u] += 1;
+                }
+            } else {
+                // u からは t に到達できないので、1 つ戻ってその辺を使わないようにする
+                match path.pop() {
+                    Some((p, _)) => {
+                        iter[p] += 1;
+                        u = p;
+                    }
+                    None => return C::default(),
+                }
+            }
+        }
+
+        let f = path
+            .iter()
+            .map(|&(u, i)| self.graph[u][i].1)
+            .fold(C::MAX, std::cmp::min);
+
+        for &(u, i) in &path {
+            let (v, _, rev) = self.graph[u][i];
+            self.graph[u][i].1 -= f;
+            self.graph[v][rev].1 += f;
+        }
+
+        f
+    }
+}
@@ -0,0 +1,111 @@
+//! 頂点数が小さいグラフの最大独立集合を求める
+//!
+//! 隣接関係を `u64` のビット集合で持ち、次数が $`1`$ 以下の頂点は必ず採用し、そうでなければ次数最大の頂点を採用するかしないかで分岐する。
+//! 次数が $`3`$ 以上の頂点で分岐すると、採用する側では $`4`$ 頂点以上が取り除かれるので、計算量は $`O(1.381^N)`$ 程度になる。
+//!
+//! 辺の向き・重みは無視し、自己ループをもつ頂点は独立集合に含めない。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::graph::UndirectedAdjGraph;
+//! use library::maximum_independent_set::maximum_independent_set;
+//!
+//! // 長さ 5 の閉路
+//! let graph = UndirectedAdjGraph::from_edges_no_weight(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+//!
+//! let set = maximum_independent_set(&graph);
+//! assert_eq!(set.len(), 2);
+//!
+//! // 星グラフ
+//! let graph = UndirectedAdjGraph::from_edges_no_weight(5, &[(0, 1), (0, 2), (0, 3), (0, 4)]);
+//! assert_eq!(maximum_independent_set(&graph), vec![1, 2, 3, 4]);
+//! ```
+//!
+//! ## 計算量
+//!
+//! $`N`$ を頂点数、$`M`$ を辺数として、$`O(1.381^N N + M)`$ である。$`N \leq 64`$ でなければならない。
+//!
+//! ## Verified problems
+//!
+//! * [Maximum Independent Set](../../src/lc_maximum_independent_set/lc_maximum_independent_set.rs.html)
+//!
+
+use crate::graph::{Graph, Index};
+
+/// `graph` の最大独立集合の 1 つを、頂点番号の昇順に並べて返す
+pub fn maximum_independent_set(graph: &impl Graph) -> Vec<Index> {
+    let size = graph.size() as usize;
+    assert!(size <= 64);
+
+    let mut adj = vec![0u64; size];
+    let mut rem = if size == 64 { !0 } else { (1u64 << size) - 1 };
+
+    for u in 0..graph.size() {
+        for &(v, _) in graph.adjacent(u) {
+            if u == v {
+                rem &= !(1 << u);
+            } else {
+                adj[u as usize] |= 1 << v;
+                adj[v as usize] |= 1 << u;
+            }
+        }
+    }
+
+    let best = maximum_independent_set_bits(&adj, rem);
+
+    (0..size as Index).filter(|&v| best >> v & 1 == 1).collect()
+}
+
+/// 隣接関係をビット集合で与えて、`rem` に含まれる頂点の中での最大独立集合を求める
+pub(crate) fn maximum_independent_set_bits(adj: &[u64], rem: u64) -> u64 {
+    let mut best = 0;
+    search(adj, rem, 0, &mut best);
+    best
+}
+
+fn search(adj: &[u64], mut rem: u64, mut cur: u64, best: &mut u64) {
+    // 次数が 1 以下の頂点は採用してよい
+    loop {
+        let mut found = false;
+        let mut r = rem;
+
+        while r != 0 {
+            let v = r.trailing_zeros() as usize;
+            r &= r - 1;
+
+            if rem >> v & 1 == 1 && (adj[v] & rem).count_ones() <= 1 {
+                cur |= 1 << v;
+                rem &= !(1 << v) & !adj[v];
+                found = true;
+            }
+        }
+
+        if !found {
+            break;
+        }
+    }
+
+    if cur.count_ones() + rem.count_ones() <= best.count_ones() {
+        return;
+    }
+
+    if rem == 0 {
+        *best = cur;
+        return;
+    }
+
+    let mut v = rem.trailing_zeros() as usize;
+    let mut r = rem;
+    while r != 0 {
+        let u = r.trailing_zeros() as usize;
+        r &= r - 1;
+
+        if (adj[u] & rem).count_ones() > (adj[v] & rem).count_ones() {
+            v = u;
+        }
+    }
+
+    search(adj, rem & !(1 << v) & !adj[v], cur | 1 << v, best);
+    search(adj, rem & !(1 << v), cur, best);
+}
@@ -0,0 +1,175 @@
+//! $`(\min, +)`$ 半環上の行列積を用いて、辺の本数を指定した最短経路を求める。
+//!
+//! 頂点 $`i`$ から頂点 $`j`$ への辺の重みの最小値を $`A_{i, j}`$ (辺がなければ $`\infty`$) とする。
+//! $`(\min, +)`$ 半環上の行列積 $`(AB)_{i, j} = \min_k (A_{i, k} + B_{k, j})`$ について、
+//! $`(A^k)_{i, j}`$ はちょうど $`k`$ 本の辺を通る $`i`$ から $`j`$ へのウォークの重みの最小値である。
+//! 繰り返し二乗法を使えば、$`k`$ が大きくても $`O(N^3 \log k)`$ で計算できる。
+//!
+//! * [`shortest_paths_exact_k_edges()`] は、ちょうど $`k`$ 本の辺を通るときの全点対の最短距離を求める。
+//! * [`shortest_paths_at_most_k_edges()`] は、$`k`$ 本以下の辺を通るときの全点対の最短距離を求める。
+//!   $`A`$ の対角成分を $`0`$ との最小値に置き換える(重み $`0`$ の自己ループを加える)ことで計算している。
+//!
+//! 到達できない場合、距離は [`Dist::UNREACHABLE`] になる。辺の重みは負でもよい。
+//!
+//! 内部では [`MinPlus`](crate::algebra::MinPlus) 半環上で [`Matrix::semiring_pow()`] を使って計算しており、到達できないことを `W::MAX` で表している。
+//! そのため `W` は [`MinPlus`](crate::algebra::MinPlus) が [`Semiring`](crate::algebra::Semiring) を実装している整数型に限られる。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::dijkstra::Dist;
+//! use library::graph::DirectedAdjGraph;
+//! use library::min_plus::{shortest_paths_at_most_k_edges, shortest_paths_exact_k_edges};
+//!
+//! let graph = DirectedAdjGraph::from_edges(3, &[(0, 1, 1i64), (1, 2, 1), (0, 2, 5), (2, 0, -1)]);
+//!
+//! let exact = shortest_paths_exact_k_edges(&graph, 2);
+//! assert_eq!(exact[0][2], Dist::VALUE(2));
+//! assert_eq!(exact[0][0], Dist::VALUE(4));
+//! assert_eq!(exact[0][1], Dist::UNREACHABLE);
+//!
+//! let at_most = shortest_paths_at_most_k_edges(&graph, 1);
+//! assert_eq!(at_most[0][0], Dist::VALUE(0));
+//! assert_eq!(at_most[0][2], Dist::VALUE(5));
+//!
+//! // 0 -> 1 -> 2 -> 0 -> 1 -> 2 -> ... と進むのはちょうど 3 本ごとに重み 1 である
+//! let exact = shortest_paths_exact_k_edges(&graph, 3_000_000_000_000);
+//! assert_eq!(exact[0][0], Dist::VALUE(1_000_000_000_000));
+//! ```
+//!
+//! ## 長さ $`L`$ のウォークの数え上げ
+//!
+//! $`(\min, +)`$ を $`(+, \times)`$ に置き換えると、$`(A^L)_{i, j}`$ は $`i`$ から $`j`$ への長さ $`L`$ のウォークの数になる。
+//! 例えば $`A_{i, j}`$ を辺の本数として、通常の行列積で $`A^L`$ を計算すればよい。
+//! $`\mathbb{Z} / P \mathbb{Z}`$ 上で数える場合は [`count_walks()`](crate::walk_count::count_walks) が使える。
+//!
+//! ```
+//! // 三角形 0 - 1 - 2 - 0 上の長さ 3 のウォークで、0 から 0 に戻るものは 0 -> 1 -> 2 -> 0 と 0 -> 2 -> 1 -> 0 の 2 つ
+//! let a = [[0u64, 1, 1], [1, 0, 1], [1, 1, 0]];
+//! let mul = |x: &[[u64; 3]; 3], y: &[[u64; 3]; 3]| {
+//!     let mut z = [[0; 3]; 3];
+//!     for i in 0..3 {
+//!         for k in 0..3 {
+//!             for j in 0..3 {
+//!                 z[i][j] += x[i][k] * y[k][j];
+//!             }
+//!         }
+//!     }
+//!     z
+//! };
+//! let a3 = mul(&mul(&a, &a), &a);
+//! assert_eq!(a3[0][0], 2);
+//! ```
+//!
+//! ## 計算量
+//!
+//! $`N`$ を頂点数とする。
+//!
+//! | 関数 | 計算量 |
+//! | --- | --- |
+//! | [`min_plus_product()`] | $`O(N^3)`$ |
+//! | [`shortest_paths_exact_k_edges()`], [`shortest_paths_at_most_k_edges()`] | $`O(N^3 \log k)`$ |
+//!
+
+use crate::algebra::{MinPlus, Semiring};
+use crate::dijkstra::Dist;
+use crate::graph::Graph;
+use crate::matrix::Matrix;
+
+/// $`(\min, +)`$ 半環上の行列積 $`(AB)_{i, j} = \min_k (A_{i, k} + B_{k, j})`$ を求める
+pub fn min_plus_product<W: Copy + Eq>(a: &[Vec<Dist<W>>], b: &[Vec<Dist<W>>]) -> Vec<Vec<Dist<W>>>
+where
+    MinPlus<W>: Semiring<S = W>,
+{
+    from_matrix(&to_matrix(a).semiring_mul::<MinPlus<W>>(&to_matrix(b)))
+}
+
+/// ちょうど $`k`$ 本の辺を通るときの、全点対の最短距離を求める
+///
+/// 返り値の `[i][j]` が、頂点 $`i`$ から頂点 $`j`$ への最短距離である。
+pub fn shortest_paths_exact_k_edges<W: Copy + Eq>(
+    graph: &impl Graph<Weight = W>,
+    k: u64,
+) -> Vec<Vec<Dist<W>>>
+where
+    MinPlus<W>: Semiring<S = W>,
+{
+    from_matrix(&adjacency_matrix(graph).semiring_pow::<MinPlus<W>>(k))
+}
+
+/// $`k`$ 本以下の辺を通るときの、全点対の最短距離を求める
+///
+/// 返り値の `[i][j]` が、頂点 $`i`$ から頂点 $`j`$ への最短距離である。
+pub fn shortest_paths_at_most_k_edges<W: Copy + Eq>(
+    graph: &impl Graph<Weight = W>,
+    k: u64,
+) -> Vec<Vec<Dist<W>>>
+where
+    MinPlus<W>: Semiring<S = W>,
+{
+    let mut a = adjacency_matrix(graph);
+
+    // 重み 0 (乗法の単位元) の自己ループを加える
+    for i in 0..a.rows() {
+        a[(i, i)] = MinPlus::<W>::add(&a[(i, i)], &MinPlus::<W>::ONE);
+    }
+
+    from_matrix(&a.semiring_pow::<MinPlus<W>>(k))
+}
+
+/// [`Dist`] の行列を、`MinPlus<W>` の $`\infty`$ (加法の単位元) で到達不能を表す行列に変換する
+fn to_matrix<W: Copy + Eq>(a: &[Vec<Dist<W>>]) -> Matrix<W>
+where
+    MinPlus<W>: Semiring<S = W>,
+{
+    let a = a
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|d| match *d {
+                    Dist::VALUE(x) => x,
+                    Dist::UNREACHABLE => MinPlus::<W>::ZERO,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    Matrix::from(&a)
+}
+
+fn from_matrix<W: Copy + Eq>(a: &Matrix<W>) -> Vec<Vec<Dist<W>>>
+where
+    MinPlus<W>: Semiring<S = W>,
+{
+    (0..a.rows())
+        .map(|i| {
+            a.row(i)
+                .iter()
+                .map(|&x| {
+                    if x == MinPlus::<W>::ZERO {
+                        Dist::UNREACHABLE
+                    } else {
+                        Dist::VALUE(x)
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn adjacency_matrix<W: Copy + Eq>(graph: &impl Graph<Weight = W>) -> Matrix<W>
+where
+    MinPlus<W>: Semiring<S = W>,
+{
+    let n = graph.size() as usize;
+    let mut a = Matrix::from(&vec![vec![MinPlus::<W>::ZERO; n]; n]);
+
+    for u in 0..n {
+        for &(v, w) in graph.adjacent(u as u32) {
+            let (u, v) = (u, v as usize);
+            a[(u, v)] = MinPlus::<W>::add(&a[(u, v)], &w);
+        }
+    }
+
+    a
+}
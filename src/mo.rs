@@ -0,0 +1,240 @@
+/// Mo's algorithm で管理する区間の状態
+///
+/// 区間 $`\lbrack l, r)`$ の状態から、端点を $`1`$ つ動かした区間の状態へ移る操作を実装する。
+pub trait MoState {
+    type Output;
+
+    /// 位置 $`i`$ の要素を区間に加える
+    fn add(&mut self, i: usize);
+
+    /// 位置 $`i`$ の要素を区間から取り除く
+    fn remove(&mut self, i: usize);
+
+    /// 現在の区間に対するクエリの答えを求める
+    fn answer(&self) -> Self::Output;
+}
+
+/// 一点更新つきの Mo's algorithm で管理する区間の状態
+pub trait MoUpdateState: MoState {
+    /// 現在の区間が $`\lbrack l, r)`$ であるときに、$`t`$ 番目の更新を適用する
+    ///
+    /// 同じ $`t`$ について $`2`$ 回呼ぶと元に戻るように実装する必要がある。
+    /// 更新を「列の値と更新後の値を入れ替える」操作として持っておくとよい。
+    fn apply(&mut self, t: usize, left: usize, right: usize);
+}
+
+/// 長さ $`N`$ の列に対する区間クエリ $`\lbrack l, r)`$ を、Mo's algorithm でまとめて処理する
+///
+/// クエリを左端のブロックごとに右端でソートして処理し、各クエリでの [`MoState::answer`] を返す。
+///
+/// ## Examples
+///
+/// ```
+/// use library::mo::{mo, MoState};
+///
+/// struct Distinct {
+///     a: Vec<usize>,
+///     count: Vec<usize>,
+///     kinds: usize,
+/// }
+///
+/// impl MoState for Distinct {
+///     type Output = usize;
+///
+///     fn add(&mut self, i: usize) {
+///         self.count[self.a[i]] += 1;
+///         if self.count[self.a[i]] == 1 {
+///             self.kinds += 1;
+///         }
+///     }
+///
+///     fn remove(&mut self, i: usize) {
+///         self.count[self.a[i]] -= 1;
+///         if self.count[self.a[i]] == 0 {
+///             self.kinds -= 1;
+///         }
+///     }
+///
+///     fn answer(&self) -> usize {
+///         self.kinds
+///     }
+/// }
+///
+/// let a = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+/// let mut state = Distinct { a, count: vec![0; 10], kinds: 0 };
+///
+/// let queries = [(0, 4), (1, 4), (0, 10), (3, 9), (5, 5)];
+/// assert_eq!(mo(&mut state, 10, &queries), [3, 2, 7, 5, 0]);
+/// ```
+///
+/// ## 計算量
+///
+/// 列の長さを $`N`$、クエリの数を $`Q`$ として、[`MoState::add`]・[`MoState::remove`] の呼び出し回数は $`O(N \sqrt{Q})`$ である。
+///
+/// ## Verified problems
+///
+/// * [Static Range Count Distinct](../../src/lc_static_range_count_distinct_02/lc_static_range_count_distinct_02.rs.html)
+///
+pub fn mo<S: MoState>(state: &mut S, n: usize, queries: &[(usize, usize)]) -> Vec<S::Output> {
+    let q = queries.len();
+    let block = std::cmp::max(1, n / std::cmp::max(1, (q as f64).sqrt() as usize));
+
+    let mut order = (0..q).collect::<Vec<_>>();
+    order.sort_by_key(|&i| {
+        let (left, right) = queries[i];
+        let b = left / block;
+
+        if b % 2 == 0 {
+            (b, right)
+        } else {
+            (b, n - right)
+        }
+    });
+
+    let mut ret = (0..q).map(|_| None).collect::<Vec<_>>();
+    let (mut l, mut r) = (0, 0);
+
+    for i in order {
+        let (left, right) = queries[i];
+        assert!(left <= right && right <= n);
+
+        move_range(state, (&mut l, &mut r), (left, right));
+        ret[i] = Some(state.answer());
+    }
+
+    ret.into_iter().map(|x| x.unwrap()).collect()
+}
+
+/// 長さ $`N`$ の列に対する一点更新と区間クエリを、時刻を $`3`$ つ目の次元とした Mo's algorithm でまとめて処理する
+///
+/// クエリ $`(l, r, t)`$ は、最初の $`t`$ 個の更新を適用した列の区間 $`\lbrack l, r)`$ に対するクエリを表す。
+/// 各クエリでの [`MoState::answer`] を返す。
+///
+/// ## Examples
+///
+/// ```
+/// use library::mo::{mo_with_updates, MoState, MoUpdateState};
+///
+/// struct Distinct {
+///     a: Vec<usize>,
+///     updates: Vec<(usize, usize)>,
+///     count: Vec<usize>,
+///     kinds: usize,
+/// }
+///
+/// impl MoState for Distinct {
+///     type Output = usize;
+///
+///     fn add(&mut self, i: usize) {
+///         self.count[self.a[i]] += 1;
+///         if self.count[self.a[i]] == 1 {
+///             self.kinds += 1;
+///         }
+///     }
+///
+///     fn remove(&mut self, i: usize) {
+///         self.count[self.a[i]] -= 1;
+///         if self.count[self.a[i]] == 0 {
+///             self.kinds -= 1;
+///         }
+///     }
+///
+///     fn answer(&self) -> usize {
+///         self.kinds
+///     }
+/// }
+///
+/// impl MoUpdateState for Distinct {
+///     fn apply(&mut self, t: usize, left: usize, right: usize) {
+///         let p = self.updates[t].0;
+///         let inside = left <= p && p < right;
+///
+///         if inside {
+///             self.remove(p);
+///         }
+///         std::mem::swap(&mut self.a[p], &mut self.updates[t].1);
+///         if inside {
+///             self.add(p);
+///         }
+///     }
+/// }
+///
+/// let a = vec![1, 2, 1, 3, 2];
+///
+/// // 位置 2 の値を 4 にし、その後位置 0 の値を 2 にする
+/// let updates = vec![(2, 4), (0, 2)];
+/// let mut state = Distinct { a, updates, count: vec![0; 5], kinds: 0 };
+///
+/// let queries = [(0, 3, 0), (0, 3, 1), (0, 3, 2), (0, 5, 2), (2, 5, 0)];
+/// assert_eq!(mo_with_updates(&mut state, 5, &queries), [2, 3, 2, 3, 3]);
+/// ```
+///
+/// ## 計算量
+///
+/// 列の長さを $`N`$、クエリの数を $`Q`$、更新の数を $`T`$ とする。
+/// ブロックの大きさを $`N^{2/3}`$ とすると、[`MoState::add`]・[`MoState::remove`]・[`MoUpdateState::apply`] の呼び出し回数は $`O(N^{2/3} Q + N^{1/3} T)`$ である。
+///
+pub fn mo_with_updates<S: MoUpdateState>(
+    state: &mut S,
+    n: usize,
+    queries: &[(usize, usize, usize)],
+) -> Vec<S::Output> {
+    let q = queries.len();
+    let block = std::cmp::max(1, (n as f64).powf(2.0 / 3.0) as usize);
+
+    let mut order = (0..q).collect::<Vec<_>>();
+    order.sort_by_key(|&i| {
+        let (left, right, time) = queries[i];
+        (left / block, right / block, time)
+    });
+
+    let mut ret = (0..q).map(|_| None).collect::<Vec<_>>();
+    let (mut l, mut r, mut t) = (0, 0, 0);
+
+    for i in order {
+        let (left, right, time) = queries[i];
+        assert!(left <= right && right <= n);
+
+        move_range(state, (&mut l, &mut r), (left, right));
+
+        while t < time {
+            state.apply(t, l, r);
+            t += 1;
+        }
+
+        while t > time {
+            t -= 1;
+            state.apply(t, l, r);
+        }
+
+        ret[i] = Some(state.answer());
+    }
+
+    ret.into_iter().map(|x| x.unwrap()).collect()
+}
+
+fn move_range<S: MoState>(
+    state: &mut S,
+    (l, r): (&mut usize, &mut usize),
+    (left, right): (usize, usize),
+) {
+    while *l > left {
+        *l -= 1;
+        state.add(*l);
+    }
+
+    while *r < right {
+        state.add(*r);
+        *r += 1;
+    }
+
+    while *l < left {
+        state.remove(*l);
+        *l += 1;
+    }
+
+    while *r > right {
+        *r -= 1;
+        state.remove(*r);
+    }
+}
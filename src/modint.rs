@@ -10,6 +10,12 @@ use std::{fmt::*, ops::*};
 pub struct ModInt<const P: u32>(u32);
 
 impl<const P: u32> ModInt<P> {
+    /// 加法の単位元 $`0`$
+    pub const ZERO: Self = Self(0);
+
+    /// 乗法の単位元 $`1`$
+    pub const ONE: Self = Self(1);
+
     /// `value` から `ModInt<P>` を生成する  
     /// $`\text{value} < P`$ であることを要求する代わりに、`ModInt<P>` への変換時に割り算を行わない。
     pub fn from_raw(value: u32) -> Self {
@@ -17,8 +23,10 @@ impl<const P: u32> ModInt<P> {
         Self(value)
     }
 
-    /// `self` の `x` 乗を計算する
-    pub fn pow(&self, mut x: u32) -> Self {
+    /// `self` の `x` 乗を計算する  
+    /// `x` は [`Exponent`] を実装した整数型 (`u64`, `u128` なども可) で、符号付き整数型のときは非負でなければならない。
+    pub fn pow<E: Exponent>(&self, x: E) -> Self {
+        let mut x = x.to_u128();
         let mut a = *self;
         let mut r = Self::from_raw(1);
 
@@ -34,6 +42,16 @@ impl<const P: u32> ModInt<P> {
         r
     }
 
+    /// `self` の `x` 乗を計算する  
+    /// `x` が負の場合は、乗法逆元の $`-x`$ 乗を計算する (`self` が $`0`$ でないことを前提としている)
+    pub fn pow_signed(&self, x: i64) -> Self {
+        if x >= 0 {
+            self.pow(x as u64)
+        } else {
+            self.inv().pow(x.unsigned_abs())
+        }
+    }
+
     /// `self` の乗法逆元を計算する  
     /// フェルマーの小定理より、`self` の $`P - 2`$ 乗を計算している (`P` が素数であることを前提としている)
     pub fn inv(&self) -> Self {
@@ -41,6 +59,58 @@ impl<const P: u32> ModInt<P> {
     }
 }
 
+/// $`0!, 1!, \dots, n!`$ とその乗法逆元を求める
+pub(crate) fn factorials<const P: u32>(n: usize) -> (Vec<ModInt<P>>, Vec<ModInt<P>>) {
+    let mut fact = vec![ModInt::from_raw(1); n + 1];
+
+    for i in 1..=n {
+        fact[i] = fact[i - 1] * ModInt::from(i);
+    }
+
+    let mut inv_fact = vec![ModInt::from_raw(1); n + 1];
+    inv_fact[n] = fact[n].inv();
+
+    for i in (1..=n).rev() {
+        inv_fact[i - 1] = inv_fact[i] * ModInt::from(i);
+    }
+
+    (fact, inv_fact)
+}
+
+/// [`ModInt::pow`] の指数として使える型
+pub trait Exponent: Copy {
+    fn to_u128(self) -> u128;
+}
+
+macro_rules! impl_exponent {
+    ($($t: ty), *) => {
+        $(
+            impl Exponent for $t {
+                fn to_u128(self) -> u128 {
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_exponent!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_exponent_to_signed {
+    ($($t: ty), *) => {
+        $(
+            impl Exponent for $t {
+                fn to_u128(self) -> u128 {
+                    assert!(self >= 0);
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_exponent_to_signed!(i8, i16, i32, i64, i128, isize);
+
 impl<const P: u32> Add for ModInt<P> {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
@@ -0,0 +1,250 @@
+//! Monotone minima と、それを使った分割統治 DP 高速化を行う。
+//!
+//! $`H \times W`$ 行列 $`A`$ の各行の最小値をとる列(複数ある場合は最も左のもの)を $`\text{argmin}_i`$ とする。
+//! $`\text{argmin}_0 \leq \text{argmin}_1 \leq \dots \leq \text{argmin}_{H - 1}`$ が成り立つとき、$`A`$ は monotone であるという。
+//!
+//! * [`monotone_minima()`] は monotone な行列の各行の最小値をとる列を求める。
+//! * [`smawk()`] は totally monotone な行列の各行の最小値をとる列を、SMAWK algorithm で求める。
+//! * [`divide_and_conquer_dp()`] は、$`\text{cost}`$ が Monge であるような DP
+//!   $`\displaystyle \text{dp}_{c, j} = \min_{i < j} (\text{dp}_{c - 1, i} + \text{cost}(i, j))`$ を高速に計算する。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::monotone_minima::monotone_minima;
+//!
+//! let a = [
+//!     [5, 3, 4, 8],
+//!     [6, 2, 2, 7],
+//!     [9, 7, 1, 4],
+//!     [8, 8, 3, 3],
+//! ];
+//!
+//! assert_eq!(monotone_minima(4, 4, |i, j| a[i][j]), [1, 1, 2, 2]);
+//! ```
+//!
+//! 任意の部分行列が monotone であるとき、$`A`$ は totally monotone であるという。例えば Monge な行列は totally monotone である。
+//! このとき [`smawk()`] を使うと、より高速に各行の最小値をとる列を求められる。
+//!
+//! ```
+//! use library::monotone_minima::{monotone_minima, smawk};
+//!
+//! // A[i][j] = (x_i - y_j)^2 は Monge である
+//! let x = [1i64, 4, 4, 7, 10, 15, 22];
+//! let y = [0i64, 2, 3, 8, 9, 13, 20, 30];
+//! let f = |i: usize, j: usize| (x[i] - y[j]) * (x[i] - y[j]);
+//!
+//! assert_eq!(smawk(7, 8, f), [0, 2, 2, 3, 4, 5, 6]);
+//! assert_eq!(smawk(7, 8, f), monotone_minima(7, 8, f));
+//! ```
+//!
+//! 列を $`k`$ 個の空でない区間に分割するとき、各区間の (要素の和)$`^2`$ の総和の最小値を求める。
+//!
+//! ```
+//! use library::monotone_minima::divide_and_conquer_dp;
+//!
+//! let a = [3, 1, 4, 1, 5, 9, 2, 6];
+//! let mut s = vec![0];
+//! for &x in a.iter() {
+//!     s.push(s.last().unwrap() + x);
+//! }
+//!
+//! // 区間 [i, j) のコスト
+//! let cost = |i: usize, j: usize| (s[j] - s[i]) * (s[j] - s[i]);
+//! let dp = divide_and_conquer_dp(a.len(), 3, cost);
+//!
+//! // 1 個、2 個、3 個に分割したときの最小値
+//! assert_eq!(dp, [961, 485, 341]);
+//! ```
+//!
+//! ## 計算量
+//!
+//! 行列の要素や $`\text{cost}`$ の計算が $`O(1)`$ で行えることを仮定する。
+//!
+//! | 関数 | 計算量 |
+//! | --- | --- |
+//! | `monotone_minima(h, w, f)` | $`O(H + W \log H)`$ |
+//! | `smawk(h, w, f)` | $`O(H + W)`$ |
+//! | `divide_and_conquer_dp(n, k, cost)` | $`O(k N \log N)`$ |
+//!
+
+/// $`H \times W`$ の monotone な行列 $`A_{i, j} = f(i, j)`$ の各行について、最小値をとる列を求める
+///
+/// 最小値をとる列が複数ある場合は、最も左のものを返す。
+pub fn monotone_minima<T: PartialOrd>(
+    h: usize,
+    w: usize,
+    mut f: impl FnMut(usize, usize) -> T,
+) -> Vec<usize> {
+    let mut argmin = vec![0; h];
+
+    if w == 0 {
+        return argmin;
+    }
+
+    // (行の範囲, 列の範囲) を分割統治する
+    let mut stack = vec![(0, h, 0, w)];
+
+    while let Some((top, bottom, left, right)) = stack.pop() {
+        if top >= bottom {
+            continue;
+        }
+
+        let mid = (top + bottom) / 2;
+        let mut best = left;
+        let mut best_value = f(mid, left);
+
+        for j in left + 1..right {
+            let value = f(mid, j);
+
+            if value < best_value {
+                best = j;
+                best_value = value;
+            }
+        }
+
+        argmin[mid] = best;
+        stack.push((top, mid, left, best + 1));
+        stack.push((mid + 1, bottom, best, right));
+    }
+
+    argmin
+}
+
+/// $`H \times W`$ の totally monotone な行列 $`A_{i, j} = f(i, j)`$ の各行について、最小値をとる列を求める
+///
+/// 最小値をとる列が複数ある場合は、最も左のものを返す。
+pub fn smawk<T: PartialOrd>(
+    h: usize,
+    w: usize,
+    mut f: impl FnMut(usize, usize) -> T,
+) -> Vec<usize> {
+    fn solve<T: PartialOrd>(
+        rows: &[usize],
+        cols: &[usize],
+        f: &mut impl FnMut(usize, usize) -> T,
+        argmin: &mut [usize],
+    ) {
+        if rows.is_empty() {
+            return;
+        }
+
+        // REDUCE: どの行でも最小値をとらない列を取り除き、列の数を行の数以下にする
+        let mut reduced: Vec<usize> = vec![];
+
+        for &c in cols {
+            while let Some(&top) = reduced.last() {
+                let r = rows[reduced.len() - 1];
+
+                if f(r, top) <= f(r, c) {
+                    break;
+                }
+
+                reduced.pop();
+            }
+
+            if reduced.len() < rows.len() {
+                reduced.push(c);
+            }
+        }
+
+        // 奇数番目の行を再帰的に解く
+        let odd_rows = rows.iter().skip(1).step_by(2).copied().collect::<Vec<_>>();
+        solve(&odd_rows, &reduced, f, argmin);
+
+        // 偶数番目の行は、前後の奇数番目の行の答えの間を調べればよい
+        let mut k = 0;
+
+        for i in (0..rows.len()).step_by(2) {
+            let last = if i + 1 < rows.len() {
+                argmin[rows[i + 1]]
+            } else {
+                *reduced.last().unwrap()
+            };
+
+            let mut best = reduced[k];
+
+            while reduced[k] != last {
+                k += 1;
+
+                if f(rows[i], best) > f(rows[i], reduced[k]) {
+                    best = reduced[k];
+                }
+            }
+
+            argmin[rows[i]] = best;
+        }
+    }
+
+    let mut argmin = vec![0; h];
+
+    if w == 0 {
+        return argmin;
+    }
+
+    let rows = (0..h).collect::<Vec<_>>();
+    let cols = (0..w).collect::<Vec<_>>();
+    solve(&rows, &cols, &mut f, &mut argmin);
+
+    argmin
+}
+
+/// 長さ $`N`$ の列を $`c`$ 個の空でない区間に分割するときの、区間のコストの和の最小値を $`c = 1, 2, \dots, k`$ について求める
+///
+/// 区間 $`\lbrack i, j)`$ のコストを $`\text{cost}(i, j)`$ とする。$`\text{cost}`$ は Monge であること、つまり
+/// $`i \leq i' \leq j \leq j'`$ に対して $`\text{cost}(i, j) + \text{cost}(i', j') \leq \text{cost}(i, j') + \text{cost}(i', j)`$ を満たすことを要求する。
+///
+/// 返り値の $`c - 1`$ 番目の要素が、$`c`$ 個の区間に分割するときの最小値である。$`k \leq N`$ であることを要求する。
+pub fn divide_and_conquer_dp<T: Copy + PartialOrd + std::ops::Add<Output = T>>(
+    n: usize,
+    k: usize,
+    mut cost: impl FnMut(usize, usize) -> T,
+) -> Vec<T> {
+    assert!(k <= n);
+
+    if k == 0 {
+        return vec![];
+    }
+
+    // dp[j] = 列の先頭 j 個を c 個に分割したときの最小値 (c <= j)
+    let mut dp = (0..=n)
+        .map(|j| if j == 0 { None } else { Some(cost(0, j)) })
+        .collect::<Vec<_>>();
+    let mut ret = vec![dp[n].unwrap()];
+
+    for c in 2..=k {
+        let mut next = vec![None; n + 1];
+
+        // next[j] (c <= j <= n) を、i の候補 c - 1 <= i <= j - 1 から求める
+        let mut stack = vec![(c, n + 1, c - 1, n)];
+
+        while let Some((top, bottom, left, right)) = stack.pop() {
+            if top >= bottom {
+                continue;
+            }
+
+            let mid = (top + bottom) / 2;
+            let right_mid = std::cmp::min(right, mid);
+            let mut best = left;
+            let mut best_value = dp[left].unwrap() + cost(left, mid);
+
+            for (i, d) in dp.iter().enumerate().take(right_mid).skip(left + 1) {
+                let value = d.unwrap() + cost(i, mid);
+
+                if value < best_value {
+                    best = i;
+                    best_value = value;
+                }
+            }
+
+            next[mid] = Some(best_value);
+            stack.push((top, mid, left, best + 1));
+            stack.push((mid + 1, bottom, best, right));
+        }
+
+        dp = next;
+        ret.push(dp[n].unwrap());
+    }
+
+    ret
+}
@@ -0,0 +1,162 @@
+//! 主なクエリの愚直な実装を定義する。
+//!
+//! 計算量は悪いが実装が単純で正しさを確かめやすいので、`tests/` のストレステストで高速なデータ構造の答えと比較するのに使う。
+//!
+//! * [`fold()`], [`range_sum()`], [`range_min()`] は区間の総積・総和・最小値を求める。
+//! * [`kth_smallest()`] は区間内で $`k`$ 番目に小さい値を求める。
+//! * [`shortest_path()`] は単純パスを深さ優先探索ですべて調べて最短距離を求める。
+//! * [`lca()`] は親をたどって最小共通祖先を求める。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::algebra::Max;
+//! use library::dijkstra::Dist;
+//! use library::graph::DirectedAdjGraph;
+//! use library::naive;
+//!
+//! let a = [3, 1, 4, 1, 5, 9, 2, 6];
+//! assert_eq!(naive::range_sum(&a, 2..5), 10);
+//! assert_eq!(naive::range_min(&a, 4..), 2);
+//! assert_eq!(naive::fold::<Max<i32>>(&a, ..=4), 5);
+//! assert_eq!(naive::kth_smallest(&a, 1..6, 2), 4);
+//!
+//! let graph = DirectedAdjGraph::from_edges(4, &[(0, 1, 1u32), (1, 2, 1), (0, 2, 5)]);
+//! assert_eq!(naive::shortest_path(&graph, 0, 2), Dist::VALUE(2));
+//! assert_eq!(naive::shortest_path(&graph, 0, 3), Dist::UNREACHABLE);
+//!
+//! // 0 が根で、parent[v] が v の親
+//! let parent = [u32::MAX, 0, 0, 1, 1, 2];
+//! assert_eq!(naive::lca(&parent, 3, 4), 1);
+//! assert_eq!(naive::lca(&parent, 4, 5), 0);
+//! ```
+//!
+//! ## 計算量
+//!
+//! 列の長さを $`N`$、グラフの頂点数を $`V`$ とする。
+//!
+//! | 関数 | 計算量 |
+//! | --- | --- |
+//! | [`fold()`], [`range_sum()`], [`range_min()`] | $`O(N)`$ |
+//! | [`kth_smallest()`] | $`O(N \log N)`$ |
+//! | [`shortest_path()`] | 単純パスの数に比例する(最悪 $`O(V!)`$) |
+//! | [`lca()`] | $`O(V)`$ |
+//!
+
+use crate::algebra::Monoid;
+use crate::dijkstra::Dist;
+use crate::graph::Graph;
+
+/// `range` 内の要素の総積を求める
+pub fn fold<M: Monoid>(a: &[M::S], range: impl std::ops::RangeBounds<usize>) -> M::S {
+    let (left, right) = to_range(a.len(), range);
+    a[left..right].iter().fold(M::E, |acc, x| M::op(&acc, x))
+}
+
+/// `range` 内の要素の総和を求める
+pub fn range_sum<T: Copy + Default + std::ops::Add<Output = T>>(
+    a: &[T],
+    range: impl std::ops::RangeBounds<usize>,
+) -> T {
+    let (left, right) = to_range(a.len(), range);
+    a[left..right].iter().fold(T::default(), |acc, &x| acc + x)
+}
+
+/// `range` 内の要素の最小値を求める。`range` は空でないことを要求する
+pub fn range_min<T: Copy + Ord>(a: &[T], range: impl std::ops::RangeBounds<usize>) -> T {
+    let (left, right) = to_range(a.len(), range);
+    *a[left..right].iter().min().expect("range is empty")
+}
+
+/// `range` 内の要素を昇順に並べたとき、$`k`$ 番目 (0-indexed) の要素を求める
+pub fn kth_smallest<T: Clone + Ord>(
+    a: &[T],
+    range: impl std::ops::RangeBounds<usize>,
+    k: usize,
+) -> T {
+    let (left, right) = to_range(a.len(), range);
+    let mut b = a[left..right].to_vec();
+    b.sort();
+    b[k].clone()
+}
+
+/// `src` から `dst` への単純パスをすべて調べて、最短距離を求める
+///
+/// 負閉路がなければ、最短経路は単純パスなので正しい答えになる。
+pub fn shortest_path<W: Copy + Default + Ord + std::ops::Add<Output = W>>(
+    graph: &impl Graph<Weight = W>,
+    src: u32,
+    dst: u32,
+) -> Dist<W> {
+    fn dfs<W: Copy + Ord + std::ops::Add<Output = W>>(
+        graph: &impl Graph<Weight = W>,
+        u: u32,
+        dst: u32,
+        d: W,
+        visited: &mut [bool],
+        best: &mut Dist<W>,
+    ) {
+        if u == dst {
+            if let Dist::VALUE(b) = *best {
+                if b <= d {
+                    return;
+                }
+            }
+
+            *best = Dist::VALUE(d);
+            return;
+        }
+
+        visited[u as usize] = true;
+
+        for &(v, w) in graph.adjacent(u) {
+            if !visited[v as usize] {
+                dfs(graph, v, dst, d + w, visited, best);
+            }
+        }
+
+        visited[u as usize] = false;
+    }
+
+    let mut visited = vec![false; graph.size() as usize];
+    let mut best = Dist::UNREACHABLE;
+    dfs(graph, src, dst, W::default(), &mut visited, &mut best);
+    best
+}
+
+/// 根付き木で、`u` と `v` の最小共通祖先を求める
+///
+/// `parent[v]` は頂点 $`v`$ の親で、根の親は `u32::MAX` とする。
+pub fn lca(parent: &[u32], u: u32, v: u32) -> u32 {
+    let mut is_ancestor = vec![false; parent.len()];
+    let mut w = u;
+
+    while w != u32::MAX {
+        is_ancestor[w as usize] = true;
+        w = parent[w as usize];
+    }
+
+    let mut w = v;
+
+    while !is_ancestor[w as usize] {
+        w = parent[w as usize];
+    }
+
+    w
+}
+
+fn to_range(len: usize, range: impl std::ops::RangeBounds<usize>) -> (usize, usize) {
+    let left = match range.start_bound() {
+        std::ops::Bound::Included(&l) => l,
+        std::ops::Bound::Excluded(&l) => l + 1,
+        std::ops::Bound::Unbounded => 0,
+    };
+
+    let right = match range.end_bound() {
+        std::ops::Bound::Included(&r) => r + 1,
+        std::ops::Bound::Excluded(&r) => r,
+        std::ops::Bound::Unbounded => len,
+    };
+
+    (left, right)
+}
@@ -0,0 +1,96 @@
+use crate::graph::{Index, Tree};
+use crate::unionfind::UnionFind;
+
+/// 根付き木の最小共通祖先 (LCA) を求めるクエリをまとめて、オフラインで処理する
+///
+/// Tarjan's offline LCA algorithm を用いる。根を `root` として、各クエリ $`(u, v)`$ について $`u`$ と $`v`$ の LCA を求める。
+/// ダブリングのような $`O(N \log N)`$ の表を持たないため、省メモリである。
+///
+/// ## Examples
+///
+/// ```
+/// use library::graph::UndirectedAdjGraph;
+/// use library::offline_lca::offline_lca;
+///
+/// //       0
+/// //      / \
+/// //     1   2
+/// //    / \   \
+/// //   3   4   5
+/// //       |
+/// //       6
+/// let tree = UndirectedAdjGraph::from_edges_no_weight(
+///     7,
+///     &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (4, 6)],
+/// );
+///
+/// let lca = offline_lca(&tree, 0, &[(3, 6), (6, 5), (4, 6), (2, 2), (1, 6)]);
+/// assert_eq!(lca, [1, 0, 4, 2, 1]);
+///
+/// // 根を変えると LCA も変わる
+/// let lca = offline_lca(&tree, 6, &[(3, 5), (0, 2)]);
+/// assert_eq!(lca, [1, 0]);
+/// ```
+///
+/// ## 計算量
+///
+/// 木の頂点数を $`N`$、クエリの数を $`Q`$ として、$`O((N + Q) \alpha(N))`$ である。
+///
+/// ## Verified problems
+///
+/// * [Lowest Common Ancestor](../../src/lc_lca_02/lc_lca_02.rs.html)
+///
+pub fn offline_lca<W>(
+    tree: &dyn Tree<Weight = W>,
+    root: Index,
+    queries: &[(Index, Index)],
+) -> Vec<Index> {
+    let size = tree.size() as usize;
+
+    // 各頂点について、その頂点を含むクエリの (相手の頂点, クエリの番号) を持つ
+    let mut pending = vec![vec![]; size];
+
+    for (i, &(u, v)) in queries.iter().enumerate() {
+        pending[u as usize].push((v, i));
+        pending[v as usize].push((u, i));
+    }
+
+    let mut uf = UnionFind::new(size);
+    let mut ancestor = (0..size as Index).collect::<Vec<_>>();
+    let mut finished = vec![false; size];
+    let mut lca = vec![Index::MAX; queries.len()];
+
+    // (頂点, 親, 次に見る隣接辺の番号) を積んで、再帰を使わずに深さ優先探索を行う
+    let mut stack = vec![(root, Index::MAX, 0)];
+
+    while let Some((u, parent, i)) = stack.pop() {
+        let adjacent = tree.adjacent(u);
+
+        if i < adjacent.len() {
+            stack.push((u, parent, i + 1));
+            let v = adjacent[i].0;
+
+            if v != parent {
+                stack.push((v, u, 0));
+            }
+
+            continue;
+        }
+
+        finished[u as usize] = true;
+
+        for &(v, q) in pending[u as usize].iter() {
+            if finished[v as usize] {
+                lca[q] = ancestor[uf.find(v as usize)];
+            }
+        }
+
+        if parent != Index::MAX {
+            uf.unite(u as usize, parent as usize);
+            let r = uf.find(parent as usize);
+            ancestor[r] = parent;
+        }
+    }
+
+    lca
+}
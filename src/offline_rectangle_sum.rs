@@ -0,0 +1,171 @@
+use crate::binary_indexed_tree::BinaryIndexedTree;
+
+enum Operation<T> {
+    Point(i64, i64, T),
+    Query(i64, i64, i64, i64, usize),
+}
+
+/// 平面上への重み付きの点の追加と、長方形領域内の点の重みの和を求めるクエリを、オフラインでまとめて処理する
+///
+/// 操作を追加した順に処理したものとして、各クエリの答えを求める。時刻について分割統治 (CDQ 分割統治) を行い、
+/// 前半の点が後半のクエリに与える寄与を、$`x`$ 座標の走査と [`BinaryIndexedTree`] で計算する。
+///
+/// ## Examples
+///
+/// ```
+/// use library::offline_rectangle_sum::OfflineRectangleSum;
+///
+/// let mut rs = OfflineRectangleSum::new();
+/// rs.add_point(0, 0, 1u64);
+/// rs.add_point(2, 3, 10);
+/// let q0 = rs.add_query(0, 3, 0, 4);
+/// rs.add_point(1, 1, 100);
+/// let q1 = rs.add_query(0, 3, 0, 4);
+/// // x = 2 の点は 0 <= x < 2 に含まれない
+/// let q2 = rs.add_query(0, 2, 0, 4);
+///
+/// let ans = rs.solve();
+/// assert_eq!([ans[q0], ans[q1], ans[q2]], [11, 111, 101]);
+/// ```
+///
+/// ## 計算量
+///
+/// 点の数を $`N`$、クエリの数を $`Q`$ として、$`O((N + Q) \log^2 (N + Q))`$ である。
+///
+/// ## Verified problems
+///
+/// * [Rectangle Sum](../../src/lc_rectangle_sum/lc_rectangle_sum.rs.html)
+/// * [Point Add Rectangle Sum](../../src/lc_point_add_rectangle_sum/lc_point_add_rectangle_sum.rs.html)
+///
+pub struct OfflineRectangleSum<T> {
+    operations: Vec<Operation<T>>,
+    queries: usize,
+}
+
+impl<
+        T: Default
+            + Clone
+            + Copy
+            + std::ops::AddAssign
+            + std::ops::Sub<Output = T>
+            + std::ops::Add<Output = T>,
+    > OfflineRectangleSum<T>
+{
+    /// 操作が空の状態で初期化する
+    pub fn new() -> Self {
+        Self {
+            operations: vec![],
+            queries: 0,
+        }
+    }
+
+    /// 点 $`(x, y)`$ に重み $`w`$ を追加する
+    pub fn add_point(&mut self, x: i64, y: i64, w: T) {
+        self.operations.push(Operation::Point(x, y, w));
+    }
+
+    /// $`\text{xl} \leq x < \text{xr}`$ かつ $`\text{yl} \leq y < \text{yr}`$ を満たす点の重みの和を求めるクエリを追加し、クエリの番号を返す
+    ///
+    /// クエリの番号は、[`solve()`](Self::solve) の返り値の添字に対応する。$`\text{xl} \leq \text{xr}`$ かつ $`\text{yl} \leq \text{yr}`$ であることを要求する。
+    pub fn add_query(&mut self, xl: i64, xr: i64, yl: i64, yr: i64) -> usize {
+        assert!(xl <= xr && yl <= yr);
+        self.operations
+            .push(Operation::Query(xl, xr, yl, yr, self.queries));
+        self.queries += 1;
+        self.queries - 1
+    }
+
+    /// すべてのクエリの答えを求める
+    pub fn solve(self) -> Vec<T> {
+        // 答えを (足す分, 引く分) に分けて持つ
+        let mut positive = vec![T::default(); self.queries];
+        let mut negative = vec![T::default(); self.queries];
+
+        self._solve(0, self.operations.len(), &mut positive, &mut negative);
+
+        positive
+            .into_iter()
+            .zip(negative)
+            .map(|(p, n)| p - n)
+            .collect()
+    }
+
+    fn _solve(&self, l: usize, r: usize, positive: &mut [T], negative: &mut [T]) {
+        if r - l <= 1 {
+            return;
+        }
+
+        let m = (l + r) / 2;
+        self._solve(l, m, positive, negative);
+        self._solve(m, r, positive, negative);
+
+        let mut points = self.operations[l..m]
+            .iter()
+            .filter_map(|op| match *op {
+                Operation::Point(x, y, w) => Some((x, y, w)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // (x 座標, 足すか, クエリ)
+        let mut edges = self.operations[m..r]
+            .iter()
+            .filter_map(|op| match *op {
+                Operation::Query(xl, xr, yl, yr, q) => {
+                    Some([(xl, false, yl, yr, q), (xr, true, yl, yr, q)])
+                }
+                _ => None,
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+
+        if points.is_empty() || edges.is_empty() {
+            return;
+        }
+
+        points.sort_by_key(|p| p.0);
+        edges.sort_by_key(|e| e.0);
+
+        let mut ys = points.iter().map(|p| p.1).collect::<Vec<_>>();
+        ys.sort();
+        ys.dedup();
+
+        let mut bit = BinaryIndexedTree::new(ys.len());
+        let mut i = 0;
+
+        for (x, add, yl, yr, q) in edges {
+            while i < points.len() && points[i].0 < x {
+                let (_, y, w) = points[i];
+                bit.add(ys.partition_point(|&v| v < y), w);
+                i += 1;
+            }
+
+            let lo = ys.partition_point(|&v| v < yl);
+            let hi = ys.partition_point(|&v| v < yr);
+
+            if lo >= hi {
+                continue;
+            }
+
+            if add {
+                positive[q] += bit.sum(lo..hi);
+            } else {
+                negative[q] += bit.sum(lo..hi);
+            }
+        }
+    }
+}
+
+impl<
+        T: Default
+            + Clone
+            + Copy
+            + std::ops::AddAssign
+            + std::ops::Sub<Output = T>
+            + std::ops::Add<Output = T>,
+    > Default for OfflineRectangleSum<T>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,172 @@
+/// 回文木 (eertree)
+///
+/// 列の末尾に要素を追加しながら、それまでに現れた相異なる回文(連続部分列)を管理する。
+/// 各頂点が相異なる回文 $`1`$ つに対応し、回文 $`p`$ の両端に要素 $`c`$ を付け足した回文 $`c p c`$ への辺と、
+/// $`p`$ の真の接尾辞である最長の回文への辺 (suffix link) を持つ。
+///
+/// 頂点 $`0`$ は長さ $`-1`$ の、頂点 $`1`$ は長さ $`0`$ の仮想的な回文に対応する根で、頂点 $`2`$ 以降が空でない回文に対応する。
+/// 頂点は作られた順に番号付けされ、suffix link の先の頂点番号は常に小さい。
+///
+/// ## Examples
+///
+/// ```
+/// use library::palindromic_tree::PalindromicTree;
+///
+/// let mut tree = PalindromicTree::new();
+/// for c in "abacaba".chars() {
+///     tree.push(c);
+/// }
+///
+/// // a, b, c, aba, aca, bacab, abacaba
+/// assert_eq!(tree.distinct_count(), 7);
+///
+/// // 最後に追加した位置で終わる最長の回文は "abacaba"
+/// let v = tree.last();
+/// assert_eq!(tree.len(v), 7);
+/// assert_eq!(tree.first_occurrence(v), 0..7);
+///
+/// // 各回文の出現回数: "a" は 4 回、"aba" は 2 回
+/// let count = tree.occurrences();
+/// let a = tree.suffix_link(tree.suffix_link(v));
+/// assert_eq!(tree.len(a), 1);
+/// assert_eq!(count[a], 4);
+/// assert_eq!(count[tree.suffix_link(v)], 2);
+/// ```
+///
+/// ## 計算量
+///
+/// 列の長さを $`N`$ とし、要素のハッシュ値の計算が $`O(1)`$ で行えることを仮定する。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `new()` | 空の列に対する回文木を作る | $`O(1)`$ |
+/// | `self.push(c)` | 列の末尾に `c` を追加する | ならし $`O(1)`$ |
+/// | `self.occurrences()` | 各回文の出現回数を求める | $`O(N)`$ |
+/// | その他 | | $`O(1)`$ |
+///
+pub struct PalindromicTree<T> {
+    s: Vec<T>,
+    len: Vec<isize>,
+    link: Vec<usize>,
+    next: Vec<std::collections::HashMap<T, usize>>,
+    // 各頂点が、追加した位置で終わる最長の回文になった回数
+    count: Vec<usize>,
+    // 各頂点が初めて現れたときの終端
+    end: Vec<usize>,
+    last: usize,
+}
+
+impl<T: Eq + std::hash::Hash + Copy> PalindromicTree<T> {
+    /// 空の列に対する回文木を作る
+    pub fn new() -> Self {
+        Self {
+            s: vec![],
+            len: vec![-1, 0],
+            link: vec![0, 0],
+            next: vec![
+                std::collections::HashMap::new(),
+                std::collections::HashMap::new(),
+            ],
+            count: vec![0, 0],
+            end: vec![0, 0],
+            last: 1,
+        }
+    }
+
+    /// `s[..=i]` の回文である接尾辞のうち、頂点 `v` から suffix link を辿って最初に見つかる、両端に `s[i]` を付け足せるものを探す
+    fn find(&self, mut v: usize, i: usize) -> usize {
+        loop {
+            let j = i as isize - self.len[v] - 1;
+            if j >= 0 && self.s[j as usize] == self.s[i] {
+                return v;
+            }
+            v = self.link[v];
+        }
+    }
+
+    /// 列の末尾に `c` を追加し、追加した位置で終わる最長の回文の頂点を返す
+    pub fn push(&mut self, c: T) -> usize {
+        let i = self.s.len();
+        self.s.push(c);
+
+        let cur = self.find(self.last, i);
+
+        if let Some(&v) = self.next[cur].get(&c) {
+            self.count[v] += 1;
+            self.last = v;
+            return v;
+        }
+
+        let link = if self.len[cur] == -1 {
+            1
+        } else {
+            let w = self.find(self.link[cur], i);
+            self.next[w][&c]
+        };
+
+        let v = self.len.len();
+        self.len.push(self.len[cur] + 2);
+        self.link.push(link);
+        self.next.push(std::collections::HashMap::new());
+        self.count.push(1);
+        self.end.push(i + 1);
+        self.next[cur].insert(c, v);
+
+        self.last = v;
+        v
+    }
+
+    /// 最後に追加した位置で終わる最長の回文の頂点 (空の列なら $`1`$)
+    pub fn last(&self) -> usize {
+        self.last
+    }
+
+    /// 根を含めた頂点数
+    pub fn node_count(&self) -> usize {
+        self.len.len()
+    }
+
+    /// 相異なる空でない回文の数
+    pub fn distinct_count(&self) -> usize {
+        self.len.len() - 2
+    }
+
+    /// 頂点 `v` の回文の長さ ($`v \geq 1`$)
+    pub fn len(&self, v: usize) -> usize {
+        assert!(v >= 1);
+        self.len[v] as usize
+    }
+
+    /// 頂点 `v` の suffix link の先の頂点
+    pub fn suffix_link(&self, v: usize) -> usize {
+        self.link[v]
+    }
+
+    /// 頂点 `v` の回文の両端に `c` を付け足した回文の頂点
+    pub fn next(&self, v: usize, c: T) -> Option<usize> {
+        self.next[v].get(&c).copied()
+    }
+
+    /// 頂点 `v` の回文が初めて現れた区間 ($`v \geq 2`$)
+    pub fn first_occurrence(&self, v: usize) -> std::ops::Range<usize> {
+        assert!(v >= 2);
+        self.end[v] - self.len[v] as usize..self.end[v]
+    }
+
+    /// 各頂点の回文が、列に何回現れるかを求める
+    pub fn occurrences(&self) -> Vec<usize> {
+        let mut count = self.count.clone();
+
+        for (v, &link) in self.link.iter().enumerate().skip(2).rev() {
+            count[link] += count[v];
+        }
+
+        count
+    }
+}
+
+impl<T: Eq + std::hash::Hash + Copy> Default for PalindromicTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
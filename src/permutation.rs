@@ -0,0 +1,385 @@
+/// $`\{ 0, 1, \dots, N - 1 \}`$ 上の置換
+///
+/// $`\sigma(i)`$ を `self[i]` で表す。構築時に全単射であることを確認する。
+///
+/// [`Doubling`](crate::doubling::Doubling) は一般の写像 $`f`$ について $`f^K`$ を計算するが、置換であれば巡回置換分解を使って $`\sigma^K`$ を直接計算できる。
+///
+/// ## Examples
+///
+/// ```
+/// use library::permutation::Permutation;
+///
+/// let p = Permutation::from(&[1, 2, 0, 4, 3]);
+/// let q = Permutation::from(&[0, 2, 1, 3, 4]);
+///
+/// // (p * q)[i] = p[q[i]]
+/// assert_eq!(p.compose(&q), Permutation::from(&[1, 0, 2, 4, 3]));
+/// assert_eq!(&p * &q, p.compose(&q));
+///
+/// assert_eq!(p.inverse(), Permutation::from(&[2, 0, 1, 4, 3]));
+/// assert_eq!(p.compose(&p.inverse()), Permutation::identity(5));
+///
+/// assert_eq!(p.cycles(), vec![vec![0, 1, 2], vec![3, 4]]);
+/// assert_eq!(p.order(), 6);
+/// assert_eq!(p.pow(6), Permutation::identity(5));
+/// assert_eq!(p.pow(1_000_000_000_000), p.pow(4));
+///
+/// // 長さ 3 の巡回置換は偶置換、互換は奇置換なので、p は奇置換
+/// assert!(!p.is_even());
+/// assert!(q.compose(&q).is_even());
+///
+/// // a の要素を並べ替える: b[i] = a[p[i]]
+/// assert_eq!(p.apply(&['a', 'b', 'c', 'd', 'e']), ['b', 'c', 'a', 'e', 'd']);
+/// ```
+///
+/// 辞書順で何番目の置換かを、階乗進数表記を使って相互に変換できる。
+///
+/// ```
+/// use library::permutation::{next_permutation, Permutation};
+///
+/// let p = Permutation::from_rank(4, 10);
+/// assert_eq!(p, Permutation::from(&[1, 3, 0, 2]));
+/// assert_eq!(p.rank(), 10);
+///
+/// // 辞書順で次の置換は 11 番目である
+/// let mut a = [1, 3, 0, 2];
+/// assert!(next_permutation(&mut a));
+/// assert_eq!(Permutation::from(&a).rank(), 11);
+/// ```
+///
+/// ## 計算量
+///
+/// $`N`$ を置換の大きさとする。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `from(perm)` | `perm` が全単射であることを確認して置換を生成する | $`O(N)`$ |
+/// | `from_rank(n, k)` | 辞書順で $`k`$ 番目の置換を生成する | $`O(N^2)`$ |
+/// | `self.rank()` | 自身が辞書順で何番目かを求める | $`O(N^2)`$ |
+/// | `identity(n)` | 恒等置換を生成する | $`O(N)`$ |
+/// | `self.compose(other)` | $`\text{self} \circ \text{other}`$ を求める | $`O(N)`$ |
+/// | `self.inverse()` | 逆置換を求める | $`O(N)`$ |
+/// | `self.cycles()` | 巡回置換分解を求める | $`O(N)`$ |
+/// | `self.pow(k)` | $`\text{self}^k`$ を求める | $`O(N)`$ |
+/// | `self.order()` | $`\text{self}^k`$ が恒等置換になる最小の正整数 $`k`$ を求める | $`O(N \log N)`$ |
+/// | `self.is_even()` | 偶置換であるかを調べる | $`O(N)`$ |
+/// | `self.apply(a)` | `a` を並べ替える | $`O(N)`$ |
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Permutation {
+    perm: Vec<usize>,
+}
+
+impl Permutation {
+    /// `perm` から置換を生成する
+    /// `perm` が $`\{ 0, 1, \dots, \lvert \text{perm} \rvert - 1 \}`$ 上の全単射でなければ panic する
+    pub fn from(perm: &[usize]) -> Self {
+        let mut seen = vec![false; perm.len()];
+
+        for &p in perm {
+            assert!(p < perm.len() && !seen[p], "not a permutation");
+            seen[p] = true;
+        }
+
+        Self {
+            perm: perm.to_vec(),
+        }
+    }
+
+    /// 大きさ `n` の置換を辞書順に並べたときの、$`k`$ 番目 (0-indexed) の置換を生成する
+    ///
+    /// 階乗進数表記を使う。$`n \leq 20`$ かつ $`k < n!`$ であることを要求する。
+    pub fn from_rank(n: usize, mut k: u64) -> Self {
+        assert!(n <= 20);
+        let factorial = Self::factorials(n);
+        assert!(k < factorial[n]);
+
+        let mut unused = (0..n).collect::<Vec<_>>();
+        let mut perm = vec![];
+
+        for i in (0..n).rev() {
+            let d = (k / factorial[i]) as usize;
+            k %= factorial[i];
+            perm.push(unused.remove(d));
+        }
+
+        Self { perm }
+    }
+
+    /// 大きさが同じ置換を辞書順に並べたとき、自身が何番目 (0-indexed) かを求める
+    ///
+    /// 大きさが $`20`$ 以下であることを要求する。
+    pub fn rank(&self) -> u64 {
+        let n = self.len();
+        assert!(n <= 20);
+        let factorial = Self::factorials(n);
+
+        let mut used = vec![false; n];
+        let mut rank = 0;
+
+        for (i, &p) in self.perm.iter().enumerate() {
+            let smaller = (0..p).filter(|&q| !used[q]).count() as u64;
+            rank += smaller * factorial[n - 1 - i];
+            used[p] = true;
+        }
+
+        rank
+    }
+
+    fn factorials(n: usize) -> Vec<u64> {
+        let mut factorial = vec![1u64; n + 1];
+
+        for i in 1..=n {
+            factorial[i] = factorial[i - 1] * i as u64;
+        }
+
+        factorial
+    }
+
+    /// 大きさ `n` の恒等置換を生成する
+    pub fn identity(n: usize) -> Self {
+        Self {
+            perm: (0..n).collect(),
+        }
+    }
+
+    /// 置換の大きさを返す
+    pub fn len(&self) -> usize {
+        self.perm.len()
+    }
+
+    /// 置換の大きさが $`0`$ であるかを返す
+    pub fn is_empty(&self) -> bool {
+        self.perm.is_empty()
+    }
+
+    /// $`(\text{self} \circ \text{other})(i) = \text{self}(\text{other}(i))`$ である置換を求める
+    pub fn compose(&self, other: &Self) -> Self {
+        assert_eq!(self.len(), other.len());
+
+        Self {
+            perm: other.perm.iter().map(|&i| self.perm[i]).collect(),
+        }
+    }
+
+    /// 逆置換を求める
+    pub fn inverse(&self) -> Self {
+        let mut perm = vec![0; self.len()];
+
+        for (i, &p) in self.perm.iter().enumerate() {
+            perm[p] = i;
+        }
+
+        Self { perm }
+    }
+
+    /// 巡回置換分解を求める
+    ///
+    /// 各巡回は $`c_0 \mapsto c_1 \mapsto \dots \mapsto c_0`$ の順に並んでいて、$`c_0`$ はその巡回で最小の要素である。
+    /// 巡回は $`c_0`$ の昇順に並ぶ。
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; self.len()];
+        let mut cycles = vec![];
+
+        for i in 0..self.len() {
+            if seen[i] {
+                continue;
+            }
+
+            let mut cycle = vec![];
+            let mut v = i;
+
+            while !seen[v] {
+                seen[v] = true;
+                cycle.push(v);
+                v = self.perm[v];
+            }
+
+            cycles.push(cycle);
+        }
+
+        cycles
+    }
+
+    /// $`\text{self}^k`$ を求める
+    pub fn pow(&self, k: u64) -> Self {
+        let mut perm = vec![0; self.len()];
+
+        for cycle in self.cycles() {
+            let l = cycle.len();
+            let shift = (k % l as u64) as usize;
+
+            for (j, &v) in cycle.iter().enumerate() {
+                perm[v] = cycle[(j + shift) % l];
+            }
+        }
+
+        Self { perm }
+    }
+
+    /// $`\text{self}^k`$ が恒等置換になる最小の正整数 $`k`$ を求める
+    ///
+    /// 巡回の長さの最小公倍数である。`u128` に収まらない場合は panic する。
+    pub fn order(&self) -> u128 {
+        fn gcd(a: u128, b: u128) -> u128 {
+            if b == 0 {
+                a
+            } else {
+                gcd(b, a % b)
+            }
+        }
+
+        let mut order = 1u128;
+
+        for cycle in self.cycles() {
+            let l = cycle.len() as u128;
+            order = (order / gcd(order, l))
+                .checked_mul(l)
+                .expect("order overflows u128");
+        }
+
+        order
+    }
+
+    /// 偶置換であるかを調べる
+    pub fn is_even(&self) -> bool {
+        (self.len() - self.cycles().len()) % 2 == 0
+    }
+
+    /// `a` を並べ替えた列 `b` を求める
+    /// $`b_i = a_{\text{self}(i)}`$ である
+    pub fn apply<T: Clone>(&self, a: &[T]) -> Vec<T> {
+        assert_eq!(self.len(), a.len());
+        self.perm.iter().map(|&i| a[i].clone()).collect()
+    }
+}
+
+impl std::ops::Index<usize> for Permutation {
+    type Output = usize;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.perm[index]
+    }
+}
+
+impl std::ops::Mul for &Permutation {
+    type Output = Permutation;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.compose(rhs)
+    }
+}
+
+impl std::fmt::Display for Permutation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.perm
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    }
+}
+
+/// `a` を辞書順で次の並びに並べ替える
+///
+/// 次の並びが存在する場合は `true` を返す。存在しない場合、`a` を辞書順で最小の並び(昇順)にして `false` を返す。
+///
+/// ## Examples
+///
+/// ```
+/// use library::permutation::next_permutation;
+///
+/// let mut a = [1, 2, 2];
+/// let mut all = vec![a];
+///
+/// while next_permutation(&mut a) {
+///     all.push(a);
+/// }
+///
+/// assert_eq!(all, [[1, 2, 2], [2, 1, 2], [2, 2, 1]]);
+/// assert_eq!(a, [1, 2, 2]);
+/// ```
+///
+/// ## 計算量
+///
+/// `a` の長さを $`N`$ として、$`O(N)`$ である。
+///
+pub fn next_permutation<T: Ord>(a: &mut [T]) -> bool {
+    if a.len() < 2 {
+        return false;
+    }
+
+    // a[i] < a[i + 1] を満たす最大の i を探す
+    let mut i = a.len() - 1;
+
+    while i > 0 && a[i - 1] >= a[i] {
+        i -= 1;
+    }
+
+    if i == 0 {
+        a.reverse();
+        return false;
+    }
+
+    let mut j = a.len() - 1;
+
+    while a[j] <= a[i - 1] {
+        j -= 1;
+    }
+
+    a.swap(i - 1, j);
+    a[i..].reverse();
+    true
+}
+
+/// `a` を辞書順で前の並びに並べ替える
+///
+/// 前の並びが存在する場合は `true` を返す。存在しない場合、`a` を辞書順で最大の並び(降順)にして `false` を返す。
+///
+/// ## Examples
+///
+/// ```
+/// use library::permutation::prev_permutation;
+///
+/// let mut a = ['c', 'a', 'b'];
+/// assert!(prev_permutation(&mut a));
+/// assert_eq!(a, ['b', 'c', 'a']);
+///
+/// let mut a = [1, 2, 3];
+/// assert!(!prev_permutation(&mut a));
+/// assert_eq!(a, [3, 2, 1]);
+/// ```
+///
+/// ## 計算量
+///
+/// `a` の長さを $`N`$ として、$`O(N)`$ である。
+///
+pub fn prev_permutation<T: Ord>(a: &mut [T]) -> bool {
+    if a.len() < 2 {
+        return false;
+    }
+
+    // a[i] > a[i + 1] を満たす最大の i を探す
+    let mut i = a.len() - 1;
+
+    while i > 0 && a[i - 1] <= a[i] {
+        i -= 1;
+    }
+
+    if i == 0 {
+        a.reverse();
+        return false;
+    }
+
+    let mut j = a.len() - 1;
+
+    while a[j] >= a[i - 1] {
+        j -= 1;
+    }
+
+    a.swap(i - 1, j);
+    a[i..].reverse();
+    true
+}
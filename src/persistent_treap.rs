@@ -0,0 +1,248 @@
+use rand::Rng;
+use std::rc::Rc;
+
+struct Node<T> {
+    value: T,
+    size: usize,
+    left: Option<Rc<Node<T>>>,
+    right: Option<Rc<Node<T>>>,
+}
+
+type Link<T> = Option<Rc<Node<T>>>;
+
+fn size<T>(node: &Link<T>) -> usize {
+    node.as_ref().map_or(0, |n| n.size)
+}
+
+fn make<T>(value: T, left: Link<T>, right: Link<T>) -> Link<T> {
+    Some(Rc::new(Node {
+        size: size(&left) + size(&right) + 1,
+        value,
+        left,
+        right,
+    }))
+}
+
+/// 列を表す永続平衡二分探索木
+///
+/// 操作のたびに根からのパス上の頂点だけを複製するので、操作前の列もそのまま使い続けられる。
+/// 複製すると頂点の優先度が重複してしまうため、優先度は持たず、[`merge()`](Self::merge) では部分木の大きさに比例した確率で根を選ぶ。
+///
+/// ## Examples
+///
+/// ```
+/// use library::persistent_treap::PersistentTreap;
+///
+/// let v0 = PersistentTreap::from(&[1, 2, 3, 4, 5]);
+/// let v1 = v0.insert(2, 10);
+/// let v2 = v1.remove(0);
+/// let v3 = v2.set(3, 20);
+///
+/// // 以前の列は変更されない
+/// assert_eq!(v0.to_vec(), [1, 2, 3, 4, 5]);
+/// assert_eq!(v1.to_vec(), [1, 2, 10, 3, 4, 5]);
+/// assert_eq!(v2.to_vec(), [2, 10, 3, 4, 5]);
+/// assert_eq!(v3.to_vec(), [2, 10, 3, 20, 5]);
+///
+/// assert_eq!(v3.get(1), &10);
+/// assert_eq!(v3.len(), 5);
+///
+/// // 列を分割・連結する
+/// let (a, b) = v0.split(2);
+/// assert_eq!(a.to_vec(), [1, 2]);
+/// assert_eq!(b.to_vec(), [3, 4, 5]);
+/// assert_eq!(b.merge(&a).merge(&a).to_vec(), [3, 4, 5, 1, 2, 1, 2]);
+/// ```
+///
+/// ## 計算量
+///
+/// $`N`$ を列の長さとする。`from` 以外の操作は、期待 $`O(\log N)`$ 個の頂点を新たに作る。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `from(a)` | 列 `a` を表す木を構築する | $`O(N)`$ |
+/// | `self.get(k)` | $`k`$ 番目の要素を求める | 期待 $`O(\log N)`$ |
+/// | `self.split(k)` | 先頭 $`k`$ 個とそれ以降に分割する | 期待 $`O(\log N)`$ |
+/// | `self.merge(other)` | `self` と `other` を連結する | 期待 $`O(\log N)`$ |
+/// | `self.insert(k, value)` | $`k`$ 番目に `value` を挿入する | 期待 $`O(\log N)`$ |
+/// | `self.remove(k)` | $`k`$ 番目の要素を削除する | 期待 $`O(\log N)`$ |
+/// | `self.set(k, value)` | $`k`$ 番目の要素を `value` にする | 期待 $`O(\log N)`$ |
+/// | `self.to_vec()` | 列を `Vec` に変換する | $`O(N)`$ |
+///
+pub struct PersistentTreap<T> {
+    root: Link<T>,
+}
+
+impl<T> Clone for PersistentTreap<T> {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+        }
+    }
+}
+
+impl<T: Clone> PersistentTreap<T> {
+    /// 空の列を生成する
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+
+    /// 列 `a` を表す木を構築する
+    pub fn from(a: &[T]) -> Self {
+        fn build<T: Clone>(a: &[T]) -> Link<T> {
+            if a.is_empty() {
+                return None;
+            }
+
+            let m = a.len() / 2;
+            make(a[m].clone(), build(&a[..m]), build(&a[m + 1..]))
+        }
+
+        Self { root: build(a) }
+    }
+
+    /// 列の長さを返す
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// 列が空であるかを返す
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// $`k`$ 番目 (0-indexed) の要素を返す
+    pub fn get(&self, mut k: usize) -> &T {
+        assert!(k < self.len());
+        let mut node = self.root.as_ref().unwrap();
+
+        loop {
+            let l = size(&node.left);
+
+            match k.cmp(&l) {
+                std::cmp::Ordering::Less => node = node.left.as_ref().unwrap(),
+                std::cmp::Ordering::Equal => return &node.value,
+                std::cmp::Ordering::Greater => {
+                    k -= l + 1;
+                    node = node.right.as_ref().unwrap();
+                }
+            }
+        }
+    }
+
+    /// 先頭 $`k`$ 個の要素からなる列と、残りの要素からなる列に分割する
+    pub fn split(&self, k: usize) -> (Self, Self) {
+        assert!(k <= self.len());
+        let (l, r) = Self::_split(&self.root, k);
+        (Self { root: l }, Self { root: r })
+    }
+
+    /// `self` の後ろに `other` を連結した列を返す
+    pub fn merge(&self, other: &Self) -> Self {
+        Self {
+            root: Self::_merge(&self.root, &other.root),
+        }
+    }
+
+    /// $`k`$ 番目に `value` を挿入した列を返す
+    pub fn insert(&self, k: usize, value: T) -> Self {
+        assert!(k <= self.len());
+        let (l, r) = Self::_split(&self.root, k);
+        let mid = make(value, None, None);
+
+        Self {
+            root: Self::_merge(&Self::_merge(&l, &mid), &r),
+        }
+    }
+
+    /// $`k`$ 番目の要素を削除した列を返す
+    pub fn remove(&self, k: usize) -> Self {
+        assert!(k < self.len());
+        let (l, r) = Self::_split(&self.root, k);
+        let (_, r) = Self::_split(&r, 1);
+
+        Self {
+            root: Self::_merge(&l, &r),
+        }
+    }
+
+    /// $`k`$ 番目の要素を `value` に変更した列を返す
+    pub fn set(&self, k: usize, value: T) -> Self {
+        fn set<T: Clone>(node: &Rc<Node<T>>, k: usize, value: T) -> Link<T> {
+            let l = size(&node.left);
+
+            match k.cmp(&l) {
+                std::cmp::Ordering::Less => {
+                    let left = set(node.left.as_ref().unwrap(), k, value);
+                    make(node.value.clone(), left, node.right.clone())
+                }
+                std::cmp::Ordering::Equal => make(value, node.left.clone(), node.right.clone()),
+                std::cmp::Ordering::Greater => {
+                    let right = set(node.right.as_ref().unwrap(), k - l - 1, value);
+                    make(node.value.clone(), node.left.clone(), right)
+                }
+            }
+        }
+
+        assert!(k < self.len());
+
+        Self {
+            root: set(self.root.as_ref().unwrap(), k, value),
+        }
+    }
+
+    /// 列を `Vec` に変換する
+    pub fn to_vec(&self) -> Vec<T> {
+        fn dfs<T: Clone>(node: &Link<T>, ret: &mut Vec<T>) {
+            if let Some(node) = node {
+                dfs(&node.left, ret);
+                ret.push(node.value.clone());
+                dfs(&node.right, ret);
+            }
+        }
+
+        let mut ret = Vec::with_capacity(self.len());
+        dfs(&self.root, &mut ret);
+        ret
+    }
+
+    fn _split(node: &Link<T>, k: usize) -> (Link<T>, Link<T>) {
+        let node = match node {
+            Some(node) => node,
+            None => return (None, None),
+        };
+
+        let l = size(&node.left);
+
+        if k <= l {
+            let (a, b) = Self::_split(&node.left, k);
+            (a, make(node.value.clone(), b, node.right.clone()))
+        } else {
+            let (a, b) = Self::_split(&node.right, k - l - 1);
+            (make(node.value.clone(), node.left.clone(), a), b)
+        }
+    }
+
+    fn _merge(a: &Link<T>, b: &Link<T>) -> Link<T> {
+        let (x, y) = match (a, b) {
+            (None, _) => return b.clone(),
+            (_, None) => return a.clone(),
+            (Some(x), Some(y)) => (x, y),
+        };
+
+        // 大きさに比例した確率で根を選ぶと、ランダムな優先度を持たせたときと同じ分布になる
+        if rand::thread_rng().gen_range(0..x.size + y.size) < x.size {
+            let right = Self::_merge(&x.right, b);
+            make(x.value.clone(), x.left.clone(), right)
+        } else {
+            let left = Self::_merge(a, &y.left);
+            make(y.value.clone(), left, y.right.clone())
+        }
+    }
+}
+
+impl<T: Clone> Default for PersistentTreap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
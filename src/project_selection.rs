@@ -0,0 +1,113 @@
+use crate::integer_traits::HasMaxValue;
+use crate::max_flow::MaxFlow;
+
+/// 燃やす埋める問題 (project selection problem) を最小カットに帰着して解く
+///
+/// $`N`$ 個の項目それぞれについて、選ぶか選ばないかを決める。選ぶ・選ばないことによる利得と、
+/// 「$`a`$ を選んで $`b`$ を選ばないと $`c \geq 0`$ の損失」という形の制約を追加していき、利得の合計の最大値を求める。
+///
+/// 始点側を「選ぶ」、終点側を「選ばない」として、各制約をカットの辺に変換する。
+///
+/// ## Examples
+///
+/// ```
+/// use library::project_selection::ProjectSelection;
+///
+/// // 項目 0, 1 はそれぞれ利益 10, 3、項目 2 は費用 8 (利益 -8) で、項目 0 を選ぶには項目 2 も選ぶ必要がある
+/// let mut ps = ProjectSelection::new(3);
+/// ps.gain_if_chosen(0, 10i64);
+/// ps.gain_if_chosen(1, 3);
+/// ps.gain_if_chosen(2, -8);
+/// ps.require(0, 2);
+///
+/// assert_eq!(ps.solve(), (5, vec![true, true, true]));
+///
+/// // 項目 1 を選んで項目 2 を選ばないと 4 の損失
+/// let mut ps = ProjectSelection::new(3);
+/// ps.gain_if_chosen(1, 3i64);
+/// ps.gain_if_not_chosen(2, 2);
+/// ps.penalty_if_chosen_without(1, 2, 4);
+///
+/// assert_eq!(ps.solve(), (3, vec![false, true, true]));
+/// ```
+///
+/// ## 計算量
+///
+/// 項目数を $`N`$、制約の数を $`M`$ として、[`MaxFlow`] の計算量 $`O((N + M)^2 M)`$ で抑えられる。
+///
+pub struct ProjectSelection<C> {
+    size: usize,
+    base: C,
+    flow: MaxFlow<C>,
+}
+
+impl<
+        C: Default
+            + Copy
+            + Ord
+            + std::ops::Add<Output = C>
+            + std::ops::Sub<Output = C>
+            + std::ops::AddAssign
+            + std::ops::SubAssign
+            + HasMaxValue,
+    > ProjectSelection<C>
+{
+    /// 項目数 `size` で、制約のない状態から始める
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            base: C::default(),
+            flow: MaxFlow::new(size + 2),
+        }
+    }
+
+    /// 項目 `i` を選ぶと `g` の利得を得る (`g` は負でもよい)
+    pub fn gain_if_chosen(&mut self, i: usize, g: C) {
+        if g >= C::default() {
+            self.base += g;
+            self.penalty_if_not_chosen(i, g);
+        } else {
+            self.penalty_if_chosen(i, C::default() - g);
+        }
+    }
+
+    /// 項目 `i` を選ばないと `g` の利得を得る (`g` は負でもよい)
+    pub fn gain_if_not_chosen(&mut self, i: usize, g: C) {
+        if g >= C::default() {
+            self.base += g;
+            self.penalty_if_chosen(i, g);
+        } else {
+            self.penalty_if_not_chosen(i, C::default() - g);
+        }
+    }
+
+    /// 項目 `a` を選んで項目 `b` を選ばないと `c` の損失を被る (`c` は非負)
+    pub fn penalty_if_chosen_without(&mut self, a: usize, b: usize, c: C) {
+        assert!(c >= C::default());
+        self.flow.add_edge(a, b, c);
+    }
+
+    /// 項目 `a` を選ぶなら、項目 `b` も選ばなければならない
+    pub fn require(&mut self, a: usize, b: usize) {
+        self.flow.add_edge(a, b, C::MAX);
+    }
+
+    /// 利得の合計の最大値と、そのときに各項目を選ぶかを返す
+    pub fn solve(mut self) -> (C, Vec<bool>) {
+        let (s, t) = (self.size, self.size + 1);
+
+        let cut = self.flow.flow(s, t);
+        let mut chosen = self.flow.min_cut(s);
+        chosen.truncate(self.size);
+
+        (self.base - cut, chosen)
+    }
+
+    fn penalty_if_chosen(&mut self, i: usize, c: C) {
+        self.flow.add_edge(i, self.size + 1, c);
+    }
+
+    fn penalty_if_not_chosen(&mut self, i: usize, c: C) {
+        self.flow.add_edge(self.size, i, c);
+    }
+}
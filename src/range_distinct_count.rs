@@ -0,0 +1,59 @@
+use crate::binary_indexed_tree::BinaryIndexedTree;
+
+/// 静的な列 `a` について、区間 $`\lbrack l, r)`$ に含まれる値の種類数を求めるクエリをまとめて処理する
+///
+/// クエリを右端の昇順に処理し、各値について最後に現れた位置にだけ $`1`$ を置いた [`BinaryIndexedTree`] の区間和を求める。
+///
+/// ## Examples
+///
+/// ```
+/// use library::range_distinct_count::range_distinct_count;
+///
+/// let a = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+///
+/// let queries = [(0, 4), (1, 4), (0, 10), (3, 9), (5, 5)];
+/// assert_eq!(range_distinct_count(&a, &queries), [3, 2, 7, 5, 0]);
+/// ```
+///
+/// ## 計算量
+///
+/// 列の長さを $`N`$、クエリの数を $`Q`$ として、$`O((N + Q) \log N + Q \log Q)`$ である。
+///
+/// ## Verified problems
+///
+/// * [Static Range Count Distinct](../../src/lc_static_range_count_distinct/lc_static_range_count_distinct.rs.html)
+///
+pub fn range_distinct_count<T: Eq + std::hash::Hash>(
+    a: &[T],
+    queries: &[(usize, usize)],
+) -> Vec<usize> {
+    let n = a.len();
+
+    let mut order = (0..queries.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&q| queries[q].1);
+
+    let mut bit: BinaryIndexedTree<i32> = BinaryIndexedTree::new(n);
+    let mut last = std::collections::HashMap::new();
+    let mut ret = vec![0; queries.len()];
+    let mut r = 0;
+
+    for q in order {
+        let (left, right) = queries[q];
+        assert!(left <= right && right <= n);
+
+        while r < right {
+            if let Some(prev) = last.insert(&a[r], r) {
+                bit.add(prev, -1);
+            }
+
+            bit.add(r, 1);
+            r += 1;
+        }
+
+        if left < right {
+            ret[q] = bit.sum(left..right) as usize;
+        }
+    }
+
+    ret
+}
@@ -0,0 +1,162 @@
+/// 静的な列に対して、区間の最頻値とその出現回数を求める
+///
+/// 列を長さ $`B \approx \sqrt{N}`$ のブロックに分け、連続するブロックの並びごとの最頻値を前計算しておく。
+/// クエリでは、ブロックからはみ出した $`O(B)`$ 個の要素について、各値の出現位置のリストを使って出現回数を 1 ずつ伸ばせるかを調べる。
+///
+/// ## Examples
+///
+/// ```
+/// use library::range_mode::RangeMode;
+///
+/// let a = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+/// let rm = RangeMode::from(&a);
+///
+/// assert_eq!(rm.mode(..), Some((5, 3)));
+/// assert_eq!(rm.mode(0..4), Some((1, 2)));
+/// assert_eq!(rm.mode(5..7).unwrap().1, 1);
+/// assert_eq!(rm.mode(3..3), None);
+/// ```
+///
+/// ## 計算量
+///
+/// 列の長さを $`N`$ とする。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `from(a)` | 前計算を行う | $`O(N \sqrt{N})`$ |
+/// | `self.mode(range)` | `range` 内の最頻値とその出現回数を求める | $`O(\sqrt{N})`$ |
+///
+pub struct RangeMode<T> {
+    values: Vec<T>,
+    // 座標圧縮した列
+    compressed: Vec<usize>,
+    // positions[v] = 値 v が現れる位置の昇順のリスト
+    positions: Vec<Vec<usize>>,
+    // rank[i] = positions[compressed[i]] において i が何番目か
+    rank: Vec<usize>,
+    block: usize,
+    // modes[i * blocks + j] = ブロック i から j までの最頻値と出現回数
+    modes: Vec<(usize, usize)>,
+}
+
+impl<T: Ord + Clone> RangeMode<T> {
+    /// 列 `a` から前計算を行う
+    pub fn from(a: &[T]) -> Self {
+        let n = a.len();
+
+        let mut values = a.to_vec();
+        values.sort();
+        values.dedup();
+
+        let compressed = a
+            .iter()
+            .map(|x| values.binary_search(x).unwrap())
+            .collect::<Vec<_>>();
+
+        let mut positions = vec![vec![]; values.len()];
+        let mut rank = vec![0; n];
+
+        for (i, &v) in compressed.iter().enumerate() {
+            rank[i] = positions[v].len();
+            positions[v].push(i);
+        }
+
+        let mut block = 1;
+        while block * block < n {
+            block += 1;
+        }
+
+        let blocks = (n + block - 1) / block;
+        let mut modes = vec![(0, 0); blocks * blocks];
+        let mut count = vec![0; values.len()];
+
+        for i in 0..blocks {
+            let mut best = (0, 0);
+
+            for j in i..blocks {
+                for &v in compressed[j * block..std::cmp::min((j + 1) * block, n)].iter() {
+                    count[v] += 1;
+
+                    if count[v] > best.1 {
+                        best = (v, count[v]);
+                    }
+                }
+
+                modes[i * blocks + j] = best;
+            }
+
+            for &v in compressed[i * block..].iter() {
+                count[v] = 0;
+            }
+        }
+
+        Self {
+            values,
+            compressed,
+            positions,
+            rank,
+            block,
+            modes,
+        }
+    }
+
+    /// `range` 内の最頻値とその出現回数を求める
+    ///
+    /// 最頻値が複数ある場合は、そのうちのいずれかを返す。`range` が空の場合は `None` を返す。
+    pub fn mode<R: std::ops::RangeBounds<usize>>(&self, range: R) -> Option<(T, usize)> {
+        let n = self.compressed.len();
+
+        let left = match range.start_bound() {
+            std::ops::Bound::Included(&l) => l,
+            std::ops::Bound::Excluded(&l) => l + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+
+        let right = match range.end_bound() {
+            std::ops::Bound::Included(&r) => r + 1,
+            std::ops::Bound::Excluded(&r) => r,
+            std::ops::Bound::Unbounded => n,
+        };
+
+        assert!(left <= right && right <= n);
+
+        if left == right {
+            return None;
+        }
+
+        // ブロック bl, bl + 1, ..., br - 1 は区間に完全に含まれる
+        let bl = (left + self.block - 1) / self.block;
+        let br = right / self.block;
+
+        let (mut best, mut count, prefix_end, suffix_start) = if bl < br {
+            let blocks = (n + self.block - 1) / self.block;
+            let (v, c) = self.modes[bl * blocks + br - 1];
+            (v, c, bl * self.block, br * self.block)
+        } else {
+            (0, 0, right, right)
+        };
+
+        for i in left..prefix_end {
+            let v = self.compressed[i];
+            let positions = &self.positions[v];
+
+            while self.rank[i] + count < positions.len() && positions[self.rank[i] + count] < right
+            {
+                best = v;
+                count += 1;
+            }
+        }
+
+        for i in (suffix_start..right).rev() {
+            let v = self.compressed[i];
+            let positions = &self.positions[v];
+
+            while self.rank[i] >= count && positions[self.rank[i] - count] >= left {
+                best = v;
+                count += 1;
+            }
+        }
+
+        Some((self.values[best].clone(), count))
+    }
+}
@@ -1,3 +1,7 @@
+use crate::algebra::Monoid;
+use crate::segtree::SegmentTree;
+use rand::Rng;
+
 /// 文字列をハッシュ化する
 ///
 /// ## Examples
@@ -6,6 +10,8 @@
 /// * `STR_BASE` は文字をハッシュ化する際の基準になる。`s` に含まれる文字の中で最もUnicode scalar valueが小さいもの以下のvalueに相当する文字を与えればよい。
 /// * `BASE` はハッシュの基数になる。
 ///
+/// `s` の要素は [`Token`] を実装していればよく、`char` の他に整数型の列もハッシュ化できる。整数型のときは `STR_BASE` は使われない。
+///
 /// ```
 /// use library::rolling_hash::RollingHash;
 ///
@@ -17,77 +23,234 @@
 /// assert_eq!(rh.hash(..), rh.hash(..));
 /// ```
 ///
+/// 整数列や、すでに整数に変換したアルファベットの列もそのままハッシュ化できる。
+///
+/// ```
+/// use library::rolling_hash::RollingHash;
+///
+/// let a = [3u64, 1, 4, 1, 5, 3, 1, 4];
+/// let rh: RollingHash<'a', 100> = RollingHash::from(&a);
+///
+/// assert_eq!(rh.hash(0..3), rh.hash(5..));
+/// assert_ne!(rh.hash(1..3), rh.hash(3..5));
+///
+/// let b = [0u8, 25, 0, 25];
+/// let rh: RollingHash<'a', 100> = RollingHash::from(&b);
+/// assert_eq!(rh.hash(..2), rh.hash(2..));
+/// ```
+///
+/// `&str` は `from_chars(s)` でそのままハッシュ化できる。`STR_BASE` に `'\0'` を指定すると、どんな文字を含んでいても安全に扱える。
+///
+/// ```
+/// use library::rolling_hash::RollingHash;
+///
+/// let rh: RollingHash<'\0', 100> = RollingHash::from_chars("あいうあい");
+/// assert_eq!(rh.hash(..2), rh.hash(3..));
+///
+/// // バイト列としてハッシュ化してもよい
+/// let rb: RollingHash<'\0', 100> = RollingHash::from("abcab".as_bytes());
+/// assert_eq!(rb.hash(..2), rb.hash(3..));
+/// ```
+///
+/// 基数がコンパイル時に決まっていると、それを狙った入力でハッシュを衝突させられてしまう。
+/// `from_random_base(s)` を使うと、基数を実行時に一様ランダムに選ぶ。このとき `BASE` は使われない。  
+/// 基数はプロセスごとに一度だけ選ばれるので、`from_random_base` で構築したもの同士であればハッシュ値を比較できる。
+///
+/// ```
+/// use library::rolling_hash::RollingHash;
+///
+/// let s = "abracadabra".chars().collect::<Vec<_>>();
+/// let t = "cadabra".chars().collect::<Vec<_>>();
+/// let rs: RollingHash<'a', 0> = RollingHash::from_random_base(&s);
+/// let rt: RollingHash<'a', 0> = RollingHash::from_random_base(&t);
+///
+/// assert_eq!(rs.hash(4..), rt.hash(..));
+/// assert_eq!(rs.hash(..4), rs.hash(7..));
+/// assert_ne!(rs.hash(..4), rt.hash(..4));
+/// ```
+///
+/// 最長共通接頭辞の長さを二分探索で求めることで、部分文字列同士を辞書順で比較することもできる。
+/// これを使うと、接尾辞配列を使わずに部分文字列をソートできる。
+///
+/// ```
+/// use library::rolling_hash::RollingHash;
+///
+/// let s = "abracadabra".chars().collect::<Vec<_>>();
+/// let rh: RollingHash<'a', 100> = RollingHash::from(&s);
+///
+/// assert_eq!(rh.lcp(0.., 7..), 4);
+/// assert_eq!(rh.lcp(1..4, 8..), 3);
+/// assert_eq!(rh.cmp_substrings(0.., 7..), std::cmp::Ordering::Greater);
+///
+/// // 接尾辞を辞書順に並べる
+/// let mut suffixes = (0..s.len()).collect::<Vec<_>>();
+/// suffixes.sort_by(|&i, &j| rh.cmp_substrings(i.., j..));
+/// assert_eq!(suffixes, [10, 7, 0, 3, 5, 8, 1, 4, 6, 9, 2]);
+/// ```
+///
 /// ## 計算量
 ///
 /// | 関数 | 概要 | 計算量 |
 /// | --- | --- | --- |
 /// | `from(s)` | 文字列 `s` の部分文字列のハッシュ値を計算するための事前計算を行う | $`O(\lvert \text{s} \rvert)`$ |
+/// | `from_chars(s)` | `&str` を `char` の列とみなして `from(s)` と同じ事前計算を行う | $`O(\lvert \text{s} \rvert)`$ |
+/// | `from_random_base(s)` | 基数をランダムに選んで、`from(s)` と同じ事前計算を行う | $`O(\lvert \text{s} \rvert)`$ |
 /// | `self.hash(range)` | `range` の範囲の部分文字列のハッシュ値を求める | $`O(1)`$ |
+/// | `self.lcp(range_a, range_b)` | 2 つの部分文字列の最長共通接頭辞の長さを求める | $`O(\log \lvert \text{s} \rvert)`$ |
+/// | `self.cmp_substrings(range_a, range_b)` | 2 つの部分文字列を辞書順で比較する | $`O(\log \lvert \text{s} \rvert)`$ |
+/// | `self.segment(range)` | `range` の範囲の部分文字列のハッシュ値と長さの組を求める | $`O(1)`$ |
+/// | `self.concat(h1, h2, len2)` | ハッシュ値が `h1` の文字列と、ハッシュ値が `h2` で長さ `len2` の文字列を連結したもののハッシュ値を求める | $`O(1)`$ ($`\text{len2} > \lvert \text{s} \rvert`$ のときは $`O(\log(\text{len2}))`$) |
 ///
 /// ## Verified problems
 ///
 /// * [Naive String Search](../../src/aoj_alds1_14_a/aoj_alds1_14_a.rs.html)
 /// * [String Search](../../src/aoj_alds1_14_b/aoj_alds1_14_b.rs.html)
 ///
-
 pub struct RollingHash<const STR_BASE: char, const BASE: u64> {
     hash: Vec<u64>,
     pow: Vec<u64>,
+    base: u64,
 }
 
-impl<const STR_BASE: char, const BASE: u64> RollingHash<STR_BASE, BASE> {
-    const MOD: u64 = (1_u64 << 61) - 1;
-    const MASK_30: u64 = (1_u64 << 30) - 1;
-    const MASK_31: u64 = (1_u64 << 31) - 1;
-    const MASK_61: u64 = (1_u64 << 61) - 1;
-
-    fn mul(a: u64, b: u64) -> u64 {
-        let au = a >> 31;
-        let ad = a & Self::MASK_31;
-        let bu = b >> 31;
-        let bd = b & Self::MASK_31;
-        let mid = ad * bu + au * bd;
-        let midu = mid >> 30;
-        let midd = mid & Self::MASK_30;
-
-        Self::cmod(au * bu * 2 + midu + (midd << 31) + ad * bd)
-    }
-
-    fn cmod(x: u64) -> u64 {
-        let xu = x >> 61;
-        let xd = x & Self::MASK_61;
-        let ret = xu + xd;
-        if ret >= Self::MOD {
-            ret - Self::MOD
-        } else {
-            ret
-        }
+/// [`RollingHash`] でハッシュ化できる列の要素
+///
+/// 要素ごとに $`1`$ 以上 $`2^{61} - 1`$ 未満の値を、相異なる要素には相異なる値を、大小関係を保つように割り当てる。
+/// `str_base` は `char` のときにだけ使われる。
+///
+/// 64 bit の整数型は全ての値を割り当てられないので、
+/// 符号なし整数は $`0 \leq x \leq 2^{61} - 3`$、符号付き整数は $`-2^{60} \leq x \leq 2^{60} - 3`$ の範囲の値だけを扱える。範囲外の値を渡すとパニックする。
+pub trait Token: Copy {
+    /// ハッシュ化するときに要素に割り当てる値を返す
+    fn value(self, str_base: char) -> u64;
+}
+
+impl Token for char {
+    fn value(self, str_base: char) -> u64 {
+        assert!(self >= str_base);
+        self as u64 + 1 - str_base as u64
+    }
+}
+
+macro_rules! impl_token_to_narrow_integers {
+    ($($t: ty), *) => {
+        $(
+            impl Token for $t {
+                fn value(self, _str_base: char) -> u64 {
+                    (self as i64 - <$t>::MIN as i64) as u64 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_token_to_narrow_integers!(u8, u16, u32, i8, i16, i32);
+
+macro_rules! impl_token_to_wide_unsigned_integers {
+    ($($t: ty), *) => {
+        $(
+            impl Token for $t {
+                fn value(self, _str_base: char) -> u64 {
+                    assert!(self as u64 <= MOD - 2);
+                    self as u64 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_token_to_wide_unsigned_integers!(u64, usize);
+
+macro_rules! impl_token_to_wide_signed_integers {
+    ($($t: ty), *) => {
+        $(
+            impl Token for $t {
+                fn value(self, _str_base: char) -> u64 {
+                    assert!(-(1 << 60) <= self as i64 && self as i64 <= (1 << 60) - 3);
+                    (self as i64 + (1 << 60)) as u64 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_token_to_wide_signed_integers!(i64, isize);
+
+const MOD: u64 = (1_u64 << 61) - 1;
+const MASK_30: u64 = (1_u64 << 30) - 1;
+const MASK_31: u64 = (1_u64 << 31) - 1;
+const MASK_61: u64 = (1_u64 << 61) - 1;
+
+fn mul(a: u64, b: u64) -> u64 {
+    let au = a >> 31;
+    let ad = a & MASK_31;
+    let bu = b >> 31;
+    let bd = b & MASK_31;
+    let mid = ad * bu + au * bd;
+    let midu = mid >> 30;
+    let midd = mid & MASK_30;
+
+    cmod(au * bu * 2 + midu + (midd << 31) + ad * bd)
+}
+
+fn cmod(x: u64) -> u64 {
+    let xu = x >> 61;
+    let xd = x & MASK_61;
+    let ret = xu + xd;
+    if ret >= MOD {
+        ret - MOD
+    } else {
+        ret
     }
+}
+
+/// 長さ `len2` の列のハッシュ値が `h2` であり、$`\text{pow2} = \text{BASE}^{\text{len2}}`$ であるとき、ハッシュ値が `h1` の列の後ろにそれを連結した列のハッシュ値を求める
+fn _concat(h1: u64, h2: u64, pow2: u64) -> u64 {
+    cmod(mul(h1, pow2) + h2)
+}
 
+impl<const STR_BASE: char, const BASE: u64> RollingHash<STR_BASE, BASE> {
     /// 文字列 `s` の部分文字列のハッシュ値を計算するための事前計算を行う
-    pub fn from(s: &[char]) -> Self {
+    pub fn from<T: Token>(s: &[T]) -> Self {
+        Self::build(s, BASE)
+    }
+
+    /// 文字列 `s` を `char` の列とみなして、`from` と同じ事前計算を行う
+    pub fn from_chars(s: &str) -> Self {
+        Self::from(&s.chars().collect::<Vec<_>>())
+    }
+
+    /// 基数を実行時にランダムに選んで、文字列 `s` の部分文字列のハッシュ値を計算するための事前計算を行う  
+    /// 基数はプロセスごとに一度だけ選ばれ、`BASE` は使われない
+    pub fn from_random_base<T: Token>(s: &[T]) -> Self {
+        Self::build(s, Self::random_base())
+    }
+
+    /// `from_random_base` で使われる基数を返す  
+    /// 初めて呼ばれたときに $`\lbrack 2, 2^{61} - 2 \rbrack`$ から一様ランダムに選ぶ
+    pub fn random_base() -> u64 {
+        static RANDOM_BASE: std::sync::OnceLock<u64> = std::sync::OnceLock::new();
+        *RANDOM_BASE.get_or_init(|| rand::thread_rng().gen_range(2..(1_u64 << 61) - 1))
+    }
+
+    fn build<T: Token>(s: &[T], base: u64) -> Self {
         let length = s.len();
 
         let mut hash = vec![0];
         let mut pow = vec![1];
 
         for i in 0..length {
-            hash.push(Self::cmod(Self::mul(hash[i], BASE)) + s[i] as u64 + 1 - STR_BASE as u64);
-            pow.push(Self::cmod(Self::mul(pow[i], BASE)));
+            hash.push(cmod(mul(hash[i], base)) + s[i].value(STR_BASE));
+            pow.push(cmod(mul(pow[i], base)));
         }
 
-        Self {
-            hash: hash,
-            pow: pow,
-        }
+        Self { hash, pow, base }
     }
 
     fn _h(&self, l: usize, r: usize) -> u64 {
-        Self::cmod(self.hash[r] + Self::MOD * 4 - Self::mul(self.hash[l], self.pow[r - l]))
+        cmod(self.hash[r] + MOD * 4 - mul(self.hash[l], self.pow[r - l]))
     }
 
-    /// `range` が指定した部分文字列のハッシュ値を計算する
-    pub fn hash<R: std::ops::RangeBounds<usize>>(&self, range: R) -> u64 {
+    fn _range<R: std::ops::RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
         let left = match range.start_bound() {
             std::ops::Bound::Included(&l) => l,
             std::ops::Bound::Excluded(&l) => l + 1,
@@ -100,6 +263,275 @@ impl<const STR_BASE: char, const BASE: u64> RollingHash<STR_BASE, BASE> {
             std::ops::Bound::Unbounded => self.hash.len() - 1,
         };
 
+        (left, right)
+    }
+
+    /// `range` が指定した部分文字列のハッシュ値を計算する
+    pub fn hash<R: std::ops::RangeBounds<usize>>(&self, range: R) -> u64 {
+        let (left, right) = self._range(range);
         self._h(left, right)
     }
+
+    /// `range` が指定した部分文字列を、ハッシュ値と長さの組 [`HashedSegment`] として返す
+    pub fn segment<R: std::ops::RangeBounds<usize>>(&self, range: R) -> HashedSegment {
+        let (left, right) = self._range(range);
+
+        HashedSegment {
+            hash: self._h(left, right),
+            len: right - left,
+            pow: self.pow[right - left],
+        }
+    }
+
+    /// `range_a` が指定した部分文字列と `range_b` が指定した部分文字列の最長共通接頭辞の長さを求める
+    pub fn lcp<R1: std::ops::RangeBounds<usize>, R2: std::ops::RangeBounds<usize>>(
+        &self,
+        range_a: R1,
+        range_b: R2,
+    ) -> usize {
+        let (la, ra) = self._range(range_a);
+        let (lb, rb) = self._range(range_b);
+        self._lcp(la, lb, std::cmp::min(ra - la, rb - lb))
+    }
+
+    fn _lcp(&self, la: usize, lb: usize, max_len: usize) -> usize {
+        if self._h(la, la + max_len) == self._h(lb, lb + max_len) {
+            return max_len;
+        }
+
+        // 長さ ok の接頭辞は一致していて、長さ ng の接頭辞は一致していない
+        let mut ok = 0;
+        let mut ng = max_len;
+
+        while ng - ok > 1 {
+            let m = ok + (ng - ok) / 2;
+
+            if self._h(la, la + m) == self._h(lb, lb + m) {
+                ok = m;
+            } else {
+                ng = m;
+            }
+        }
+
+        ok
+    }
+
+    /// `range_a` が指定した部分文字列と `range_b` が指定した部分文字列を辞書順で比較する
+    pub fn cmp_substrings<R1: std::ops::RangeBounds<usize>, R2: std::ops::RangeBounds<usize>>(
+        &self,
+        range_a: R1,
+        range_b: R2,
+    ) -> std::cmp::Ordering {
+        let (la, ra) = self._range(range_a);
+        let (lb, rb) = self._range(range_b);
+        let (len_a, len_b) = (ra - la, rb - lb);
+        let lcp = self._lcp(la, lb, std::cmp::min(len_a, len_b));
+
+        if lcp == std::cmp::min(len_a, len_b) {
+            return len_a.cmp(&len_b);
+        }
+
+        // 長さ 1 の部分文字列のハッシュ値は Token::value() の値で、要素の大小と一致する
+        self._h(la + lcp, la + lcp + 1)
+            .cmp(&self._h(lb + lcp, lb + lcp + 1))
+    }
+
+    /// 文字(要素) `c` だけからなる長さ $`1`$ の列を [`HashedSegment`] として返す
+    pub fn char_segment<T: Token>(&self, c: T) -> HashedSegment {
+        HashedSegment {
+            hash: c.value(STR_BASE),
+            len: 1,
+            pow: self.base,
+        }
+    }
+
+    /// ハッシュ値が `h1` の文字列の後ろに、ハッシュ値が `h2` で長さが `len2` の文字列を連結した文字列のハッシュ値を求める
+    pub fn concat(&self, h1: u64, h2: u64, len2: usize) -> u64 {
+        let pow2 = if len2 < self.pow.len() {
+            self.pow[len2]
+        } else {
+            let (mut a, mut e, mut r) = (self.base, len2, 1);
+
+            while e > 0 {
+                if e & 1 == 1 {
+                    r = mul(r, a);
+                }
+
+                a = mul(a, a);
+                e >>= 1;
+            }
+
+            r
+        };
+
+        _concat(h1, h2, pow2)
+    }
+}
+
+/// 部分文字列のハッシュ値と長さの組
+///
+/// [`RollingHash::segment()`] などで得られる。`+` で連結した文字列のハッシュ値を、文字列を作り直さずに計算できる。  
+/// 異なる基数の [`RollingHash`] から得たもの同士を連結してはいけない。
+///
+/// ## Examples
+///
+/// 1 文字だけ置き換えた文字列と、別の部分文字列を比較する。
+///
+/// ```
+/// use library::rolling_hash::RollingHash;
+///
+/// let s = "abcxefabcdef".chars().collect::<Vec<_>>();
+/// let rh: RollingHash<'a', 100> = RollingHash::from(&s);
+///
+/// // s[0..6] = "abcxef" の 3 文字目を 'd' に置き換えると s[6..12] = "abcdef" に一致する
+/// let replaced = rh.segment(0..3) + rh.char_segment('d') + rh.segment(4..6);
+/// assert_eq!(replaced, rh.segment(6..12));
+/// assert_eq!(replaced.len, 6);
+///
+/// assert_eq!(rh.concat(rh.hash(0..3), rh.hash(3..6), 3), rh.hash(0..6));
+/// ```
+///
+/// 反転した文字列のハッシュも用意しておくと、2 つの部分文字列を連結したものが回文かどうかを判定できる。
+/// $`s \lbrack a..b \rbrack`$ を反転したものは、反転した文字列の $`\lbrack n - b..n - a \rbrack`$ に対応する。
+///
+/// ```
+/// use library::rolling_hash::RollingHash;
+///
+/// let s = "abcxyzcba".chars().collect::<Vec<_>>();
+/// let r = s.iter().rev().copied().collect::<Vec<_>>();
+/// let n = s.len();
+/// let rs: RollingHash<'a', 100> = RollingHash::from(&s);
+/// let rr: RollingHash<'a', 100> = RollingHash::from(&r);
+///
+/// let is_palindrome = |(a, b): (usize, usize), (c, d): (usize, usize)| {
+///     rs.segment(a..b) + rs.segment(c..d) == rr.segment(n - d..n - c) + rr.segment(n - b..n - a)
+/// };
+///
+/// // "abc" + "cba"
+/// assert!(is_palindrome((0, 3), (6, 9)));
+/// // "ab" + "zcba"
+/// assert!(!is_palindrome((0, 2), (5, 9)));
+/// // "bc" + "cb"
+/// assert!(is_palindrome((1, 3), (6, 8)));
+/// ```
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct HashedSegment {
+    /// ハッシュ値
+    pub hash: u64,
+    /// 長さ
+    pub len: usize,
+    pow: u64,
+}
+
+impl std::ops::Add for HashedSegment {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            hash: _concat(self.hash, rhs.hash, rhs.pow),
+            len: self.len + rhs.len,
+            pow: mul(self.pow, rhs.pow),
+        }
+    }
+}
+
+/// [`HashedSegment`] の連結がなすモノイド
+///
+/// [`SegmentTree`] に載せることで、一点更新ができるローリングハッシュ [`UpdatableRollingHash`] を構成するのに使う。
+pub enum HashConcat {}
+
+impl Monoid for HashConcat {
+    type S = HashedSegment;
+    fn op(lhs: &Self::S, rhs: &Self::S) -> Self::S {
+        *lhs + *rhs
+    }
+    const E: Self::S = HashedSegment {
+        hash: 0,
+        len: 0,
+        pow: 1,
+    };
+}
+
+/// 一点更新ができるローリングハッシュ
+///
+/// [`HashConcat`] を載せた [`SegmentTree`] で部分文字列のハッシュ値を管理する。  
+/// 文字を書き換えながら部分文字列を比較するような問題で、ハッシュを作り直さずに済む。
+///
+/// 同じ `STR_BASE`, `BASE` の [`RollingHash`] とハッシュ値が一致するので、両者を混ぜて比較できる。
+///
+/// ## Examples
+///
+/// ```
+/// use library::rolling_hash::{RollingHash, UpdatableRollingHash};
+///
+/// let s = "abcxef".chars().collect::<Vec<_>>();
+/// let t = "abcdef".chars().collect::<Vec<_>>();
+/// let mut urh: UpdatableRollingHash<'a', 100> = UpdatableRollingHash::from(&s);
+/// let rh: RollingHash<'a', 100> = RollingHash::from(&t);
+///
+/// assert_ne!(urh.hash(..), rh.hash(..));
+/// assert_eq!(urh.hash(4..), rh.hash(4..));
+///
+/// urh.set(3, 'd');
+/// assert_eq!(urh.hash(..), rh.hash(..));
+/// assert_eq!(urh.segment(1..4), rh.segment(1..4));
+/// ```
+///
+/// ## 計算量
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `from(s)` | 文字列 `s` からデータ構造を構築する | $`O(\lvert \text{s} \rvert)`$ |
+/// | `from_random_base(s)` | 基数を [`RollingHash::random_base()`] にして、`from(s)` と同じ構築を行う | $`O(\lvert \text{s} \rvert)`$ |
+/// | `self.set(i, c)` | $`i`$ 番目の文字を `c` に変更する | $`O(\log \lvert \text{s} \rvert)`$ |
+/// | `self.hash(range)` | `range` の範囲の部分文字列のハッシュ値を求める | $`O(\log \lvert \text{s} \rvert)`$ |
+/// | `self.segment(range)` | `range` の範囲の部分文字列のハッシュ値と長さの組を求める | $`O(\log \lvert \text{s} \rvert)`$ |
+///
+pub struct UpdatableRollingHash<const STR_BASE: char, const BASE: u64> {
+    tree: SegmentTree<HashConcat>,
+    base: u64,
+}
+
+impl<const STR_BASE: char, const BASE: u64> UpdatableRollingHash<STR_BASE, BASE> {
+    /// 文字列 `s` からデータ構造を構築する
+    pub fn from<T: Token>(s: &[T]) -> Self {
+        Self::build(s, BASE)
+    }
+
+    /// 基数を [`RollingHash::random_base()`] にして、文字列 `s` からデータ構造を構築する
+    pub fn from_random_base<T: Token>(s: &[T]) -> Self {
+        Self::build(s, RollingHash::<STR_BASE, BASE>::random_base())
+    }
+
+    fn build<T: Token>(s: &[T], base: u64) -> Self {
+        let leaves = s.iter().map(|&c| Self::leaf(c, base)).collect::<Vec<_>>();
+
+        Self {
+            tree: SegmentTree::from(&leaves),
+            base,
+        }
+    }
+
+    fn leaf<T: Token>(c: T, base: u64) -> HashedSegment {
+        HashedSegment {
+            hash: c.value(STR_BASE),
+            len: 1,
+            pow: base,
+        }
+    }
+
+    /// $`i`$ 番目の文字を `c` に変更する
+    pub fn set<T: Token>(&mut self, i: usize, c: T) {
+        self.tree.insert(i, Self::leaf(c, self.base));
+    }
+
+    /// `range` が指定した部分文字列のハッシュ値を計算する
+    pub fn hash<R: std::ops::RangeBounds<usize>>(&self, range: R) -> u64 {
+        self.tree.prod(range).hash
+    }
+
+    /// `range` が指定した部分文字列を、ハッシュ値と長さの組 [`HashedSegment`] として返す
+    pub fn segment<R: std::ops::RangeBounds<usize>>(&self, range: R) -> HashedSegment {
+        self.tree.prod(range)
+    }
 }
@@ -0,0 +1,137 @@
+use crate::graph::{Index, Tree};
+
+/// 根付き木
+///
+/// 無向の木と根を受け取り、各頂点の親・深さ・部分木の大きさ・子の列と、深さ優先探索の行きがけ順を前計算する。
+/// 再帰を使わずに計算するので、深い木でもスタックオーバーフローしない。
+///
+/// `order()` を前から辿ると親を子より先に、後ろから辿ると子を親より先に処理できるので、木 DP を再帰なしで書ける。
+///
+/// ## Examples
+///
+/// ```
+/// use library::graph::UndirectedAdjGraph;
+/// use library::rooted_tree::RootedTree;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let tree = UndirectedAdjGraph::from_edges_no_weight(5, &[(0, 1), (0, 2), (1, 3), (1, 4)]);
+/// let rt = RootedTree::from(&tree, 0);
+///
+/// assert_eq!(rt.root(), 0);
+/// assert_eq!(rt.parent(3), 1);
+/// assert_eq!(rt.parent(0), u32::MAX);
+/// assert_eq!(rt.depth(4), 2);
+/// assert_eq!(rt.subtree_size(1), 3);
+/// assert_eq!(rt.children(1), &[3, 4]);
+/// assert_eq!(rt.order(), &[0, 1, 3, 4, 2]);
+///
+/// // 葉から順に、部分木に含まれる葉の数を数える
+/// let mut leaves = vec![0; 5];
+/// for &v in rt.order().iter().rev() {
+///     leaves[v as usize] = if rt.children(v).is_empty() {
+///         1
+///     } else {
+///         rt.children(v).iter().map(|&c| leaves[c as usize]).sum()
+///     };
+/// }
+/// assert_eq!(leaves, [3, 2, 1, 1, 1]);
+/// ```
+///
+/// ## 計算量
+///
+/// 木の頂点数を $`N`$ とする。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `from(tree, root)` | `root` を根として前計算する | $`O(N)`$ |
+/// | その他 | | $`O(1)`$ |
+///
+pub struct RootedTree {
+    root: Index,
+    parent: Vec<Index>,
+    depth: Vec<u32>,
+    subtree_size: Vec<usize>,
+    children: Vec<Vec<Index>>,
+    order: Vec<Index>,
+}
+
+impl RootedTree {
+    /// `root` を根として前計算する
+    pub fn from<W>(tree: &dyn Tree<Weight = W>, root: Index) -> Self {
+        let size = tree.size() as usize;
+
+        let mut parent = vec![Index::MAX; size];
+        let mut depth = vec![0; size];
+        let mut subtree_size = vec![1; size];
+        let mut children = vec![vec![]; size];
+        let mut order = Vec::with_capacity(size);
+
+        let mut stack = vec![root];
+
+        while let Some(u) = stack.pop() {
+            order.push(u);
+
+            for &(v, _) in tree.adjacent(u) {
+                if v == parent[u as usize] {
+                    continue;
+                }
+
+                parent[v as usize] = u;
+                depth[v as usize] = depth[u as usize] + 1;
+                children[u as usize].push(v);
+            }
+
+            // 隣接リストの順に子を訪れるよう、逆順に積む
+            stack.extend(children[u as usize].iter().rev());
+        }
+
+        for &v in order.iter().rev() {
+            if v != root {
+                subtree_size[parent[v as usize] as usize] += subtree_size[v as usize];
+            }
+        }
+
+        Self {
+            root,
+            parent,
+            depth,
+            subtree_size,
+            children,
+            order,
+        }
+    }
+
+    /// 根
+    pub fn root(&self) -> Index {
+        self.root
+    }
+
+    /// $`v`$ の親 (根の場合は `Index::MAX`)
+    pub fn parent(&self, v: Index) -> Index {
+        self.parent[v as usize]
+    }
+
+    /// $`v`$ の深さ
+    pub fn depth(&self, v: Index) -> u32 {
+        self.depth[v as usize]
+    }
+
+    /// $`v`$ の部分木に含まれる頂点の数
+    pub fn subtree_size(&self, v: Index) -> usize {
+        self.subtree_size[v as usize]
+    }
+
+    /// $`v`$ の子の列
+    pub fn children(&self, v: Index) -> &[Index] {
+        &self.children[v as usize]
+    }
+
+    /// 深さ優先探索の行きがけ順に頂点を並べた列
+    pub fn order(&self) -> &[Index] {
+        &self.order
+    }
+}
@@ -0,0 +1,49 @@
+//! ランレングス圧縮を行う。
+//!
+//! 列の中で同じ要素が連続している部分を、(要素, 連続している個数) の組にまとめる。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::run_length_encoding::{run_length_decode, run_length_encode};
+//!
+//! let s = "aaabccddd".chars().collect::<Vec<_>>();
+//! let rle = run_length_encode(&s);
+//!
+//! assert_eq!(rle, [('a', 3), ('b', 1), ('c', 2), ('d', 3)]);
+//! assert_eq!(run_length_decode(&rle), s);
+//! ```
+//!
+//! ## 計算量
+//!
+//! 元の列の長さを $`N`$ として、[`run_length_encode()`], [`run_length_decode()`] いずれも $`O(N)`$ である。
+//!
+
+/// 列 `a` をランレングス圧縮する
+pub fn run_length_encode<T: PartialEq + Clone>(a: &[T]) -> Vec<(T, usize)> {
+    let mut rle: Vec<(T, usize)> = vec![];
+
+    for x in a {
+        match rle.last_mut() {
+            Some((y, cnt)) if y == x => {
+                *cnt += 1;
+            }
+            _ => {
+                rle.push((x.clone(), 1));
+            }
+        }
+    }
+
+    rle
+}
+
+/// ランレングス圧縮された列 `rle` を元の列に戻す
+pub fn run_length_decode<T: Clone>(rle: &[(T, usize)]) -> Vec<T> {
+    let mut a = vec![];
+
+    for (x, cnt) in rle {
+        a.extend(std::iter::repeat(x.clone()).take(*cnt));
+    }
+
+    a
+}
@@ -57,17 +57,26 @@ pub fn strongly_connected_components<T>(graph: &dyn DirectedGraph<Weight = T>) -
         }
 
         fn dfs<T>(&mut self, v: u32, graph: &dyn DirectedGraph<Weight = T>) {
+            // 深いグラフでもスタックオーバーフローしないよう、(頂点, 次に見る辺の番号) を積んで DFS する
+            let mut stack = vec![(v, 0)];
             self.seen[v as usize] = true;
 
-            for &(u, _) in graph.adjacent(v) {
-                if self.seen[u as usize] {
-                    continue;
-                }
+            while let Some((v, idx)) = stack.last_mut() {
+                let adjacent = graph.adjacent(*v);
 
-                self.dfs(u, graph);
-            }
+                if *idx < adjacent.len() {
+                    let u = adjacent[*idx].0;
+                    *idx += 1;
 
-            self.stop.push(v);
+                    if !self.seen[u as usize] {
+                        self.seen[u as usize] = true;
+                        stack.push((u, 0));
+                    }
+                } else {
+                    self.stop.push(*v);
+                    stack.pop();
+                }
+            }
         }
     }
 
@@ -0,0 +1,182 @@
+//! 平面上の線分の交差判定を行う。
+//!
+//! 線分は両端点を含む閉線分として扱い、端点で接する場合や一部が重なる場合も交差しているとみなす。
+//! 座標の絶対値は $`10^9`$ 以下であることを要求する。
+//!
+//! * [`segments_intersect()`] は 2 本の線分が交差するかを判定する。
+//! * [`find_intersecting_pair()`] は $`N`$ 本の線分のうち、交差するものの組を 1 つ求める(Shamos–Hoey のアルゴリズム)。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::segment_intersection::{find_intersecting_pair, segments_intersect};
+//!
+//! assert!(segments_intersect(((0, 0), (4, 4)), ((0, 4), (4, 0))));
+//! assert!(segments_intersect(((0, 0), (2, 0)), ((2, 0), (2, 5))));
+//! assert!(!segments_intersect(((0, 0), (1, 1)), ((2, 2), (3, 3))));
+//!
+//! let segments = [((0, 0), (2, 1)), ((0, 3), (3, 3)), ((3, 0), (6, 2)), ((4, 4), (7, 0))];
+//! assert_eq!(find_intersecting_pair(&segments), Some((2, 3)));
+//!
+//! let segments = [((0, 0), (2, 1)), ((0, 3), (3, 3)), ((3, 0), (6, 2))];
+//! assert_eq!(find_intersecting_pair(&segments), None);
+//! ```
+//!
+//! ## 計算量
+//!
+//! | 関数 | 計算量 |
+//! | --- | --- |
+//! | [`segments_intersect()`] | $`O(1)`$ |
+//! | [`find_intersecting_pair()`] | $`O(N \log N)`$ |
+//!
+
+type Point = (i64, i64);
+type Segment = (Point, Point);
+
+fn cross(o: Point, a: Point, b: Point) -> i128 {
+    (a.0 - o.0) as i128 * (b.1 - o.1) as i128 - (a.1 - o.1) as i128 * (b.0 - o.0) as i128
+}
+
+fn on_segment(p: Point, (a, b): Segment) -> bool {
+    cross(a, b, p) == 0
+        && std::cmp::min(a.0, b.0) <= p.0
+        && p.0 <= std::cmp::max(a.0, b.0)
+        && std::cmp::min(a.1, b.1) <= p.1
+        && p.1 <= std::cmp::max(a.1, b.1)
+}
+
+/// 線分 `s` と線分 `t` が交差するかを判定する
+pub fn segments_intersect(s: Segment, t: Segment) -> bool {
+    let d1 = cross(t.0, t.1, s.0).signum();
+    let d2 = cross(t.0, t.1, s.1).signum();
+    let d3 = cross(s.0, s.1, t.0).signum();
+    let d4 = cross(s.0, s.1, t.1).signum();
+
+    if d1 * d2 < 0 && d3 * d4 < 0 {
+        return true;
+    }
+
+    on_segment(s.0, t) || on_segment(s.1, t) || on_segment(t.0, s) || on_segment(t.1, s)
+}
+
+/// 走査線上に並ぶ線分
+///
+/// 2 本の線分の左端の $`x`$ 座標の大きい方における $`y`$ 座標で比較する。
+/// 交差する線分がまだ見つかっていない間は、走査線上に同時に存在する線分の上下関係は変わらないので、全順序になる。
+struct SweepSegment {
+    left: Point,
+    right: Point,
+    index: usize,
+}
+
+impl SweepSegment {
+    // x における y 座標を分数 (分子, 分母) で返す。垂直な線分は下端の y 座標を返す
+    fn y_at(&self, x: i64) -> (i128, i128) {
+        let dx = (self.right.0 - self.left.0) as i128;
+
+        if dx == 0 {
+            return (std::cmp::min(self.left.1, self.right.1) as i128, 1);
+        }
+
+        let dy = (self.right.1 - self.left.1) as i128;
+        (
+            self.left.1 as i128 * dx + dy * (x - self.left.0) as i128,
+            dx,
+        )
+    }
+}
+
+impl PartialEq for SweepSegment {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for SweepSegment {}
+
+impl PartialOrd for SweepSegment {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SweepSegment {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let x = std::cmp::max(self.left.0, other.left.0);
+        let (n1, d1) = self.y_at(x);
+        let (n2, d2) = other.y_at(x);
+
+        (n1 * d2).cmp(&(n2 * d1)).then(self.index.cmp(&other.index))
+    }
+}
+
+/// 線分の列 `segments` の中から、交差する 2 本の線分の添字の組 $`(i, j)`$ $`(i < j)`$ を 1 つ求める
+///
+/// 交差する組が存在しない場合は `None` を返す。
+pub fn find_intersecting_pair(segments: &[Segment]) -> Option<(usize, usize)> {
+    let segments = segments
+        .iter()
+        .enumerate()
+        .map(|(index, &(p, q))| {
+            let (left, right) = if p <= q { (p, q) } else { (q, p) };
+            SweepSegment { left, right, index }
+        })
+        .collect::<Vec<_>>();
+
+    // (x 座標, 0: 追加 / 1: 削除, y 座標, 線分の添字)
+    let mut events = vec![];
+
+    for (i, s) in segments.iter().enumerate() {
+        events.push((s.left.0, 0, s.left.1, i));
+        events.push((s.right.0, 1, s.right.1, i));
+    }
+
+    events.sort();
+
+    let check = |i: usize, j: usize| {
+        let (s, t) = (&segments[i], &segments[j]);
+
+        if segments_intersect((s.left, s.right), (t.left, t.right)) {
+            Some((
+                std::cmp::min(s.index, t.index),
+                std::cmp::max(s.index, t.index),
+            ))
+        } else {
+            None
+        }
+    };
+
+    let mut active: std::collections::BTreeSet<&SweepSegment> = std::collections::BTreeSet::new();
+
+    for (_, kind, _, i) in events {
+        let s = &segments[i];
+        let below = active
+            .range::<&SweepSegment, _>(..s)
+            .next_back()
+            .map(|t| t.index);
+        let above = active
+            .range::<&SweepSegment, _>((std::ops::Bound::Excluded(s), std::ops::Bound::Unbounded))
+            .next()
+            .map(|t| t.index);
+
+        if kind == 0 {
+            for j in below.into_iter().chain(above) {
+                if let Some(pair) = check(i, j) {
+                    return Some(pair);
+                }
+            }
+
+            active.insert(s);
+        } else {
+            active.remove(s);
+
+            if let (Some(j), Some(k)) = (below, above) {
+                if let Some(pair) = check(j, k) {
+                    return Some(pair);
+                }
+            }
+        }
+    }
+
+    None
+}
@@ -18,6 +18,11 @@ use crate::algebra::Monoid;
 ///
 /// stree.insert(2, 100);
 /// assert_eq!(stree.prod(0..=3), 1101);
+///
+/// // 0 から始まる区間で、総和が 101 以下になる最長のもの
+/// assert_eq!(stree.max_right(0, |&s| s <= 101), 3);
+/// // 5 で終わる区間で、総和が 1000 以下になる最長のもの
+/// assert_eq!(stree.min_left(5, |&s| s <= 1000), 3);
 /// ```
 ///
 /// ## 計算量
@@ -27,8 +32,11 @@ use crate::algebra::Monoid;
 /// | 関数 | 概要 | 計算量 |
 /// | --- | --- | --- |
 /// | `new(size)` | 大きさ `size` で各要素が単位元の `SegmentTree` を生成する | $`O(\text{size})`$ |
+/// | `from_vec(array)` | `array` を消費して `SegmentTree` を生成する | $`O(\lvert \text{array} \rvert)`$ |
 /// | `self.insert(i, s)` | $`i`$ 番目の要素を $`s`$ に更新する | $`O(\log(\text{self.size}))`$ |
 /// | `self.prod(range)` | `range` 内の要素の総積を求める | $`O(\log(\text{self.size}))`$ |
+/// | `self.max_right(l, f)` | $`f(\text{self.prod}(l..r))`$ が true となる最大の $`r`$ を求める | $`O(\log(\text{self.size}))`$ |
+/// | `self.min_left(r, f)` | $`f(\text{self.prod}(l..r))`$ が true となる最小の $`l`$ を求める | $`O(\log(\text{self.size}))`$ |
 ///
 /// ## Verified Problems
 ///
@@ -36,40 +44,53 @@ use crate::algebra::Monoid;
 ///
 pub struct SegmentTree<M: Monoid> {
     size: usize,
+    /// 葉の数 (`size` 以上の最小の $`2`$ の冪)
+    offset: usize,
     tree: Vec<M::S>,
 }
 
 impl<M: Monoid> SegmentTree<M> {
     /// 大きさ `size` で、すべての要素が `M` の単位元である `SegmentTree<M>` を生成する
     pub fn new(size: usize) -> Self {
+        let offset = size.next_power_of_two();
+
         Self {
             size,
-            tree: vec![M::E; size << 1],
+            offset,
+            tree: vec![M::E; offset << 1],
         }
     }
 
     /// `array` から `SegmentTree` を生成する
     pub fn from(array: &[M::S]) -> Self {
+        Self::from_vec(array.to_vec())
+    }
+
+    /// `array` を消費して `SegmentTree` を生成する
+    pub fn from_vec(mut array: Vec<M::S>) -> Self {
         let size = array.len();
+        let offset = size.next_power_of_two();
         let tree = {
-            let mut tree = vec![M::E; size];
-            tree.append(&mut array.clone().to_vec());
+            let mut tree = Vec::with_capacity(offset << 1);
+            tree.resize(offset, M::E);
+            tree.append(&mut array);
+            tree.resize(offset << 1, M::E);
 
-            for i in (1..size).rev() {
+            for i in (1..offset).rev() {
                 tree[i] = M::op(&tree[i << 1], &tree[i << 1 | 1]);
             }
 
             tree
         };
 
-        return Self { size, tree };
+        return Self { size, offset, tree };
     }
 
     /// $`i`$ 番目の要素を `s` に変更する
     pub fn insert(&mut self, mut i: usize, s: M::S) {
         assert!(i < self.size);
 
-        i += self.size;
+        i += self.offset;
 
         self.tree[i] = s;
 
@@ -82,7 +103,7 @@ impl<M: Monoid> SegmentTree<M> {
     /// $`i`$ 番目の要素を返す
     pub fn get(&self, i: usize) -> M::S {
         assert!(i < self.size);
-        self.tree[i + self.size].clone()
+        self.tree[i + self.offset].clone()
     }
 
     /// $`\displaystyle \prod_{i \in \text{range}} \text{self} \lbrack i \rbrack`$ を返す
@@ -102,9 +123,96 @@ impl<M: Monoid> SegmentTree<M> {
         return self._prod(left, right);
     }
 
+    /// `f` が単調 ($`f(\text{self.prod}(l..r))`$ が $`r`$ について true から false に一度だけ変わる) であるとして、  
+    /// $`f(\text{self.prod}(l..r))`$ が true となる最大の $`r`$ を返す
+    ///
+    /// $`f(e)`$ が true であることを要求する。
+    pub fn max_right(&self, left: usize, f: impl Fn(&M::S) -> bool) -> usize {
+        assert!(left <= self.size);
+        assert!(f(&M::E));
+
+        if left == self.size {
+            return self.size;
+        }
+
+        let mut l = left + self.offset;
+        let mut sm = M::E;
+
+        loop {
+            while l & 1 == 0 {
+                l >>= 1;
+            }
+
+            if !f(&M::op(&sm, &self.tree[l])) {
+                while l < self.offset {
+                    l <<= 1;
+
+                    let s = M::op(&sm, &self.tree[l]);
+                    if f(&s) {
+                        sm = s;
+                        l += 1;
+                    }
+                }
+
+                return l - self.offset;
+            }
+
+            sm = M::op(&sm, &self.tree[l]);
+            l += 1;
+
+            if l.is_power_of_two() {
+                return self.size;
+            }
+        }
+    }
+
+    /// `f` が単調 ($`f(\text{self.prod}(l..r))`$ が $`l`$ について true から false に一度だけ変わる) であるとして、  
+    /// $`f(\text{self.prod}(l..r))`$ が true となる最小の $`l`$ を返す
+    ///
+    /// $`f(e)`$ が true であることを要求する。
+    pub fn min_left(&self, right: usize, f: impl Fn(&M::S) -> bool) -> usize {
+        assert!(right <= self.size);
+        assert!(f(&M::E));
+
+        if right == 0 {
+            return 0;
+        }
+
+        let mut r = right + self.offset;
+        let mut sm = M::E;
+
+        loop {
+            r -= 1;
+
+            while r > 1 && r & 1 == 1 {
+                r >>= 1;
+            }
+
+            if !f(&M::op(&self.tree[r], &sm)) {
+                while r < self.offset {
+                    r = r << 1 | 1;
+
+                    let s = M::op(&self.tree[r], &sm);
+                    if f(&s) {
+                        sm = s;
+                        r -= 1;
+                    }
+                }
+
+                return r + 1 - self.offset;
+            }
+
+            sm = M::op(&self.tree[r], &sm);
+
+            if r.is_power_of_two() {
+                return 0;
+            }
+        }
+    }
+
     fn _prod(&self, mut left: usize, mut right: usize) -> M::S {
-        left += self.size;
-        right += self.size;
+        left += self.offset;
+        right += self.offset;
         let (mut sl, mut sr) = (M::E, M::E);
 
         while left < right {
@@ -126,10 +234,31 @@ impl<M: Monoid> SegmentTree<M> {
     }
 }
 
+impl<M: Monoid> FromIterator<M::S> for SegmentTree<M> {
+    fn from_iter<I: IntoIterator<Item = M::S>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+/// 末尾に要素を追加する  
+/// 全体を構築し直すので、追加前の要素数を $`N`$、追加する要素数を $`K`$ として $`O(N + K)`$ かかる
+impl<M: Monoid> Extend<M::S> for SegmentTree<M> {
+    fn extend<I: IntoIterator<Item = M::S>>(&mut self, iter: I) {
+        let tree = std::mem::take(&mut self.tree);
+        let array = tree
+            .into_iter()
+            .skip(self.offset)
+            .take(self.size)
+            .chain(iter)
+            .collect();
+        *self = Self::from_vec(array);
+    }
+}
+
 impl<M: Monoid> std::ops::Index<usize> for SegmentTree<M> {
     type Output = M::S;
     fn index(&self, index: usize) -> &Self::Output {
-        &self.tree[index + self.size]
+        &self.tree[index + self.offset]
     }
 }
 
@@ -148,3 +277,117 @@ impl<M: Monoid<S = S>, S: std::fmt::Display> std::fmt::Display for SegmentTree<M
         )
     }
 }
+
+/// 二項演算と単位元をクロージャで与える [`SegmentTree`]
+///
+/// [`Monoid`] を実装した型を定義しなくても使える。二項演算が結合法則を満たし、`e()` がその単位元であることを要求する。
+///
+/// ## Examples
+///
+/// ```
+/// use library::segtree::FnSegmentTree;
+///
+/// let m = 1_000_000_007u64;
+/// let mut stree = FnSegmentTree::from(&[1, 2, 3, 4, 5], move |a: &u64, b: &u64| a * b % m, || 1);
+///
+/// assert_eq!(stree.prod(..), 120);
+/// stree.insert(2, 1_000_000);
+/// assert_eq!(stree.prod(1..4), 8_000_000);
+/// assert_eq!(stree.prod(3..3), 1);
+/// ```
+///
+/// ## 計算量
+///
+/// [`SegmentTree`] と同じである。
+///
+pub struct FnSegmentTree<S, F> {
+    size: usize,
+    offset: usize,
+    tree: Vec<S>,
+    op: F,
+    e: S,
+}
+
+impl<S: Clone, F: Fn(&S, &S) -> S> FnSegmentTree<S, F> {
+    /// 大きさ `size` で、すべての要素が単位元である `FnSegmentTree` を生成する
+    pub fn new(size: usize, op: F, e: impl Fn() -> S) -> Self {
+        Self::from(&vec![e(); size], op, e)
+    }
+
+    /// `array` から、二項演算を `op`、単位元を `e()` とする `FnSegmentTree` を生成する
+    pub fn from(array: &[S], op: F, e: impl Fn() -> S) -> Self {
+        let size = array.len();
+        let offset = size.next_power_of_two();
+        let e = e();
+
+        let mut tree = vec![e.clone(); offset << 1];
+        tree[offset..offset + size].clone_from_slice(array);
+
+        for i in (1..offset).rev() {
+            tree[i] = op(&tree[i << 1], &tree[i << 1 | 1]);
+        }
+
+        Self {
+            size,
+            offset,
+            tree,
+            op,
+            e,
+        }
+    }
+
+    /// $`i`$ 番目の要素を `s` に変更する
+    pub fn insert(&mut self, mut i: usize, s: S) {
+        assert!(i < self.size);
+
+        i += self.offset;
+        self.tree[i] = s;
+
+        while i > 1 {
+            i >>= 1;
+            self.tree[i] = (self.op)(&self.tree[i << 1], &self.tree[i << 1 | 1]);
+        }
+    }
+
+    /// $`i`$ 番目の要素を返す
+    pub fn get(&self, i: usize) -> S {
+        assert!(i < self.size);
+        self.tree[i + self.offset].clone()
+    }
+
+    /// $`\displaystyle \prod_{i \in \text{range}} \text{self} \lbrack i \rbrack`$ を返す
+    pub fn prod<R: std::ops::RangeBounds<usize>>(&self, range: R) -> S {
+        let mut left = match range.start_bound() {
+            std::ops::Bound::Included(&l) => l,
+            std::ops::Bound::Excluded(&l) => l + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+
+        let mut right = match range.end_bound() {
+            std::ops::Bound::Included(&r) => r + 1,
+            std::ops::Bound::Excluded(&r) => r,
+            std::ops::Bound::Unbounded => self.size,
+        };
+
+        left += self.offset;
+        right += self.offset;
+        let (mut sl, mut sr) = (self.e.clone(), self.e.clone());
+
+        while left < right {
+            if left & 1 == 1 {
+                sl = (self.op)(&sl, &self.tree[left]);
+                left += 1;
+            }
+
+            if right & 1 == 1 {
+                right ^= 1;
+                sr = (self.op)(&self.tree[right], &sr);
+            }
+
+            left >>= 1;
+            right >>= 1;
+        }
+
+        (self.op)(&sl, &sr)
+    }
+}
@@ -0,0 +1,213 @@
+/// 平方分割による順序付き多重集合
+///
+/// 要素を、ソートされた長さ $`O(\sqrt{N})`$ 程度のバケットの列として持つ。
+/// バケットが大きくなりすぎたら半分に分割し、空になったら取り除く。
+/// [`std::collections::BTreeSet`] では求められない $`k`$ 番目の要素や、ある値未満の要素の個数を求められる。
+///
+/// ## Examples
+///
+/// ```
+/// use library::sorted_list::SortedList;
+///
+/// let mut list = SortedList::from(&[3, 1, 4, 1, 5]);
+///
+/// assert_eq!(list.len(), 5);
+/// assert_eq!(list.kth(1), Some(&1));
+/// assert_eq!(list.kth(2), Some(&3));
+/// assert_eq!(list.kth(5), None);
+///
+/// list.insert(2);
+/// assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 1, 2, 3, 4, 5]);
+///
+/// assert_eq!(list.bisect_left(&3), 3);
+/// assert_eq!(list.bisect_right(&1), 2);
+/// assert_eq!(list.count(&1), 2);
+///
+/// assert!(list.remove(&1));
+/// assert!(!list.remove(&6));
+/// assert_eq!(list.count(&1), 1);
+/// ```
+///
+/// ## 計算量
+///
+/// 要素数を $`N`$ とする。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `new()` | 空の `SortedList` を生成する | $`O(1)`$ |
+/// | `from(array)` | `array` の要素からなる `SortedList` を生成する | $`O(N \log N)`$ |
+/// | `self.insert(x)` | $`x`$ を追加する | ならし $`O(\sqrt{N})`$ |
+/// | `self.remove(x)` | $`x`$ を $`1`$ つ取り除く | ならし $`O(\sqrt{N})`$ |
+/// | `self.kth(k)` | 小さい方から $`k`$ 番目 (0-indexed) の要素を返す | $`O(\sqrt{N})`$ |
+/// | `self.bisect_left(x)` | $`x`$ 未満の要素の個数 ($`x`$ の順位) を返す | $`O(\sqrt{N})`$ |
+/// | `self.bisect_right(x)` | $`x`$ 以下の要素の個数を返す | $`O(\sqrt{N})`$ |
+/// | `self.count(x)` | $`x`$ の個数を返す | $`O(\sqrt{N})`$ |
+///
+/// ## Verified problems
+///
+/// * [Ordered Set](../../src/lc_ordered_set/lc_ordered_set.rs.html)
+///
+#[derive(Clone, Debug)]
+pub struct SortedList<T> {
+    len: usize,
+    buckets: Vec<Vec<T>>,
+}
+
+impl<T: Ord + Clone> SortedList<T> {
+    const BUCKET_RATIO: usize = 16;
+    const SPLIT_RATIO: usize = 24;
+
+    /// 空の `SortedList` を生成する
+    pub fn new() -> Self {
+        Self {
+            len: 0,
+            buckets: vec![],
+        }
+    }
+
+    /// `array` の要素からなる `SortedList` を生成する
+    pub fn from(array: &[T]) -> Self {
+        let mut a = array.to_vec();
+        a.sort();
+        Self::from_sorted(a)
+    }
+
+    fn from_sorted(a: Vec<T>) -> Self {
+        let len = a.len();
+        let bucket_count = ((len as f64 / Self::BUCKET_RATIO as f64).sqrt().ceil()) as usize;
+
+        let mut buckets = Vec::with_capacity(bucket_count);
+        let mut it = a.into_iter();
+
+        for i in 0..bucket_count {
+            let size = len * (i + 1) / bucket_count - len * i / bucket_count;
+            buckets.push(it.by_ref().take(size).collect());
+        }
+
+        Self { len, buckets }
+    }
+
+    /// 要素数を返す
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 要素数が $`0`$ かどうかを返す
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 要素を小さい順に走査するイテレータを返す
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.buckets.iter().flatten()
+    }
+
+    /// $`x`$ を追加する
+    pub fn insert(&mut self, x: T) {
+        if self.buckets.is_empty() {
+            self.buckets.push(vec![x]);
+            self.len = 1;
+            return;
+        }
+
+        let b = self
+            .buckets
+            .iter()
+            .position(|bucket| x <= *bucket.last().unwrap())
+            .unwrap_or(self.buckets.len() - 1);
+
+        let limit = self.buckets.len() * Self::SPLIT_RATIO;
+        let bucket = &mut self.buckets[b];
+        let i = bucket.partition_point(|y| *y < x);
+        bucket.insert(i, x);
+        self.len += 1;
+
+        if bucket.len() > limit {
+            let half = bucket.len() / 2;
+            let right = bucket.split_off(half);
+            self.buckets.insert(b + 1, right);
+        }
+    }
+
+    /// $`x`$ を $`1`$ つ取り除く  
+    /// $`x`$ が含まれていなければ何もせず `false` を返す
+    pub fn remove(&mut self, x: &T) -> bool {
+        let b = match self
+            .buckets
+            .iter()
+            .position(|bucket| *x <= *bucket.last().unwrap())
+        {
+            Some(b) => b,
+            None => return false,
+        };
+
+        let bucket = &mut self.buckets[b];
+        let i = bucket.partition_point(|y| y < x);
+
+        if bucket[i] != *x {
+            return false;
+        }
+
+        bucket.remove(i);
+        self.len -= 1;
+
+        if bucket.is_empty() {
+            self.buckets.remove(b);
+        }
+
+        true
+    }
+
+    /// 小さい方から $`k`$ 番目 (0-indexed) の要素を返す
+    pub fn kth(&self, mut k: usize) -> Option<&T> {
+        for bucket in &self.buckets {
+            if k < bucket.len() {
+                return Some(&bucket[k]);
+            }
+
+            k -= bucket.len();
+        }
+
+        None
+    }
+
+    /// $`x`$ 未満の要素の個数を返す
+    pub fn bisect_left(&self, x: &T) -> usize {
+        self.count_while(|y| y < x)
+    }
+
+    /// $`x`$ 以下の要素の個数を返す
+    pub fn bisect_right(&self, x: &T) -> usize {
+        self.count_while(|y| y <= x)
+    }
+
+    /// $`x`$ の個数を返す
+    pub fn count(&self, x: &T) -> usize {
+        self.bisect_right(x) - self.bisect_left(x)
+    }
+
+    /// $`x`$ が含まれているかどうかを返す
+    pub fn contains(&self, x: &T) -> bool {
+        self.count(x) > 0
+    }
+
+    fn count_while(&self, pred: impl Fn(&T) -> bool) -> usize {
+        let mut ret = 0;
+
+        for bucket in &self.buckets {
+            if !pred(bucket.last().unwrap()) {
+                return ret + bucket.partition_point(&pred);
+            }
+
+            ret += bucket.len();
+        }
+
+        ret
+    }
+}
+
+impl<T: Ord + Clone> Default for SortedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -58,7 +58,9 @@ use crate::algebra::Band;
 ///
 pub struct SparseTable<B: Band> {
     size: usize,
+    /// $`h`$ 段目は、$`\lbrack i, i + 2^h)`$ の積を `table[offset[h] + i]` に持つ ($`0 \leq i \leq \text{size} - 2^h`$)
     table: Vec<B::S>,
+    offset: Vec<usize>,
 }
 
 impl<B: Band<S = S>, S: Clone + Copy> SparseTable<B> {
@@ -66,28 +68,29 @@ impl<B: Band<S = S>, S: Clone + Copy> SparseTable<B> {
         let size = array.len();
         let height = size.next_power_of_two().trailing_zeros() as usize;
 
-        let mut table = array.to_vec();
-
-        for _ in 1..height {
-            table.append(&mut array.to_vec());
+        let mut offset = vec![0];
+        for h in 1..height {
+            offset.push(offset[h - 1] + size + 1 - (1 << (h - 1)));
         }
 
-        let flatten = |y: usize, x: usize| y * size + x;
+        let capacity = (0..height.max(1)).map(|h| size + 1 - (1 << h)).sum();
+        let mut table = Vec::with_capacity(capacity);
+        table.extend_from_slice(array);
 
         for h in 1..height {
-            for i in 0..size {
-                if i + (1 << (h - 1)) - 1 >= size {
-                    break;
-                }
-
-                table[flatten(h, i)] = B::op(
-                    &table[flatten(h - 1, i)],
-                    &table[flatten(h - 1, i + (1 << (h - 1)))],
-                );
+            let half = 1 << (h - 1);
+
+            for i in 0..size + 1 - (1 << h) {
+                let x = B::op(&table[offset[h - 1] + i], &table[offset[h - 1] + i + half]);
+                table.push(x);
             }
         }
 
-        return Self { size, table };
+        Self {
+            size,
+            table,
+            offset,
+        }
     }
 
     fn _prod(&self, l: usize, r: usize) -> S {
@@ -101,9 +104,10 @@ impl<B: Band<S = S>, S: Clone + Copy> SparseTable<B> {
         let w = (length.next_power_of_two()) >> 1;
         let h = ((length.next_power_of_two()) >> 1).trailing_zeros() as usize;
 
-        let flatten = |y: usize, x: usize| y * self.size + x;
-
-        B::op(&self.table[flatten(h, l)], &self.table[flatten(h, r - w)])
+        B::op(
+            &self.table[self.offset[h] + l],
+            &self.table[self.offset[h] + r - w],
+        )
     }
 
     pub fn prod<R: std::ops::RangeBounds<usize>>(&self, range: R) -> S {
@@ -122,3 +126,98 @@ impl<B: Band<S = S>, S: Clone + Copy> SparseTable<B> {
         self._prod(left, right)
     }
 }
+
+/// 二項演算をクロージャで与える [`SparseTable`]
+///
+/// [`Band`] を実装した型を定義しなくても使える。二項演算が結合法則を満たし、冪等であることを要求する。
+///
+/// ## Examples
+///
+/// ```
+/// use library::sparse_table::FnSparseTable;
+///
+/// let a = [12, 18, 8, 20, 30];
+/// let gcd = |a: &u32, b: &u32| {
+///     let (mut a, mut b) = (*a, *b);
+///     while b != 0 {
+///         (a, b) = (b, a % b);
+///     }
+///     a
+/// };
+/// let st = FnSparseTable::from(&a, gcd);
+///
+/// assert_eq!(st.prod(0..2), 6);
+/// assert_eq!(st.prod(..3), 2);
+/// assert_eq!(st.prod(3..), 10);
+/// ```
+///
+/// ## 計算量
+///
+/// [`SparseTable`] と同じである。
+///
+pub struct FnSparseTable<S, F> {
+    size: usize,
+    table: Vec<S>,
+    offset: Vec<usize>,
+    op: F,
+}
+
+impl<S: Clone, F: Fn(&S, &S) -> S> FnSparseTable<S, F> {
+    /// `array` から、二項演算を `op` とする `FnSparseTable` を構築する
+    pub fn from(array: &[S], op: F) -> Self {
+        let size = array.len();
+        let height = size.next_power_of_two().trailing_zeros() as usize;
+
+        let mut offset = vec![0];
+        for h in 1..height {
+            offset.push(offset[h - 1] + size + 1 - (1 << (h - 1)));
+        }
+
+        let mut table = array.to_vec();
+
+        for h in 1..height {
+            let half = 1 << (h - 1);
+
+            for i in 0..size + 1 - (1 << h) {
+                let x = op(&table[offset[h - 1] + i], &table[offset[h - 1] + i + half]);
+                table.push(x);
+            }
+        }
+
+        Self {
+            size,
+            table,
+            offset,
+            op,
+        }
+    }
+
+    /// $`\displaystyle \prod_{i \in \text{range}} \text{array} \lbrack i \rbrack`$ を返す (`range` は空でない)
+    pub fn prod<R: std::ops::RangeBounds<usize>>(&self, range: R) -> S {
+        let l = match range.start_bound() {
+            std::ops::Bound::Included(&l) => l,
+            std::ops::Bound::Excluded(&l) => l + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+
+        let r = match range.end_bound() {
+            std::ops::Bound::Included(&r) => r + 1,
+            std::ops::Bound::Excluded(&r) => r,
+            std::ops::Bound::Unbounded => self.size,
+        };
+
+        assert!(l < r && r <= self.size);
+
+        if r == l + 1 {
+            return self.table[l].clone();
+        }
+
+        let w = (r - l).next_power_of_two() >> 1;
+        let h = w.trailing_zeros() as usize;
+
+        (self.op)(
+            &self.table[self.offset[h] + l],
+            &self.table[self.offset[h] + r - w],
+        )
+    }
+}
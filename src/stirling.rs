@@ -0,0 +1,177 @@
+//! スターリング数とベル数を $`\mathbb{Z} / P \mathbb{Z}`$ 上で計算する。
+//!
+//! * 第 1 種スターリング数 $`s(n, k)`$ は $`\displaystyle x(x - 1) \cdots (x - n + 1) = \sum_k s(n, k) x^k`$ で定まる(符号付き)。
+//!   $`\lvert s(n, k) \rvert`$ は、$`n`$ 要素の置換のうち巡回がちょうど $`k`$ 個であるものの数である。
+//! * 第 2 種スターリング数 $`S(n, k)`$ は、$`n`$ 個の区別できる要素を $`k`$ 個の空でないグループに分ける方法の数である。
+//! * ベル数 $`B_n = \displaystyle \sum_k S(n, k)`$ は、$`n`$ 個の区別できる要素をいくつかの空でないグループに分ける方法の数である。
+//!
+//! $`n`$ を固定した 1 行分は [`convolution()`] を使って計算し、表全体は漸化式で計算する。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::modint::ModInt;
+//! use library::stirling::*;
+//!
+//! type Mint = ModInt<998244353>;
+//!
+//! // x(x - 1)(x - 2)(x - 3) = x^4 - 6x^3 + 11x^2 - 6x
+//! let s = stirling_first_row::<998244353>(4);
+//! assert_eq!(s, [0, -6, 11, -6, 1].map(Mint::from));
+//!
+//! let s = stirling_second_row::<998244353>(4);
+//! assert_eq!(s, [0, 1, 7, 6, 1].map(Mint::from));
+//!
+//! let table = stirling_second_table::<998244353>(4);
+//! assert_eq!(table[4], s);
+//! assert_eq!(stirling_first_table::<998244353>(4)[4][2], Mint::from(11));
+//!
+//! assert_eq!(bell_numbers::<998244353>(6), [1, 1, 2, 5, 15, 52, 203].map(Mint::from));
+//! ```
+//!
+//! ## 計算量
+//!
+//! | 関数 | 計算量 |
+//! | --- | --- |
+//! | [`stirling_first_row()`] | $`O(N \log^2 N)`$ |
+//! | [`stirling_second_row()`] | $`O(N \log N)`$ |
+//! | [`stirling_first_table()`], [`stirling_second_table()`] | $`O(N^2)`$ |
+//! | [`bell_numbers()`] | $`O(N^2)`$ |
+//!
+//! ## Verified problems
+//!
+//! * [Stirling Number of the First Kind](../../src/lc_stirling_number_of_the_first_kind/lc_stirling_number_of_the_first_kind.rs.html)
+//! * [Stirling Number of the Second Kind](../../src/lc_stirling_number_of_the_second_kind/lc_stirling_number_of_the_second_kind.rs.html)
+//!
+
+use crate::convolution::convolution;
+use crate::modint::{factorials, ModInt};
+
+/// $`s(n, 0), s(n, 1), \dots, s(n, n)`$ を求める
+///
+/// $`P`$ は [`convolution()`] が使える素数であることを要求する。
+pub fn stirling_first_row<const P: u32>(n: usize) -> Vec<ModInt<P>> {
+    // x - i を葉として、多項式の積を分割統治で計算する
+    let mut polys = (0..n)
+        .map(|i| vec![-ModInt::from(i), ModInt::from_raw(1)])
+        .collect::<Vec<_>>();
+
+    if polys.is_empty() {
+        return vec![ModInt::from_raw(1)];
+    }
+
+    while polys.len() > 1 {
+        polys = polys
+            .chunks(2)
+            .map(|c| {
+                if c.len() == 2 {
+                    convolution(&c[0], &c[1])
+                } else {
+                    c[0].clone()
+                }
+            })
+            .collect();
+    }
+
+    polys.pop().unwrap()
+}
+
+/// $`S(n, 0), S(n, 1), \dots, S(n, n)`$ を求める
+///
+/// $`S(n, k) = \displaystyle \sum_{i = 0}^{k} \frac{(-1)^{k - i} i^n}{i! (k - i)!}`$ を畳み込みで計算する。
+/// $`P`$ は [`convolution()`] が使える、$`n`$ より大きい素数であることを要求する。
+pub fn stirling_second_row<const P: u32>(n: usize) -> Vec<ModInt<P>> {
+    let (_, inv_fact) = factorials::<P>(n);
+
+    let a = (0..=n)
+        .map(|i| ModInt::<P>::from(i).pow(n as u32) * inv_fact[i])
+        .collect::<Vec<_>>();
+    let b = (0..=n)
+        .map(|j| {
+            if j % 2 == 0 {
+                inv_fact[j]
+            } else {
+                -inv_fact[j]
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut s = convolution(&a, &b);
+    s.truncate(n + 1);
+    s
+}
+
+/// $`0 \leq k \leq m \leq n`$ について $`s(m, k)`$ を求め、`table[m][k]` に格納する
+pub fn stirling_first_table<const P: u32>(n: usize) -> Vec<Vec<ModInt<P>>> {
+    let mut table = vec![vec![ModInt::from_raw(0)]; n + 1];
+    table[0][0] = ModInt::from_raw(1);
+
+    for m in 1..=n {
+        // s(m, k) = s(m - 1, k - 1) - (m - 1) s(m - 1, k)
+        let prev = &table[m - 1];
+        let coef = ModInt::from(m - 1);
+
+        table[m] = (0..=m)
+            .map(|k| {
+                let a = if k > 0 {
+                    prev[k - 1]
+                } else {
+                    ModInt::from_raw(0)
+                };
+                let b = if k < m { prev[k] } else { ModInt::from_raw(0) };
+                a - coef * b
+            })
+            .collect();
+    }
+
+    table
+}
+
+/// $`0 \leq k \leq m \leq n`$ について $`S(m, k)`$ を求め、`table[m][k]` に格納する
+pub fn stirling_second_table<const P: u32>(n: usize) -> Vec<Vec<ModInt<P>>> {
+    let mut table = vec![vec![ModInt::from_raw(0)]; n + 1];
+    table[0][0] = ModInt::from_raw(1);
+
+    for m in 1..=n {
+        // S(m, k) = S(m - 1, k - 1) + k S(m - 1, k)
+        let prev = &table[m - 1];
+
+        table[m] = (0..=m)
+            .map(|k| {
+                let a = if k > 0 {
+                    prev[k - 1]
+                } else {
+                    ModInt::from_raw(0)
+                };
+                let b = if k < m { prev[k] } else { ModInt::from_raw(0) };
+                a + ModInt::from(k) * b
+            })
+            .collect();
+    }
+
+    table
+}
+
+/// $`B_0, B_1, \dots, B_n`$ を求める
+///
+/// ベルの三角形を用いる。1 つの $`B_n`$ だけが必要な場合は、[`stirling_second_row()`] の総和をとる方が速い。
+pub fn bell_numbers<const P: u32>(n: usize) -> Vec<ModInt<P>> {
+    let mut bell = vec![ModInt::from_raw(1)];
+    let mut row = vec![ModInt::from_raw(1)];
+
+    for _ in 0..n {
+        // 次の行は、前の行の末尾から始めて、前の行の要素を順に足していく
+        let mut next = vec![*row.last().unwrap()];
+
+        for &x in row.iter() {
+            let last = *next.last().unwrap();
+            next.push(last + x);
+        }
+
+        bell.push(next[0]);
+        row = next;
+    }
+
+    bell.truncate(n + 1);
+    bell
+}
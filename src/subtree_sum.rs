@@ -0,0 +1,167 @@
+use crate::binary_indexed_tree::BinaryIndexedTree;
+use crate::graph::{Index, Tree, UndirectedAdjGraph};
+use crate::tree_dist::TreeDist;
+
+/// 根付き木の頂点に値を持ち、一点加算と部分木の総和・パスの総和を求める
+///
+/// オイラーツアーで頂点 $`v`$ に入った時刻を $`\text{in}_v`$、出た時刻を $`\text{out}_v`$ とすると、$`v`$ の部分木は区間 $`\lbrack \text{in}_v, \text{out}_v)`$ に対応する。
+/// これを [`BinaryIndexedTree`] で管理する。
+///
+/// パスの総和には、$`\text{in}_v`$ に $`+w`$、$`\text{out}_v`$ に $`-w`$ を置いたときの接頭辞和が根から $`v`$ へのパスの総和になることを使う。
+/// 符号なし整数でも使えるように、$`+w`$ と $`-w`$ は別の [`BinaryIndexedTree`] で管理している。
+///
+/// ## Examples
+///
+/// ```
+/// use library::graph::UndirectedAdjGraph;
+/// use library::subtree_sum::SubtreeSum;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let tree = UndirectedAdjGraph::from_edges_no_weight(5, &[(0, 1), (0, 2), (1, 3), (1, 4)]);
+/// let mut ss = SubtreeSum::from(&tree, 0, &[1u64, 10, 100, 1000, 10000]);
+///
+/// assert_eq!(ss.subtree_sum(1), 11010);
+/// assert_eq!(ss.subtree_sum(0), 11111);
+/// assert_eq!(ss.path_sum(3, 2), 1111);
+/// assert_eq!(ss.root_path_sum(4), 10011);
+///
+/// ss.add_vertex(1, 5);
+/// assert_eq!(ss.get(1), 15);
+/// assert_eq!(ss.subtree_sum(0), 11116);
+/// assert_eq!(ss.path_sum(3, 4), 11015);
+/// ```
+///
+/// ## 計算量
+///
+/// 木の頂点数を $`N`$ とする。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `from(tree, root, values)` | `root` を根とし、頂点 $`v`$ の値を `values[v]` として初期化する | $`O(N \log N)`$ |
+/// | `self.add_vertex(v, w)` | 頂点 $`v`$ の値に $`w`$ を足す | $`O(\log N)`$ |
+/// | `self.subtree_sum(v)` | $`v`$ の部分木の値の総和を求める | $`O(\log N)`$ |
+/// | `self.root_path_sum(v)` | 根から $`v`$ へのパス上の値の総和を求める | $`O(\log N)`$ |
+/// | `self.path_sum(u, v)` | $`u`$ から $`v`$ へのパス上の値の総和を求める | $`O(\log N)`$ |
+///
+/// ## Verified problems
+///
+/// * [Vertex Add Subtree Sum](../../src/lc_vertex_add_subtree_sum/lc_vertex_add_subtree_sum.rs.html)
+/// * [Vertex Add Path Sum](../../src/lc_vertex_add_path_sum/lc_vertex_add_path_sum.rs.html)
+///
+pub struct SubtreeSum<T> {
+    tin: Vec<usize>,
+    tout: Vec<usize>,
+    values: Vec<T>,
+    subtree: BinaryIndexedTree<T>,
+    path_in: BinaryIndexedTree<T>,
+    path_out: BinaryIndexedTree<T>,
+    lca: TreeDist<u32>,
+}
+
+impl<
+        T: Default
+            + Clone
+            + Copy
+            + PartialOrd
+            + Ord
+            + std::ops::AddAssign
+            + std::ops::Sub<Output = T>
+            + std::ops::Add<Output = T>,
+    > SubtreeSum<T>
+{
+    /// `root` を根とし、頂点 $`v`$ の値を `values[v]` として初期化する
+    pub fn from<W>(tree: &dyn Tree<Weight = W>, root: Index, values: &[T]) -> Self {
+        let size = tree.size() as usize;
+        assert_eq!(values.len(), size);
+
+        let mut tin = vec![0; size];
+        let mut tout = vec![0; size];
+        let mut edges = vec![];
+        let mut time = 0;
+
+        // (頂点, 親, 次に見る隣接辺の番号)
+        let mut stack = vec![(root, Index::MAX, 0)];
+
+        while let Some((u, parent, i)) = stack.pop() {
+            if i == 0 {
+                tin[u as usize] = time;
+                time += 1;
+
+                if parent != Index::MAX {
+                    edges.push((parent, u, 1));
+                }
+            }
+
+            let adjacent = tree.adjacent(u);
+            let mut i = i;
+
+            while i < adjacent.len() && adjacent[i].0 == parent {
+                i += 1;
+            }
+
+            if i < adjacent.len() {
+                stack.push((u, parent, i + 1));
+                stack.push((adjacent[i].0, u, 0));
+            } else {
+                tout[u as usize] = time;
+            }
+        }
+
+        let mut ss = Self {
+            tin,
+            tout,
+            values: vec![T::default(); size],
+            subtree: BinaryIndexedTree::new(size),
+            path_in: BinaryIndexedTree::new(size),
+            path_out: BinaryIndexedTree::new(size),
+            lca: TreeDist::from(&UndirectedAdjGraph::from_edges(size as Index, &edges), root),
+        };
+
+        for (v, &w) in values.iter().enumerate() {
+            ss.add_vertex(v as Index, w);
+        }
+
+        ss
+    }
+
+    /// 頂点 $`v`$ の値を求める
+    pub fn get(&self, v: Index) -> T {
+        self.values[v as usize]
+    }
+
+    /// 頂点 $`v`$ の値に $`w`$ を足す
+    pub fn add_vertex(&mut self, v: Index, w: T) {
+        let v = v as usize;
+        self.values[v] += w;
+        self.subtree.add(self.tin[v], w);
+        self.path_in.add(self.tin[v], w);
+
+        if self.tout[v] < self.tin.len() {
+            self.path_out.add(self.tout[v], w);
+        }
+    }
+
+    /// $`v`$ の部分木の値の総和を求める
+    pub fn subtree_sum(&self, v: Index) -> T {
+        let v = v as usize;
+        self.subtree.sum(self.tin[v]..self.tout[v])
+    }
+
+    /// 根から $`v`$ へのパス上の値の総和を求める
+    pub fn root_path_sum(&self, v: Index) -> T {
+        let t = self.tin[v as usize];
+        self.path_in.prefix_sum(t) - self.path_out.prefix_sum(t)
+    }
+
+    /// $`u`$ から $`v`$ へのパス上の値の総和を求める
+    pub fn path_sum(&self, u: Index, v: Index) -> T {
+        let l = self.lca.lca(u, v);
+        let r = self.root_path_sum(l);
+
+        (self.root_path_sum(u) - r) + (self.root_path_sum(v) - r) + self.values[l as usize]
+    }
+}
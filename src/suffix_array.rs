@@ -0,0 +1,355 @@
+//! 接尾辞配列を SA-IS で構築する
+//!
+//! 列 $`s`$ の接尾辞 $`s \lbrack i.. \rbrack`$ を辞書順に並べたときの開始位置 $`i`$ の列を接尾辞配列という。
+//!
+//! * [`suffix_array()`] は接尾辞配列を求める。
+//! * [`lcp_array()`] は接尾辞配列で隣り合う接尾辞の最長共通接頭辞 (LCP) の長さを Kasai のアルゴリズムで求める。
+//! * [`SuffixArray`] は接尾辞配列と LCP 配列を持ち、パターンの出現位置を二分探索で求める。
+//!   LCP 配列を [`SparseTable`] に載せて、任意の 2 つの接尾辞の LCP や、部分列同士の辞書順比較を $`O(1)`$ で行う。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::suffix_array::{lcp_array, suffix_array, SuffixArray};
+//!
+//! let s = "abracadabra".chars().collect::<Vec<_>>();
+//! assert_eq!(suffix_array(&s), [10, 7, 0, 3, 5, 8, 1, 4, 6, 9, 2]);
+//!
+//! let sa = SuffixArray::from(&s);
+//! assert_eq!(sa.find_all(&['a', 'b', 'r']), [0, 7]);
+//! assert_eq!(sa.find_all(&['a']), [0, 3, 5, 7, 10]);
+//! assert_eq!(sa.count(&['c', 'a']), 1);
+//! assert!(sa.find_all(&['x']).is_empty());
+//!
+//! // "abra" と "abracadabra" の LCP は 4
+//! assert_eq!(sa.lcp(7, 0), 4);
+//! assert_eq!(lcp_array(&s, sa.sa()), [1, 4, 1, 1, 0, 3, 0, 0, 0, 2]);
+//!
+//! // "cad" < "dab", "abr" = "abr", "ab" < "abr"
+//! use std::cmp::Ordering;
+//! assert_eq!(sa.compare(4..7, 6..9), Ordering::Less);
+//! assert_eq!(sa.compare(0..3, 7..10), Ordering::Equal);
+//! assert_eq!(sa.compare(7..9, 0..3), Ordering::Less);
+//! ```
+//!
+//! ## 計算量
+//!
+//! 列の長さを $`N`$、パターンの長さを $`M`$ とする。
+//!
+//! | 関数 | 計算量 |
+//! | --- | --- |
+//! | [`suffix_array()`] | $`O(N \log N)`$ (座標圧縮) $`+ O(N)`$ (SA-IS) |
+//! | [`lcp_array()`] | $`O(N)`$ |
+//! | [`SuffixArray::from()`] | $`O(N \log N)`$ |
+//! | [`SuffixArray::lcp()`] | $`O(1)`$ |
+//! | [`SuffixArray::compare()`] | $`O(1)`$ |
+//! | [`SuffixArray::count()`] | $`O(M \log N)`$ |
+//! | [`SuffixArray::find_all()`] | $`O(M \log N + K \log K)`$ ($`K`$ は出現回数) |
+//!
+//! ## Verified problems
+//!
+//! * [Suffix Array](../../src/lc_suffixarray/lc_suffixarray.rs.html)
+//! * [Number of Substrings](../../src/lc_number_of_substrings/lc_number_of_substrings.rs.html)
+//!
+
+use crate::algebra::Min;
+use crate::sparse_table::SparseTable;
+
+/// 列 `s` の接尾辞配列を求める
+pub fn suffix_array<T: Ord>(s: &[T]) -> Vec<usize> {
+    // 座標圧縮して 0, 1, ..., upper の列にする
+    let mut idx = (0..s.len()).collect::<Vec<_>>();
+    idx.sort_by(|&a, &b| s[a].cmp(&s[b]));
+
+    let mut t = vec![0; s.len()];
+    let mut upper = 0;
+    for i in 0..idx.len() {
+        if i > 0 && s[idx[i - 1]] != s[idx[i]] {
+            upper += 1;
+        }
+        t[idx[i]] = upper;
+    }
+
+    sa_is(&t, upper)
+}
+
+/// 各要素が `upper` 以下の列の接尾辞配列を SA-IS で求める
+fn sa_is(s: &[usize], upper: usize) -> Vec<usize> {
+    let n = s.len();
+    match n {
+        0 => return vec![],
+        1 => return vec![0],
+        2 => return if s[0] < s[1] { vec![0, 1] } else { vec![1, 0] },
+        _ => {}
+    }
+
+    let mut sa = vec![0; n];
+
+    // ls[i]: s[i..] が s[i + 1..] より辞書順で小さい (S 型) なら true
+    let mut ls = vec![false; n];
+    for i in (0..n - 1).rev() {
+        ls[i] = if s[i] == s[i + 1] {
+            ls[i + 1]
+        } else {
+            s[i] < s[i + 1]
+        };
+    }
+
+    // sum_l[c]: 文字 c の L 型の区間の先頭, sum_s[c]: 文字 c の S 型の区間の先頭
+    let mut sum_l = vec![0; upper + 1];
+    let mut sum_s = vec![0; upper + 1];
+    for i in 0..n {
+        if !ls[i] {
+            sum_s[s[i]] += 1;
+        } else {
+            sum_l[s[i] + 1] += 1;
+        }
+    }
+    for i in 0..=upper {
+        sum_s[i] += sum_l[i];
+        if i < upper {
+            sum_l[i + 1] += sum_s[i];
+        }
+    }
+
+    // LMS 型の位置から、誘導ソートで接尾辞配列を作る
+    let induce = |sa: &mut [usize], lms: &[usize]| {
+        sa.fill(usize::MAX);
+
+        let mut buf = sum_s.clone();
+        for &d in lms {
+            if d == n {
+                continue;
+            }
+            sa[buf[s[d]]] = d;
+            buf[s[d]] += 1;
+        }
+
+        buf.copy_from_slice(&sum_l);
+        sa[buf[s[n - 1]]] = n - 1;
+        buf[s[n - 1]] += 1;
+        for i in 0..n {
+            let v = sa[i];
+            if v != usize::MAX && v >= 1 && !ls[v - 1] {
+                sa[buf[s[v - 1]]] = v - 1;
+                buf[s[v - 1]] += 1;
+            }
+        }
+
+        buf.copy_from_slice(&sum_l);
+        for i in (0..n).rev() {
+            let v = sa[i];
+            if v != usize::MAX && v >= 1 && ls[v - 1] {
+                buf[s[v - 1] + 1] -= 1;
+                sa[buf[s[v - 1] + 1]] = v - 1;
+            }
+        }
+    };
+
+    // lms_map[i]: 位置 i が LMS 型なら、その番号
+    let mut lms_map = vec![usize::MAX; n + 1];
+    let mut m = 0;
+    for i in 1..n {
+        if !ls[i - 1] && ls[i] {
+            lms_map[i] = m;
+            m += 1;
+        }
+    }
+
+    let lms = (1..n).filter(|&i| !ls[i - 1] && ls[i]).collect::<Vec<_>>();
+
+    induce(&mut sa, &lms);
+
+    if m > 0 {
+        let sorted_lms = sa
+            .iter()
+            .copied()
+            .filter(|&v| lms_map[v] != usize::MAX)
+            .collect::<Vec<_>>();
+
+        // LMS 部分文字列に番号を付け、縮約した列の接尾辞配列を再帰的に求める
+        let mut rec_s = vec![0; m];
+        let mut rec_upper = 0;
+        rec_s[lms_map[sorted_lms[0]]] = 0;
+
+        for i in 1..m {
+            let mut l = sorted_lms[i - 1];
+            let mut r = sorted_lms[i];
+
+            let end_l = if lms_map[l] + 1 < m {
+                lms[lms_map[l] + 1]
+            } else {
+                n
+            };
+            let end_r = if lms_map[r] + 1 < m {
+                lms[lms_map[r] + 1]
+            } else {
+                n
+            };
+
+            let mut same = true;
+            if end_l - l != end_r - r {
+                same = false;
+            } else {
+                while l < end_l {
+                    if s[l] != s[r] {
+                        break;
+                    }
+                    l += 1;
+                    r += 1;
+                }
+                if l == n || r == n || s[l] != s[r] {
+                    same = false;
+                }
+            }
+
+            if !same {
+                rec_upper += 1;
+            }
+            rec_s[lms_map[sorted_lms[i]]] = rec_upper;
+        }
+
+        let rec_sa = sa_is(&rec_s, rec_upper);
+
+        let sorted_lms = rec_sa.iter().map(|&i| lms[i]).collect::<Vec<_>>();
+        induce(&mut sa, &sorted_lms);
+    }
+
+    sa
+}
+
+/// 列 `s` とその接尾辞配列 `sa` から、LCP 配列を求める
+///
+/// 返り値の `[i]` は、`s[sa[i]..]` と `s[sa[i + 1]..]` の最長共通接頭辞の長さである (長さ $`N - 1`$)。
+pub fn lcp_array<T: Eq>(s: &[T], sa: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    if n == 0 {
+        return vec![];
+    }
+
+    let mut rank = vec![0; n];
+    for (i, &p) in sa.iter().enumerate() {
+        rank[p] = i;
+    }
+
+    let mut lcp = vec![0; n - 1];
+    let mut h: usize = 0;
+
+    for i in 0..n {
+        h = h.saturating_sub(1);
+
+        if rank[i] == 0 {
+            continue;
+        }
+
+        let j = sa[rank[i] - 1];
+        while i + h < n && j + h < n && s[i + h] == s[j + h] {
+            h += 1;
+        }
+        lcp[rank[i] - 1] = h;
+    }
+
+    lcp
+}
+
+/// 接尾辞配列と LCP 配列を使って、パターンの出現位置や接尾辞の LCP を求める
+pub struct SuffixArray<T> {
+    s: Vec<T>,
+    sa: Vec<usize>,
+    rank: Vec<usize>,
+    lcp: Vec<usize>,
+    st: SparseTable<Min<usize>>,
+}
+
+impl<T: Ord + Clone> SuffixArray<T> {
+    /// 列 `s` の接尾辞配列と LCP 配列を構築する
+    pub fn from(s: &[T]) -> Self {
+        let sa = suffix_array(s);
+        let lcp = lcp_array(s, &sa);
+
+        let mut rank = vec![0; s.len()];
+        for (i, &p) in sa.iter().enumerate() {
+            rank[p] = i;
+        }
+
+        Self {
+            s: s.to_vec(),
+            st: SparseTable::from(&lcp),
+            sa,
+            rank,
+            lcp,
+        }
+    }
+
+    /// 接尾辞配列
+    pub fn sa(&self) -> &[usize] {
+        &self.sa
+    }
+
+    /// LCP 配列
+    pub fn lcp_array(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// 接尾辞 `s[i..]` が接尾辞配列の何番目にあるか
+    pub fn rank(&self, i: usize) -> usize {
+        self.rank[i]
+    }
+
+    /// 接尾辞 `s[i..]` と `s[j..]` の最長共通接頭辞の長さ
+    pub fn lcp(&self, i: usize, j: usize) -> usize {
+        let n = self.s.len();
+        if i == j {
+            return n - i;
+        }
+        if i == n || j == n {
+            return 0;
+        }
+
+        let (a, b) = (
+            self.rank[i].min(self.rank[j]),
+            self.rank[i].max(self.rank[j]),
+        );
+        self.st.prod(a..b)
+    }
+
+    /// 部分列 `s[a]` と `s[b]` を辞書順で比較する
+    pub fn compare(
+        &self,
+        a: std::ops::Range<usize>,
+        b: std::ops::Range<usize>,
+    ) -> std::cmp::Ordering {
+        let l = self.lcp(a.start, b.start).min(a.len()).min(b.len());
+
+        if l == a.len() || l == b.len() {
+            a.len().cmp(&b.len())
+        } else {
+            self.s[a.start + l].cmp(&self.s[b.start + l])
+        }
+    }
+
+    /// `pattern` で始まる接尾辞が並ぶ、接尾辞配列上の区間
+    pub fn range(&self, pattern: &[T]) -> std::ops::Range<usize> {
+        let prefix = |i: usize| {
+            let suffix = &self.s[i..];
+            &suffix[..suffix.len().min(pattern.len())]
+        };
+
+        let l = self.sa.partition_point(|&i| prefix(i) < pattern);
+        let r = self.sa.partition_point(|&i| prefix(i) <= pattern);
+
+        l..r
+    }
+
+    /// `pattern` の出現回数
+    pub fn count(&self, pattern: &[T]) -> usize {
+        self.range(pattern).len()
+    }
+
+    /// `pattern` が出現する位置を昇順に列挙する
+    pub fn find_all(&self, pattern: &[T]) -> Vec<usize> {
+        let mut res = self.sa[self.range(pattern)].to_vec();
+        res.sort_unstable();
+        res
+    }
+}
@@ -0,0 +1,189 @@
+//! ランダムなテストケースを生成する。
+//!
+//! 手元で愚直解と比較するストレステストに使う。乱数生成器は呼び出し側で用意して渡すので、シードを固定すれば再現できる。
+//! 頂点は $`0, 1, \dots, N - 1`$ で表す。
+//!
+//! * [`random_tree()`] は、Prüfer 列を一様に選ぶことで、頂点にラベルのついた木を一様ランダムに生成する。
+//! * [`random_connected_graph()`] は、多重辺・自己ループのない連結な無向グラフを生成する。
+//! * [`random_weighted_dag()`] は、重み付きの DAG を生成する。
+//! * [`random_array()`], [`random_distinct_array()`], [`random_permutation()`], [`random_string()`] は、列や文字列を生成する。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::graph::UndirectedAdjGraph;
+//! use library::testgen::*;
+//! use rand::SeedableRng;
+//!
+//! let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+//!
+//! let edges = random_tree(&mut rng, 10);
+//! assert_eq!(edges.len(), 9);
+//! let tree = UndirectedAdjGraph::from_edges_no_weight(10, &edges);
+//! assert_eq!(tree.size(), 10);
+//!
+//! let edges = random_connected_graph(&mut rng, 10, 20);
+//! assert_eq!(edges.len(), 20);
+//!
+//! let edges = random_weighted_dag(&mut rng, 10, 15, 1..=100u64);
+//! assert!(edges.iter().all(|&(_, _, w)| 1 <= w && w <= 100));
+//!
+//! let a = random_array(&mut rng, 5, -10..10i64);
+//! assert!(a.iter().all(|&x| -10 <= x && x < 10));
+//! let p = random_permutation(&mut rng, 5);
+//! assert_eq!(p.len(), 5);
+//! let b = random_distinct_array(&mut rng, 5, 0..10u32);
+//! assert_eq!(b.len(), 5);
+//! let s = random_string(&mut rng, 8, &['a', 'b', 'c']);
+//! assert_eq!(s.len(), 8);
+//! ```
+//!
+
+use rand::distributions::uniform::{SampleRange, SampleUniform};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// 頂点数 $`n`$ の木を一様ランダムに生成し、辺の列を返す
+pub fn random_tree(rng: &mut impl Rng, n: usize) -> Vec<(u32, u32)> {
+    if n <= 1 {
+        return vec![];
+    }
+
+    let prufer = (0..n - 2).map(|_| rng.gen_range(0..n)).collect::<Vec<_>>();
+
+    let mut degree = vec![1; n];
+    for &v in prufer.iter() {
+        degree[v] += 1;
+    }
+
+    // 次数 1 の頂点のうち番号が最小のものを葉として取り出す
+    let mut leaves = (0..n)
+        .filter(|&v| degree[v] == 1)
+        .map(std::cmp::Reverse)
+        .collect::<std::collections::BinaryHeap<_>>();
+    let mut edges = Vec::with_capacity(n - 1);
+
+    for &v in prufer.iter() {
+        let std::cmp::Reverse(leaf) = leaves.pop().unwrap();
+        edges.push((leaf as u32, v as u32));
+
+        degree[v] -= 1;
+        if degree[v] == 1 {
+            leaves.push(std::cmp::Reverse(v));
+        }
+    }
+
+    let std::cmp::Reverse(u) = leaves.pop().unwrap();
+    let std::cmp::Reverse(v) = leaves.pop().unwrap();
+    edges.push((u as u32, v as u32));
+
+    edges
+}
+
+/// 頂点数 $`n`$、辺数 $`m`$ の、多重辺・自己ループのない連結な無向グラフを生成し、辺の列を返す
+///
+/// $`n - 1 \leq m \leq \frac{n(n - 1)}{2}`$ であることを要求する。
+pub fn random_connected_graph(rng: &mut impl Rng, n: usize, m: usize) -> Vec<(u32, u32)> {
+    assert!(n >= 1 && n - 1 <= m && m <= n * (n - 1) / 2);
+
+    let mut edges = random_tree(rng, n);
+    let normalize = |(u, v): (u32, u32)| (std::cmp::min(u, v), std::cmp::max(u, v));
+    let mut used = edges
+        .iter()
+        .map(|&e| normalize(e))
+        .collect::<std::collections::HashSet<_>>();
+
+    if 2 * m > n * (n - 1) / 2 {
+        // 密なときは、残りの辺の候補をすべて列挙して選ぶ
+        let mut candidates = (0..n as u32)
+            .flat_map(|u| (u + 1..n as u32).map(move |v| (u, v)))
+            .filter(|e| !used.contains(e))
+            .collect::<Vec<_>>();
+        candidates.shuffle(rng);
+        edges.extend(candidates.into_iter().take(m - (n - 1)));
+    } else {
+        while edges.len() < m {
+            let (u, v) = (rng.gen_range(0..n as u32), rng.gen_range(0..n as u32));
+
+            if u != v && used.insert(normalize((u, v))) {
+                edges.push((u, v));
+            }
+        }
+    }
+
+    edges.shuffle(rng);
+    edges
+}
+
+/// 頂点数 $`n`$、辺数 $`m`$ の、多重辺のない重み付き DAG を生成し、辺 $`(u, v, w)`$ の列を返す
+///
+/// 頂点の番号はランダムに並べ替えられていて、トポロジカル順序とは限らない。重みは `weight` から一様に選ぶ。
+/// $`m \leq \frac{n(n - 1)}{2}`$ であることを要求する。
+pub fn random_weighted_dag<W: SampleUniform, R: SampleRange<W> + Clone>(
+    rng: &mut impl Rng,
+    n: usize,
+    m: usize,
+    weight: R,
+) -> Vec<(u32, u32, W)> {
+    assert!(m <= n * (n.max(1) - 1) / 2);
+
+    let order = random_permutation(rng, n);
+    let mut used = std::collections::HashSet::new();
+    let mut edges = Vec::with_capacity(m);
+
+    while edges.len() < m {
+        let (i, j) = (rng.gen_range(0..n), rng.gen_range(0..n));
+
+        if i < j && used.insert((i, j)) {
+            edges.push((order[i], order[j], rng.gen_range(weight.clone())));
+        }
+    }
+
+    edges
+}
+
+/// 各要素を `range` から一様に選んだ長さ $`n`$ の列を生成する
+pub fn random_array<T: SampleUniform, R: SampleRange<T> + Clone>(
+    rng: &mut impl Rng,
+    n: usize,
+    range: R,
+) -> Vec<T> {
+    (0..n).map(|_| rng.gen_range(range.clone())).collect()
+}
+
+/// 相異なる要素を `range` から選んだ長さ $`n`$ の列を生成する
+///
+/// `range` に $`n`$ 個以上の値が含まれることを要求する。
+pub fn random_distinct_array<
+    T: SampleUniform + Eq + std::hash::Hash + Copy,
+    R: SampleRange<T> + Clone,
+>(
+    rng: &mut impl Rng,
+    n: usize,
+    range: R,
+) -> Vec<T> {
+    let mut used = std::collections::HashSet::new();
+    let mut a = Vec::with_capacity(n);
+
+    while a.len() < n {
+        let x = rng.gen_range(range.clone());
+
+        if used.insert(x) {
+            a.push(x);
+        }
+    }
+
+    a
+}
+
+/// $`0, 1, \dots, n - 1`$ を一様ランダムに並べ替えた列を生成する
+pub fn random_permutation(rng: &mut impl Rng, n: usize) -> Vec<u32> {
+    let mut p = (0..n as u32).collect::<Vec<_>>();
+    p.shuffle(rng);
+    p
+}
+
+/// 各文字を `alphabet` から一様に選んだ長さ $`n`$ の文字列を生成する
+pub fn random_string(rng: &mut impl Rng, n: usize, alphabet: &[char]) -> String {
+    (0..n).map(|_| *alphabet.choose(rng).unwrap()).collect()
+}
@@ -5,6 +5,7 @@ pub struct Diameter<W> {
     src: u32,
     dst: u32,
     pub path: Vec<u32>,
+    weights: Vec<W>,
 }
 
 impl<W> Diameter<W> {
@@ -13,6 +14,17 @@ impl<W> Diameter<W> {
     }
 }
 
+impl<W: Copy> Diameter<W> {
+    /// 直径をなすパスの辺を、`path` の順に `(u, v, w)` の形で列挙する
+    pub fn path_edges(&self) -> Vec<(u32, u32, W)> {
+        self.path
+            .windows(2)
+            .zip(&self.weights)
+            .map(|(p, &w)| (p[0], p[1], w))
+            .collect()
+    }
+}
+
 /// 木の直径を求める。
 ///
 /// * 木 $`T = (V, E)`$ の最遠頂点対の間のパスのことを木の直径という。
@@ -26,6 +38,7 @@ impl<W> Diameter<W> {
 /// |`path`| 直径をなすパス |
 /// |`dist`| 直径をなすパスの重みの和 |
 /// |`furthest_vertex_pair()`| 直径の両端の頂点 |
+/// |`path_edges()`| 直径をなすパスの辺と、その重み |
 ///
 /// 具体的には、[Examples](#Examples) を見るとよい。
 ///
@@ -50,6 +63,10 @@ impl<W> Diameter<W> {
 /// assert_eq!(diameter.path, [5, 4, 0, 1, 2]);
 /// assert_eq!(diameter.dist, 11101);
 /// assert_eq!(diameter.furthest_vertex_pair(), (5, 2));
+/// assert_eq!(
+///     diameter.path_edges(),
+///     [(5, 4, 10000), (4, 0, 1000), (0, 1, 1), (1, 2, 100)]
+/// );
 /// ```
 ///
 /// ## 計算量
@@ -61,7 +78,6 @@ impl<W> Diameter<W> {
 ///
 /// * [Tree Diameter](../../src/lc_tree_diameter/lc_tree_diameter.rs.html)
 /// * [Diameter of a Tree](../../src/aoj_grl_5_a/aoj_grl_5_a.rs.html)
-/// * [Height of a Tree](../../src/aoj_grl_5_b/aoj_grl_5_b.rs.html)
 ///
 pub fn tree_diameter<W: Default + Copy + Ord + std::ops::Add<Output = W>>(
     tree: &dyn Tree<Weight = W>,
@@ -96,7 +112,7 @@ pub fn tree_diameter<W: Default + Copy + Ord + std::ops::Add<Output = W>>(
     let flag = 2;
     seen[r1 as usize] = flag;
     dist[r1 as usize] = W::default();
-    let mut prev = vec![u32::MAX; size];
+    let mut prev = vec![(u32::MAX, W::default()); size];
 
     q.push_front(r1);
 
@@ -109,7 +125,7 @@ pub fn tree_diameter<W: Default + Copy + Ord + std::ops::Add<Output = W>>(
             seen[v as usize] = flag;
             dist[v as usize] = dist[u as usize] + w;
             q.push_front(v);
-            prev[v as usize] = u;
+            prev[v as usize] = (u, w);
         }
     }
 
@@ -118,19 +134,55 @@ pub fn tree_diameter<W: Default + Copy + Ord + std::ops::Add<Output = W>>(
     let dist = dist[r2 as usize];
 
     let mut path = vec![r2];
+    let mut weights = vec![];
     let mut v = r2;
 
     while v != r1 {
-        v = prev[v as usize];
+        let (u, w) = prev[v as usize];
+        v = u;
         path.push(v);
+        weights.push(w);
     }
 
     path.reverse();
+    weights.reverse();
 
     Diameter {
         dist,
         src: r1,
         dst: r2,
         path,
+        weights,
     }
 }
+
+/// 木の各頂点の離心数(その頂点から最も遠い頂点までの距離)を求める
+///
+/// 各頂点から最も遠い頂点の 1 つは直径の端点であるから、直径の両端からの距離の大きい方が離心数になる。計算量は $`O(|V| + |E|)`$ である。
+///
+/// ```
+/// use library::graph::UndirectedAdjGraph;
+/// use library::tree_diameter::eccentricities;
+///
+/// let graph = UndirectedAdjGraph::from_edges(4, &[(0, 1, 2u32), (1, 2, 1), (2, 3, 4)]);
+/// assert_eq!(eccentricities(&graph), [7, 5, 4, 7]);
+/// ```
+///
+/// ## Verified problems
+///
+/// * [Height of a Tree](../../src/aoj_grl_5_b/aoj_grl_5_b.rs.html)
+///
+pub fn eccentricities<W: Default + Copy + Ord + std::ops::Add<Output = W>>(
+    tree: &dyn Tree<Weight = W>,
+) -> Vec<W> {
+    let (u, v) = tree_diameter(tree).furthest_vertex_pair();
+
+    let dist_u = tree.dist(u);
+    let dist_v = tree.dist(v);
+
+    dist_u
+        .into_iter()
+        .zip(dist_v)
+        .map(|(a, b)| std::cmp::max(a, b))
+        .collect()
+}
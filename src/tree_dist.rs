@@ -0,0 +1,130 @@
+use crate::algebra::Min;
+use crate::graph::{Index, Tree};
+use crate::sparse_table::SparseTable;
+
+/// 重み付きの木について、2 頂点の最小共通祖先 (LCA) と距離を高速に求める
+///
+/// 根からの距離と深さを前計算し、LCA はオイラーツアー上の区間で深さが最小の頂点として [`SparseTable`] で求める。
+/// 頂点 $`u, v`$ の距離は $`d(u) + d(v) - 2 d(\text{lca}(u, v))`$ で計算できる($`d`$ は根からの距離)。
+///
+/// ## Examples
+///
+/// ```
+/// use library::graph::UndirectedAdjGraph;
+/// use library::tree_dist::TreeDist;
+///
+/// //        0
+/// //    1 /   \ 10
+/// //     1     2
+/// // 100 |     | 1000
+/// //     3     4
+/// let tree = UndirectedAdjGraph::from_edges(5, &[(0, 1, 1u64), (0, 2, 10), (1, 3, 100), (2, 4, 1000)]);
+/// let td = TreeDist::from(&tree, 0);
+///
+/// assert_eq!(td.lca(3, 4), 0);
+/// assert_eq!(td.lca(1, 3), 1);
+/// assert_eq!(td.dist(3, 4), 1111);
+/// assert_eq!(td.dist(2, 4), 1000);
+/// assert_eq!(td.depth(3), 2);
+/// assert_eq!(td.path_length(3, 4), 4);
+/// ```
+///
+/// ## 計算量
+///
+/// 木の頂点数を $`N`$ とする。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `from(tree, root)` | `root` を根として前計算を行う | $`O(N \log N)`$ |
+/// | `self.lca(u, v)` | $`u`$ と $`v`$ の LCA を求める | $`O(1)`$ |
+/// | `self.dist(u, v)` | $`u`$ と $`v`$ の距離を求める | $`O(1)`$ |
+/// | `self.path_length(u, v)` | $`u`$ と $`v`$ を結ぶパスの辺の数を求める | $`O(1)`$ |
+///
+/// ## Verified problems
+///
+/// * [Lowest Common Ancestor](../../src/lc_lca_03/lc_lca_03.rs.html)
+///
+pub struct TreeDist<W> {
+    // オイラーツアーで初めて訪れた時刻
+    tin: Vec<usize>,
+    // オイラーツアーの各時刻の (深さ << 32 | 頂点) の最小値
+    table: SparseTable<Min<u64>>,
+    depth: Vec<u32>,
+    dist: Vec<W>,
+}
+
+impl<W: Copy + Default + std::ops::Add<Output = W> + std::ops::Sub<Output = W>> TreeDist<W> {
+    /// `root` を根として前計算を行う
+    pub fn from(tree: &dyn Tree<Weight = W>, root: Index) -> Self {
+        let size = tree.size() as usize;
+
+        let mut tin = vec![usize::MAX; size];
+        let mut depth = vec![0; size];
+        let mut dist = vec![W::default(); size];
+        let mut tour = vec![];
+
+        // (頂点, 親, 次に見る隣接辺の番号)
+        let mut stack = vec![(root, Index::MAX, 0)];
+
+        while let Some((u, parent, i)) = stack.pop() {
+            if i == 0 {
+                tin[u as usize] = tour.len();
+            }
+
+            tour.push((depth[u as usize] as u64) << 32 | u as u64);
+
+            let adjacent = tree.adjacent(u);
+            let mut i = i;
+
+            while i < adjacent.len() && adjacent[i].0 == parent {
+                i += 1;
+            }
+
+            if i < adjacent.len() {
+                let (v, w) = adjacent[i];
+                depth[v as usize] = depth[u as usize] + 1;
+                dist[v as usize] = dist[u as usize] + w;
+
+                stack.push((u, parent, i + 1));
+                stack.push((v, u, 0));
+            }
+        }
+
+        Self {
+            tin,
+            table: SparseTable::from(&tour),
+            depth,
+            dist,
+        }
+    }
+
+    /// $`u`$ と $`v`$ の LCA を求める
+    pub fn lca(&self, u: Index, v: Index) -> Index {
+        let (tu, tv) = (self.tin[u as usize], self.tin[v as usize]);
+        let (l, r) = if tu <= tv { (tu, tv) } else { (tv, tu) };
+
+        (self.table.prod(l..=r) & 0xffff_ffff) as Index
+    }
+
+    /// $`u`$ と $`v`$ の距離を求める
+    pub fn dist(&self, u: Index, v: Index) -> W {
+        let w = self.dist[self.lca(u, v) as usize];
+        (self.dist[u as usize] - w) + (self.dist[v as usize] - w)
+    }
+
+    /// $`u`$ と $`v`$ を結ぶパスの辺の数を求める
+    pub fn path_length(&self, u: Index, v: Index) -> u32 {
+        let d = self.depth[self.lca(u, v) as usize];
+        self.depth[u as usize] + self.depth[v as usize] - 2 * d
+    }
+
+    /// 根から $`v`$ への距離を求める
+    pub fn dist_from_root(&self, v: Index) -> W {
+        self.dist[v as usize]
+    }
+
+    /// $`v`$ の深さ (根から $`v`$ へのパスの辺の数) を求める
+    pub fn depth(&self, v: Index) -> u32 {
+        self.depth[v as usize]
+    }
+}
@@ -0,0 +1,143 @@
+//! 木の同型性を判定するため、木に標準的な番号を割り当てる
+//!
+//! 根付き木の各頂点について、子の部分木の番号を昇順に並べた列を作り、同じ列には同じ番号を割り当てる。
+//! これを葉から順に行うと、根付き木として同型な部分木にちょうど同じ番号が割り当てられる。ハッシュを使わないので、衝突することはない。
+//!
+//! 根のない木は重心を根として番号を求める。重心が $`2`$ つある場合は、それぞれを根としたときの番号の小さい方を使う。
+//!
+//! 同じ [`TreeIsomorphism`] で番号を求めた木同士であれば、番号が等しいことと同型であることが同値になる。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::graph::UndirectedAdjGraph;
+//! use library::tree_isomorphism::TreeIsomorphism;
+//!
+//! let mut iso = TreeIsomorphism::new();
+//!
+//! // 0 を根とすると、部分木 1 と部分木 2 はどちらも頂点 2 つのパス
+//! let tree = UndirectedAdjGraph::from_edges_no_weight(5, &[(0, 1), (0, 2), (1, 3), (2, 4)]);
+//! let id = iso.rooted(&tree, 0);
+//! assert_eq!(id[1], id[2]);
+//! assert_eq!(id[3], id[4]);
+//! assert_ne!(id[0], id[1]);
+//!
+//! // 頂点番号の付け方が違うだけの、長さ 4 のパス
+//! let a = UndirectedAdjGraph::from_edges_no_weight(5, &[(0, 1), (1, 2), (2, 3), (3, 4)]);
+//! let b = UndirectedAdjGraph::from_edges_no_weight(5, &[(3, 0), (0, 4), (4, 1), (1, 2)]);
+//! assert_eq!(iso.unrooted(&a), iso.unrooted(&b));
+//!
+//! // 星グラフはパスと同型ではない
+//! let c = UndirectedAdjGraph::from_edges_no_weight(5, &[(0, 1), (0, 2), (0, 3), (0, 4)]);
+//! assert_ne!(iso.unrooted(&a), iso.unrooted(&c));
+//! ```
+//!
+//! ## 計算量
+//!
+//! $`N`$ を頂点数として、期待 $`O(N \log N)`$ である。
+//!
+//! ## Verified problems
+//!
+//! * [Rooted Tree Isomorphism Classification](../../src/lc_rooted_tree_isomorphism_classification/lc_rooted_tree_isomorphism_classification.rs.html)
+//!
+
+use crate::graph::{Index, Tree};
+
+/// 木に同型性を判定するための番号を割り当てる
+pub struct TreeIsomorphism {
+    ids: std::collections::HashMap<Vec<usize>, usize>,
+}
+
+impl TreeIsomorphism {
+    /// まだ番号を 1 つも割り当てていない状態で初期化する
+    pub fn new() -> Self {
+        Self {
+            ids: std::collections::HashMap::new(),
+        }
+    }
+
+    /// これまでに割り当てた番号の種類数を返す
+    /// 割り当てた番号は $`0`$ 以上この値未満である
+    pub fn count(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// `root` を根とする根付き木について、各頂点を根とする部分木の番号を求める
+    pub fn rooted<W>(&mut self, tree: &dyn Tree<Weight = W>, root: Index) -> Vec<usize> {
+        let (order, parent) = bfs(tree, root);
+
+        let mut children = vec![vec![]; tree.size() as usize];
+        let mut id = vec![0; tree.size() as usize];
+
+        for &v in order.iter().rev() {
+            let mut c = std::mem::take(&mut children[v as usize]);
+            c.sort_unstable();
+
+            let next = self.ids.len();
+            id[v as usize] = *self.ids.entry(c).or_insert(next);
+
+            if parent[v as usize] != Index::MAX {
+                children[parent[v as usize] as usize].push(id[v as usize]);
+            }
+        }
+
+        id
+    }
+
+    /// 根のない木として、`tree` の番号を求める
+    pub fn unrooted<W>(&mut self, tree: &dyn Tree<Weight = W>) -> usize {
+        centroids(tree)
+            .into_iter()
+            .map(|c| self.rooted(tree, c)[c as usize])
+            .min()
+            .unwrap()
+    }
+}
+
+impl Default for TreeIsomorphism {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `root` から幅優先探索した順序と、各頂点の親を求める
+fn bfs<W>(tree: &dyn Tree<Weight = W>, root: Index) -> (Vec<Index>, Vec<Index>) {
+    let mut parent = vec![Index::MAX; tree.size() as usize];
+    let mut order = vec![root];
+    let mut head = 0;
+
+    while head < order.len() {
+        let u = order[head];
+        head += 1;
+
+        for &(v, _) in tree.adjacent(u) {
+            if v != root && parent[v as usize] == Index::MAX {
+                parent[v as usize] = u;
+                order.push(v);
+            }
+        }
+    }
+
+    (order, parent)
+}
+
+/// 木の重心を (1 つまたは 2 つ) 求める
+fn centroids<W>(tree: &dyn Tree<Weight = W>) -> Vec<Index> {
+    let size = tree.size() as usize;
+    let (order, parent) = bfs(tree, 0);
+
+    let mut sub = vec![1; size];
+    let mut max_child = vec![0; size];
+    for &v in order.iter().rev() {
+        let p = parent[v as usize];
+        if p != Index::MAX {
+            sub[p as usize] += sub[v as usize];
+            max_child[p as usize] = max_child[p as usize].max(sub[v as usize]);
+        }
+    }
+
+    (0..size)
+        .filter(|&v| 2 * max_child[v].max(size - sub[v]) <= size)
+        .map(|v| v as Index)
+        .collect()
+}
@@ -0,0 +1,99 @@
+//! 無向グラフの三角形(長さ $`3`$ の閉路)を列挙する
+//!
+//! 各辺を次数の小さい頂点から大きい頂点へ向き付けると、各頂点の出次数は $`O(\sqrt{M})`$ になる。
+//! 各頂点 $`u`$ について、$`u \to v \to w`$ と辿った $`w`$ が $`u`$ の出辺の先にあるかを調べることで、三角形をちょうど $`1`$ 回ずつ列挙できる。
+//!
+//! 辺の向き・重みは無視し、自己ループは取り除き、多重辺は $`1`$ 本の辺として扱う。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::graph::UndirectedAdjGraph;
+//! use library::triangle::{count_triangles, enumerate_triangles};
+//!
+//! // 完全グラフ K_4
+//! let graph = UndirectedAdjGraph::from_edges_no_weight(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+//! assert_eq!(count_triangles(&graph), 4);
+//!
+//! let mut triangles = vec![];
+//! enumerate_triangles(&graph, |a, b, c| {
+//!     let mut t = [a, b, c];
+//!     t.sort();
+//!     triangles.push(t);
+//! });
+//! triangles.sort();
+//! assert_eq!(triangles, vec![[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]]);
+//! ```
+//!
+//! ## 計算量
+//!
+//! $`N`$ を頂点数、$`M`$ を辺数とする。
+//!
+//! | 関数 | 計算量 |
+//! | --- | --- |
+//! | [`enumerate_triangles()`] | $`O(N + M \sqrt{M})`$ |
+//! | [`count_triangles()`] | $`O(N + M \sqrt{M})`$ |
+//!
+//! ## Verified problems
+//!
+//! * [Enumerate Triangles](../../src/lc_enumerate_triangles/lc_enumerate_triangles.rs.html)
+//!
+
+use crate::graph::{Graph, Index};
+
+/// 三角形をすべて列挙し、それぞれの頂点 `(a, b, c)` について `f` を呼ぶ
+///
+/// 頂点の並び順は定めない。
+pub fn enumerate_triangles(graph: &impl Graph, mut f: impl FnMut(Index, Index, Index)) {
+    let size = graph.size() as usize;
+
+    let mut edges = graph
+        .edges()
+        .filter(|&(u, v, _)| u != v)
+        .map(|(u, v, _)| (u.min(v), u.max(v)))
+        .collect::<Vec<_>>();
+    edges.sort_unstable();
+    edges.dedup();
+
+    let mut deg = vec![0usize; size];
+    for &(u, v) in &edges {
+        deg[u as usize] += 1;
+        deg[v as usize] += 1;
+    }
+
+    // 次数が小さい方(同じなら番号が小さい方)から大きい方へ向き付ける
+    let mut out = vec![vec![]; size];
+    for &(u, v) in &edges {
+        if (deg[u as usize], u) < (deg[v as usize], v) {
+            out[u as usize].push(v);
+        } else {
+            out[v as usize].push(u);
+        }
+    }
+
+    let mut mark = vec![false; size];
+    for u in 0..size {
+        for &v in &out[u] {
+            mark[v as usize] = true;
+        }
+
+        for &v in &out[u] {
+            for &w in &out[v as usize] {
+                if mark[w as usize] {
+                    f(u as Index, v, w);
+                }
+            }
+        }
+
+        for &v in &out[u] {
+            mark[v as usize] = false;
+        }
+    }
+}
+
+/// 三角形の個数を数える
+pub fn count_triangles(graph: &impl Graph) -> u64 {
+    let mut count = 0;
+    enumerate_triangles(graph, |_, _, _| count += 1);
+    count
+}
@@ -0,0 +1,308 @@
+/// 文字列(列)の多重集合を管理する Trie木
+///
+/// 各頂点に、その頂点を通る文字列の数と、その頂点で終わる文字列の数を持たせている。
+/// bit列で構築する [`MultiBinaryTrie`](crate::binary_trie::MultiBinaryTrie) と違い、`u8` や `char` など任意の要素の列を扱える。
+///
+/// できることの例は以下の通り。
+///
+/// * 列の挿入、削除
+/// * 列がいくつ含まれているかの検索
+/// * ある列を接頭辞にもつ列の数の計算
+/// * ある列の接頭辞になっている列で最長のものの検索
+/// * ある列と、含まれている列との最長共通接頭辞の長さの計算
+/// * 頂点を直接辿る、木 DP などのための操作
+///
+/// ## Examples
+///
+/// ```
+/// use library::trie::Trie;
+///
+/// let mut trie = Trie::new();
+///
+/// trie.insert(b"apple");
+/// trie.insert(b"app");
+/// trie.insert(b"apply");
+/// trie.insert(b"app");
+///
+/// assert_eq!(trie.count(b"app"), 2);
+/// assert!(!trie.contains(b"ap"));
+/// assert_eq!(trie.count_prefix(b"app"), 4);
+/// assert_eq!(trie.count_prefix(b"appl"), 2);
+///
+/// // "applesauce" の接頭辞になっている列で最長のものは "apple"
+/// assert_eq!(trie.longest_prefix_match(b"applesauce"), Some(5));
+/// assert_eq!(trie.longest_prefix_match(b"ap"), None);
+///
+/// assert!(trie.remove(b"apple"));
+/// assert!(!trie.remove(b"apple"));
+/// assert_eq!(trie.longest_prefix_match(b"applesauce"), Some(3));
+/// assert_eq!(trie.all_count(), 3);
+/// ```
+///
+/// 頂点を直接辿ることもできる。頂点番号は根が $`0`$ で、子の番号は親より大きい。
+/// そのため、番号の大きい頂点から順に処理すれば葉から根へ向かう DP ができる。
+///
+/// ```
+/// use library::trie::Trie;
+///
+/// let mut trie = Trie::new();
+/// for s in ["abc", "abd", "b"] {
+///     trie.insert(s.as_bytes());
+/// }
+///
+/// // "abx" と最も長く一致するのは "ab" まで
+/// assert_eq!(trie.longest_common_prefix(b"abx"), 2);
+///
+/// let v = trie.child(trie.root(), b'a').unwrap();
+/// let v = trie.child(v, b'b').unwrap();
+/// assert_eq!(trie.pass_count(v), 2);
+/// assert_eq!(trie.children(v).count(), 2);
+///
+/// // 各頂点の部分木の高さ
+/// let mut height = vec![0; trie.node_count()];
+/// for v in (0..trie.node_count()).rev() {
+///     height[v] = trie.children(v).map(|(_, u)| height[u] + 1).max().unwrap_or(0);
+/// }
+/// assert_eq!(height[trie.root()], 3);
+/// ```
+///
+/// `char` の列も扱える。
+///
+/// ```
+/// use library::trie::Trie;
+///
+/// let mut trie = Trie::new();
+///
+/// for s in ["ねこ", "ねずみ", "いぬ"] {
+///     trie.insert(&s.chars().collect::<Vec<_>>());
+/// }
+///
+/// assert_eq!(trie.count_prefix(&['ね']), 2);
+/// ```
+///
+/// ## 計算量
+///
+/// 要素のハッシュ値の計算が $`O(1)`$ で行えることを仮定する。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `new()` | 空の Trie木を生成する | $`O(1)`$ |
+/// | `self.insert(s)` | `s` を一つ追加する | $`O(\lvert s \rvert)`$ |
+/// | `self.remove(s)` | `s` を一つ削除する | $`O(\lvert s \rvert)`$ |
+/// | `self.count(s)` | `s` がいくつ含まれているかを調べる | $`O(\lvert s \rvert)`$ |
+/// | `self.count_prefix(s)` | `s` を接頭辞にもつ列の数を調べる | $`O(\lvert s \rvert)`$ |
+/// | `self.longest_prefix_match(s)` | `s` の接頭辞になっている列で最長のものの長さを調べる | $`O(\lvert s \rvert)`$ |
+/// | `self.longest_common_prefix(s)` | `s` と含まれている列の最長共通接頭辞の長さを調べる | $`O(\lvert s \rvert)`$ |
+/// | `self.children(v)` | 頂点 `v` の子を列挙する | 子の数に比例 |
+/// | その他の頂点に対する操作 | | $`O(1)`$ |
+///
+#[derive(Clone)]
+pub struct Trie<T> {
+    tree: Vec<Node<T>>,
+}
+
+impl<T: Eq + std::hash::Hash + Copy> Trie<T> {
+    /// 空の Trie木を生成する
+    pub fn new() -> Self {
+        Self {
+            tree: vec![Node::new()],
+        }
+    }
+
+    /// `s` に対応する頂点を返す
+    /// 途中で通る列がなくなった場合 `None` を返す
+    fn find(&self, s: &[T]) -> Option<usize> {
+        let mut ptr = 0;
+
+        for c in s {
+            match self.tree[ptr].children.get(c) {
+                Some(&nxt) if self.tree[nxt as usize].pass > 0 => {
+                    ptr = nxt as usize;
+                }
+                _ => {
+                    return None;
+                }
+            }
+        }
+
+        Some(ptr)
+    }
+
+    /// `s` を一つ追加する
+    pub fn insert(&mut self, s: &[T]) {
+        let mut ptr = 0;
+        self.tree[ptr].pass += 1;
+
+        for &c in s {
+            let nxt = match self.tree[ptr].children.get(&c) {
+                Some(&nxt) => nxt as usize,
+                None => {
+                    let l = self.tree.len();
+                    self.tree.push(Node::new());
+                    self.tree[ptr].children.insert(c, l as u32);
+                    l
+                }
+            };
+
+            ptr = nxt;
+            self.tree[ptr].pass += 1;
+        }
+
+        self.tree[ptr].terminal += 1;
+    }
+
+    /// `s` を一つ削除する
+    ///
+    /// 削除できる、つまり実行前に `s` を含んでいる場合 `true` を返す
+    /// そうでない場合 `false` を返す
+    pub fn remove(&mut self, s: &[T]) -> bool {
+        if !self.contains(s) {
+            return false;
+        }
+
+        let mut ptr = 0;
+        self.tree[ptr].pass -= 1;
+
+        for c in s {
+            ptr = self.tree[ptr].children[c] as usize;
+            self.tree[ptr].pass -= 1;
+        }
+
+        self.tree[ptr].terminal -= 1;
+        true
+    }
+
+    /// `s` が含まれているかを調べる
+    pub fn contains(&self, s: &[T]) -> bool {
+        self.count(s) > 0
+    }
+
+    /// `s` が何個含まれているかを調べる
+    pub fn count(&self, s: &[T]) -> u32 {
+        match self.find(s) {
+            Some(ptr) => self.tree[ptr].terminal,
+            None => 0,
+        }
+    }
+
+    /// `s` を接頭辞にもつ列が何個含まれているかを調べる
+    pub fn count_prefix(&self, s: &[T]) -> u32 {
+        match self.find(s) {
+            Some(ptr) => self.tree[ptr].pass,
+            None => 0,
+        }
+    }
+
+    /// 含まれている列のうち `s` の接頭辞になっているもので、最長のものの長さを求める
+    /// 存在しない場合 `None` を返す
+    pub fn longest_prefix_match(&self, s: &[T]) -> Option<usize> {
+        let mut ptr = 0;
+        let mut ret = if self.tree[0].terminal > 0 {
+            Some(0)
+        } else {
+            None
+        };
+
+        for (i, c) in s.iter().enumerate() {
+            match self.tree[ptr].children.get(c) {
+                Some(&nxt) if self.tree[nxt as usize].pass > 0 => {
+                    ptr = nxt as usize;
+                }
+                _ => {
+                    break;
+                }
+            }
+
+            if self.tree[ptr].terminal > 0 {
+                ret = Some(i + 1);
+            }
+        }
+
+        ret
+    }
+
+    /// 含まれている列のいずれかと `s` の最長共通接頭辞の長さを求める
+    pub fn longest_common_prefix(&self, s: &[T]) -> usize {
+        let mut ptr = 0;
+
+        for (i, c) in s.iter().enumerate() {
+            match self.child(ptr, *c) {
+                Some(nxt) => {
+                    ptr = nxt;
+                }
+                None => {
+                    return i;
+                }
+            }
+        }
+
+        s.len()
+    }
+
+    /// 根の頂点番号
+    pub fn root(&self) -> usize {
+        0
+    }
+
+    /// 頂点数 (削除された列だけが通っていた頂点も含む)
+    pub fn node_count(&self) -> usize {
+        self.tree.len()
+    }
+
+    /// 頂点 `v` から要素 `c` で進んだ先の頂点
+    /// その頂点を通る列がない場合 `None` を返す
+    pub fn child(&self, v: usize, c: T) -> Option<usize> {
+        match self.tree[v].children.get(&c) {
+            Some(&nxt) if self.tree[nxt as usize].pass > 0 => Some(nxt as usize),
+            _ => None,
+        }
+    }
+
+    /// 頂点 `v` の子を `(要素, 頂点)` の組で列挙する (順番は不定)
+    /// 通る列がない頂点は含まない
+    pub fn children(&self, v: usize) -> impl Iterator<Item = (T, usize)> + '_ {
+        self.tree[v]
+            .children
+            .iter()
+            .map(|(&c, &nxt)| (c, nxt as usize))
+            .filter(move |&(_, nxt)| self.tree[nxt].pass > 0)
+    }
+
+    /// 頂点 `v` を通る列の数
+    pub fn pass_count(&self, v: usize) -> u32 {
+        self.tree[v].pass
+    }
+
+    /// 頂点 `v` で終わる列の数
+    pub fn terminal_count(&self, v: usize) -> u32 {
+        self.tree[v].terminal
+    }
+
+    /// 今自身が何個の列を含んでいるかを求める
+    pub fn all_count(&self) -> u32 {
+        self.tree[0].pass
+    }
+}
+
+impl<T: Eq + std::hash::Hash + Copy> Default for Trie<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone)]
+struct Node<T> {
+    children: std::collections::HashMap<T, u32>,
+    pass: u32,
+    terminal: u32,
+}
+
+impl<T> Node<T> {
+    fn new() -> Self {
+        Self {
+            children: std::collections::HashMap::new(),
+            pass: 0,
+            terminal: 0,
+        }
+    }
+}
@@ -78,3 +78,190 @@ pub fn tsp_two_approximation<S: MetricSpace>(points: &[S::P]) -> Vec<usize> {
     path.push(0);
     path
 }
+
+/// bit DP (Held–Karp) で TSP の厳密解を求める
+///
+/// `dist_matrix[i][j]` を頂点 $`i`$ から頂点 $`j`$ への距離として、頂点 $`0`$ から出発して全頂点を $`1`$ 回ずつ訪れ、頂点 $`0`$ に戻る巡回路のうち長さが最小のものを求める。
+/// `(巡回路の長さ, 巡回路)` を返し、巡回路は [`tsp_two_approximation`] と同じく頂点 $`0`$ で始まり頂点 $`0`$ で終わる。
+///
+/// 距離は非対称でもよい。計算量は頂点数を $`N`$ として $`O(2^N N^2)`$ である。
+///
+/// ```
+/// use library::tsp::tsp_bitmask_dp;
+///
+/// let dist = vec![
+///     vec![0, 1, 10, 10],
+///     vec![10, 0, 1, 10],
+///     vec![10, 10, 0, 1],
+///     vec![1, 10, 10, 0],
+/// ];
+/// assert_eq!(tsp_bitmask_dp(&dist), (4, vec![0, 1, 2, 3, 0]));
+/// ```
+pub fn tsp_bitmask_dp<W: Default + Copy + Ord + std::ops::Add<Output = W>>(
+    dist_matrix: &[Vec<W>],
+) -> (W, Vec<usize>) {
+    let size = dist_matrix.len();
+    assert!(size >= 1);
+
+    // 頂点 1, ..., size - 1 を訪れたかをビットで持つ
+    // dp[mask * m + v]: mask の頂点を訪れて頂点 v + 1 にいるときの最短距離
+    let m = size - 1;
+    if m == 0 {
+        return (dist_matrix[0][0], vec![0, 0]);
+    }
+
+    let mut dp = vec![None; (1 << m) * m];
+
+    for v in 0..m {
+        dp[(1 << v) * m + v] = Some(dist_matrix[0][v + 1]);
+    }
+
+    for mask in 1..1usize << m {
+        for v in 0..m {
+            let d = match dp[mask * m + v] {
+                Some(d) => d,
+                None => continue,
+            };
+            for u in 0..m {
+                if mask >> u & 1 == 1 {
+                    continue;
+                }
+
+                let nd = d + dist_matrix[v + 1][u + 1];
+                let e = &mut dp[(mask | 1 << u) * m + u];
+                if e.map_or(true, |e| nd < e) {
+                    *e = Some(nd);
+                }
+            }
+        }
+    }
+
+    let full = (1 << m) - 1;
+    let (best, mut v) = (0..m)
+        .map(|v| (dp[full * m + v].unwrap() + dist_matrix[v + 1][0], v))
+        .min()
+        .unwrap();
+
+    // 経路を復元する
+    let mut tour = vec![0, v + 1];
+    let mut mask = full;
+    while mask != 1 << v {
+        let d = dp[mask * m + v].unwrap();
+        let prev = mask ^ 1 << v;
+        v = (0..m)
+            .find(|&u| {
+                prev >> u & 1 == 1
+                    && dp[prev * m + u].map_or(false, |e| e + dist_matrix[u + 1][v + 1] == d)
+            })
+            .unwrap();
+        mask = prev;
+        tour.push(v + 1);
+    }
+    tour.push(0);
+    tour.reverse();
+
+    (best, tour)
+}
+
+/// 2-opt 近傍と Or-opt 近傍の局所探索で、巡回路を改善する
+///
+/// `tour` は [`tsp_two_approximation`] の返り値と同じく、頂点 $`0`$ で始まり頂点 $`0`$ で終わる巡回路とする。
+/// 改善できなくなるか、`time_limit` の時間が経過するまで探索を続け、改善後の巡回路を返す。
+///
+/// * 2-opt: 巡回路の 2 辺を選び、その間を反転する
+/// * Or-opt: 連続する高々 $`3`$ 頂点を取り出し、(反転も含めて)別の位置に挿入する
+///
+/// 1 回の走査の計算量は頂点数を $`N`$ として $`O(N^2)`$ である。
+///
+/// ```
+/// use library::tsp::{tsp_local_search, tsp_two_approximation, EuclidianSpace2D};
+/// use std::time::Duration;
+///
+/// let points = vec![(0, 0), (10, 10), (10, 0), (0, 10)];
+///
+/// // 対角線を 2 回通る巡回路を、正方形の外周に改善する
+/// let tour = vec![0, 1, 2, 3, 0];
+/// let tour = tsp_local_search::<EuclidianSpace2D>(&points, tour, Duration::from_millis(100));
+/// assert!(tour == vec![0, 2, 1, 3, 0] || tour == vec![0, 3, 1, 2, 0]);
+///
+/// let tour = tsp_two_approximation::<EuclidianSpace2D>(&points);
+/// let tour = tsp_local_search::<EuclidianSpace2D>(&points, tour, Duration::from_millis(100));
+/// assert!(tour == vec![0, 2, 1, 3, 0] || tour == vec![0, 3, 1, 2, 0]);
+/// ```
+pub fn tsp_local_search<S: MetricSpace>(
+    points: &[S::P],
+    mut tour: Vec<usize>,
+    time_limit: std::time::Duration,
+) -> Vec<usize>
+where
+    S::W: std::ops::Add<Output = S::W>,
+{
+    let start = std::time::Instant::now();
+    let n = tour.len() - 1;
+    let d = |a: usize, b: usize| S::d(&points[a], &points[b]);
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+
+        // 2-opt: 辺 (t[i], t[i + 1]) と辺 (t[j], t[j + 1]) を (t[i], t[j]) と (t[i + 1], t[j + 1]) に繋ぎ変える
+        for i in 0..n {
+            if start.elapsed() >= time_limit {
+                return tour;
+            }
+
+            for j in i + 2..n {
+                let (a, b, c, e) = (tour[i], tour[i + 1], tour[j], tour[j + 1]);
+                if d(a, c) + d(b, e) < d(a, b) + d(c, e) {
+                    tour[i + 1..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+
+        // Or-opt: t[i..i + len] を取り出し、辺 (t[j], t[j + 1]) の間に挿入する
+        for len in 1..=3 {
+            let mut i = 1;
+            while i + len <= n {
+                if start.elapsed() >= time_limit {
+                    return tour;
+                }
+
+                let (p, f, l, q) = (tour[i - 1], tour[i], tour[i + len - 1], tour[i + len]);
+                let removed = d(p, f) + d(l, q);
+
+                let mut moved = false;
+                for j in 0..n {
+                    if i - 1 <= j && j < i + len {
+                        continue;
+                    }
+
+                    let (a, b) = (tour[j], tour[j + 1]);
+                    let old = removed + d(a, b);
+                    let forward = d(p, q) + d(a, f) + d(l, b);
+                    let backward = d(p, q) + d(a, l) + d(f, b);
+
+                    if forward < old || backward < old {
+                        let mut segment = tour.drain(i..i + len).collect::<Vec<_>>();
+                        if backward < forward {
+                            segment.reverse();
+                        }
+
+                        let pos = if j < i { j + 1 } else { j + 1 - len };
+                        tour.splice(pos..pos, segment);
+                        moved = true;
+                        break;
+                    }
+                }
+
+                if moved {
+                    improved = true;
+                } else {
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    tour
+}
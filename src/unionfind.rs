@@ -1,3 +1,5 @@
+use crate::algebra::AbelianGroup;
+
 /// 集合を合併する操作、2つの要素が同じ集合に含まれているかを検索する操作が行える素集合データ構造
 ///
 /// ## Examples
@@ -23,6 +25,19 @@
 /// assert_eq!(uf.size(1), 3);
 /// ```
 ///
+/// 連結成分の列挙もできる。
+///
+/// ```
+/// use library::unionfind::UnionFind;
+///
+/// let mut uf = UnionFind::new(5);
+/// uf.unite(3, 1);
+/// uf.unite(4, 0);
+///
+/// assert_eq!(uf.count(), 3);
+/// assert_eq!(uf.groups(), vec![vec![0, 4], vec![1, 3], vec![2]]);
+/// ```
+///
 /// ## 計算量
 ///
 /// $`\lvert \text{self} \rvert`$ を初めに生成したときの素集合の数とする。
@@ -33,6 +48,8 @@
 /// | `self.unite(a, b)` | $`a`$ が含まれている集合と $`b`$ が含まれている集合を合併する | $`O(\alpha(\lvert \text{self} \rvert))`$ |
 /// | `self.is_same(u, v)` | $`u`$ が含まれている集合と $`v`$ が含まれている集合が同じかどうかを検索する | $`O(\alpha(\lvert \text{self} \rvert))`$ |
 /// | `self.size(v)` | $`v`$ が含まれている集合の大きさを求める | $`O(\alpha(\lvert \text{self} \rvert))`$ |
+/// | `self.count()` | 素集合の個数を求める | $`O(1)`$ |
+/// | `self.groups()` | 素集合を列挙する | $`O(\lvert \text{self} \rvert \alpha(\lvert \text{self} \rvert))`$ |
 ///
 /// ## Verified problems
 ///
@@ -42,6 +59,7 @@
 
 pub struct UnionFind {
     data: Vec<i32>,
+    count: usize,
 }
 
 impl UnionFind {
@@ -49,6 +67,7 @@ impl UnionFind {
     pub fn new(size: usize) -> Self {
         return Self {
             data: vec![-1; size],
+            count: size,
         };
     }
 
@@ -73,6 +92,7 @@ impl UnionFind {
 
         self.data[a] += self.data[b];
         self.data[b] = a as i32;
+        self.count -= 1;
     }
 
     /// $`v`$ が含まれている集合の大きさを求める
@@ -82,6 +102,31 @@ impl UnionFind {
         -self.data[v]
     }
 
+    /// 素集合の個数を求める
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// 素集合を列挙する
+    ///
+    /// 各素集合の要素は昇順に並び、素集合同士は最小の要素の昇順に並ぶ。
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let n = self.data.len();
+        let mut index = vec![usize::MAX; n];
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.count);
+
+        for v in 0..n {
+            let r = self.find(v);
+            if index[r] == usize::MAX {
+                index[r] = groups.len();
+                groups.push(vec![]);
+            }
+            groups[index[r]].push(v);
+        }
+
+        groups
+    }
+
     /// $`v`$ が含まれる素集合の代表元を求める
     /// 
     /// 本来は隠蔽してよい関数だと思われるが、これを使えたほうが実装しやすい問題がそれなりにあるので、一応 `pub` にしている
@@ -95,3 +140,358 @@ impl UnionFind {
         return self.data[v] as usize;
     }
 }
+
+/// 各要素にポテンシャル (可換群 `G` の元) が割り当てられた Union-Find
+///
+/// 要素 $`v`$ のポテンシャルを $`p(v)`$ と書く。$`p(b) - p(a) = d`$ という形の情報を追加していき、同じ集合に含まれる $`2`$ 要素のポテンシャルの差を求められる。
+///
+/// ## Examples
+///
+/// ```
+/// use library::algebra::Add;
+/// use library::unionfind::PotentializedUnionFind;
+///
+/// let mut uf = PotentializedUnionFind::<Add<i64>>::new(4);
+///
+/// // p(1) - p(0) = 3, p(2) - p(1) = -5
+/// assert!(uf.unite(0, 1, 3));
+/// assert!(uf.unite(1, 2, -5));
+/// assert_eq!(uf.diff(0, 2), Some(-2));
+/// assert_eq!(uf.diff(2, 0), Some(2));
+/// assert_eq!(uf.diff(0, 3), None);
+///
+/// // 既存の情報と矛盾する
+/// assert!(!uf.unite(2, 0, 0));
+/// assert!(uf.unite(2, 0, 2));
+/// ```
+///
+/// ## 計算量
+///
+/// 二項演算・逆元の計算が $`O(1)`$ で行えることを仮定する。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `new(size)` | ポテンシャルの情報がない状態で初期化する | $`O(\text{size})`$ |
+/// | `self.unite(a, b, d)` | $`p(b) - p(a) = d`$ という情報を追加する | $`O(\alpha(\lvert \text{self} \rvert))`$ |
+/// | `self.diff(a, b)` | $`p(b) - p(a)`$ を求める | $`O(\alpha(\lvert \text{self} \rvert))`$ |
+/// | `self.is_same(u, v)` | $`u`$ が含まれている集合と $`v`$ が含まれている集合が同じかどうかを検索する | $`O(\alpha(\lvert \text{self} \rvert))`$ |
+/// | `self.size(v)` | $`v`$ が含まれている集合の大きさを求める | $`O(\alpha(\lvert \text{self} \rvert))`$ |
+///
+/// ## Verified problems
+///
+/// * [Unionfind with Potential](../../src/lc_unionfind_with_potential/lc_unionfind_with_potential.rs.html)
+///
+pub struct PotentializedUnionFind<G: AbelianGroup> {
+    data: Vec<i32>,
+    potential: Vec<G::S>,
+}
+
+impl<G: AbelianGroup> PotentializedUnionFind<G> {
+    /// ポテンシャルの情報がない状態で初期化する
+    pub fn new(size: usize) -> Self {
+        Self {
+            data: vec![-1; size],
+            potential: vec![G::E; size],
+        }
+    }
+
+    /// $`v`$ が含まれる素集合の代表元を求める
+    pub fn find(&mut self, v: usize) -> usize {
+        assert!(v < self.data.len());
+        if self.data[v] < 0 {
+            return v;
+        }
+
+        let parent = self.data[v] as usize;
+        let root = self.find(parent);
+        self.potential[v] = G::op(&self.potential[parent], &self.potential[v]);
+        self.data[v] = root as i32;
+        root
+    }
+
+    /// 代表元から見た $`v`$ のポテンシャルを求める
+    fn potential(&mut self, v: usize) -> G::S {
+        self.find(v);
+        self.potential[v].clone()
+    }
+
+    /// $`p(b) - p(a) = d`$ という情報を追加する
+    ///
+    /// 既存の情報と矛盾する場合は何もせず `false` を返す。
+    pub fn unite(&mut self, a: usize, b: usize, d: G::S) -> bool {
+        assert!(a < self.data.len() && b < self.data.len());
+        let (pa, pb) = (self.potential(a), self.potential(b));
+        let (mut ra, mut rb) = (self.find(a), self.find(b));
+
+        // p(rb) - p(ra) = d + p(a) - p(b)
+        let mut w = G::op(&G::op(&d, &pa), &G::inv(&pb));
+
+        if ra == rb {
+            return w == G::E;
+        }
+        if self.data[ra] > self.data[rb] {
+            (ra, rb) = (rb, ra);
+            w = G::inv(&w);
+        }
+
+        self.data[ra] += self.data[rb];
+        self.data[rb] = ra as i32;
+        self.potential[rb] = w;
+        true
+    }
+
+    /// $`p(b) - p(a)`$ を求める  
+    /// $`a`$ と $`b`$ が同じ集合に含まれていない場合は `None` を返す
+    pub fn diff(&mut self, a: usize, b: usize) -> Option<G::S> {
+        if !self.is_same(a, b) {
+            return None;
+        }
+
+        let (pa, pb) = (self.potential(a), self.potential(b));
+        Some(G::op(&pb, &G::inv(&pa)))
+    }
+
+    /// $`u`$ が含まれている集合と $`v`$ が含まれている集合が同じかどうかを検索する
+    pub fn is_same(&mut self, u: usize, v: usize) -> bool {
+        assert!(v < self.data.len() && u < self.data.len());
+        self.find(u) == self.find(v)
+    }
+
+    /// $`v`$ が含まれている集合の大きさを求める
+    pub fn size(&mut self, mut v: usize) -> i32 {
+        assert!(v < self.data.len());
+        v = self.find(v);
+        -self.data[v]
+    }
+}
+
+/// 部分永続 Union-Find
+///
+/// `unite` を呼ぶたびに時刻が $`1`$ 進み、過去の任意の時刻における連結性・集合の大きさを求められる。
+/// 時刻 $`t`$ の状態とは、`unite` を $`t`$ 回呼んだ直後の状態のことである。
+///
+/// ## Examples
+///
+/// ```
+/// use library::unionfind::PartiallyPersistentUnionFind;
+///
+/// let mut uf = PartiallyPersistentUnionFind::new(4);
+///
+/// uf.unite(0, 1); // 時刻 1
+/// uf.unite(2, 3); // 時刻 2
+/// uf.unite(1, 2); // 時刻 3
+///
+/// assert_eq!(uf.now(), 3);
+/// assert_eq!(uf.is_same(0, 3, 2), false);
+/// assert_eq!(uf.is_same(0, 3, 3), true);
+/// assert_eq!(uf.size(3, 1), 1);
+/// assert_eq!(uf.size(3, 2), 2);
+/// assert_eq!(uf.connected_time(0, 3), Some(3));
+/// assert_eq!(uf.connected_time(0, 0), Some(0));
+/// ```
+///
+/// ## 計算量
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `new(size)` | $`\{ 0 \}, \{ 1 \}, \dots, \{ \text{size} - 1 \}`$ で初期化する | $`O(\text{size})`$ |
+/// | `self.unite(a, b)` | 時刻を $`1`$ 進め、$`a`$ が含まれている集合と $`b`$ が含まれている集合を合併する | $`O(\log(\lvert \text{self} \rvert))`$ |
+/// | `self.is_same(u, v, t)` | 時刻 $`t`$ に $`u`$ と $`v`$ が同じ集合に含まれていたかを判定する | $`O(\log(\lvert \text{self} \rvert))`$ |
+/// | `self.size(v, t)` | 時刻 $`t`$ に $`v`$ が含まれていた集合の大きさを求める | $`O(\log(\lvert \text{self} \rvert))`$ |
+/// | `self.connected_time(u, v)` | $`u`$ と $`v`$ が初めて同じ集合に含まれた時刻を求める | $`O(\log(\lvert \text{self} \rvert))`$ |
+///
+pub struct PartiallyPersistentUnionFind {
+    parent: Vec<usize>,
+    /// 親を持った時刻 (根であれば `usize::MAX`)
+    time: Vec<usize>,
+    /// 根であるときの (時刻, 集合の大きさ) の履歴
+    history: Vec<Vec<(usize, usize)>>,
+    now: usize,
+}
+
+impl PartiallyPersistentUnionFind {
+    /// $`\{ 0 \}, \{ 1 \}, \dots, \{ \text{size} - 1 \}`$ で初期化する
+    pub fn new(size: usize) -> Self {
+        Self {
+            parent: (0..size).collect(),
+            time: vec![usize::MAX; size],
+            history: vec![vec![(0, 1)]; size],
+            now: 0,
+        }
+    }
+
+    /// 現在の時刻を返す
+    pub fn now(&self) -> usize {
+        self.now
+    }
+
+    /// 時刻を $`1`$ 進め、$`a`$ が含まれている集合と $`b`$ が含まれている集合を合併する
+    ///
+    /// 合併が行われたかを返す。
+    pub fn unite(&mut self, a: usize, b: usize) -> bool {
+        assert!(a < self.parent.len() && b < self.parent.len());
+        self.now += 1;
+
+        let mut a = self.find(a, self.now);
+        let mut b = self.find(b, self.now);
+        if a == b {
+            return false;
+        }
+
+        let sa = self.history[a].last().unwrap().1;
+        let sb = self.history[b].last().unwrap().1;
+        if sa < sb {
+            (a, b) = (b, a);
+        }
+
+        self.parent[b] = a;
+        self.time[b] = self.now;
+        self.history[a].push((self.now, sa + sb));
+        true
+    }
+
+    /// 時刻 $`t`$ に $`v`$ が含まれていた素集合の代表元を求める
+    pub fn find(&self, mut v: usize, t: usize) -> usize {
+        assert!(v < self.parent.len());
+        while self.time[v] <= t {
+            v = self.parent[v];
+        }
+        v
+    }
+
+    /// 時刻 $`t`$ に $`u`$ と $`v`$ が同じ集合に含まれていたかを判定する
+    pub fn is_same(&self, u: usize, v: usize, t: usize) -> bool {
+        self.find(u, t) == self.find(v, t)
+    }
+
+    /// 時刻 $`t`$ に $`v`$ が含まれていた集合の大きさを求める
+    pub fn size(&self, v: usize, t: usize) -> usize {
+        let r = self.find(v, t);
+        let i = self.history[r].partition_point(|&(s, _)| s <= t);
+        self.history[r][i - 1].1
+    }
+
+    /// $`u`$ と $`v`$ が初めて同じ集合に含まれた時刻を求める  
+    /// 現在の時刻でも同じ集合に含まれていない場合は `None` を返す
+    pub fn connected_time(&self, mut u: usize, mut v: usize) -> Option<usize> {
+        assert!(u < self.parent.len() && v < self.parent.len());
+
+        // 根へのパス上で時刻は単調増加なので、時刻の小さい方から辿ると最初に出会う点が合流点になる
+        let mut t = 0;
+        while u != v {
+            if self.time[u] < self.time[v] {
+                t = self.time[u];
+                u = self.parent[u];
+            } else if self.time[v] != usize::MAX {
+                t = self.time[v];
+                v = self.parent[v];
+            } else {
+                return None;
+            }
+        }
+        Some(t)
+    }
+}
+
+/// 各素集合にデータを持たせた Union-Find
+///
+/// 合併するときは、大きい方の素集合のデータに小さい方の素集合のデータを `merge` で併合する (マージテク)。
+/// 例えば、各素集合の要素の集合を `merge` で挿入していくと、挿入の回数は全体で $`O(n \log n)`$ 回になる。
+///
+/// ## Examples
+///
+/// ```
+/// use library::unionfind::UnionFindWithData;
+/// use std::collections::BTreeSet;
+///
+/// let colors = [3, 1, 3, 2];
+/// let mut uf = UnionFindWithData::new(
+///     colors.iter().map(|&c| BTreeSet::from([c])).collect(),
+///     |large: &mut BTreeSet<u32>, small: BTreeSet<u32>| large.extend(small),
+/// );
+///
+/// uf.unite(0, 2);
+/// assert_eq!(uf.data(2).len(), 1);
+///
+/// uf.unite(1, 2);
+/// assert_eq!(uf.data(0), &BTreeSet::from([1, 3]));
+/// assert_eq!(uf.data(3), &BTreeSet::from([2]));
+///
+/// uf.data_mut(3).insert(5);
+/// assert_eq!(uf.data(3).len(), 2);
+/// ```
+///
+/// ## 計算量
+///
+/// `merge` の計算量を除く。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `new(data, merge)` | $`\{ 0 \}, \{ 1 \}, \dots`$ の各素集合にデータ `data` を持たせて初期化する | $`O(\lvert \text{data} \rvert)`$ |
+/// | `self.unite(a, b)` | $`a`$ が含まれている集合と $`b`$ が含まれている集合を合併し、データを併合する | $`O(\alpha(\lvert \text{self} \rvert))`$ |
+/// | `self.is_same(u, v)` | $`u`$ が含まれている集合と $`v`$ が含まれている集合が同じかどうかを検索する | $`O(\alpha(\lvert \text{self} \rvert))`$ |
+/// | `self.size(v)` | $`v`$ が含まれている集合の大きさを求める | $`O(\alpha(\lvert \text{self} \rvert))`$ |
+/// | `self.data(v)` | $`v`$ が含まれている集合のデータを参照する | $`O(\alpha(\lvert \text{self} \rvert))`$ |
+///
+pub struct UnionFindWithData<D, F> {
+    uf: UnionFind,
+    data: Vec<Option<D>>,
+    merge: F,
+}
+
+impl<D, F: FnMut(&mut D, D)> UnionFindWithData<D, F> {
+    /// $`\{ 0 \}, \{ 1 \}, \dots, \{ \lvert \text{data} \rvert - 1 \}`$ で初期化し、$`\{ i \}`$ にデータ `data[i]` を持たせる
+    ///
+    /// `merge(large, small)` は、大きい方の素集合のデータ `large` に小さい方の素集合のデータ `small` を併合する。
+    pub fn new(data: Vec<D>, merge: F) -> Self {
+        Self {
+            uf: UnionFind::new(data.len()),
+            data: data.into_iter().map(Some).collect(),
+            merge,
+        }
+    }
+
+    /// $`a`$ が含まれている集合と $`b`$ が含まれている集合を合併し、データを併合する
+    ///
+    /// 合併が行われたかを返す。
+    pub fn unite(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.uf.find(a), self.uf.find(b));
+        if a == b {
+            return false;
+        }
+
+        self.uf.unite(a, b);
+        let (root, child) = if self.uf.find(a) == a { (a, b) } else { (b, a) };
+
+        let small = self.data[child].take().unwrap();
+        (self.merge)(self.data[root].as_mut().unwrap(), small);
+        true
+    }
+
+    /// $`u`$ が含まれている集合と $`v`$ が含まれている集合が同じかどうかを検索する
+    pub fn is_same(&mut self, u: usize, v: usize) -> bool {
+        self.uf.is_same(u, v)
+    }
+
+    /// $`v`$ が含まれている集合の大きさを求める
+    pub fn size(&mut self, v: usize) -> i32 {
+        self.uf.size(v)
+    }
+
+    /// $`v`$ が含まれる素集合の代表元を求める
+    pub fn find(&mut self, v: usize) -> usize {
+        self.uf.find(v)
+    }
+
+    /// $`v`$ が含まれている集合のデータを参照する
+    pub fn data(&mut self, v: usize) -> &D {
+        let r = self.uf.find(v);
+        self.data[r].as_ref().unwrap()
+    }
+
+    /// $`v`$ が含まれている集合のデータを可変参照する
+    pub fn data_mut(&mut self, v: usize) -> &mut D {
+        let r = self.uf.find(v);
+        self.data[r].as_mut().unwrap()
+    }
+}
@@ -0,0 +1,90 @@
+//! グラフ上の長さ $`k`$ のウォークの数を、$`\mathbb{Z} / P \mathbb{Z}`$ 上の行列累乗 ([`Matrix::pow()`]) で数える。
+//!
+//! 頂点 $`i`$ から頂点 $`j`$ への辺の本数を $`A_{i, j}`$ とすると、$`(A^k)_{i, j}`$ は $`i`$ から $`j`$ への長さ $`k`$ のウォーク(同じ頂点・辺を何度通ってもよい経路)の数である。
+//! 辺の重みは無視し、多重辺はそれぞれ別の辺として数える。
+//!
+//! * [`count_walks()`] は全点対について数える。
+//! * [`count_walks_from()`] は始点を固定して、各頂点への数を求める。
+//!
+//! ## Examples
+//!
+//! ```
+//! use library::graph::{DirectedAdjGraph, UndirectedAdjGraph};
+//! use library::modint::ModInt;
+//! use library::walk_count::{count_walks, count_walks_from};
+//!
+//! type Mint = ModInt<998244353>;
+//!
+//! // 三角形 0 - 1 - 2 - 0
+//! let graph = UndirectedAdjGraph::from_edges_no_weight(3, &[(0, 1), (1, 2), (2, 0)]);
+//!
+//! // 0 -> 1 -> 2 -> 0 と 0 -> 2 -> 1 -> 0
+//! assert_eq!(count_walks::<998244353, _>(&graph, 3)[0][0], Mint::from(2));
+//! assert_eq!(count_walks_from::<998244353, _>(&graph, 0, 3), [2, 3, 3].map(Mint::from));
+//!
+//! // 0 -> 0 の自己ループと 0 -> 1 の辺: 長さ 10^18 のウォークで 0 から 1 に行くものは 1 通り
+//! let graph = DirectedAdjGraph::from_edges_no_weight(2, &[(0, 0), (0, 1)]);
+//! let walks = count_walks::<998244353, _>(&graph, 1_000_000_000_000_000_000);
+//! assert_eq!(walks[0], [1, 1].map(Mint::from));
+//! ```
+//!
+//! ## 計算量
+//!
+//! $`N`$ を頂点数とする。
+//!
+//! | 関数 | 計算量 |
+//! | --- | --- |
+//! | [`count_walks()`] | $`O(N^3 \log k)`$ |
+//! | [`count_walks_from()`] | $`O(N^3 \log k)`$ |
+//!
+
+use crate::graph::Graph;
+use crate::matrix::Matrix;
+use crate::modint::ModInt;
+
+/// 全点対について、長さ $`k`$ のウォークの数を求める
+///
+/// 返り値の `[i][j]` が、頂点 $`i`$ から頂点 $`j`$ への長さ $`k`$ のウォークの数である。
+pub fn count_walks<const P: u32, W>(graph: &impl Graph<Weight = W>, k: u64) -> Vec<Vec<ModInt<P>>> {
+    adjacency_matrix(graph).pow(k).to_vec()
+}
+
+/// 頂点 `src` から各頂点への、長さ $`k`$ のウォークの数を求める
+pub fn count_walks_from<const P: u32, W>(
+    graph: &impl Graph<Weight = W>,
+    src: u32,
+    mut k: u64,
+) -> Vec<ModInt<P>> {
+    let n = graph.size() as usize;
+    let mut a = adjacency_matrix(graph);
+    let mut v = Matrix::new(1, n);
+    v[(0, src as usize)] = ModInt::from_raw(1);
+
+    // 行ベクトルに右から A^{2^i} を掛けていく
+    while k > 0 {
+        if k & 1 == 1 {
+            v = &v * &a;
+        }
+
+        k >>= 1;
+
+        if k > 0 {
+            a = &a * &a;
+        }
+    }
+
+    v.row(0).to_vec()
+}
+
+fn adjacency_matrix<const P: u32, W>(graph: &impl Graph<Weight = W>) -> Matrix<ModInt<P>> {
+    let n = graph.size() as usize;
+    let mut a = Matrix::new(n, n);
+
+    for u in 0..n {
+        for &(v, _) in graph.adjacent(u as u32) {
+            a[(u, v as usize)] += ModInt::from_raw(1);
+        }
+    }
+
+    a
+}
@@ -1,3 +1,5 @@
+use crate::binary_indexed_tree::BinaryIndexedTree;
+
 /// [`WaveletMatrix`] は事前にデータ構造を構築することで、区間内の $`k`$ 番目に小さい要素や、$`a`$ 以上 $`b`$ 以下の要素の数などを高速に求めることができる
 ///
 /// ## Examples
@@ -366,6 +368,223 @@ impl<T> std::fmt::Display for WaveletMatrix<T> {
     }
 }
 
+/// 一点更新ができる [`WaveletMatrix`]
+///
+/// 更新で現れる (位置, 値) の組をすべて先読みして、それらを並べた [`WaveletMatrix`] を構築する。
+/// 各段には [`BinaryIndexedTree`] を持たせて、それぞれの組が現在の列に含まれているかを管理する。
+///
+/// ## Examples
+///
+/// ```
+/// use library::wavelet_matrix::DynamicWaveletMatrix;
+///
+/// // 位置 1 の値を 7 に、位置 3 の値を 2 にする更新を先読みしておく
+/// let mut wm = DynamicWaveletMatrix::from(&[3, 1, 4, 1, 5], &[(1, 7), (3, 2)], 3);
+///
+/// assert_eq!(wm.quantile(0, 5, 1), 1);
+/// assert_eq!(wm.range_freq(0, 5, 4), 3);
+///
+/// wm.set(1, 7);
+/// assert_eq!(wm.access(1), 7);
+/// // [3, 7, 4, 1, 5] の区間 [0, 4) を整列して [1, 3, 4, 7]
+/// assert_eq!(wm.quantile(0, 4, 1), 3);
+/// assert_eq!(wm.quantile(0, 4, 3), 7);
+/// assert_eq!(wm.range_freq(0, 5, 4), 2);
+///
+/// wm.set(3, 2);
+/// assert_eq!(wm.quantile(0, 5, 0), 2);
+/// ```
+///
+/// ## 計算量
+///
+/// 先読みした組の数を $`M`$、値のビット数を $`H`$ とする。
+///
+/// | 関数 | 概要 | 計算量 |
+/// | --- | --- | --- |
+/// | `from(array, candidates, height)` | `array` と、更新で現れる (位置, 値) の組 `candidates` から構築する | $`O(M (\log M + H))`$ |
+/// | `self.set(i, v)` | $`i`$ 番目の要素を $`v`$ に更新する | $`O(\log M + H \log M)`$ |
+/// | `self.access(i)` | $`i`$ 番目の要素を返す | $`O(1)`$ |
+/// | `self.quantile(l, r, k)` | $`\lbrack l, r)`$ で $`k`$ 番目 (0-indexed) に小さい値を求める | $`O(\log M + H \log M)`$ |
+/// | `self.range_freq(l, r, upper)` | $`\lbrack l, r)`$ で `upper` 未満の要素の数を求める | $`O(\log M + H \log M)`$ |
+///
+pub struct DynamicWaveletMatrix {
+    points: Vec<(usize, u64)>,
+    current: Vec<usize>,
+    bvs: Vec<BitVector>,
+    zeros: Vec<usize>,
+    /// `bits[j + 1]` は $`j`$ 段目で分ける前、`bits[j]` は分けた後の並びについて、各組が含まれているかを持つ
+    bits: Vec<BinaryIndexedTree<i32>>,
+    height: usize,
+}
+
+impl DynamicWaveletMatrix {
+    /// 列 `array` と、更新で現れる (位置, 値) の組 `candidates` から構築する  
+    /// 値は $`2^{\text{height}}`$ 未満でなければならない
+    pub fn from(array: &[u64], candidates: &[(usize, u64)], height: usize) -> Self {
+        let mut points = array.iter().copied().enumerate().collect::<Vec<_>>();
+        points.extend_from_slice(candidates);
+        points.sort();
+        points.dedup();
+
+        let m = points.len();
+        let mut bvs = vec![];
+        let mut zeros = vec![];
+        let mut values = points.iter().map(|p| p.1).collect::<Vec<_>>();
+
+        for j in (0..height).rev() {
+            let mut bv = vec![0; (m + 63) / 64];
+            let (mut a0, mut a1) = (vec![], vec![]);
+
+            for (k, &v) in values.iter().enumerate() {
+                if (v >> j) & 1 == 1 {
+                    bv[k / 64] |= 1 << (k % 64);
+                    a1.push(v);
+                } else {
+                    a0.push(v);
+                }
+            }
+
+            bvs.push(BitVector::from(&bv));
+            zeros.push(a0.len());
+            a0.append(&mut a1);
+            values = a0;
+        }
+
+        bvs.reverse();
+        zeros.reverse();
+
+        let mut wm = Self {
+            points,
+            current: vec![usize::MAX; array.len()],
+            bvs,
+            zeros,
+            bits: (0..=height).map(|_| BinaryIndexedTree::new(m)).collect(),
+            height,
+        };
+
+        for (i, &a) in array.iter().enumerate() {
+            let k = wm.point_index(i, a);
+            wm.current[i] = k;
+            wm.toggle(k, 1);
+        }
+
+        wm
+    }
+
+    /// 列の長さを返す
+    pub fn len(&self) -> usize {
+        self.current.len()
+    }
+
+    /// 列が空かどうかを返す
+    pub fn is_empty(&self) -> bool {
+        self.current.is_empty()
+    }
+
+    /// $`i`$ 番目の要素を返す
+    pub fn access(&self, i: usize) -> u64 {
+        self.points[self.current[i]].1
+    }
+
+    /// $`i`$ 番目の要素を $`v`$ に更新する  
+    /// $`(i, v)`$ は構築時に先読みした組でなければならない
+    pub fn set(&mut self, i: usize, v: u64) {
+        let k = self.point_index(i, v);
+        self.toggle(self.current[i], -1);
+        self.current[i] = k;
+        self.toggle(k, 1);
+    }
+
+    /// $`\lbrack l, r)`$ で $`k`$ 番目 (0-indexed) に小さい値を求める
+    pub fn quantile(&self, l: usize, r: usize, mut k: usize) -> u64 {
+        let (mut a, mut b) = self.point_range(l, r);
+        assert!(k < Self::count(&self.bits[self.height], a, b));
+
+        let mut ret = 0;
+
+        for j in (0..self.height).rev() {
+            let (za, zb) = (self.bvs[j].zeros_before(a), self.bvs[j].zeros_before(b));
+            let cnt = Self::count(&self.bits[j], za, zb);
+
+            if k < cnt {
+                a = za;
+                b = zb;
+            } else {
+                k -= cnt;
+                ret |= 1 << j;
+                a = self.zeros[j] + a - za;
+                b = self.zeros[j] + b - zb;
+            }
+        }
+
+        ret
+    }
+
+    /// $`\lbrack l, r)`$ で `upper` 未満の要素の数を求める
+    pub fn range_freq(&self, l: usize, r: usize, upper: u64) -> usize {
+        let (mut a, mut b) = self.point_range(l, r);
+
+        if self.height < 64 && upper >> self.height != 0 {
+            return Self::count(&self.bits[self.height], a, b);
+        }
+
+        let mut ret = 0;
+
+        for j in (0..self.height).rev() {
+            let (za, zb) = (self.bvs[j].zeros_before(a), self.bvs[j].zeros_before(b));
+
+            if (upper >> j) & 1 == 1 {
+                ret += Self::count(&self.bits[j], za, zb);
+                a = self.zeros[j] + a - za;
+                b = self.zeros[j] + b - zb;
+            } else {
+                a = za;
+                b = zb;
+            }
+        }
+
+        ret
+    }
+
+    fn point_index(&self, i: usize, v: u64) -> usize {
+        self.points
+            .binary_search(&(i, v))
+            .expect("(i, v) is not in the candidates")
+    }
+
+    fn point_range(&self, l: usize, r: usize) -> (usize, usize) {
+        assert!(l <= r && r <= self.len());
+        (
+            self.points.partition_point(|p| p.0 < l),
+            self.points.partition_point(|p| p.0 < r),
+        )
+    }
+
+    fn toggle(&mut self, mut k: usize, d: i32) {
+        self.bits[self.height].add(k, d);
+
+        for j in (0..self.height).rev() {
+            let z = self.bvs[j].zeros_before(k);
+
+            k = if self.bvs[j].access(k) {
+                self.zeros[j] + k - z
+            } else {
+                z
+            };
+
+            self.bits[j].add(k, d);
+        }
+    }
+
+    fn count(bit: &BinaryIndexedTree<i32>, l: usize, r: usize) -> usize {
+        if l < r {
+            bit.sum(l..r) as usize
+        } else {
+            0
+        }
+    }
+}
+
 pub struct BitVector {
     row: Vec<u64>,
     cs: Vec<u32>,
@@ -412,6 +631,15 @@ impl BitVector {
         }
     }
 
+    /// i bit 目より前 (i bit 目は含まない) の 0 の数を数える
+    fn zeros_before(&self, i: usize) -> usize {
+        if i == 0 {
+            0
+        } else {
+            self.rank(i - 1, false) as usize
+        }
+    }
+
     /// [TODO] とりあえず使わないので後回しにする
     #[allow(unused)]
     fn select(&self, i: usize, b: bool) -> usize {
@@ -0,0 +1,135 @@
+use library::algebra::{MaxIndex, MinCount, MinIndex, SumCount};
+use library::segtree::SegmentTree;
+use library::sparse_table::SparseTable;
+use rand::Rng;
+
+#[test]
+fn test_001_min_max_index_random() {
+    let mut rng = rand::thread_rng();
+
+    for n in 1..50 {
+        let a = (0..n).map(|_| rng.gen_range(0..10)).collect::<Vec<i32>>();
+        let b = a.iter().copied().zip(0..).collect::<Vec<_>>();
+
+        let min_st: SparseTable<MinIndex<i32>> = SparseTable::from(&b);
+        let max_st: SparseTable<MaxIndex<i32>> = SparseTable::from(&b);
+        let min_seg: SegmentTree<MinIndex<i32>> = SegmentTree::from(&b);
+        let max_seg: SegmentTree<MaxIndex<i32>> = SegmentTree::from(&b);
+
+        for l in 0..n {
+            for r in l + 1..=n {
+                let min = *a[l..r].iter().min().unwrap();
+                let max = *a[l..r].iter().max().unwrap();
+                let argmin = (l..r).find(|&i| a[i] == min).unwrap();
+                let argmax = (l..r).find(|&i| a[i] == max).unwrap();
+
+                assert_eq!(min_st.prod(l..r), (min, argmin));
+                assert_eq!(max_st.prod(l..r), (max, argmax));
+                assert_eq!(min_seg.prod(l..r), (min, argmin));
+                assert_eq!(max_seg.prod(l..r), (max, argmax));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_002_min_count_sum_count_random() {
+    let mut rng = rand::thread_rng();
+
+    for n in 1..50 {
+        let a = (0..n).map(|_| rng.gen_range(0..5)).collect::<Vec<u64>>();
+        let b = a.iter().map(|&x| (x, 1)).collect::<Vec<_>>();
+
+        let min_seg: SegmentTree<MinCount<u64>> = SegmentTree::from(&b);
+        let sum_seg: SegmentTree<SumCount<u64>> = SegmentTree::from(&b);
+
+        for l in 0..n {
+            for r in l..=n {
+                let min = a[l..r].iter().min().copied().unwrap_or(u64::MAX);
+                let count = a[l..r].iter().filter(|&&x| x == min).count();
+
+                assert_eq!(min_seg.prod(l..r), (min, count));
+                assert_eq!(sum_seg.prod(l..r), (a[l..r].iter().sum(), r - l));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_003_modint_monoids() {
+    use library::algebra::{Add, Mul};
+    use library::modint::ModInt;
+
+    type Mint = ModInt<998244353>;
+
+    let a = (1..=20).map(Mint::from).collect::<Vec<_>>();
+    let sum: SegmentTree<Add<Mint>> = SegmentTree::from(&a);
+    let prod: SegmentTree<Mul<Mint>> = SegmentTree::from(&a);
+
+    assert_eq!(sum.prod(..), Mint::from(210));
+    assert_eq!(sum.prod(3..3), Mint::from(0));
+    assert_eq!(prod.prod(..5), Mint::from(120));
+    assert_eq!(
+        prod.prod(..),
+        (1..=20u64).fold(Mint::from(1), |acc, x| acc * x)
+    );
+}
+
+#[test]
+fn test_004_affine_random() {
+    use library::algebra::Affine;
+
+    let mut rng = rand::thread_rng();
+
+    for n in 0..30 {
+        let f = (0..n)
+            .map(|_| (rng.gen_range(-3..3), rng.gen_range(-3..3)))
+            .collect::<Vec<(i64, i64)>>();
+        let stree: SegmentTree<Affine<i64>> = SegmentTree::from(&f);
+
+        for l in 0..=n {
+            for r in l..=n {
+                let x = rng.gen_range(-10..10);
+                let expected = f[l..r].iter().fold(x, |x, &(a, b)| a * x + b);
+                let (a, b) = stree.prod(l..r);
+
+                assert_eq!(a * x + b, expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_005_semiring_axioms() {
+    use library::algebra::{MaxPlus, MinPlus, PlusTimes, Semiring};
+
+    fn check<R: Semiring>(values: &[R::S])
+    where
+        R::S: std::fmt::Debug,
+    {
+        for a in values {
+            assert_eq!(R::add(a, &R::ZERO), *a);
+            assert_eq!(R::mul(a, &R::ONE), *a);
+            assert_eq!(R::mul(&R::ONE, a), *a);
+            assert_eq!(R::mul(a, &R::ZERO), R::ZERO);
+            assert_eq!(R::mul(&R::ZERO, a), R::ZERO);
+
+            for b in values {
+                assert_eq!(R::add(a, b), R::add(b, a));
+
+                for c in values {
+                    assert_eq!(
+                        R::mul(a, &R::add(b, c)),
+                        R::add(&R::mul(a, b), &R::mul(a, c))
+                    );
+                    assert_eq!(R::mul(&R::mul(a, b), c), R::mul(a, &R::mul(b, c)));
+                }
+            }
+        }
+    }
+
+    let values = [-3, -1, 0, 2, 5];
+    check::<PlusTimes<i64>>(&values);
+    check::<MinPlus<i64>>(&[&values[..], &[i64::MAX]].concat());
+    check::<MaxPlus<i64>>(&[&values[..], &[i64::MIN]].concat());
+}
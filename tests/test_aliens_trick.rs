@@ -0,0 +1,36 @@
+use library::aliens_trick::aliens_trick;
+use library::monotone_minima::divide_and_conquer_dp;
+
+#[test]
+fn test_001_aliens_trick_partition() {
+    let a = [5i64, 2, 9, 1, 1, 7, 3, 8, 2, 6, 4, 4];
+    let n = a.len();
+    let mut s = vec![0];
+
+    for &x in a.iter() {
+        s.push(s.last().unwrap() + x);
+    }
+
+    let cost = |i: usize, j: usize| (s[j] - s[i]) * (s[j] - s[i]);
+
+    // 区間を 1 個作るごとにペナルティ λ を加えて、O(N^2) の DP を行う
+    let solve = |lambda: i64| {
+        let mut dp = vec![(0, 0); n + 1];
+
+        for j in 1..=n {
+            dp[j] = (0..j)
+                .map(|i| (dp[i].0 + cost(i, j) + lambda, dp[i].1 + 1))
+                .min()
+                .unwrap();
+        }
+
+        dp[n]
+    };
+
+    let expected = divide_and_conquer_dp(n, n, cost);
+    let m = s[n] * s[n] + 1;
+
+    for k in 1..=n {
+        assert_eq!(aliens_trick(k as i64, -m, m, solve), expected[k - 1]);
+    }
+}
@@ -0,0 +1,144 @@
+use library::arborescence::minimum_spanning_arborescence;
+use library::graph::DirectedAdjGraph;
+use rand::Rng;
+
+/// 各頂点に入る辺を全探索する
+fn naive(n: usize, root: usize, edges: &[(u32, u32, i64)]) -> Option<i64> {
+    let incoming = (0..n)
+        .map(|v| {
+            edges
+                .iter()
+                .filter(|e| e.1 as usize == v && e.0 != e.1)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut best = None;
+    let mut choice = vec![0; n];
+
+    loop {
+        let valid = (0..n).all(|v| v == root || choice[v] < incoming[v].len());
+
+        if valid {
+            // 全頂点から親を辿って根に到達できるか
+            let reaches_root = (0..n).all(|v| {
+                let mut u = v;
+                for _ in 0..n {
+                    if u == root {
+                        return true;
+                    }
+                    u = incoming[u][choice[u]].0 as usize;
+                }
+                u == root
+            });
+
+            if reaches_root {
+                let w = (0..n)
+                    .filter(|&v| v != root)
+                    .map(|v| incoming[v][choice[v]].2)
+                    .sum::<i64>();
+                best = Some(best.map_or(w, |b: i64| b.min(w)));
+            }
+        }
+
+        // 次の選び方に進む
+        let mut i = 0;
+        while i < n {
+            if i != root && choice[i] + 1 < incoming[i].len() {
+                choice[i] += 1;
+                break;
+            }
+            choice[i] = 0;
+            i += 1;
+        }
+        if i == n {
+            break;
+        }
+    }
+
+    best
+}
+
+#[test]
+fn test_001_random_naive() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(1..6);
+        let m = rng.gen_range(0..12);
+        let edges = (0..m)
+            .map(|_| {
+                (
+                    rng.gen_range(0..n as u32),
+                    rng.gen_range(0..n as u32),
+                    rng.gen_range(-5..10i64),
+                )
+            })
+            .collect::<Vec<_>>();
+        let root = rng.gen_range(0..n);
+
+        let graph = DirectedAdjGraph::from_edges(n as u32, &edges);
+        let res = minimum_spanning_arborescence(&graph, root as u32);
+        let expected = naive(n, root, &edges);
+
+        assert_eq!(res.as_ref().map(|r| r.0), expected);
+
+        if let Some((weight, parent)) = res {
+            assert_eq!(parent[root], u32::MAX);
+
+            let mut sum = 0;
+            for v in (0..n).filter(|&v| v != root) {
+                let p = parent[v];
+                sum += edges
+                    .iter()
+                    .filter(|e| e.0 == p && e.1 as usize == v)
+                    .map(|e| e.2)
+                    .min()
+                    .unwrap();
+
+                let mut u = v;
+                for _ in 0..n {
+                    if u != root {
+                        u = parent[u] as usize;
+                    }
+                }
+                assert_eq!(u, root);
+            }
+            assert_eq!(sum, weight);
+        }
+    }
+}
+
+#[test]
+fn test_002_unsigned() {
+    let graph = DirectedAdjGraph::from_edges(3, &[(0, 1, 5u32), (1, 2, 3), (0, 2, 10)]);
+    let (weight, parent) = minimum_spanning_arborescence(&graph, 0).unwrap();
+    assert_eq!(weight, 8);
+    assert_eq!(parent, [u32::MAX, 0, 1]);
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(1..6);
+        let m = rng.gen_range(0..12);
+        let edges = (0..m)
+            .map(|_| {
+                (
+                    rng.gen_range(0..n as u32),
+                    rng.gen_range(0..n as u32),
+                    rng.gen_range(0..10u32),
+                )
+            })
+            .collect::<Vec<_>>();
+        let root = rng.gen_range(0..n);
+
+        let graph = DirectedAdjGraph::from_edges(n as u32, &edges);
+        let res = minimum_spanning_arborescence(&graph, root as u32);
+        let signed = edges
+            .iter()
+            .map(|&(u, v, w)| (u, v, w as i64))
+            .collect::<Vec<_>>();
+
+        assert_eq!(res.map(|r| r.0 as i64), naive(n, root, &signed));
+    }
+}
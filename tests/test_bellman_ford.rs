@@ -0,0 +1,61 @@
+use library::bellman_ford::bellman_ford;
+use library::dijkstra::Dist;
+use library::graph::DirectedAdjGraph;
+
+#[test]
+fn test_001_random() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..8);
+        let m = rng.gen_range(0..15);
+        let edges = (0..m)
+            .map(|_| {
+                (
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(-3..10i64),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let graph = DirectedAdjGraph::from_edges(n, &edges);
+        let res = bellman_ford(&graph, 0);
+
+        // Floyd–Warshall で愚直に計算する
+        let n = n as usize;
+        let inf = i64::MAX / 4;
+        let mut d = vec![vec![inf; n]; n];
+        for (i, row) in d.iter_mut().enumerate() {
+            row[i] = 0;
+        }
+        for &(u, v, w) in &edges {
+            d[u as usize][v as usize] = d[u as usize][v as usize].min(w);
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    if d[i][k] < inf && d[k][j] < inf {
+                        d[i][j] = d[i][j].min(d[i][k] + d[k][j]);
+                    }
+                }
+            }
+        }
+
+        for v in 0..n {
+            let negative = (0..n).any(|c| d[0][c] < inf && d[c][c] < 0 && d[c][v] < inf);
+            assert_eq!(res.is_negative_infinity(v as u32), negative);
+
+            if !negative {
+                let expected = if d[0][v] < inf {
+                    Dist::VALUE(d[0][v])
+                } else {
+                    Dist::UNREACHABLE
+                };
+                assert_eq!(res.get(v as u32), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use library::bernoulli::sum_of_powers;
+use library::modint::ModInt;
+
+#[test]
+fn test_001_sum_of_powers_naive() {
+    type Mint = ModInt<998244353>;
+
+    for k in 0..20 {
+        let mut sum = Mint::from(0);
+
+        for n in 0..50u64 {
+            assert_eq!(sum_of_powers::<998244353>(n, k), sum);
+            sum += Mint::from(n + 1).pow(k as u32);
+        }
+    }
+}
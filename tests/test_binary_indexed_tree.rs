@@ -1,4 +1,6 @@
-use library::binary_indexed_tree::BinaryIndexedTree;
+use library::binary_indexed_tree::{
+    BinaryIndexedTree, GroupBinaryIndexedTree, RangeBinaryIndexedTree,
+};
 
 #[test]
 fn test_001_range() {
@@ -22,3 +24,123 @@ fn test_002_lower_bound() {
     assert_eq!(bit.upper_bound(123), 3);
     assert_eq!(bit.upper_bound(11), 2);
 }
+
+#[test]
+fn test_003_from_iter_extend() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+
+    for n in 0..50 {
+        let a = (0..n).map(|_| rng.gen_range(0..100)).collect::<Vec<u64>>();
+        let k = rng.gen_range(0..=n);
+
+        let mut bit: BinaryIndexedTree<u64> = a[..k].iter().copied().collect();
+        bit.extend(a[k..].iter().copied());
+        assert_eq!(bit, BinaryIndexedTree::from(&a));
+
+        for l in 0..n {
+            for r in l + 1..=n {
+                assert_eq!(bit.sum(l..r), a[l..r].iter().sum());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_004_range_add() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    let n = 30;
+
+    let mut a = (0..n)
+        .map(|_| rng.gen_range(-100..100))
+        .collect::<Vec<i64>>();
+    let mut bit = RangeBinaryIndexedTree::from(&a);
+
+    for _ in 0..200 {
+        let l = rng.gen_range(0..=n);
+        let r = rng.gen_range(l..=n);
+
+        if rng.gen_bool(0.5) {
+            let w = rng.gen_range(-100..100);
+            bit.add(l..r, w);
+            a[l..r].iter_mut().for_each(|x| *x += w);
+        } else {
+            assert_eq!(bit.sum(l..r), a[l..r].iter().sum());
+        }
+    }
+}
+
+#[test]
+fn test_005_modint() {
+    use library::modint::ModInt;
+
+    type Mint = ModInt<1000000007>;
+
+    let a = (1..=10u64)
+        .map(|x| Mint::from(x * 123456789))
+        .collect::<Vec<_>>();
+    let mut bit: BinaryIndexedTree<Mint> = a.iter().copied().collect();
+
+    for l in 0..a.len() {
+        for r in l + 1..=a.len() {
+            let expected = a[l..r].iter().fold(Mint::from(0u32), |s, &x| s + x);
+            assert_eq!(bit.sum(l..r), expected);
+        }
+    }
+
+    bit.add(3, Mint::from(1000000006u32));
+    assert_eq!(bit.sum(3..4), a[3] - Mint::from(1u32));
+}
+
+#[test]
+fn test_006_group() {
+    use library::algebra::{Add, BitXor};
+    use library::modint::ModInt;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    let n = 20;
+
+    let mut a = (0..n).map(|_| rng.gen::<u32>()).collect::<Vec<_>>();
+    let mut bit = GroupBinaryIndexedTree::<BitXor<u32>>::from(&a);
+
+    for _ in 0..100 {
+        let i = rng.gen_range(0..n);
+        let w = rng.gen::<u32>();
+        bit.add(i, w);
+        a[i] ^= w;
+
+        let l = rng.gen_range(0..=n);
+        let r = rng.gen_range(l..=n);
+        assert_eq!(bit.sum(l..r), a[l..r].iter().fold(0, |s, x| s ^ x));
+    }
+
+    type Mint = ModInt<998244353>;
+    let mut bit = GroupBinaryIndexedTree::<Add<Mint>>::new(5);
+    bit.add(2, Mint::from(998244352u32));
+    bit.add(4, Mint::from(3u32));
+    assert_eq!(bit.sum(..), Mint::from(2u32));
+    assert_eq!(bit.sum(3..), Mint::from(3u32));
+}
+
+#[test]
+fn test_007_partition_point() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+
+    for n in 0..30 {
+        let a = (0..n).map(|_| rng.gen_range(0..10)).collect::<Vec<u32>>();
+        let bit = BinaryIndexedTree::from(&a);
+
+        for w in 0..60 {
+            let expected = (0..n)
+                .find(|&i| a[..=i].iter().sum::<u32>() >= w)
+                .unwrap_or(n);
+            assert_eq!(bit.partition_point(|s| s < w), expected);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use library::binary_search::{binary_search, binary_search_f64};
+use library::coordinate_compression::CoordinateCompress;
+use rand::Rng;
+
+#[test]
+fn test_001_empty_range() {
+    let mut called = false;
+    assert_eq!(
+        binary_search(3usize, 4, |_| {
+            called = true;
+            true
+        }),
+        3
+    );
+    assert_eq!(
+        binary_search(4i64, 3, |_| {
+            called = true;
+            true
+        }),
+        4
+    );
+    assert_eq!(
+        binary_search(5u32, 5, |_| {
+            called = true;
+            true
+        }),
+        5
+    );
+    assert!(!called);
+
+    assert_eq!(binary_search_f64(1.0, 2.0, 0, |_| unreachable!()), 1.0);
+}
+
+#[test]
+fn test_002_all_true() {
+    assert_eq!(binary_search(0usize, 100, |_| true), 99);
+    assert_eq!(binary_search(100usize, 0, |_| true), 1);
+    assert_eq!(binary_search(-50i64, 50, |_| true), 49);
+    assert_eq!(binary_search(50i64, -50, |_| true), -49);
+
+    let x = binary_search_f64(0.0, 1.0, 100, |_| true);
+    assert!((x - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_003_all_false() {
+    assert_eq!(binary_search(0usize, 100, |_| false), 0);
+    assert_eq!(binary_search(100usize, 0, |_| false), 100);
+    assert_eq!(binary_search(-50i64, 50, |_| false), -50);
+    assert_eq!(binary_search(50i64, -50, |_| false), 50);
+
+    let x = binary_search_f64(0.0, 1.0, 100, |_| false);
+    assert_eq!(x, 0.0);
+}
+
+#[test]
+fn test_004_random_naive() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..1000 {
+        let lo = rng.gen_range(-100i64..100);
+        let hi = rng.gen_range(lo + 1..=lo + 100);
+        let border = rng.gen_range(lo..hi);
+
+        let mut calls = vec![];
+        let ok = binary_search(lo, hi, |x| {
+            calls.push(x);
+            x <= border
+        });
+        assert_eq!(ok, border);
+        assert!(calls.iter().all(|&x| lo < x && x < hi));
+
+        let border = rng.gen_range(lo + 1..=hi);
+        let ok = binary_search(hi, lo, |x| x >= border);
+        assert_eq!(ok, border);
+    }
+}
+
+#[test]
+fn test_005_coordinate_compress_random_naive() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let a = (0..rng.gen_range(0..20))
+            .map(|_| rng.gen_range(-30i32..30))
+            .collect::<Vec<_>>();
+        let cc = CoordinateCompress::from(&a);
+
+        let mut s = a.clone();
+        s.sort_unstable();
+        s.dedup();
+        assert_eq!(cc.len(), s.len());
+        for (i, &x) in s.iter().enumerate() {
+            assert_eq!(cc[i], x);
+        }
+
+        for v in -35..35 {
+            let next_index = s.iter().position(|&x| x >= v);
+            let prev_index = s.iter().rposition(|&x| x <= v);
+            let index = s.iter().position(|&x| x == v);
+
+            assert_eq!(cc.next_index(v), next_index);
+            assert_eq!(cc.next(v), next_index.map(|i| s[i]));
+            assert_eq!(cc.prev_index(v), prev_index);
+            assert_eq!(cc.prev(v), prev_index.map(|i| s[i]));
+            assert_eq!(cc.index(v), index);
+            assert_eq!(cc.contains(v), index.is_some());
+        }
+    }
+}
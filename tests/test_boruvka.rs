@@ -0,0 +1,51 @@
+use library::boruvka::boruvka;
+use library::unionfind::UnionFind;
+use rand::Rng;
+
+#[test]
+fn test_001_compare_with_kruskal() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(1..12);
+        let m = rng.gen_range(0..30);
+        let edges = (0..m)
+            .map(|_| {
+                (
+                    rng.gen_range(0..5u64),
+                    rng.gen_range(0..n as u32),
+                    rng.gen_range(0..n as u32),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let (weight, tree) = boruvka(n, |comp| {
+            let k = comp.iter().max().unwrap() + 1;
+            let mut best = vec![None; k];
+            for &(w, u, v) in &edges {
+                for (a, b) in [(u, v), (v, u)] {
+                    let c = comp[a as usize];
+                    if c != comp[b as usize] && best[c].map_or(true, |e| (w, a, b) < e) {
+                        best[c] = Some((w, a, b));
+                    }
+                }
+            }
+            best
+        });
+
+        let mut sorted = edges.clone();
+        sorted.sort();
+        let mut uf = UnionFind::new(n);
+        let mut expected = 0;
+        for &(w, u, v) in &sorted {
+            if !uf.is_same(u as usize, v as usize) {
+                uf.unite(u as usize, v as usize);
+                expected += w;
+            }
+        }
+
+        assert_eq!(weight, expected);
+        assert_eq!(tree.len(), n - uf.count());
+        assert_eq!(tree.iter().map(|e| e.2).sum::<u64>(), weight);
+    }
+}
@@ -0,0 +1,63 @@
+use library::clique::{max_clique, maximal_cliques};
+use library::graph::UndirectedAdjGraph;
+use rand::Rng;
+
+#[test]
+fn test_001_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(1..11u32);
+        let m = rng.gen_range(0..40);
+        let edges = (0..m)
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+            .collect::<Vec<_>>();
+
+        let graph = UndirectedAdjGraph::from_edges_no_weight(n, &edges);
+
+        let mut adj = vec![0u32; n as usize];
+        for &(u, v) in &edges {
+            if u != v {
+                adj[u as usize] |= 1 << v;
+                adj[v as usize] |= 1 << u;
+            }
+        }
+        let is_clique =
+            |s: u32| (0..n).all(|v| s >> v & 1 == 0 || s & !(1 << v) & !adj[v as usize] == 0);
+
+        // 愚直に極大クリークを列挙する
+        let mut expected = (0..1u32 << n)
+            .filter(|&s| is_clique(s) && (0..n).all(|v| s >> v & 1 == 1 || !is_clique(s | 1 << v)))
+            .collect::<Vec<_>>();
+        expected.sort();
+
+        let mut cliques = vec![];
+        maximal_cliques(&graph, |c| {
+            assert!(c.windows(2).all(|w| w[0] < w[1]));
+            cliques.push(c.iter().fold(0u32, |s, &v| s | 1 << v));
+        });
+        cliques.sort();
+        assert_eq!(cliques, expected);
+
+        let best = max_clique(&graph);
+        assert!(is_clique(best.iter().fold(0u32, |s, &v| s | 1 << v)));
+        assert_eq!(
+            best.len() as u32,
+            expected.iter().map(|s| s.count_ones()).max().unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_002_large() {
+    // 64 頂点を 4 つずつの組に分けた完全多部グラフ: 極大クリークは 4^16 個あるが、最大クリークは 16 頂点
+    let edges = (0..64u32)
+        .flat_map(|i| {
+            (i + 1..64)
+                .filter(move |j| i / 4 != j / 4)
+                .map(move |j| (i, j))
+        })
+        .collect::<Vec<_>>();
+    let graph = UndirectedAdjGraph::from_edges_no_weight(64, &edges);
+    assert_eq!(max_clique(&graph).len(), 16);
+}
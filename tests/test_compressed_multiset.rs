@@ -0,0 +1,55 @@
+use library::compressed_multiset::CompressedMultiset;
+use rand::Rng;
+
+#[test]
+fn test_001_empty() {
+    let set: CompressedMultiset<u32> = CompressedMultiset::from(&[]);
+
+    assert!(set.is_empty());
+    assert_eq!(set.count(0), 0);
+    assert_eq!(set.count_less(0), 0);
+    assert_eq!(set.kth(0), None);
+}
+
+#[test]
+fn test_002_random_naive() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let values = (0..rng.gen_range(1..30))
+            .map(|_| rng.gen_range(0..100))
+            .collect::<Vec<i32>>();
+        let mut set = CompressedMultiset::from(&values);
+        let mut a = vec![];
+
+        for _ in 0..300 {
+            match rng.gen_range(0..3) {
+                0 => {
+                    let x = values[rng.gen_range(0..values.len())];
+                    let i = a.partition_point(|&y| y < x);
+                    a.insert(i, x);
+                    set.insert(x);
+                }
+                1 => {
+                    let x = rng.gen_range(-10..110);
+                    let i = a.partition_point(|&y| y < x);
+                    let found = i < a.len() && a[i] == x;
+                    if found {
+                        a.remove(i);
+                    }
+                    assert_eq!(set.remove(x), found);
+                }
+                _ => {
+                    let x = rng.gen_range(-10..110);
+                    assert_eq!(set.count_less(x), a.partition_point(|&y| y < x));
+                    assert_eq!(set.count(x), a.iter().filter(|&&y| y == x).count());
+
+                    let k = rng.gen_range(0..=a.len());
+                    assert_eq!(set.kth(k), a.get(k).copied());
+                }
+            }
+
+            assert_eq!(set.len(), a.len());
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use library::convolution::convolution;
+use library::modint::ModInt;
+use rand::Rng;
+
+#[test]
+fn test_001_convolution_naive() {
+    type Mint = ModInt<998244353>;
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..20 {
+        let n = rng.gen_range(0..200usize);
+        let m = rng.gen_range(0..200);
+        let a = (0..n)
+            .map(|_| Mint::from(rng.gen_range(0..998244353u32)))
+            .collect::<Vec<_>>();
+        let b = (0..m)
+            .map(|_| Mint::from(rng.gen_range(0..998244353u32)))
+            .collect::<Vec<_>>();
+
+        let mut expected = if n == 0 || m == 0 {
+            vec![]
+        } else {
+            vec![Mint::from(0); n + m - 1]
+        };
+
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                expected[i + j] += x * y;
+            }
+        }
+
+        assert_eq!(convolution(&a, &b), expected);
+    }
+}
@@ -0,0 +1,14 @@
+use library::coordinate_compression::CoordinateCompress;
+
+#[test]
+fn test_001_prev_index_above_max() {
+    let cc = CoordinateCompress::from(&[30, 10, 20, 10]);
+
+    assert_eq!(cc.prev_index(30), Some(2));
+    assert_eq!(cc.prev_index(31), Some(2));
+    assert_eq!(cc.prev_index(1000), Some(2));
+    assert_eq!(cc.prev(1000), Some(30));
+
+    assert_eq!(cc.prev_index(9), None);
+    assert_eq!(cc.prev_index(15), Some(0));
+}
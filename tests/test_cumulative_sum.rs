@@ -14,3 +14,38 @@ fn test_001_range() {
     assert_eq!(cs.sum(..3), 111);
     assert_eq!(cs.sum(..), 11111);
 }
+
+#[test]
+fn test_002_from_iter_extend() {
+    let mut cs: CumulativeSum<i32> = (1..=3).collect();
+    cs.extend([10, 20]);
+
+    assert_eq!(cs.sum(..), 36);
+    assert_eq!(cs.sum(2..4), 13);
+    assert_eq!(cs.sum(4..), 20);
+}
+
+#[test]
+fn test_003_group() {
+    use library::algebra::{Add, BitXor};
+    use library::cumulative_sum::GroupCumulativeSum;
+    use library::modint::ModInt;
+
+    let a = [5, -2, 7, 0, -9, 4];
+    let cs = GroupCumulativeSum::<Add<i32>>::from(&a);
+    for l in 0..=a.len() {
+        for r in l..=a.len() {
+            assert_eq!(cs.sum(l..r), a[l..r].iter().sum::<i32>());
+        }
+    }
+
+    let b = [3u64, 14, 15, 92, 65];
+    let cs = GroupCumulativeSum::<BitXor<u64>>::from(&b);
+    assert_eq!(cs.sum(1..=3), 14 ^ 15 ^ 92);
+    assert_eq!(cs.sum(2..2), 0);
+
+    type Mint = ModInt<998244353>;
+    let c = [Mint::from(998244352u32), Mint::from(5u32), Mint::from(3u32)];
+    let cs = GroupCumulativeSum::<Add<Mint>>::from(&c);
+    assert_eq!(cs.sum(..2), Mint::from(4u32));
+}
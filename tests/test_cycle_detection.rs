@@ -12,3 +12,50 @@ fn test_001_cycle_detection_directed() {
     let has_cycle = cycle_detection(&graph);
     assert_eq!(has_cycle, true);
 }
+
+#[test]
+fn test_002_undirected_random() {
+    use library::unionfind::UnionFind;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..500 {
+        let n = rng.gen_range(1..10);
+        let m = rng.gen_range(0..n + 2);
+        let edges = (0..m)
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+            .collect::<Vec<_>>();
+
+        let mut uf = UnionFind::new(n as usize);
+        let mut expected = false;
+        for &(u, v) in &edges {
+            if uf.is_same(u as usize, v as usize) {
+                expected = true;
+            }
+            uf.unite(u as usize, v as usize);
+        }
+
+        let graph = UndirectedAdjGraph::from_edges_no_weight(n, &edges);
+        assert_eq!(undirected_cycle_detection(&graph), expected);
+
+        if let Some(cycle) = find_undirected_cycle(&graph) {
+            let l = cycle.len();
+            let mut sorted = cycle.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(sorted.len(), l);
+
+            // 閉路の辺を 1 本ずつ使う
+            let mut rest = edges.clone();
+            for i in 0..l {
+                let (a, b) = (cycle[i], cycle[(i + 1) % l]);
+                let pos = rest
+                    .iter()
+                    .position(|&(u, v)| (u, v) == (a, b) || (u, v) == (b, a))
+                    .unwrap();
+                rest.swap_remove(pos);
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use library::dag::{count_dag_paths, dag_longest_path, topological_sort};
+use library::graph::DirectedAdjGraph;
+use rand::Rng;
+
+fn random_dag(rng: &mut impl Rng, n: u32, m: usize) -> Vec<(u32, u32, i64)> {
+    // 頂点番号をランダムに並べ替え、その順に辺を張る
+    let mut perm = (0..n).collect::<Vec<_>>();
+    for i in (1..n as usize).rev() {
+        perm.swap(i, rng.gen_range(0..=i));
+    }
+
+    (0..m)
+        .filter_map(|_| {
+            let a = rng.gen_range(0..n) as usize;
+            let b = rng.gen_range(0..n) as usize;
+            if a < b {
+                Some((perm[a], perm[b], rng.gen_range(-5..10)))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_001_topological_sort() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..10);
+        let m = rng.gen_range(0..30);
+        let edges = random_dag(&mut rng, n, m);
+        let graph = DirectedAdjGraph::from_edges(n, &edges);
+
+        let order = topological_sort(&graph).unwrap();
+        let mut pos = vec![usize::MAX; n as usize];
+        for (i, &v) in order.iter().enumerate() {
+            pos[v as usize] = i;
+        }
+        assert!(pos.iter().all(|&p| p != usize::MAX));
+        for &(u, v, _) in &edges {
+            assert!(pos[u as usize] < pos[v as usize]);
+        }
+    }
+
+    let graph = DirectedAdjGraph::from_edges_no_weight(3, &[(0, 1), (1, 2), (2, 1)]);
+    assert_eq!(topological_sort(&graph), None);
+
+    let graph = DirectedAdjGraph::from_edges_no_weight(2, &[(1, 1)]);
+    assert_eq!(topological_sort(&graph), None);
+}
+
+#[test]
+fn test_002_longest_path() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..8);
+        let m = rng.gen_range(0..20);
+        let edges = random_dag(&mut rng, n, m);
+        let graph = DirectedAdjGraph::from_edges(n, &edges);
+
+        let (len, path) = dag_longest_path(&graph).unwrap();
+
+        // パスが実在し、重みの和が len に一致することを確認する
+        assert!(!path.is_empty());
+        let mut sum = 0;
+        for p in path.windows(2) {
+            sum += graph
+                .adjacent(p[0])
+                .iter()
+                .filter(|&&(v, _)| v == p[1])
+                .map(|&(_, w)| w)
+                .max()
+                .unwrap();
+        }
+        assert_eq!(sum, len);
+
+        // DFS で全パスを列挙して最大値を求める
+        fn dfs(graph: &DirectedAdjGraph<i64>, v: u32) -> i64 {
+            graph
+                .adjacent(v)
+                .iter()
+                .map(|&(u, w)| w + dfs(graph, u))
+                .max()
+                .unwrap_or(0)
+                .max(0)
+        }
+        let expected = (0..n).map(|v| dfs(&graph, v)).max().unwrap();
+        assert_eq!(len, expected);
+    }
+
+    let graph = DirectedAdjGraph::<i64>::new(0);
+    assert_eq!(dag_longest_path(&graph), Some((0, vec![])));
+}
+
+#[test]
+fn test_003_count_paths() {
+    use library::modint::ModInt;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..8);
+        let m = rng.gen_range(0..20);
+        let edges = random_dag(&mut rng, n, m);
+        let graph = DirectedAdjGraph::from_edges(n, &edges);
+        let src = rng.gen_range(0..n);
+
+        let count = count_dag_paths::<u64>(&graph, src).unwrap();
+        let count_mod = count_dag_paths::<ModInt<7>>(&graph, src).unwrap();
+
+        // DFS で全パスを列挙して数える
+        fn dfs(graph: &DirectedAdjGraph<i64>, v: u32, cnt: &mut [u64]) {
+            cnt[v as usize] += 1;
+            for &(u, _) in graph.adjacent(v) {
+                dfs(graph, u, cnt);
+            }
+        }
+        let mut expected = vec![0; n as usize];
+        dfs(&graph, src, &mut expected);
+
+        assert_eq!(count, expected);
+        assert_eq!(
+            count_mod,
+            expected
+                .iter()
+                .map(|&c| ModInt::from(c))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    let graph = DirectedAdjGraph::from_edges_no_weight(3, &[(0, 1), (1, 2), (2, 1)]);
+    assert_eq!(count_dag_paths::<u64>(&graph, 0), None);
+}
@@ -0,0 +1,166 @@
+use library::dijkstra::dijkstras_algorithm;
+use library::graph::DirectedAdjGraph;
+use library::naive;
+use rand::Rng;
+
+#[test]
+fn test_001_dijkstra_random_naive() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(1..8);
+        let edges = (0..rng.gen_range(0..20))
+            .map(|_| {
+                (
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..10u32),
+                )
+            })
+            .collect::<Vec<_>>();
+        let graph = DirectedAdjGraph::from_edges(n, &edges);
+
+        for src in 0..n {
+            let res = dijkstras_algorithm(&graph, src);
+
+            for dst in 0..n {
+                assert_eq!(res.get(dst), naive::shortest_path(&graph, src, dst));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_002_dijkstra_float_random_naive() {
+    use library::dijkstra::Dist;
+    use ordered_float::OrderedFloat;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(1..8);
+        let edges = (0..rng.gen_range(0..20))
+            .map(|_| {
+                (
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..10u32),
+                )
+            })
+            .collect::<Vec<_>>();
+        let float_edges = edges
+            .iter()
+            .map(|&(u, v, w)| (u, v, OrderedFloat(w as f64 / 4.0)))
+            .collect::<Vec<_>>();
+
+        let graph = DirectedAdjGraph::from_edges(n, &edges);
+        let float_graph = DirectedAdjGraph::from_edges(n, &float_edges);
+
+        for src in 0..n {
+            let res = dijkstras_algorithm(&float_graph, src);
+
+            for dst in 0..n {
+                let expected = match naive::shortest_path(&graph, src, dst) {
+                    Dist::VALUE(d) => Dist::VALUE(OrderedFloat(d as f64 / 4.0)),
+                    Dist::UNREACHABLE => Dist::UNREACHABLE,
+                };
+                assert_eq!(res.get(dst), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_003_dijkstra_no_overflow() {
+    use library::dijkstra::Dist;
+
+    let graph = DirectedAdjGraph::from_edges(4, &[(0, 1, 200u8), (1, 2, 100), (0, 3, 255)]);
+    let res = dijkstras_algorithm(&graph, 0);
+
+    assert_eq!(res.get(1), Dist::VALUE(200));
+    assert_eq!(res.get(2), Dist::UNREACHABLE);
+    assert_eq!(res.get(3), Dist::UNREACHABLE);
+}
+
+#[test]
+fn test_004_zero_one_bfs() {
+    use library::dijkstra::zero_one_bfs;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..10);
+        let m = rng.gen_range(0..25);
+        let edges = (0..m)
+            .map(|_| {
+                (
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..2u32),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let graph = DirectedAdjGraph::from_edges(n, &edges);
+        let src = rng.gen_range(0..n);
+
+        let dist = zero_one_bfs(&graph, src);
+        let expected = dijkstras_algorithm(&graph, src);
+
+        for v in 0..n {
+            assert_eq!(dist[v as usize], expected.get(v));
+        }
+    }
+}
+
+#[test]
+fn test_005_a_star_grid() {
+    use library::dijkstra::{a_star, Dist};
+    use library::graph::UndirectedAdjGraph;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let (h, w) = (rng.gen_range(1..7u32), rng.gen_range(1..7u32));
+        let mut edges = vec![];
+        for i in 0..h {
+            for j in 0..w {
+                if i + 1 < h && rng.gen_bool(0.8) {
+                    edges.push((i * w + j, (i + 1) * w + j, rng.gen_range(1..5u32)));
+                }
+                if j + 1 < w && rng.gen_bool(0.8) {
+                    edges.push((i * w + j, i * w + j + 1, rng.gen_range(1..5u32)));
+                }
+            }
+        }
+
+        let graph = UndirectedAdjGraph::from_edges(h * w, &edges);
+        let (src, dst) = (rng.gen_range(0..h * w), rng.gen_range(0..h * w));
+        let (ti, tj) = (dst / w, dst % w);
+
+        let (dist, path) = a_star(&graph, src, dst, |v| {
+            (v / w).abs_diff(ti) + (v % w).abs_diff(tj)
+        });
+        let expected = dijkstras_algorithm(&graph, src).get(dst);
+        assert_eq!(dist, expected);
+
+        if let Dist::VALUE(d) = dist {
+            assert_eq!(path[0], src);
+            assert_eq!(*path.last().unwrap(), dst);
+
+            let mut sum = 0;
+            for p in path.windows(2) {
+                sum += graph
+                    .adjacent(p[0])
+                    .iter()
+                    .filter(|e| e.0 == p[1])
+                    .map(|e| e.1)
+                    .min()
+                    .unwrap();
+            }
+            assert_eq!(sum, d);
+        } else {
+            assert!(path.is_empty());
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use library::dominator_tree::dominator_tree;
+use library::graph::DirectedAdjGraph;
+use rand::Rng;
+
+/// `removed` を通らずに `src` から到達できる頂点
+fn reachable(n: usize, edges: &[(u32, u32)], src: u32, removed: Option<u32>) -> Vec<bool> {
+    let mut seen = vec![false; n];
+    if Some(src) == removed {
+        return seen;
+    }
+
+    seen[src as usize] = true;
+    let mut stack = vec![src];
+    while let Some(v) = stack.pop() {
+        for &(a, b) in edges {
+            if a == v && Some(b) != removed && !seen[b as usize] {
+                seen[b as usize] = true;
+                stack.push(b);
+            }
+        }
+    }
+    seen
+}
+
+#[test]
+fn test_001_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(1..10u32);
+        let m = rng.gen_range(0..25);
+        let edges = (0..m)
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+            .collect::<Vec<_>>();
+        let src = rng.gen_range(0..n);
+
+        let graph = DirectedAdjGraph::from_edges_no_weight(n, &edges);
+        let idom = dominator_tree(&graph, src);
+
+        // dom[v]: v を支配する頂点 (v 自身を含む)
+        let n = n as usize;
+        let base = reachable(n, &edges, src, None);
+        let mut dom = vec![vec![]; n];
+        for d in 0..n as u32 {
+            let r = reachable(n, &edges, src, Some(d));
+            for v in 0..n {
+                if base[v] && !r[v] {
+                    dom[v].push(d);
+                }
+            }
+        }
+
+        for v in 0..n {
+            if !base[v] {
+                assert_eq!(idom[v], u32::MAX);
+            } else if v as u32 == src {
+                assert_eq!(idom[v], src);
+            } else {
+                // 直接支配頂点は、v 以外の支配頂点のうち支配頂点の数が最大のもの
+                let expected = dom[v]
+                    .iter()
+                    .filter(|&&d| d != v as u32)
+                    .max_by_key(|&&d| dom[d as usize].len())
+                    .unwrap();
+                assert_eq!(idom[v], *expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_002_long_path() {
+    let n = 200_000;
+    let mut edges = (1..n).map(|i| (i - 1, i)).collect::<Vec<_>>();
+    edges.push((0, n - 1));
+
+    let graph = DirectedAdjGraph::from_edges_no_weight(n, &edges);
+    let idom = dominator_tree(&graph, 0);
+
+    assert_eq!(idom[0], 0);
+    assert_eq!(idom[1], 0);
+    assert_eq!(idom[n as usize / 2], n / 2 - 1);
+    assert_eq!(idom[n as usize - 1], 0);
+}
@@ -0,0 +1,32 @@
+use library::dynamic_binary_indexed_tree::DynamicBinaryIndexedTree2D;
+
+#[test]
+fn test_001_2d_random() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    let (h, w) = (12, 9);
+
+    let mut a = vec![vec![0u64; w]; h];
+    let mut bit = DynamicBinaryIndexedTree2D::new(h, w);
+
+    for _ in 0..300 {
+        if rng.gen_bool(0.5) {
+            let (x, y) = (rng.gen_range(0..h), rng.gen_range(0..w));
+            let v = rng.gen_range(0..100);
+            bit.add(x, y, v);
+            a[x][y] += v;
+        } else {
+            let xl = rng.gen_range(0..=h);
+            let xr = rng.gen_range(xl..=h);
+            let yl = rng.gen_range(0..=w);
+            let yr = rng.gen_range(yl..=w);
+
+            let expected = a[xl..xr]
+                .iter()
+                .map(|row| row[yl..yr].iter().sum::<u64>())
+                .sum::<u64>();
+            assert_eq!(bit.sum(xl..xr, yl..yr), expected);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use library::edit_distance::{edit_distance, edit_distance_with_operations, EditOperation};
+use rand::Rng;
+
+fn random_string(rng: &mut impl Rng, max_len: usize) -> Vec<u8> {
+    (0..rng.gen_range(0..=max_len))
+        .map(|_| rng.gen_range(b'a'..=b'c'))
+        .collect()
+}
+
+fn edit_distance_naive(a: &[u8], b: &[u8]) -> usize {
+    let (n, m) = (a.len(), b.len());
+    let mut dp = vec![vec![usize::MAX; m + 1]; n + 1];
+
+    for i in 0..=n {
+        for j in 0..=m {
+            dp[i][j] = if i == 0 {
+                j
+            } else if j == 0 {
+                i
+            } else {
+                let cost = usize::from(a[i - 1] != b[j - 1]);
+                (dp[i - 1][j] + 1)
+                    .min(dp[i][j - 1] + 1)
+                    .min(dp[i - 1][j - 1] + cost)
+            };
+        }
+    }
+
+    dp[n][m]
+}
+
+fn check_operations(a: &[u8], b: &[u8], dist: usize, ops: &[EditOperation]) {
+    let mut c = vec![];
+    let (mut next_i, mut next_j) = (0, 0);
+    let mut cost = 0;
+
+    for &op in ops {
+        match op {
+            EditOperation::Keep(i, j) => {
+                assert_eq!((i, j), (next_i, next_j));
+                assert_eq!(a[i], b[j]);
+                c.push(a[i]);
+                next_i += 1;
+                next_j += 1;
+            }
+            EditOperation::Replace(i, j) => {
+                assert_eq!((i, j), (next_i, next_j));
+                c.push(b[j]);
+                next_i += 1;
+                next_j += 1;
+                cost += 1;
+            }
+            EditOperation::Insert(j) => {
+                assert_eq!(j, next_j);
+                c.push(b[j]);
+                next_j += 1;
+                cost += 1;
+            }
+            EditOperation::Delete(i) => {
+                assert_eq!(i, next_i);
+                next_i += 1;
+                cost += 1;
+            }
+        }
+    }
+
+    assert_eq!((next_i, next_j), (a.len(), b.len()));
+    assert_eq!(c, b);
+    assert_eq!(cost, dist);
+}
+
+#[test]
+fn test_001_empty() {
+    let empty: [u8; 0] = [];
+
+    assert_eq!(edit_distance(&empty, &empty), 0);
+    assert_eq!(edit_distance(b"abc", &empty), 3);
+    assert_eq!(edit_distance(&empty, b"ab"), 2);
+
+    assert_eq!(edit_distance_with_operations(&empty, &empty), (0, vec![]));
+    assert_eq!(
+        edit_distance_with_operations(b"ab", &empty),
+        (2, vec![EditOperation::Delete(0), EditOperation::Delete(1)])
+    );
+    assert_eq!(
+        edit_distance_with_operations(&empty, b"ab"),
+        (2, vec![EditOperation::Insert(0), EditOperation::Insert(1)])
+    );
+}
+
+#[test]
+fn test_002_random_naive() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..1000 {
+        let a = random_string(&mut rng, 8);
+        let b = random_string(&mut rng, 8);
+        let expected = edit_distance_naive(&a, &b);
+
+        assert_eq!(edit_distance(&a, &b), expected);
+
+        let (dist, ops) = edit_distance_with_operations(&a, &b);
+        assert_eq!(dist, expected);
+        check_operations(&a, &b, dist, &ops);
+    }
+}
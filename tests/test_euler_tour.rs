@@ -0,0 +1,42 @@
+use library::euler_tour::EulerTour;
+use library::graph::UndirectedAdjGraph;
+use library::naive;
+use library::testgen::random_tree;
+
+#[test]
+fn test_001_random_tree() {
+    let mut rng = rand::thread_rng();
+
+    for n in 1..30 {
+        let edges = random_tree(&mut rng, n);
+        let tree = UndirectedAdjGraph::from_edges_no_weight(n as u32, &edges);
+        let et = EulerTour::from(&tree, 0);
+
+        let parent = (0..n as u32).map(|v| et.parent(v)).collect::<Vec<_>>();
+        assert_eq!(et.tour().len(), 2 * n - 1);
+
+        for v in 0..n as u32 {
+            assert_eq!(et.order()[et.tin(v)], v);
+            assert_eq!(et.tour()[et.first(v)], v);
+            assert!(et.edge_in(v) < et.edge_out(v));
+
+            for u in 0..n as u32 {
+                // u が v の部分木に含まれるか
+                let in_subtree = naive::lca(&parent, u, v) == v;
+                assert_eq!(et.subtree(v).contains(&et.tin(u)), in_subtree);
+                assert_eq!(
+                    et.edge_in(v) <= et.edge_in(u) && et.edge_out(u) <= et.edge_out(v),
+                    in_subtree
+                );
+
+                let (l, r) = (et.first(u).min(et.first(v)), et.first(u).max(et.first(v)));
+                let m = (l..=r).min_by_key(|&i| et.depth_tour()[i]).unwrap();
+                assert_eq!(et.tour()[m], naive::lca(&parent, u, v));
+            }
+        }
+
+        for (i, &v) in et.tour().iter().enumerate() {
+            assert_eq!(et.depth_tour()[i], et.depth(v));
+        }
+    }
+}
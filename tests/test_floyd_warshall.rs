@@ -0,0 +1,69 @@
+use library::bellman_ford::bellman_ford;
+use library::floyd_warshall::floyd_warshall;
+use library::graph::DirectedAdjGraph;
+
+#[test]
+fn test_001_compare_with_bellman_ford() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..8);
+        let m = rng.gen_range(0..15);
+        let edges = (0..m)
+            .map(|_| {
+                (
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(-2..10i64),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let graph = DirectedAdjGraph::from_edges(n, &edges);
+        let res = floyd_warshall(&graph);
+
+        let bf = (0..n).map(|s| bellman_ford(&graph, s)).collect::<Vec<_>>();
+        let negative = bf.iter().any(|r| r.has_negative_cycle());
+        assert_eq!(res.has_negative_cycle(), negative);
+
+        if !negative {
+            for u in 0..n {
+                for v in 0..n {
+                    assert_eq!(res.get(u, v), bf[u as usize].get(v));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_002_add_edge() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let n = rng.gen_range(1..8);
+        let mut edges = vec![];
+        let mut res = floyd_warshall(&DirectedAdjGraph::from_edges(n, &edges));
+
+        for _ in 0..15 {
+            let e = (
+                rng.gen_range(0..n),
+                rng.gen_range(0..n),
+                rng.gen_range(0..10u64),
+            );
+            edges.push(e);
+            res.add_edge(e.0, e.1, e.2);
+
+            let expected = floyd_warshall(&DirectedAdjGraph::from_edges(n, &edges));
+            for u in 0..n {
+                for v in 0..n {
+                    assert_eq!(res.get(u, v), expected.get(u, v));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use library::algebra::Monoid;
+use library::foldable_deque::FoldableDeque;
+use rand::Rng;
+
+// 列の連結は可換でないモノイドである
+enum Concat {}
+impl Monoid for Concat {
+    type S = Vec<u32>;
+    fn op(lhs: &Vec<u32>, rhs: &Vec<u32>) -> Vec<u32> {
+        let mut ret = lhs.clone();
+        ret.extend_from_slice(rhs);
+        ret
+    }
+    const E: Vec<u32> = Vec::new();
+}
+
+#[test]
+fn test_001_foldable_deque_random() {
+    let mut rng = rand::thread_rng();
+    let mut deque: FoldableDeque<Concat> = FoldableDeque::new();
+    let mut naive = std::collections::VecDeque::new();
+
+    for _ in 0..3000 {
+        match rng.gen_range(0..4) {
+            0 => {
+                let x = rng.gen();
+                deque.push_front(vec![x]);
+                naive.push_front(x);
+            }
+            1 => {
+                let x = rng.gen();
+                deque.push_back(vec![x]);
+                naive.push_back(x);
+            }
+            2 => assert_eq!(deque.pop_front(), naive.pop_front().map(|x| vec![x])),
+            _ => assert_eq!(deque.pop_back(), naive.pop_back().map(|x| vec![x])),
+        }
+
+        assert_eq!(deque.len(), naive.len());
+        assert_eq!(deque.fold(), naive.iter().copied().collect::<Vec<_>>());
+        assert_eq!(deque.front(), naive.front().map(|&x| vec![x]).as_ref());
+        assert_eq!(deque.back(), naive.back().map(|&x| vec![x]).as_ref());
+    }
+}
@@ -0,0 +1,151 @@
+use library::convolution::convolution;
+use library::fps::{
+    berlekamp_massey, coefficient_of_rational, fps_inv, kth_term_of_linear_recurrence,
+};
+use library::modint::ModInt;
+use rand::Rng;
+
+#[test]
+fn test_001_fps_inv_random() {
+    type Mint = ModInt<998244353>;
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..20 {
+        let m = rng.gen_range(1..100);
+        let n = rng.gen_range(0..300);
+        let f = (0..m)
+            .map(|i| Mint::from(rng.gen_range((i == 0) as u32..998244353)))
+            .collect::<Vec<_>>();
+
+        let g = fps_inv(&f, n);
+        assert_eq!(g.len(), n);
+
+        let fg = convolution(&f, &g);
+
+        for (i, x) in fg.into_iter().take(n).enumerate() {
+            assert_eq!(x, Mint::from((i == 0) as u32));
+        }
+    }
+}
+
+#[test]
+fn test_002_coefficient_of_rational() {
+    type Mint = ModInt<998244353>;
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let p = (0..rng.gen_range(0..10))
+            .map(|_| Mint::from(rng.gen_range(0..998244353u32)))
+            .collect::<Vec<_>>();
+        let q = (0..rng.gen_range(1..10))
+            .map(|i| Mint::from(rng.gen_range((i == 0) as u32..998244353)))
+            .collect::<Vec<_>>();
+
+        let n = 200;
+        let f = convolution(&p, &fps_inv(&q, n));
+
+        for k in 0..n {
+            let expected = f.get(k).copied().unwrap_or(Mint::from(0));
+            assert_eq!(coefficient_of_rational(&p, &q, k as u64), expected);
+        }
+    }
+}
+
+#[test]
+fn test_003_kth_term_of_linear_recurrence() {
+    type Mint = ModInt<998244353>;
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let d = rng.gen_range(0..8);
+        let a = (0..d)
+            .map(|_| Mint::from(rng.gen_range(0..998244353u32)))
+            .collect::<Vec<_>>();
+        let c = (0..d)
+            .map(|_| Mint::from(rng.gen_range(0..998244353u32)))
+            .collect::<Vec<_>>();
+
+        let mut seq = a.clone();
+        for i in d..200 {
+            let x = (1..=d).fold(Mint::from(0), |acc, j| acc + c[j - 1] * seq[i - j]);
+            seq.push(x);
+        }
+
+        for (k, &x) in seq.iter().enumerate() {
+            assert_eq!(kth_term_of_linear_recurrence(&a, &c, k as u64), x);
+        }
+    }
+}
+
+#[test]
+fn test_004_berlekamp_massey() {
+    type Mint = ModInt<998244353>;
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        // 次数 d の漸化式から作った長さ 2d 以上の列からは、次数 d 以下の漸化式が復元される
+        let d = rng.gen_range(0..8);
+        let a = (0..d)
+            .map(|_| Mint::from(rng.gen_range(0..998244353u32)))
+            .collect::<Vec<_>>();
+        let c = (0..d)
+            .map(|_| Mint::from(rng.gen_range(0..998244353u32)))
+            .collect::<Vec<_>>();
+
+        let n = 2 * d + rng.gen_range(0..5);
+        let mut seq = a.clone();
+        for i in d..n {
+            let x = (1..=d).fold(Mint::from(0), |acc, j| acc + c[j - 1] * seq[i - j]);
+            seq.push(x);
+        }
+
+        let found = berlekamp_massey(&seq);
+        assert!(found.len() <= d);
+
+        for i in found.len()..n {
+            let x = (1..=found.len()).fold(Mint::from(0), |acc, j| acc + found[j - 1] * seq[i - j]);
+            assert_eq!(x, seq[i]);
+        }
+
+        for k in 0..100 {
+            assert_eq!(
+                kth_term_of_linear_recurrence(&seq[..found.len()], &found, k),
+                kth_term_of_linear_recurrence(&a, &c, k)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_005_berlekamp_massey_shortest() {
+    // 小さい素数で、全ての短い漸化式を試して最短であることを確かめる
+    type Mint = ModInt<3>;
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(0..8);
+        let seq = (0..n)
+            .map(|_| Mint::from(rng.gen_range(0..3u32)))
+            .collect::<Vec<_>>();
+
+        let found = berlekamp_massey(&seq);
+        let satisfies = |c: &[Mint]| {
+            (c.len()..n).all(|i| {
+                (1..=c.len()).fold(Mint::from(0), |acc, j| acc + c[j - 1] * seq[i - j]) == seq[i]
+            })
+        };
+        assert!(satisfies(&found));
+
+        let shortest = (0..=n)
+            .find(|&d| {
+                (0..3usize.pow(d as u32)).any(|mask| {
+                    let c = (0..d)
+                        .map(|j| Mint::from(mask / 3usize.pow(j as u32) % 3))
+                        .collect::<Vec<_>>();
+                    satisfies(&c)
+                })
+            })
+            .unwrap();
+        assert_eq!(found.len(), shortest);
+    }
+}
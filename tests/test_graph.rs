@@ -41,3 +41,72 @@ fn test_005_bfs() {
         vec![0, 1, 2, 3, 4]
     );
 }
+
+#[test]
+fn test_006_reverse() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let n = rng.gen_range(1..10u32);
+        let edges = (0..rng.gen_range(0..30))
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n), rng.gen::<u16>()))
+            .collect::<Vec<_>>();
+
+        let graph = DirectedAdjGraph::from_edges(n, &edges);
+        let rev = graph.reverse();
+        let rev_crs = DirectedAdjGraph::from_edges(n, &edges).to_crs().reverse();
+
+        let reversed = edges.iter().map(|&(u, v, w)| (v, u, w)).collect::<Vec<_>>();
+        let expected = DirectedAdjGraph::from_edges(n, &reversed);
+
+        for v in 0..n {
+            let mut a = rev.adjacent(v).to_vec();
+            let mut b = rev_crs.adjacent(v).to_vec();
+            let mut c = expected.adjacent(v).to_vec();
+            a.sort();
+            b.sort();
+            c.sort();
+            assert_eq!(a, c);
+            assert_eq!(b, c);
+        }
+    }
+}
+
+#[test]
+fn test_007_edges() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let n = rng.gen_range(1..8u32);
+        let mut edges = (0..rng.gen_range(0..20))
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n), rng.gen::<u8>()))
+            .collect::<Vec<_>>();
+
+        let directed = DirectedAdjGraph::from_edges(n, &edges);
+        let undirected = UndirectedAdjGraph::from_edges(n, &edges);
+
+        let mut a = directed
+            .edges()
+            .map(|(u, v, &w)| (u, v, w))
+            .collect::<Vec<_>>();
+        a.sort();
+        edges.sort();
+        assert_eq!(a, edges);
+
+        let mut b = undirected
+            .edges()
+            .map(|(u, v, &w)| (u, v, w))
+            .collect::<Vec<_>>();
+        b.sort();
+        let mut normalized = edges
+            .iter()
+            .map(|&(u, v, w)| (u.min(v), u.max(v), w))
+            .collect::<Vec<_>>();
+        normalized.sort();
+        assert_eq!(b, normalized);
+    }
+}
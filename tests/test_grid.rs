@@ -0,0 +1,41 @@
+use library::grid::{Grid, Neighborhood};
+use rand::Rng;
+
+#[test]
+fn test_001_edges() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let (h, w) = (rng.gen_range(1..6), rng.gen_range(1..6));
+        let ok = (0..h)
+            .map(|_| (0..w).map(|_| rng.gen_bool(0.7)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let grid = Grid::new(h, w);
+
+        for (nb, max_d) in [(Neighborhood::Four, 1), (Neighborhood::Eight, 2)] {
+            let graph = grid.build_graph(|i, j| ok[i][j], nb);
+
+            for u in 0..grid.size() as u32 {
+                let (i, j) = grid.position(u);
+                assert_eq!(grid.index(i, j), u);
+
+                let mut expected = (0..grid.size() as u32)
+                    .filter(|&v| {
+                        let (a, b) = grid.position(v);
+                        let (di, dj) = (i.abs_diff(a), j.abs_diff(b));
+                        ok[i][j]
+                            && ok[a][b]
+                            && di <= 1
+                            && dj <= 1
+                            && 0 < di + dj
+                            && di + dj <= max_d
+                    })
+                    .collect::<Vec<_>>();
+                let mut actual = graph.adjacent(u).iter().map(|e| e.0).collect::<Vec<_>>();
+                expected.sort();
+                actual.sort();
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+}
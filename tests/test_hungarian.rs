@@ -0,0 +1,36 @@
+use itertools::Itertools;
+use library::hungarian::assignment;
+use rand::Rng;
+
+#[test]
+fn test_001_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(1..6);
+        let m = rng.gen_range(n..7);
+        let cost = (0..n)
+            .map(|_| {
+                (0..m)
+                    .map(|_| rng.gen_range(-100..100i64))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let (total, assign) = assignment(&cost);
+
+        assert_eq!(assign.len(), n);
+        assert!(assign.iter().all_unique());
+        assert_eq!((0..n).map(|i| cost[i][assign[i]]).sum::<i64>(), total);
+
+        // 列の選び方を全通り試す
+        let expected = (0..m)
+            .permutations(n)
+            .map(|p| (0..n).map(|i| cost[i][p[i]]).sum::<i64>())
+            .min()
+            .unwrap();
+        assert_eq!(total, expected);
+    }
+
+    assert_eq!(assignment::<i64>(&[]), (0, vec![]));
+}
@@ -0,0 +1,38 @@
+use library::floyd_warshall::floyd_warshall;
+use library::graph::DirectedAdjGraph;
+use library::johnson::johnson;
+use rand::Rng;
+
+#[test]
+fn test_001_compare_with_floyd_warshall() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(1..8);
+        let m = rng.gen_range(0..15);
+        let edges = (0..m)
+            .map(|_| {
+                (
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(-2..10i64),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let graph = DirectedAdjGraph::from_edges(n, &edges);
+        let expected = floyd_warshall(&graph);
+
+        match johnson(&graph) {
+            None => assert!(expected.has_negative_cycle()),
+            Some(dist) => {
+                assert!(!expected.has_negative_cycle());
+                for u in 0..n {
+                    for v in 0..n {
+                        assert_eq!(dist[u as usize][v as usize], expected.get(u, v));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use library::graph::{DirectedAdjGraph, UndirectedAdjGraph};
+use library::k_shortest_paths::k_shortest_paths;
+use rand::Rng;
+
+/// 単純パスを全列挙する
+fn all_simple_paths(
+    n: usize,
+    edges: &[(u32, u32, u32)],
+    directed: bool,
+    src: u32,
+    dst: u32,
+) -> Vec<(u32, Vec<u32>)> {
+    let mut w = vec![vec![None; n]; n];
+    for &(u, v, c) in edges {
+        let mut arcs = vec![(u, v)];
+        if !directed {
+            arcs.push((v, u));
+        }
+        for (a, b) in arcs {
+            let e: &mut Option<u32> = &mut w[a as usize][b as usize];
+            *e = Some(e.map_or(c, |x| x.min(c)));
+        }
+    }
+
+    fn dfs(
+        w: &[Vec<Option<u32>>],
+        path: &mut Vec<u32>,
+        cost: u32,
+        dst: u32,
+        res: &mut Vec<(u32, Vec<u32>)>,
+    ) {
+        let v = *path.last().unwrap();
+        if v == dst {
+            res.push((cost, path.clone()));
+            return;
+        }
+        for u in 0..w.len() as u32 {
+            if let Some(c) = w[v as usize][u as usize] {
+                if !path.contains(&u) {
+                    path.push(u);
+                    dfs(w, path, cost + c, dst, res);
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    let mut res = vec![];
+    dfs(&w, &mut vec![src], 0, dst, &mut res);
+    res.sort();
+    res
+}
+
+#[test]
+fn test_001_random_naive() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..7);
+        let m = rng.gen_range(0..15);
+        let edges = (0..m)
+            .map(|_| {
+                (
+                    rng.gen_range(0..n as u32),
+                    rng.gen_range(0..n as u32),
+                    rng.gen_range(0..5u32),
+                )
+            })
+            .collect::<Vec<_>>();
+        let (src, dst) = (rng.gen_range(0..n as u32), rng.gen_range(0..n as u32));
+        let k = rng.gen_range(0..10);
+
+        for directed in [true, false] {
+            let res = if directed {
+                k_shortest_paths(&DirectedAdjGraph::from_edges(n as u32, &edges), src, dst, k)
+            } else {
+                k_shortest_paths(
+                    &UndirectedAdjGraph::from_edges(n as u32, &edges),
+                    src,
+                    dst,
+                    k,
+                )
+            };
+
+            let expected = all_simple_paths(n, &edges, directed, src, dst);
+            let expected_costs = expected.iter().take(k).map(|p| p.0).collect::<Vec<_>>();
+            assert_eq!(res.iter().map(|p| p.0).collect::<Vec<_>>(), expected_costs);
+
+            for (cost, path) in &res {
+                assert!(expected.contains(&(*cost, path.clone())));
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use library::kd_tree::KdTree;
+use rand::Rng;
+
+#[test]
+fn test_001_kd_tree_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let n = rng.gen_range(0..100);
+        let c = rng.gen_range(1..30);
+        let points = (0..n)
+            .map(|_| (rng.gen_range(-c..=c), rng.gen_range(-c..=c)))
+            .collect::<Vec<(i64, i64)>>();
+        let tree = KdTree::from(&points);
+        let dist = |p: (i64, i64), q: (i64, i64)| (p.0 - q.0).pow(2) + (p.1 - q.1).pow(2);
+
+        for _ in 0..50 {
+            let p = (rng.gen_range(-c..=c), rng.gen_range(-c..=c));
+            let expected = points.iter().map(|&q| dist(p, q)).min();
+
+            match tree.nearest(p) {
+                Some((i, d)) => {
+                    assert_eq!(Some(d), expected);
+                    assert_eq!(dist(p, points[i]), d);
+                }
+                None => assert_eq!(expected, None),
+            }
+
+            let (xl, xr) = (rng.gen_range(-c..=c), rng.gen_range(-c..=c));
+            let (yl, yr) = (rng.gen_range(-c..=c), rng.gen_range(-c..=c));
+            let mut inside = tree.range(xl, xr, yl, yr);
+            inside.sort();
+            let expected = (0..n)
+                .filter(|&i| {
+                    let (x, y) = points[i];
+                    xl <= x && x < xr && yl <= y && y < yr
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(inside, expected);
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use library::algebra::{
+    AddAction, AssignAction, Max, Min, MinCount, RangeAddRangeMax, RangeAddRangeMin,
+    RangeAddRangeSum, RangeAffineRangeSum, RangeAssignRangeSum,
+};
+use library::lazy_segtree::LazySegmentTree;
+use library::modint::ModInt;
+use rand::Rng;
+
+fn random_range(rng: &mut impl Rng, n: usize) -> (usize, usize) {
+    let l = rng.gen_range(0..=n);
+    (l, rng.gen_range(l..=n))
+}
+
+#[test]
+fn test_001_range_add_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(1..40);
+        let mut a = (0..n)
+            .map(|_| rng.gen_range(-100..100))
+            .collect::<Vec<i64>>();
+
+        let mut sum: LazySegmentTree<RangeAddRangeSum<i64>> =
+            LazySegmentTree::from(&a.iter().map(|&x| (x, 1)).collect::<Vec<_>>());
+        let mut min: LazySegmentTree<RangeAddRangeMin<i64>> = LazySegmentTree::from(&a);
+        let mut max: LazySegmentTree<RangeAddRangeMax<i64>> = LazySegmentTree::from(&a);
+
+        for _ in 0..100 {
+            let (l, r) = random_range(&mut rng, n);
+
+            match rng.gen_range(0..3) {
+                0 => {
+                    let x = rng.gen_range(-100..100);
+                    a[l..r].iter_mut().for_each(|v| *v += x);
+                    sum.apply(l..r, x);
+                    min.apply(l..r, x);
+                    max.apply(l..r, x);
+                }
+                1 => {
+                    let x = rng.gen_range(-100..100);
+                    a[l.min(n - 1)] = x;
+                    sum.insert(l.min(n - 1), (x, 1));
+                    min.insert(l.min(n - 1), x);
+                    max.insert(l.min(n - 1), x);
+                }
+                _ => {
+                    assert_eq!(sum.prod(l..r), (a[l..r].iter().sum(), r - l));
+                    assert_eq!(min.prod(l..r), *a[l..r].iter().min().unwrap_or(&i64::MAX));
+                    assert_eq!(max.prod(l..r), *a[l..r].iter().max().unwrap_or(&i64::MIN));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_002_range_assign_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(1..40);
+        let mut a = (0..n).map(|_| rng.gen_range(0..100)).collect::<Vec<u64>>();
+        let mut stree: LazySegmentTree<RangeAssignRangeSum<u64>> =
+            LazySegmentTree::from(&a.iter().map(|&x| (x, 1)).collect::<Vec<_>>());
+
+        for _ in 0..100 {
+            let (l, r) = random_range(&mut rng, n);
+
+            if rng.gen() {
+                let x = rng.gen_range(0..100);
+                a[l..r].iter_mut().for_each(|v| *v = x);
+                stree.apply(l..r, Some(x));
+            } else {
+                assert_eq!(stree.prod(l..r).0, a[l..r].iter().sum());
+            }
+        }
+
+        for (i, &x) in a.iter().enumerate() {
+            assert_eq!(stree.get(i), (x, 1));
+        }
+    }
+}
+
+#[test]
+fn test_003_range_affine_random() {
+    type Mint = ModInt<998244353>;
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(1..40);
+        let mut a = (0..n)
+            .map(|_| Mint::from(rng.gen::<u32>()))
+            .collect::<Vec<_>>();
+        let mut stree: LazySegmentTree<RangeAffineRangeSum<998244353>> =
+            LazySegmentTree::from(&a.iter().map(|&x| (x, 1)).collect::<Vec<_>>());
+
+        for _ in 0..100 {
+            let (l, r) = random_range(&mut rng, n);
+
+            if rng.gen() {
+                let (b, c) = (Mint::from(rng.gen::<u32>()), Mint::from(rng.gen::<u32>()));
+                a[l..r].iter_mut().for_each(|v| *v = b * *v + c);
+                stree.apply(l..r, (b, c));
+            } else {
+                let expected = a[l..r].iter().fold(Mint::from(0), |acc, &x| acc + x);
+                assert_eq!(stree.prod(l..r).0, expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_004_actions_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(1..40);
+        let mut a = (0..n).map(|_| rng.gen_range(-5..5)).collect::<Vec<i32>>();
+
+        let mut min_count: LazySegmentTree<AddAction<MinCount<i32>>> =
+            LazySegmentTree::from(&a.iter().map(|&x| (x, 1)).collect::<Vec<_>>());
+        let mut min: LazySegmentTree<AssignAction<Min<i32>>> = LazySegmentTree::from(&a);
+        let mut max: LazySegmentTree<AssignAction<Max<i32>>> = LazySegmentTree::from(&a);
+
+        for _ in 0..100 {
+            let (l, r) = random_range(&mut rng, n);
+            let x = rng.gen_range(-5..5);
+
+            match rng.gen_range(0..3) {
+                0 => {
+                    for (i, v) in a.iter_mut().enumerate().take(r).skip(l) {
+                        min_count.apply(i..=i, x - *v);
+                        *v = x;
+                    }
+                    min.apply(l..r, Some(x));
+                    max.apply(l..r, Some(x));
+                }
+                1 => {
+                    a[l..r].iter_mut().for_each(|v| *v += x);
+                    min_count.apply(l..r, x);
+                    for (i, &v) in a.iter().enumerate().take(r).skip(l) {
+                        min.insert(i, v);
+                        max.insert(i, v);
+                    }
+                }
+                _ => {
+                    let m = a[l..r].iter().min().copied().unwrap_or(i32::MAX);
+                    let count = a[l..r].iter().filter(|&&v| v == m).count();
+
+                    assert_eq!(min_count.prod(l..r), (m, count));
+                    assert_eq!(min.prod(l..r), m);
+                    assert_eq!(max.prod(l..r), *a[l..r].iter().max().unwrap_or(&i32::MIN));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_005_range_affine_integer_random() {
+    use library::algebra::{AffineAction, SumCount};
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(1..20);
+        let mut a = (0..n).map(|_| rng.gen_range(-3..3)).collect::<Vec<i64>>();
+        let mut stree: LazySegmentTree<AffineAction<SumCount<i64>>> =
+            LazySegmentTree::from(&a.iter().map(|&x| (x, 1)).collect::<Vec<_>>());
+
+        for _ in 0..20 {
+            let (l, r) = random_range(&mut rng, n);
+
+            if rng.gen() {
+                let (b, c) = (rng.gen_range(-2..3), rng.gen_range(-3..3));
+                a[l..r].iter_mut().for_each(|v| *v = b * *v + c);
+                stree.apply(l..r, (b, c));
+            } else {
+                assert_eq!(stree.prod(l..r), (a[l..r].iter().sum(), r - l));
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use library::li_chao_tree::LiChaoTree;
+use rand::Rng;
+
+#[test]
+fn test_001_empty() {
+    let mut lct = LiChaoTree::from(&[]);
+
+    lct.add_line(1, 2);
+    lct.add_segment(1, 2, -10, 10);
+}
+
+#[test]
+fn test_002_random_naive() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let xs = (0..rng.gen_range(1..30))
+            .map(|_| rng.gen_range(-20..20))
+            .collect::<Vec<i64>>();
+        let mut lct = LiChaoTree::from(&xs);
+        let mut segments = vec![];
+
+        for _ in 0..30 {
+            let (a, b) = (rng.gen_range(-10..10), rng.gen_range(-100..100));
+
+            if rng.gen() {
+                lct.add_line(a, b);
+                segments.push((a, b, i64::MIN, i64::MAX));
+            } else {
+                let l = rng.gen_range(-25..25);
+                let r = rng.gen_range(l..=25);
+                lct.add_segment(a, b, l, r);
+                segments.push((a, b, l, r));
+            }
+
+            for &x in &xs {
+                let expected = segments
+                    .iter()
+                    .filter(|&&(_, _, l, r)| l <= x && x < r)
+                    .map(|&(a, b, _, _)| a * x + b)
+                    .min();
+                assert_eq!(lct.min(x), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use library::longest_common_subsequence::longest_common_subsequence;
+use rand::Rng;
+
+fn random_string(rng: &mut impl Rng, max_len: usize) -> Vec<u8> {
+    (0..rng.gen_range(0..=max_len))
+        .map(|_| rng.gen_range(b'a'..=b'c'))
+        .collect()
+}
+
+fn lcs_length_naive(a: &[u8], b: &[u8]) -> usize {
+    let (n, m) = (a.len(), b.len());
+    let mut dp = vec![vec![0; m + 1]; n + 1];
+
+    for i in 1..=n {
+        for j in 1..=m {
+            dp[i][j] = if a[i - 1] == b[j - 1] {
+                dp[i - 1][j - 1] + 1
+            } else {
+                dp[i - 1][j].max(dp[i][j - 1])
+            };
+        }
+    }
+
+    dp[n][m]
+}
+
+#[test]
+fn test_001_empty() {
+    let empty: [u8; 0] = [];
+
+    assert!(longest_common_subsequence(&empty, &empty).is_empty());
+    assert!(longest_common_subsequence(b"abc", &empty).is_empty());
+    assert!(longest_common_subsequence(&empty, b"abc").is_empty());
+}
+
+#[test]
+fn test_002_random_naive() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..1000 {
+        let a = random_string(&mut rng, 8);
+        let b = random_string(&mut rng, 8);
+        let lcs = longest_common_subsequence(&a, &b);
+
+        assert_eq!(lcs.len(), lcs_length_naive(&a, &b));
+
+        for &(i, j) in lcs.iter() {
+            assert_eq!(a[i], b[j]);
+        }
+
+        for w in lcs.windows(2) {
+            assert!(w[0].0 < w[1].0 && w[0].1 < w[1].1);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use library::longest_increasing_subsequence::longest_increasing_subsequence;
+
+#[test]
+fn test_001_empty() {
+    let a: [u32; 0] = [];
+    assert!(longest_increasing_subsequence(&a, true).is_empty());
+    assert!(longest_increasing_subsequence(&a, false).is_empty());
+}
+
+#[test]
+fn test_002_brute_force() {
+    // 長さ 10 以下の列について、すべての部分列を調べた結果と長さが一致することを確認する
+    let a = [5u32, 2, 8, 6, 3, 6, 9, 7, 2, 5];
+
+    for n in 0..=a.len() {
+        let a = &a[..n];
+
+        for strict in [true, false] {
+            let mut best = 0;
+
+            for mask in 0..1u32 << n {
+                let b = (0..n)
+                    .filter(|&i| (mask >> i) & 1 == 1)
+                    .map(|i| a[i])
+                    .collect::<Vec<_>>();
+
+                if b.windows(2)
+                    .all(|w| if strict { w[0] < w[1] } else { w[0] <= w[1] })
+                {
+                    best = std::cmp::max(best, b.len());
+                }
+            }
+
+            let lis = longest_increasing_subsequence(a, strict);
+            assert_eq!(lis.len(), best);
+            assert!(lis.windows(2).all(|w| w[0] < w[1]
+                && if strict {
+                    a[w[0]] < a[w[1]]
+                } else {
+                    a[w[0]] <= a[w[1]]
+                }));
+        }
+    }
+}
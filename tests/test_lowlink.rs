@@ -9,3 +9,82 @@ fn test_001_lowlink_construct() {
 
     assert_eq!(lowlink.bridges(), &vec![(2, 3)]);
 }
+
+#[test]
+fn test_002_long_path() {
+    // 再帰で DFS するとスタックオーバーフローする長さのパス
+    let n = 200_000;
+    let edges = (0..n - 1).map(|i| (i, i + 1)).collect::<Vec<_>>();
+    let graph = UndirectedAdjGraph::from_edges_no_weight(n, &edges);
+
+    let lowlink = LowLink::from(&graph);
+
+    let mut bridges = lowlink.bridges().to_vec();
+    bridges.sort();
+    assert_eq!(bridges, edges);
+
+    let mut articulation_points = lowlink.articulation_points().to_vec();
+    articulation_points.sort();
+    assert_eq!(articulation_points, (1..n - 1).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_003_random() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..10u32);
+        let m = rng.gen_range(0..15);
+        let edges = (0..m)
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+            .filter(|&(u, v)| u != v)
+            .collect::<Vec<_>>();
+
+        // 連結成分の個数を数える
+        let components = |removed_vertex: Option<u32>, removed_edge: Option<usize>| {
+            let mut uf = library::unionfind::UnionFind::new(n as usize);
+            for (i, &(u, v)) in edges.iter().enumerate() {
+                if Some(i) == removed_edge || Some(u) == removed_vertex || Some(v) == removed_vertex
+                {
+                    continue;
+                }
+                uf.unite(u as usize, v as usize);
+            }
+            uf.count() - removed_vertex.is_some() as usize
+        };
+
+        let graph = UndirectedAdjGraph::from_edges_no_weight(n, &edges);
+        let lowlink = LowLink::from(&graph);
+
+        let base = components(None, None);
+
+        let expected = (0..n)
+            .filter(|&v| components(Some(v), None) > base)
+            .collect::<Vec<_>>();
+        let mut articulation_points = lowlink.articulation_points().to_vec();
+        articulation_points.sort();
+        assert_eq!(articulation_points, expected);
+
+        // 多重辺があると親への辺を区別できないので、橋は単純グラフのみ確認する
+        let mut simple = edges
+            .iter()
+            .map(|&(u, v)| (u.min(v), u.max(v)))
+            .collect::<Vec<_>>();
+        simple.sort();
+        simple.dedup();
+        if simple.len() != edges.len() {
+            continue;
+        }
+
+        let mut bridges = lowlink.bridges().to_vec();
+        bridges.sort();
+        let mut expected_bridges = (0..edges.len())
+            .filter(|&i| components(None, Some(i)) > base)
+            .map(|i| (edges[i].0.min(edges[i].1), edges[i].0.max(edges[i].1)))
+            .collect::<Vec<_>>();
+        expected_bridges.sort();
+        assert_eq!(bridges, expected_bridges);
+    }
+}
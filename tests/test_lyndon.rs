@@ -0,0 +1,44 @@
+use library::lyndon::{lyndon_factorization, minimal_rotation};
+use rand::Rng;
+
+fn is_lyndon(s: &[u8]) -> bool {
+    !s.is_empty() && (1..s.len()).all(|i| s < &s[i..])
+}
+
+#[test]
+fn test_001_lyndon_factorization() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..1000 {
+        let n = rng.gen_range(0..20);
+        let s = (0..n).map(|_| rng.gen_range(0..3u8)).collect::<Vec<_>>();
+        let a = lyndon_factorization(&s);
+
+        assert_eq!(a[0], 0);
+        assert_eq!(*a.last().unwrap(), n);
+
+        for i in 0..a.len() - 1 {
+            assert!(a[i] < a[i + 1]);
+            assert!(is_lyndon(&s[a[i]..a[i + 1]]));
+
+            if i + 2 < a.len() {
+                assert!(s[a[i]..a[i + 1]] >= s[a[i + 1]..a[i + 2]]);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_002_minimal_rotation() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..1000 {
+        let n = rng.gen_range(1..20);
+        let s = (0..n).map(|_| rng.gen_range(0..3u8)).collect::<Vec<_>>();
+
+        let rotate = |i: usize| [&s[i..], &s[..i]].concat();
+        let expected = (0..n).min_by_key(|&i| rotate(i)).unwrap();
+
+        assert_eq!(minimal_rotation(&s), expected);
+    }
+}
@@ -0,0 +1,266 @@
+use itertools::Itertools;
+use library::algebra::{MaxPlus, MinPlus};
+use library::matrix::{BitMatrix, Matrix};
+use library::modint::ModInt;
+use rand::Rng;
+
+type Mint = ModInt<998244353>;
+
+fn random_matrix(rng: &mut impl Rng, rows: usize, cols: usize) -> Matrix<Mint> {
+    let a = (0..rows)
+        .map(|_| {
+            (0..cols)
+                .map(|_| Mint::from(rng.gen_range(0..998244353u32)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    Matrix::from(&a)
+}
+
+#[test]
+fn test_001_mul() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let (n, m, l) = (
+            rng.gen_range(1..8),
+            rng.gen_range(1..8),
+            rng.gen_range(1..8),
+        );
+        let a = random_matrix(&mut rng, n, m);
+        let b = random_matrix(&mut rng, m, l);
+        let c = &a * &b;
+
+        assert_eq!((c.rows(), c.cols()), (n, l));
+        for i in 0..n {
+            for j in 0..l {
+                let expected = (0..m).fold(Mint::from(0), |acc, k| acc + a[(i, k)] * b[(k, j)]);
+                assert_eq!(c[(i, j)], expected);
+            }
+        }
+
+        // (AB)^T = B^T A^T
+        assert_eq!(c.transpose(), &b.transpose() * &a.transpose());
+
+        let v = (0..l)
+            .map(|_| Mint::from(rng.gen_range(0..998244353u32)))
+            .collect::<Vec<_>>();
+        assert_eq!(c.apply(&v), a.apply(&b.apply(&v)));
+
+        let d = random_matrix(&mut rng, n, l);
+        assert_eq!(&(&c + &d) - &d, c);
+    }
+}
+
+#[test]
+fn test_002_pow() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(0..6);
+        let a = random_matrix(&mut rng, n, n);
+
+        let mut expected = Matrix::identity(n);
+        for k in 0..20 {
+            assert_eq!(a.pow(k), expected);
+            expected = &expected * &a;
+        }
+    }
+}
+
+fn random_small_matrix<const P: u32>(
+    rng: &mut impl Rng,
+    rows: usize,
+    cols: usize,
+) -> Matrix<ModInt<P>> {
+    let a = (0..rows)
+        .map(|_| {
+            (0..cols)
+                .map(|_| ModInt::from(rng.gen_range(0..P)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    Matrix::from(&a)
+}
+
+#[test]
+fn test_003_det_inv() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(0..6);
+        let a = random_small_matrix::<5>(&mut rng, n, n);
+
+        // 置換の和として定義通りに計算する
+        let mut expected = ModInt::<5>::from(0);
+        for p in (0..n).permutations(n) {
+            let inversions = (0..n)
+                .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+                .filter(|&(i, j)| p[i] > p[j])
+                .count();
+            let prod = (0..n).fold(ModInt::from(1), |acc, i| acc * a[(i, p[i])]);
+
+            if inversions % 2 == 0 {
+                expected += prod;
+            } else {
+                expected -= prod;
+            }
+        }
+
+        assert_eq!(a.det(), expected);
+
+        match a.inv() {
+            Some(b) => {
+                assert_ne!(expected, ModInt::from(0));
+                assert_eq!(&a * &b, Matrix::identity(n));
+                assert_eq!(&b * &a, Matrix::identity(n));
+                assert_eq!(a.rank(), n);
+            }
+            None => {
+                assert_eq!(expected, ModInt::from(0));
+                assert!(a.rank() < n);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_004_solve() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let (n, m) = (rng.gen_range(1..5), rng.gen_range(0..5));
+        let a = random_small_matrix::<3>(&mut rng, n, m);
+        let b = (0..n)
+            .map(|_| ModInt::<3>::from(rng.gen_range(0..3u32)))
+            .collect::<Vec<_>>();
+
+        // 全ての x を試して解の個数を数える
+        let solutions = (0..3usize.pow(m as u32))
+            .filter(|&mask| {
+                let x = (0..m)
+                    .map(|j| ModInt::from(mask / 3usize.pow(j as u32) % 3))
+                    .collect::<Vec<_>>();
+                a.apply(&x) == b
+            })
+            .count();
+
+        match a.solve(&b) {
+            Some((x, basis)) => {
+                assert_eq!(a.apply(&x), b);
+                assert_eq!(solutions, 3usize.pow(basis.len() as u32));
+                assert_eq!(basis.len(), m - a.rank());
+
+                for y in &basis {
+                    assert!(a.apply(y).iter().all(|&v| v == ModInt::from(0)));
+                }
+                if !basis.is_empty() {
+                    assert_eq!(Matrix::from(&basis).rank(), basis.len());
+                }
+            }
+            None => {
+                assert_eq!(solutions, 0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_005_bit_matrix() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let (n, m) = (rng.gen_range(0..6), rng.gen_range(0..8));
+        let mut a = BitMatrix::new(n, m);
+        let mut c = Matrix::<ModInt<2>>::new(n, m);
+        for i in 0..n {
+            for j in 0..m {
+                let x = rng.gen_bool(0.5);
+                a.set(i, j, x);
+                c[(i, j)] = ModInt::from(x as u32);
+            }
+        }
+        let b = (0..n).map(|_| rng.gen_bool(0.5)).collect::<Vec<_>>();
+
+        assert_eq!(a.rank(), c.rank());
+
+        let solutions = (0..1usize << m)
+            .filter(|&mask| {
+                let x = (0..m).map(|j| mask >> j & 1 == 1).collect::<Vec<_>>();
+                a.apply(&x) == b
+            })
+            .count();
+
+        match a.solve(&b) {
+            Some((x, basis)) => {
+                assert_eq!(a.apply(&x), b);
+                assert_eq!(solutions, 1 << basis.len());
+
+                for y in &basis {
+                    assert!(a.apply(y).iter().all(|&v| !v));
+                }
+            }
+            None => {
+                assert_eq!(solutions, 0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_006_semiring_pow() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(0..6);
+        let a = (0..n)
+            .map(|_| {
+                (0..n)
+                    .map(|_| {
+                        if rng.gen_bool(0.3) {
+                            i64::MAX
+                        } else {
+                            rng.gen_range(-5..10)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let a = Matrix::from(&a);
+        let b = Matrix::from(
+            &a.to_vec()
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|&x| if x == i64::MAX { i64::MIN } else { x })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let mut expected_min = Matrix::semiring_identity::<MinPlus<i64>>(n);
+        let mut expected_max = Matrix::semiring_identity::<MaxPlus<i64>>(n);
+
+        for k in 0..10 {
+            assert_eq!(a.semiring_pow::<MinPlus<i64>>(k), expected_min);
+            assert_eq!(b.semiring_pow::<MaxPlus<i64>>(k), expected_max);
+
+            // 定義通りに計算した積と比べる
+            let mut next = Matrix::from(&vec![vec![i64::MAX; n]; n]);
+            for i in 0..n {
+                for j in 0..n {
+                    for l in 0..n {
+                        let (x, y) = (expected_min[(i, l)], a[(l, j)]);
+                        if x != i64::MAX && y != i64::MAX {
+                            next[(i, j)] = next[(i, j)].min(x + y);
+                        }
+                    }
+                }
+            }
+            assert_eq!(expected_min.semiring_mul::<MinPlus<i64>>(&a), next);
+
+            expected_min = next;
+            expected_max = expected_max.semiring_mul::<MaxPlus<i64>>(&b);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use library::max_flow::MaxFlow;
+use rand::Rng;
+
+#[test]
+fn test_001_compare_with_min_cut() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(2..8);
+        let m = rng.gen_range(0..20);
+        let edges = (0..m)
+            .map(|_| {
+                (
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..10i64),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut mf = MaxFlow::new(n);
+        for &(u, v, c) in &edges {
+            mf.add_edge(u, v, c);
+        }
+
+        let flow = mf.flow(0, n - 1);
+
+        // 全ての s-t カットを試す
+        let expected = (0..1usize << n)
+            .filter(|&s| s & 1 == 1 && s >> (n - 1) & 1 == 0)
+            .map(|s| {
+                edges
+                    .iter()
+                    .filter(|&&(u, v, _)| s >> u & 1 == 1 && s >> v & 1 == 0)
+                    .map(|e| e.2)
+                    .sum::<i64>()
+            })
+            .min()
+            .unwrap();
+        assert_eq!(flow, expected);
+
+        // 流量保存則と容量制約
+        let mut excess = vec![0; n];
+        for (i, &e) in edges.iter().enumerate() {
+            let (u, v, c, f) = mf.get_edge(i);
+            assert_eq!((u, v, c), e);
+            assert!(0 <= f && f <= c);
+            excess[u] -= f;
+            excess[v] += f;
+        }
+        assert_eq!(excess[0], -flow);
+        assert_eq!(excess[n - 1], flow);
+        assert!(excess[1..n - 1].iter().all(|&e| e == 0));
+
+        // 最小カットの容量が最大流に等しい
+        let cut = mf.min_cut(0);
+        assert!(cut[0] && !cut[n - 1]);
+        let cap = edges
+            .iter()
+            .filter(|&&(u, v, _)| cut[u] && !cut[v])
+            .map(|e| e.2)
+            .sum::<i64>();
+        assert_eq!(cap, flow);
+    }
+}
+
+#[test]
+fn test_002_long_path() {
+    // 再帰で DFS するとスタックオーバーフローする長さのパス
+    let n = 200_000;
+    let mut mf = MaxFlow::new(n);
+    let edges = (0..n - 1)
+        .map(|i| mf.add_edge(i, i + 1, 10 + (i as i64 * 7) % 13))
+        .collect::<Vec<_>>();
+
+    assert_eq!(mf.flow(0, n - 1), 10);
+
+    for e in edges {
+        assert_eq!(mf.get_edge(e).3, 10);
+    }
+
+    let cut = mf.min_cut(0);
+    assert_eq!(cut.iter().filter(|&&b| b).count(), 1);
+}
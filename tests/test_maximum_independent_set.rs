@@ -0,0 +1,50 @@
+use library::graph::UndirectedAdjGraph;
+use library::maximum_independent_set::maximum_independent_set;
+use rand::Rng;
+
+#[test]
+fn test_001_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(1..13u32);
+        let m = rng.gen_range(0..30);
+        let edges = (0..m)
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+            .collect::<Vec<_>>();
+
+        let graph = UndirectedAdjGraph::from_edges_no_weight(n, &edges);
+        let set = maximum_independent_set(&graph);
+
+        let is_independent = |s: u32| {
+            edges
+                .iter()
+                .all(|&(u, v)| !(s >> u & 1 == 1 && s >> v & 1 == 1))
+        };
+
+        let mask = set.iter().fold(0u32, |s, &v| s | 1 << v);
+        assert!(is_independent(mask));
+        assert!(set.windows(2).all(|w| w[0] < w[1]));
+
+        let expected = (0..1u32 << n)
+            .filter(|&s| is_independent(s))
+            .map(|s| s.count_ones())
+            .max()
+            .unwrap();
+        assert_eq!(set.len() as u32, expected);
+    }
+}
+
+#[test]
+fn test_002_large() {
+    // 完全二部グラフ K_{20, 20} と、長さ 64 の閉路
+    let edges = (0..20)
+        .flat_map(|i| (20..40).map(move |j| (i, j)))
+        .collect::<Vec<_>>();
+    let graph = UndirectedAdjGraph::from_edges_no_weight(40, &edges);
+    assert_eq!(maximum_independent_set(&graph).len(), 20);
+
+    let edges = (0..64).map(|i| (i, (i + 1) % 64)).collect::<Vec<_>>();
+    let graph = UndirectedAdjGraph::from_edges_no_weight(64, &edges);
+    assert_eq!(maximum_independent_set(&graph).len(), 32);
+}
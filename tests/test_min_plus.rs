@@ -0,0 +1,76 @@
+use library::dijkstra::Dist;
+use library::graph::DirectedAdjGraph;
+use library::min_plus::{shortest_paths_at_most_k_edges, shortest_paths_exact_k_edges};
+use rand::Rng;
+
+#[test]
+fn test_001_min_plus_naive_dp() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..30 {
+        let n = rng.gen_range(1..6);
+        let edges = (0..rng.gen_range(0..15))
+            .map(|_| {
+                (
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(-5..10i64),
+                )
+            })
+            .collect::<Vec<_>>();
+        let graph = DirectedAdjGraph::from_edges(n, &edges);
+
+        // dp[i][j] = ちょうど k 本の辺で i から j に行くときの最短距離
+        let n = n as usize;
+        let mut dp = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| if i == j { Some(0) } else { None })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut best = dp.clone();
+
+        let to_dist = |d: &Option<i64>| d.map_or(Dist::UNREACHABLE, Dist::VALUE);
+
+        for k in 0..8 {
+            for i in 0..n {
+                for j in 0..n {
+                    assert_eq!(
+                        shortest_paths_exact_k_edges(&graph, k)[i][j],
+                        to_dist(&dp[i][j])
+                    );
+                    assert_eq!(
+                        shortest_paths_at_most_k_edges(&graph, k)[i][j],
+                        to_dist(&best[i][j])
+                    );
+                }
+            }
+
+            let mut next = vec![vec![None; n]; n];
+
+            for (i, row) in dp.iter().enumerate() {
+                for (v, d) in row.iter().enumerate() {
+                    if let Some(d) = d {
+                        for &(s, t, w) in edges.iter() {
+                            if s as usize == v {
+                                let e: &mut Option<i64> = &mut next[i][t as usize];
+                                *e = Some(e.map_or(d + w, |e| std::cmp::min(e, d + w)));
+                            }
+                        }
+                    }
+                }
+            }
+
+            dp = next;
+
+            for i in 0..n {
+                for j in 0..n {
+                    if let Some(d) = dp[i][j] {
+                        best[i][j] = Some(best[i][j].map_or(d, |b: i64| std::cmp::min(b, d)));
+                    }
+                }
+            }
+        }
+    }
+}
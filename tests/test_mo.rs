@@ -0,0 +1,119 @@
+use library::mo::{mo, mo_with_updates, MoState, MoUpdateState};
+use rand::Rng;
+
+struct Distinct {
+    a: Vec<usize>,
+    updates: Vec<(usize, usize)>,
+    count: Vec<usize>,
+    kinds: usize,
+}
+
+impl MoState for Distinct {
+    type Output = usize;
+
+    fn add(&mut self, i: usize) {
+        self.count[self.a[i]] += 1;
+        if self.count[self.a[i]] == 1 {
+            self.kinds += 1;
+        }
+    }
+
+    fn remove(&mut self, i: usize) {
+        self.count[self.a[i]] -= 1;
+        if self.count[self.a[i]] == 0 {
+            self.kinds -= 1;
+        }
+    }
+
+    fn answer(&self) -> usize {
+        self.kinds
+    }
+}
+
+impl MoUpdateState for Distinct {
+    fn apply(&mut self, t: usize, left: usize, right: usize) {
+        let p = self.updates[t].0;
+        let inside = left <= p && p < right;
+
+        if inside {
+            self.remove(p);
+        }
+        std::mem::swap(&mut self.a[p], &mut self.updates[t].1);
+        if inside {
+            self.add(p);
+        }
+    }
+}
+
+fn distinct(a: &[usize]) -> usize {
+    a.iter().collect::<std::collections::HashSet<_>>().len()
+}
+
+#[test]
+fn test_001_mo_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(0..50);
+        let a = (0..n).map(|_| rng.gen_range(0..10)).collect::<Vec<usize>>();
+        let queries = (0..rng.gen_range(0..50))
+            .map(|_| {
+                let l = rng.gen_range(0..=n);
+                (l, rng.gen_range(l..=n))
+            })
+            .collect::<Vec<_>>();
+
+        let mut state = Distinct {
+            a: a.clone(),
+            updates: vec![],
+            count: vec![0; 10],
+            kinds: 0,
+        };
+        let expected = queries
+            .iter()
+            .map(|&(l, r)| distinct(&a[l..r]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(mo(&mut state, n, &queries), expected);
+    }
+}
+
+#[test]
+fn test_002_mo_with_updates_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(1..50);
+        let a = (0..n).map(|_| rng.gen_range(0..10)).collect::<Vec<usize>>();
+        let updates = (0..rng.gen_range(0..30))
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..10)))
+            .collect::<Vec<_>>();
+        let queries = (0..rng.gen_range(0..50))
+            .map(|_| {
+                let l = rng.gen_range(0..=n);
+                (l, rng.gen_range(l..=n), rng.gen_range(0..=updates.len()))
+            })
+            .collect::<Vec<_>>();
+
+        let mut versions = vec![a.clone()];
+        for &(p, x) in &updates {
+            let mut b = versions.last().unwrap().clone();
+            b[p] = x;
+            versions.push(b);
+        }
+
+        let expected = queries
+            .iter()
+            .map(|&(l, r, t)| distinct(&versions[t][l..r]))
+            .collect::<Vec<_>>();
+
+        let mut state = Distinct {
+            a,
+            updates,
+            count: vec![0; 10],
+            kinds: 0,
+        };
+
+        assert_eq!(mo_with_updates(&mut state, n, &queries), expected);
+    }
+}
@@ -0,0 +1,40 @@
+use library::modint::ModInt;
+
+type Mint = ModInt<998244353>;
+
+#[test]
+fn test_001_pow_large_exponent() {
+    let n: u64 = 1_000_000_000;
+    let e = n * (n - 1) / 2;
+    let a = Mint::from(3);
+
+    // フェルマーの小定理より、指数は P - 1 で割った余りにしてよい
+    assert_eq!(a.pow(e), a.pow(e % 998244352));
+    assert_eq!(a.pow(e as u128 * 998244352 + 5), a.pow(5u32));
+    assert_eq!(a.pow(0u64), Mint::from(1));
+}
+
+#[test]
+fn test_002_pow_signed() {
+    let a = Mint::from(12345);
+
+    assert_eq!(a.pow_signed(3), a * a * a);
+    assert_eq!(a.pow_signed(-3) * a.pow_signed(3), Mint::from(1));
+    assert_eq!(a.pow_signed(-1), a.inv());
+    assert_eq!(a.pow_signed(i64::MIN), a.inv().pow(1u64 << 63));
+}
+
+#[test]
+fn test_003_pow_literal_exponent() {
+    // 型を指定しない整数リテラルは i32 になる
+    assert_eq!(ModInt::<998244353>::from(3u32).pow(10), Mint::from(59049));
+    assert_eq!(Mint::from(2).pow(0), Mint::from(1));
+    assert_eq!(Mint::from(2).pow(10i64), Mint::from(1024));
+    assert_eq!(Mint::from(2).pow(10isize), Mint::from(1024));
+}
+
+#[test]
+#[should_panic]
+fn test_004_pow_negative_exponent() {
+    let _ = Mint::from(2).pow(-1);
+}
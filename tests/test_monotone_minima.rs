@@ -0,0 +1,78 @@
+use library::monotone_minima::*;
+
+#[test]
+fn test_001_monotone_minima_monge() {
+    // A[i][j] = (x_i - y_j)^2 は Monge なので monotone である
+    let x = [1i64, 4, 4, 7, 10, 15, 22];
+    let y = [0i64, 2, 3, 8, 9, 13, 20, 30];
+    let f = |i: usize, j: usize| (x[i] - y[j]) * (x[i] - y[j]);
+
+    let argmin = monotone_minima(x.len(), y.len(), f);
+
+    for (i, &a) in argmin.iter().enumerate() {
+        let expected = (0..y.len()).min_by_key(|&j| (f(i, j), j)).unwrap();
+        assert_eq!(a, expected);
+    }
+
+    assert_eq!(smawk(x.len(), y.len(), f), argmin);
+}
+
+#[test]
+fn test_002_divide_and_conquer_dp_naive() {
+    let a = [5i64, 2, 9, 1, 1, 7, 3, 8, 2, 6, 4, 4];
+    let n = a.len();
+    let mut s = vec![0];
+
+    for &x in a.iter() {
+        s.push(s.last().unwrap() + x);
+    }
+
+    let cost = |i: usize, j: usize| (s[j] - s[i]) * (s[j] - s[i]);
+
+    // O(kN^2) の愚直な DP と比較する
+    let mut naive = vec![];
+    let mut dp = (0..=n)
+        .map(|j| if j == 0 { None } else { Some(cost(0, j)) })
+        .collect::<Vec<_>>();
+    naive.push(dp[n].unwrap());
+
+    for _ in 2..=n {
+        let mut next = vec![None; n + 1];
+
+        for (j, nj) in next.iter_mut().enumerate() {
+            for (i, d) in dp.iter().enumerate().take(j) {
+                if let Some(d) = d {
+                    let v = d + cost(i, j);
+                    if nj.map_or(true, |w| v < w) {
+                        *nj = Some(v);
+                    }
+                }
+            }
+        }
+
+        dp = next;
+        naive.push(dp[n].unwrap());
+    }
+
+    assert_eq!(divide_and_conquer_dp(n, n, cost), naive);
+}
+
+#[test]
+fn test_003_smawk_random() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let h = rng.gen_range(0..20);
+        let w = rng.gen_range(0..20);
+        let mut x = (0..h).map(|_| rng.gen_range(0..30i64)).collect::<Vec<_>>();
+        let mut y = (0..w).map(|_| rng.gen_range(0..30i64)).collect::<Vec<_>>();
+        x.sort();
+        y.sort();
+
+        // 値の範囲を小さくして、同じ値を多く含む Monge な行列を作る
+        let f = |i: usize, j: usize| (x[i] - y[j]) * (x[i] - y[j]);
+
+        assert_eq!(smawk(h, w, f), monotone_minima(h, w, f));
+    }
+}
@@ -0,0 +1,35 @@
+use library::graph::*;
+use library::naive;
+use library::offline_lca::offline_lca;
+use rand::Rng;
+
+#[test]
+fn test_001_offline_lca_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let n = rng.gen_range(1..50);
+        let parent = (0..n)
+            .map(|v| {
+                if v == 0 {
+                    u32::MAX
+                } else {
+                    rng.gen_range(0..v)
+                }
+            })
+            .collect::<Vec<u32>>();
+        let edges = (1..n).map(|v| (v, parent[v as usize])).collect::<Vec<_>>();
+        let tree = UndirectedAdjGraph::from_edges_no_weight(n, &edges);
+
+        let queries = (0..100)
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+            .collect::<Vec<_>>();
+
+        let expected = queries
+            .iter()
+            .map(|&(u, v)| naive::lca(&parent, u, v))
+            .collect::<Vec<_>>();
+
+        assert_eq!(offline_lca(&tree, 0, &queries), expected);
+    }
+}
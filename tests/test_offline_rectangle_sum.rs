@@ -0,0 +1,77 @@
+use library::offline_rectangle_sum::OfflineRectangleSum;
+use rand::Rng;
+
+#[test]
+fn test_001_offline_rectangle_sum_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let mut rs = OfflineRectangleSum::new();
+        let mut points = vec![];
+        let mut expected = vec![];
+
+        for _ in 0..rng.gen_range(0..50) {
+            if rng.gen_bool(0.5) {
+                let (x, y, w) = (
+                    rng.gen_range(-5..5),
+                    rng.gen_range(-5..5),
+                    rng.gen_range(0..100),
+                );
+                rs.add_point(x, y, w);
+                points.push((x, y, w));
+            } else {
+                let (xl, yl) = (rng.gen_range(-6..6), rng.gen_range(-6..6));
+                let (xr, yr) = (rng.gen_range(xl..=6), rng.gen_range(yl..=6));
+                assert_eq!(rs.add_query(xl, xr, yl, yr), expected.len());
+                expected.push(
+                    points
+                        .iter()
+                        .filter(|&&(x, y, _)| xl <= x && x < xr && yl <= y && y < yr)
+                        .map(|p| p.2)
+                        .sum::<u64>(),
+                );
+            }
+        }
+
+        assert_eq!(rs.solve(), expected);
+    }
+}
+
+#[test]
+fn test_002_modint() {
+    use library::modint::ModInt;
+
+    type Mint = ModInt<998244353>;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let mut rs = OfflineRectangleSum::new();
+        let mut points = vec![];
+        let mut expected = vec![];
+
+        for _ in 0..rng.gen_range(0..50) {
+            if rng.gen_bool(0.5) {
+                let (x, y, w) = (
+                    rng.gen_range(-5..5),
+                    rng.gen_range(-5..5),
+                    Mint::from(rng.gen_range(0..998244353u32)),
+                );
+                rs.add_point(x, y, w);
+                points.push((x, y, w));
+            } else {
+                let (xl, yl) = (rng.gen_range(-6..6), rng.gen_range(-6..6));
+                let (xr, yr) = (rng.gen_range(xl..=6), rng.gen_range(yl..=6));
+                assert_eq!(rs.add_query(xl, xr, yl, yr), expected.len());
+                expected.push(
+                    points
+                        .iter()
+                        .filter(|&&(x, y, _)| xl <= x && x < xr && yl <= y && y < yr)
+                        .fold(Mint::from(0u32), |s, p| s + p.2),
+                );
+            }
+        }
+
+        assert_eq!(rs.solve(), expected);
+    }
+}
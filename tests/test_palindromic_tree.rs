@@ -0,0 +1,84 @@
+use library::palindromic_tree::PalindromicTree;
+use rand::Rng;
+use std::collections::BTreeMap;
+
+#[test]
+fn test_001_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(0..30);
+        let s = (0..n)
+            .map(|_| rng.gen_range(b'a'..=b'c'))
+            .collect::<Vec<_>>();
+
+        let mut tree = PalindromicTree::new();
+        for (i, &c) in s.iter().enumerate() {
+            let v = tree.push(c);
+            assert_eq!(v, tree.last());
+
+            // s[..=i] の回文である最長の接尾辞
+            let expected = (0..=i)
+                .find(|&l| s[l..=i].iter().eq(s[l..=i].iter().rev()))
+                .unwrap();
+            assert_eq!(tree.len(v), i + 1 - expected);
+        }
+
+        // 愚直に回文の出現回数を数える
+        let mut expected = BTreeMap::new();
+        for l in 0..n {
+            for r in l + 1..=n {
+                if s[l..r].iter().eq(s[l..r].iter().rev()) {
+                    *expected.entry(s[l..r].to_vec()).or_insert(0) += 1;
+                }
+            }
+        }
+
+        assert_eq!(tree.distinct_count(), expected.len());
+        assert_eq!(tree.node_count(), expected.len() + 2);
+
+        let count = tree.occurrences();
+        let mut actual = BTreeMap::new();
+        for (v, &c) in count.iter().enumerate().skip(2) {
+            let range = tree.first_occurrence(v);
+            assert_eq!(range.len(), tree.len(v));
+
+            let p = s[range].to_vec();
+            assert!(p.iter().eq(p.iter().rev()));
+
+            // suffix link の先は、真の接尾辞である最長の回文
+            let link = tree.suffix_link(v);
+            let expected_link = (1..p.len())
+                .find(|&l| p[l..].iter().eq(p[l..].iter().rev()))
+                .map_or(0, |l| p.len() - l);
+            assert_eq!(tree.len(link), expected_link);
+
+            actual.insert(p, c);
+        }
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn test_002_next() {
+    let mut tree = PalindromicTree::new();
+    for c in [1, 2, 1, 1, 2, 1] {
+        tree.push(c);
+    }
+
+    let one = tree.next(0, 1).unwrap();
+    let two = tree.next(0, 2).unwrap();
+    assert_eq!(tree.len(one), 1);
+    assert_eq!(tree.len(two), 1);
+
+    // 1 2 1 -> 1 1 2 1 1 は存在しない、 2 1 1 2 は存在する
+    let v = tree.next(two, 1).unwrap();
+    assert_eq!(tree.len(v), 3);
+    assert_eq!(tree.next(v, 1), None);
+
+    let w = tree.next(1, 1).unwrap();
+    let w = tree.next(w, 2).unwrap();
+    assert_eq!(tree.len(w), 4);
+    assert_eq!(tree.first_occurrence(w), 1..5);
+    assert_eq!(tree.occurrences()[w], 1);
+}
@@ -0,0 +1,36 @@
+use library::permutation::*;
+
+#[test]
+fn test_001_rank_matches_next_permutation() {
+    // 辞書順に列挙したときの順番と rank が一致することを確認する
+    let n = 6;
+    let mut a = (0..n).collect::<Vec<_>>();
+    let mut k = 0;
+
+    loop {
+        let p = Permutation::from(&a);
+        assert_eq!(p.rank(), k);
+        assert_eq!(Permutation::from_rank(n, k), p);
+
+        k += 1;
+
+        if !next_permutation(&mut a) {
+            break;
+        }
+    }
+
+    assert_eq!(k, 720);
+}
+
+#[test]
+fn test_002_prev_permutation_is_inverse_of_next() {
+    let mut a = [0, 1, 1, 2, 3];
+    let mut b = a;
+
+    while next_permutation(&mut a) {
+        assert!(prev_permutation(&mut a));
+        assert_eq!(a, b);
+        assert!(next_permutation(&mut a));
+        b = a;
+    }
+}
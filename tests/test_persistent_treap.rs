@@ -0,0 +1,59 @@
+use library::persistent_treap::PersistentTreap;
+use rand::Rng;
+
+#[test]
+fn test_001_persistent_treap_random() {
+    let mut rng = rand::thread_rng();
+
+    // 各バージョンについて、(木, 愚直に管理した列) を持つ
+    let mut versions = vec![(PersistentTreap::new(), vec![])];
+
+    for _ in 0..2000 {
+        let (tree, naive): (PersistentTreap<u32>, Vec<u32>) =
+            versions[rng.gen_range(0..versions.len())].clone();
+        let n = naive.len();
+
+        let next = match rng.gen_range(0..4) {
+            0 => {
+                let (k, v) = (rng.gen_range(0..=n), rng.gen());
+                let mut naive = naive.clone();
+                naive.insert(k, v);
+                (tree.insert(k, v), naive)
+            }
+            1 if n > 0 => {
+                let k = rng.gen_range(0..n);
+                let mut naive = naive.clone();
+                naive.remove(k);
+                (tree.remove(k), naive)
+            }
+            2 if n > 0 => {
+                let (k, v) = (rng.gen_range(0..n), rng.gen());
+                let mut naive = naive.clone();
+                naive[k] = v;
+                (tree.set(k, v), naive)
+            }
+            _ => {
+                let k = rng.gen_range(0..=n);
+                let (a, b) = tree.split(k);
+                assert_eq!(a.to_vec(), &naive[..k]);
+                assert_eq!(b.to_vec(), &naive[k..]);
+                let mut merged = naive[k..].to_vec();
+                merged.extend_from_slice(&naive[..k]);
+                (b.merge(&a), merged)
+            }
+        };
+
+        assert_eq!(next.0.len(), next.1.len());
+        assert_eq!(next.0.to_vec(), next.1);
+
+        for (k, v) in next.1.iter().enumerate() {
+            assert_eq!(next.0.get(k), v);
+        }
+
+        versions.push(next);
+    }
+
+    for (tree, naive) in versions {
+        assert_eq!(tree.to_vec(), naive);
+    }
+}
@@ -0,0 +1,71 @@
+use library::project_selection::ProjectSelection;
+use rand::Rng;
+
+#[test]
+fn test_001_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(1..8);
+
+        let chosen_gain = (0..n)
+            .map(|_| rng.gen_range(-10..10i64))
+            .collect::<Vec<_>>();
+        let not_chosen_gain = (0..n)
+            .map(|_| rng.gen_range(-10..10i64))
+            .collect::<Vec<_>>();
+        let penalties = (0..rng.gen_range(0..10))
+            .map(|_| {
+                (
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..n),
+                    rng.gen_range(0..10i64),
+                )
+            })
+            .collect::<Vec<_>>();
+        let requires = (0..rng.gen_range(0..4))
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+            .collect::<Vec<_>>();
+
+        let mut ps = ProjectSelection::new(n);
+        for i in 0..n {
+            ps.gain_if_chosen(i, chosen_gain[i]);
+            ps.gain_if_not_chosen(i, not_chosen_gain[i]);
+        }
+        for &(a, b, c) in &penalties {
+            ps.penalty_if_chosen_without(a, b, c);
+        }
+        for &(a, b) in &requires {
+            ps.require(a, b);
+        }
+
+        let value = |s: &[bool]| -> Option<i64> {
+            if requires.iter().any(|&(a, b)| s[a] && !s[b]) {
+                return None;
+            }
+            let mut v = 0;
+            for i in 0..n {
+                v += if s[i] {
+                    chosen_gain[i]
+                } else {
+                    not_chosen_gain[i]
+                };
+            }
+            for &(a, b, c) in &penalties {
+                if s[a] && !s[b] {
+                    v -= c;
+                }
+            }
+            Some(v)
+        };
+
+        let expected = (0..1usize << n)
+            .filter_map(|mask| value(&(0..n).map(|i| mask >> i & 1 == 1).collect::<Vec<_>>()))
+            .max()
+            .unwrap();
+
+        let (best, chosen) = ps.solve();
+        assert_eq!(best, expected);
+        assert_eq!(value(&chosen), Some(best));
+    }
+}
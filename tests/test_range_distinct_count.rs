@@ -0,0 +1,30 @@
+use library::range_distinct_count::range_distinct_count;
+use rand::Rng;
+
+#[test]
+fn test_001_range_distinct_count_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(0..30);
+        let a = (0..n).map(|_| rng.gen_range(0..5)).collect::<Vec<u32>>();
+        let queries = (0..30)
+            .map(|_| {
+                let l = rng.gen_range(0..=n);
+                (l, rng.gen_range(l..=n))
+            })
+            .collect::<Vec<_>>();
+
+        let expected = queries
+            .iter()
+            .map(|&(l, r)| {
+                a[l..r]
+                    .iter()
+                    .collect::<std::collections::HashSet<_>>()
+                    .len()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(range_distinct_count(&a, &queries), expected);
+    }
+}
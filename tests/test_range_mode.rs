@@ -0,0 +1,31 @@
+use library::range_mode::RangeMode;
+use rand::Rng;
+
+#[test]
+fn test_001_range_mode_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(0..60);
+        let m = rng.gen_range(1..10);
+        let a = (0..n).map(|_| rng.gen_range(0..m)).collect::<Vec<u32>>();
+        let rm = RangeMode::from(&a);
+
+        for l in 0..=n {
+            for r in l..=n {
+                let max = (0..m)
+                    .map(|x| a[l..r].iter().filter(|&&y| y == x).count())
+                    .max()
+                    .unwrap();
+
+                match rm.mode(l..r) {
+                    Some((x, c)) => {
+                        assert_eq!(c, max);
+                        assert_eq!(a[l..r].iter().filter(|&&y| y == x).count(), c);
+                    }
+                    None => assert_eq!(l, r),
+                }
+            }
+        }
+    }
+}
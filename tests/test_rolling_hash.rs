@@ -0,0 +1,200 @@
+use library::rolling_hash::{RollingHash, UpdatableRollingHash};
+use rand::Rng;
+
+#[test]
+fn test_001_signed_and_wide_tokens() {
+    let a: RollingHash<'a', 100> = RollingHash::from(&[-1i64]);
+    let b: RollingHash<'a', 100> = RollingHash::from(&[7i64]);
+    assert_ne!(a.hash(..), b.hash(..));
+
+    let s = [-5i64, 3, 3];
+    let rh: RollingHash<'a', 100> = RollingHash::from(&s);
+    assert_eq!(rh.cmp_substrings(0..2, 2..), std::cmp::Ordering::Less);
+    assert_eq!(rh.cmp_substrings(1..2, 2..), std::cmp::Ordering::Equal);
+
+    let s = [i32::MIN, -1, 0, 1, i32::MAX];
+    let rh: RollingHash<'a', 100> = RollingHash::from(&s);
+    for i in 0..s.len() {
+        for j in 0..s.len() {
+            assert_eq!(rh.cmp_substrings(i..=i, j..=j), s[i].cmp(&s[j]));
+        }
+    }
+
+    let max = (1u64 << 61) - 3;
+    let s = [0u64, 1, max - 1, max];
+    let rh: RollingHash<'a', 100> = RollingHash::from(&s);
+    for i in 0..s.len() {
+        for j in 0..s.len() {
+            assert_eq!(rh.cmp_substrings(i..=i, j..=j), s[i].cmp(&s[j]));
+        }
+    }
+
+    let s = [-(1i64 << 60), -1, 0, (1 << 60) - 3];
+    let rh: RollingHash<'a', 100> = RollingHash::from(&s);
+    for i in 0..s.len() {
+        for j in 0..s.len() {
+            assert_eq!(rh.cmp_substrings(i..=i, j..=j), s[i].cmp(&s[j]));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_002_too_large_token() {
+    let _: RollingHash<'a', 100> = RollingHash::from(&[u64::MAX]);
+}
+
+#[test]
+#[should_panic]
+fn test_003_too_small_token() {
+    let _: RollingHash<'a', 100> = RollingHash::from(&[i64::MIN]);
+}
+
+#[test]
+fn test_004_random_signed_tokens() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..30);
+        let s = (0..n).map(|_| rng.gen_range(-3i64..3)).collect::<Vec<_>>();
+        let rh: RollingHash<'a', 100> = RollingHash::from_random_base(&s);
+
+        for _ in 0..50 {
+            let (l1, r1) = random_range(&mut rng, n);
+            let (l2, r2) = random_range(&mut rng, n);
+
+            assert_eq!(rh.hash(l1..r1) == rh.hash(l2..r2), s[l1..r1] == s[l2..r2]);
+            assert_eq!(rh.cmp_substrings(l1..r1, l2..r2), s[l1..r1].cmp(&s[l2..r2]));
+        }
+    }
+}
+
+fn random_range(rng: &mut impl Rng, n: usize) -> (usize, usize) {
+    let l = rng.gen_range(0..=n);
+    let r = rng.gen_range(l..=n);
+    (l, r)
+}
+
+fn random_string(rng: &mut impl Rng, n: usize) -> Vec<char> {
+    (0..n).map(|_| rng.gen_range('a'..='c')).collect()
+}
+
+#[test]
+fn test_005_random_base() {
+    let mut rng = rand::thread_rng();
+
+    // 異なる列から構築しても、基数が共通なので部分文字列を比較できる
+    for _ in 0..200 {
+        let (n, m) = (rng.gen_range(1..30), rng.gen_range(1..30));
+        let s = random_string(&mut rng, n);
+        let t = random_string(&mut rng, m);
+        let rs: RollingHash<'a', 0> = RollingHash::from_random_base(&s);
+        let rt: RollingHash<'a', 0> = RollingHash::from_random_base(&t);
+
+        for _ in 0..50 {
+            let (l1, r1) = random_range(&mut rng, n);
+            let (l2, r2) = random_range(&mut rng, m);
+
+            assert_eq!(rs.hash(l1..r1) == rt.hash(l2..r2), s[l1..r1] == t[l2..r2]);
+        }
+    }
+}
+
+#[test]
+fn test_006_concat_segments() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..30);
+        let s = random_string(&mut rng, n);
+        let rh: RollingHash<'a', 100> = RollingHash::from(&s);
+
+        for _ in 0..50 {
+            let (l1, r1) = random_range(&mut rng, n);
+            let (l2, r2) = random_range(&mut rng, n);
+            let c = rng.gen_range('a'..='c');
+
+            // s[l1..r1] + c + s[l2..r2] を作って、別に構築したハッシュと比較する
+            let mut t = s[l1..r1].to_vec();
+            t.push(c);
+            t.extend_from_slice(&s[l2..r2]);
+            let rt: RollingHash<'a', 100> = RollingHash::from(&t);
+
+            let seg = rh.segment(l1..r1) + rh.char_segment(c) + rh.segment(l2..r2);
+            assert_eq!(seg.hash, rt.hash(..));
+            assert_eq!(seg.len, t.len());
+            assert_eq!(seg, rt.segment(..));
+
+            let h = rh.concat(rh.hash(l1..r1), rh.hash(l2..r2), r2 - l2);
+            assert_eq!(h, (rh.segment(l1..r1) + rh.segment(l2..r2)).hash);
+
+            // 連結する列は元の列より長くなりうる
+            let long = rt.hash(..);
+            assert_eq!(
+                rh.concat(long, long, t.len()),
+                (rt.segment(..) + rt.segment(..)).hash
+            );
+        }
+    }
+}
+
+#[test]
+fn test_007_lcp_and_compare() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..30);
+        let s = random_string(&mut rng, n);
+        let rh: RollingHash<'a', 100> = RollingHash::from(&s);
+
+        for _ in 0..50 {
+            let (l1, r1) = random_range(&mut rng, n);
+            let (l2, r2) = random_range(&mut rng, n);
+
+            let lcp = s[l1..r1]
+                .iter()
+                .zip(&s[l2..r2])
+                .take_while(|(a, b)| a == b)
+                .count();
+
+            assert_eq!(rh.lcp(l1..r1, l2..r2), lcp);
+            assert_eq!(rh.cmp_substrings(l1..r1, l2..r2), s[l1..r1].cmp(&s[l2..r2]));
+        }
+
+        let mut suffixes = (0..n).collect::<Vec<_>>();
+        suffixes.sort_by(|&i, &j| rh.cmp_substrings(i.., j..));
+        let mut expected = (0..n).collect::<Vec<_>>();
+        expected.sort_by(|&i, &j| s[i..].cmp(&s[j..]));
+        assert_eq!(suffixes, expected);
+    }
+}
+
+#[test]
+fn test_008_updatable() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(1..30);
+        let mut s = random_string(&mut rng, n);
+        let mut urh: UpdatableRollingHash<'a', 100> = UpdatableRollingHash::from(&s);
+
+        for _ in 0..50 {
+            let i = rng.gen_range(0..n);
+            let c = rng.gen_range('a'..='c');
+            s[i] = c;
+            urh.set(i, c);
+
+            // 書き換えた列から作り直したものと一致する
+            let rh: RollingHash<'a', 100> = RollingHash::from(&s);
+
+            for _ in 0..10 {
+                let (l1, r1) = random_range(&mut rng, n);
+                let (l2, r2) = random_range(&mut rng, n);
+
+                assert_eq!(urh.hash(l1..r1), rh.hash(l1..r1));
+                assert_eq!(urh.segment(l1..r1), rh.segment(l1..r1));
+                assert_eq!(urh.hash(l1..r1) == urh.hash(l2..r2), s[l1..r1] == s[l2..r2]);
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use library::euler_tour::EulerTour;
+use library::graph::UndirectedAdjGraph;
+use library::rooted_tree::RootedTree;
+use rand::Rng;
+
+#[test]
+fn test_001_compare_with_euler_tour() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..30u32);
+        let edges = (1..n).map(|i| (rng.gen_range(0..i), i)).collect::<Vec<_>>();
+        let tree = UndirectedAdjGraph::from_edges_no_weight(n, &edges);
+        let root = rng.gen_range(0..n);
+
+        let rt = RootedTree::from(&tree, root);
+        let et = EulerTour::from(&tree, root);
+
+        assert_eq!(rt.root(), root);
+        assert_eq!(rt.order(), et.order());
+
+        for v in 0..n {
+            assert_eq!(rt.parent(v), et.parent(v));
+            assert_eq!(rt.depth(v), et.depth(v));
+            assert_eq!(rt.subtree_size(v), et.subtree(v).len());
+
+            let mut expected = (0..n)
+                .filter(|&c| c != root && et.parent(c) == v)
+                .collect::<Vec<_>>();
+            let mut children = rt.children(v).to_vec();
+            expected.sort();
+            children.sort();
+            assert_eq!(children, expected);
+        }
+    }
+}
+
+#[test]
+fn test_002_long_path() {
+    let n = 200_000;
+    let edges = (1..n).map(|i| (i - 1, i)).collect::<Vec<_>>();
+    let tree = UndirectedAdjGraph::from_edges_no_weight(n, &edges);
+
+    let rt = RootedTree::from(&tree, 0);
+    assert_eq!(rt.depth(n - 1), n - 1);
+    assert_eq!(rt.subtree_size(0), n as usize);
+    assert_eq!(rt.order(), (0..n).collect::<Vec<_>>());
+}
@@ -0,0 +1,29 @@
+use library::run_length_encoding::{run_length_decode, run_length_encode};
+use rand::Rng;
+
+#[test]
+fn test_001_empty() {
+    let empty: [u8; 0] = [];
+    let rle = run_length_encode(&empty);
+
+    assert!(rle.is_empty());
+    assert!(run_length_decode(&rle).is_empty());
+}
+
+#[test]
+fn test_002_random_round_trip() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..1000 {
+        let a = (0..rng.gen_range(0..30))
+            .map(|_| rng.gen_range(0..3))
+            .collect::<Vec<u32>>();
+        let rle = run_length_encode(&a);
+
+        assert_eq!(run_length_decode(&rle), a);
+
+        assert_eq!(rle.iter().map(|&(_, cnt)| cnt).sum::<usize>(), a.len());
+        assert!(rle.iter().all(|&(_, cnt)| cnt > 0));
+        assert!(rle.windows(2).all(|w| w[0].0 != w[1].0));
+    }
+}
@@ -0,0 +1,66 @@
+use library::graph::DirectedAdjGraph;
+use library::scc::strongly_connected_components;
+use rand::Rng;
+
+#[test]
+fn test_001_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..10u32);
+        let m = rng.gen_range(0..20);
+        let edges = (0..m)
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+            .collect::<Vec<_>>();
+
+        let graph = DirectedAdjGraph::from_edges_no_weight(n, &edges);
+        let scc = strongly_connected_components(&graph);
+
+        // 到達可能性を愚直に求める
+        let n = n as usize;
+        let mut reach = vec![vec![false; n]; n];
+        for (i, r) in reach.iter_mut().enumerate() {
+            r[i] = true;
+        }
+        for &(u, v) in &edges {
+            reach[u as usize][v as usize] = true;
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    if reach[i][k] && reach[k][j] {
+                        reach[i][j] = true;
+                    }
+                }
+            }
+        }
+
+        for u in 0..n {
+            for v in 0..n {
+                assert_eq!(scc[u] == scc[v], reach[u][v] && reach[v][u]);
+            }
+        }
+
+        // 成分の番号がトポロジカル順序になっている
+        for &(u, v) in &edges {
+            assert!(scc[u as usize] <= scc[v as usize]);
+        }
+    }
+}
+
+#[test]
+fn test_002_long_chain() {
+    // 再帰で DFS するとスタックオーバーフローする長さのパスと閉路
+    let n = 200_000;
+
+    let edges = (0..n - 1).map(|i| (i, i + 1)).collect::<Vec<_>>();
+    let graph = DirectedAdjGraph::from_edges_no_weight(n, &edges);
+    assert_eq!(
+        strongly_connected_components(&graph),
+        (0..n).collect::<Vec<_>>()
+    );
+
+    let edges = (0..n).map(|i| (i, (i + 1) % n)).collect::<Vec<_>>();
+    let graph = DirectedAdjGraph::from_edges_no_weight(n, &edges);
+    assert_eq!(strongly_connected_components(&graph), vec![0; n as usize]);
+}
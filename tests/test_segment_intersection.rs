@@ -0,0 +1,39 @@
+use library::segment_intersection::{find_intersecting_pair, segments_intersect};
+use rand::Rng;
+
+#[test]
+fn test_001_segments_intersect() {
+    // 一部が重なる
+    assert!(segments_intersect(((0, 0), (4, 0)), ((2, 0), (6, 0))));
+    // 同一直線上にあるが重ならない
+    assert!(!segments_intersect(((0, 0), (1, 0)), ((2, 0), (3, 0))));
+    // 長さ 0 の線分
+    assert!(segments_intersect(((1, 1), (1, 1)), ((0, 0), (2, 2))));
+    assert!(!segments_intersect(((1, 2), (1, 2)), ((0, 0), (2, 2))));
+    // T 字型に接する
+    assert!(segments_intersect(((0, 0), (4, 0)), ((2, 0), (2, 3))));
+    assert!(!segments_intersect(((0, 0), (4, 0)), ((2, 1), (2, 3))));
+}
+
+#[test]
+fn test_002_find_intersecting_pair_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..3000 {
+        let n = rng.gen_range(0..8);
+        let c = rng.gen_range(1..8);
+        let mut point = || (rng.gen_range(0..c), rng.gen_range(0..c));
+        let segments = (0..n).map(|_| (point(), point())).collect::<Vec<_>>();
+
+        let exists =
+            (0..n).any(|i| (i + 1..n).any(|j| segments_intersect(segments[i], segments[j])));
+
+        match find_intersecting_pair(&segments) {
+            Some((i, j)) => {
+                assert!(i < j);
+                assert!(segments_intersect(segments[i], segments[j]));
+            }
+            None => assert!(!exists, "{:?}", segments),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use library::algebra::{Add, Min};
+use library::segtree::SegmentTree;
+use rand::Rng;
+
+#[test]
+fn test_001_from_vec_from_iter_extend() {
+    let mut rng = rand::thread_rng();
+
+    for n in 0..50 {
+        let a = (0..n).map(|_| rng.gen_range(0..100)).collect::<Vec<u32>>();
+        let k = rng.gen_range(0..=n);
+
+        let sum: SegmentTree<Add<u32>> = SegmentTree::from_vec(a.clone());
+        let mut min: SegmentTree<Min<u32>> = a[..k].iter().copied().collect();
+        min.extend(a[k..].iter().copied());
+
+        for l in 0..n {
+            for r in l..=n {
+                assert_eq!(sum.prod(l..r), a[l..r].iter().sum());
+                assert_eq!(min.prod(l..r), *a[l..r].iter().min().unwrap_or(&u32::MAX));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_002_max_right_min_left_random() {
+    let mut rng = rand::thread_rng();
+
+    for n in 0..40 {
+        let a = (0..n).map(|_| rng.gen_range(0..10)).collect::<Vec<u32>>();
+        let stree: SegmentTree<Add<u32>> = SegmentTree::from(&a);
+
+        for _ in 0..20 {
+            let x = rng.gen_range(0..50);
+            let f = |&s: &u32| s <= x;
+
+            for l in 0..=n {
+                let expected = (l..=n)
+                    .take_while(|&r| a[l..r].iter().sum::<u32>() <= x)
+                    .last()
+                    .unwrap();
+                assert_eq!(stree.max_right(l, f), expected);
+            }
+
+            for r in 0..=n {
+                let expected = (0..=r)
+                    .rev()
+                    .take_while(|&l| a[l..r].iter().sum::<u32>() <= x)
+                    .last()
+                    .unwrap();
+                assert_eq!(stree.min_left(r, f), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_003_fn_segtree_random() {
+    use library::segtree::FnSegmentTree;
+
+    let mut rng = rand::thread_rng();
+
+    for n in 0..30 {
+        let mut a = (0..n)
+            .map(|_| rng.gen_range(0..3).to_string())
+            .collect::<Vec<String>>();
+        let mut stree =
+            FnSegmentTree::from(&a, |x: &String, y: &String| x.clone() + y, String::new);
+
+        for _ in 0..30 {
+            let l = rng.gen_range(0..=n);
+            let r = rng.gen_range(l..=n);
+
+            if l < n && rng.gen() {
+                a[l] = rng.gen_range(0..3).to_string();
+                stree.insert(l, a[l].clone());
+            }
+
+            assert_eq!(stree.prod(l..r), a[l..r].concat());
+        }
+    }
+}
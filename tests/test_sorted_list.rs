@@ -0,0 +1,56 @@
+use library::sorted_list::SortedList;
+use rand::Rng;
+
+#[test]
+fn test_001_empty() {
+    let mut list: SortedList<u32> = SortedList::new();
+
+    assert!(list.is_empty());
+    assert_eq!(list.kth(0), None);
+    assert_eq!(list.bisect_left(&0), 0);
+    assert!(!list.remove(&0));
+}
+
+#[test]
+fn test_002_random_naive() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..20 {
+        let n = rng.gen_range(0..100);
+        let mut a = (0..n).map(|_| rng.gen_range(0..50)).collect::<Vec<u32>>();
+        let mut list = SortedList::from(&a);
+        a.sort();
+
+        for _ in 0..2000 {
+            let x = rng.gen_range(0..60);
+
+            match rng.gen_range(0..4) {
+                0 => {
+                    let i = a.partition_point(|&y| y < x);
+                    a.insert(i, x);
+                    list.insert(x);
+                }
+                1 => {
+                    let i = a.partition_point(|&y| y < x);
+                    let found = i < a.len() && a[i] == x;
+                    if found {
+                        a.remove(i);
+                    }
+                    assert_eq!(list.remove(&x), found);
+                }
+                2 => {
+                    let k = rng.gen_range(0..=a.len());
+                    assert_eq!(list.kth(k), a.get(k));
+                }
+                _ => {
+                    assert_eq!(list.bisect_left(&x), a.partition_point(|&y| y < x));
+                    assert_eq!(list.bisect_right(&x), a.partition_point(|&y| y <= x));
+                }
+            }
+
+            assert_eq!(list.len(), a.len());
+        }
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), a);
+    }
+}
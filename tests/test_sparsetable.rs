@@ -16,3 +16,41 @@ fn test_001() {
     assert_eq!(st.prod(2..5), 3);
     assert_eq!(st.prod(1..), 1);
 }
+
+#[test]
+fn test_002_random_naive() {
+    use library::naive;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+
+    for n in 1..50 {
+        let a = (0..n).map(|_| rng.gen_range(0..100)).collect::<Vec<u32>>();
+        let st: SparseTable<Min<u32>> = SparseTable::from(&a);
+
+        for l in 0..n {
+            for r in l + 1..=n {
+                assert_eq!(st.prod(l..r), naive::range_min(&a, l..r));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_003_fn_sparse_table_random() {
+    use library::sparse_table::FnSparseTable;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+
+    for n in 1..50 {
+        let a = (0..n).map(|_| rng.gen_range(0..100)).collect::<Vec<u32>>();
+        let st = FnSparseTable::from(&a, |x: &u32, y: &u32| *x.max(y));
+
+        for l in 0..n {
+            for r in l + 1..=n {
+                assert_eq!(st.prod(l..r), *a[l..r].iter().max().unwrap());
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use library::modint::ModInt;
+use library::stirling::*;
+
+#[test]
+fn test_001_stirling_row_table() {
+    let n = 100;
+    let first = stirling_first_table::<998244353>(n);
+    let second = stirling_second_table::<998244353>(n);
+
+    for m in 0..=n {
+        assert_eq!(stirling_first_row::<998244353>(m), first[m]);
+        assert_eq!(stirling_second_row::<998244353>(m), second[m]);
+    }
+}
+
+#[test]
+fn test_002_bell_numbers() {
+    let n = 50;
+    let second = stirling_second_table::<998244353>(n);
+    let bell = bell_numbers::<998244353>(n);
+
+    for m in 0..=n {
+        let sum = second[m]
+            .iter()
+            .fold(ModInt::from_raw(0), |acc, &x| acc + x);
+        assert_eq!(bell[m], sum);
+    }
+}
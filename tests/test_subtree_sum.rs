@@ -0,0 +1,60 @@
+use library::graph::UndirectedAdjGraph;
+use library::naive;
+use library::subtree_sum::SubtreeSum;
+use rand::Rng;
+
+#[test]
+fn test_001_subtree_sum_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let n = rng.gen_range(1..40);
+        let parent = (0..n)
+            .map(|v| {
+                if v == 0 {
+                    u32::MAX
+                } else {
+                    rng.gen_range(0..v)
+                }
+            })
+            .collect::<Vec<u32>>();
+        let edges = (1..n).map(|v| (v, parent[v as usize])).collect::<Vec<_>>();
+        let tree = UndirectedAdjGraph::from_edges_no_weight(n, &edges);
+
+        let mut values = (0..n).map(|_| rng.gen_range(0..100)).collect::<Vec<u64>>();
+        let mut ss = SubtreeSum::from(&tree, 0, &values);
+
+        let is_ancestor = |a: u32, mut v: u32| loop {
+            if v == a {
+                return true;
+            }
+            if v == u32::MAX {
+                return false;
+            }
+            v = parent[v as usize];
+        };
+
+        for _ in 0..30 {
+            let (v, w) = (rng.gen_range(0..n), rng.gen_range(0..100));
+            ss.add_vertex(v, w);
+            values[v as usize] += w;
+
+            for u in 0..n {
+                let expected = (0..n)
+                    .filter(|&x| is_ancestor(u, x))
+                    .map(|x| values[x as usize])
+                    .sum::<u64>();
+                assert_eq!(ss.subtree_sum(u), expected);
+
+                for v in 0..n {
+                    let l = naive::lca(&parent, u, v);
+                    let expected = (0..n)
+                        .filter(|&x| is_ancestor(l, x) && (is_ancestor(x, u) || is_ancestor(x, v)))
+                        .map(|x| values[x as usize])
+                        .sum::<u64>();
+                    assert_eq!(ss.path_sum(u, v), expected);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use library::suffix_array::{suffix_array, SuffixArray};
+use rand::Rng;
+
+fn naive(s: &[u8]) -> Vec<usize> {
+    let mut sa = (0..s.len()).collect::<Vec<_>>();
+    sa.sort_by(|&a, &b| s[a..].cmp(&s[b..]));
+    sa
+}
+
+#[test]
+fn test_001_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..2000 {
+        let n = rng.gen_range(0..50);
+        let sigma = rng.gen_range(1..5);
+        let s = (0..n)
+            .map(|_| b'a' + rng.gen_range(0..sigma))
+            .collect::<Vec<_>>();
+
+        assert_eq!(suffix_array(&s), naive(&s));
+    }
+}
+
+#[test]
+fn test_002_large() {
+    let mut rng = rand::thread_rng();
+
+    for s in [
+        vec![b'a'; 100_000],
+        (0..100_000).map(|i| b'a' + (i % 2) as u8).collect(),
+        (0..100_000)
+            .map(|_| rng.gen_range(b'a'..=b'c'))
+            .collect::<Vec<_>>(),
+    ] {
+        assert_eq!(suffix_array(&s), naive(&s));
+    }
+}
+
+#[test]
+fn test_003_find_all() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(0..40);
+        let s = (0..n).map(|_| rng.gen_range(0..3u64)).collect::<Vec<_>>();
+        let sa = SuffixArray::from(&s);
+
+        let m = rng.gen_range(0..4);
+        let pattern = (0..m).map(|_| rng.gen_range(0..3u64)).collect::<Vec<_>>();
+
+        let expected = (0..n)
+            .filter(|&i| s[i..].starts_with(&pattern))
+            .collect::<Vec<_>>();
+        assert_eq!(sa.find_all(&pattern), expected);
+        assert_eq!(sa.count(&pattern), expected.len());
+    }
+}
+
+#[test]
+fn test_004_lcp() {
+    use library::suffix_array::lcp_array;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(0..30);
+        let s = (0..n)
+            .map(|_| rng.gen_range(b'a'..=b'c'))
+            .collect::<Vec<_>>();
+        let sa = SuffixArray::from(&s);
+
+        let common = |i: usize, j: usize| {
+            s[i..]
+                .iter()
+                .zip(&s[j..])
+                .take_while(|(a, b)| a == b)
+                .count()
+        };
+
+        let expected = sa
+            .sa()
+            .windows(2)
+            .map(|w| common(w[0], w[1]))
+            .collect::<Vec<_>>();
+        assert_eq!(lcp_array(&s, sa.sa()), expected);
+        assert_eq!(sa.lcp_array(), expected);
+
+        for i in 0..=n {
+            if i < n {
+                assert_eq!(sa.sa()[sa.rank(i)], i);
+            }
+            for j in 0..=n {
+                assert_eq!(sa.lcp(i, j), common(i, j));
+            }
+        }
+
+        for _ in 0..50 {
+            let (a0, a1) = (rng.gen_range(0..=n), rng.gen_range(0..=n));
+            let (b0, b1) = (rng.gen_range(0..=n), rng.gen_range(0..=n));
+            let a = a0.min(a1)..a0.max(a1);
+            let b = b0.min(b1)..b0.max(b1);
+
+            assert_eq!(sa.compare(a.clone(), b.clone()), s[a].cmp(&s[b]),);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use library::testgen::*;
+use library::unionfind::UnionFind;
+use rand::SeedableRng;
+
+#[test]
+fn test_001_random_tree_connected() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+
+    for n in 1..30 {
+        let edges = random_tree(&mut rng, n);
+        assert_eq!(edges.len(), n - 1);
+
+        let mut uf = UnionFind::new(n);
+        for &(u, v) in edges.iter() {
+            assert!(!uf.is_same(u as usize, v as usize));
+            uf.unite(u as usize, v as usize);
+        }
+    }
+}
+
+#[test]
+fn test_002_random_tree_uniform() {
+    // 頂点数 4 のラベル付き木は 4^2 = 16 通りある
+    let mut rng = rand::rngs::StdRng::seed_from_u64(2);
+    let mut count = std::collections::HashMap::new();
+
+    for _ in 0..16000 {
+        let mut edges = random_tree(&mut rng, 4)
+            .into_iter()
+            .map(|(u, v)| (u.min(v), u.max(v)))
+            .collect::<Vec<_>>();
+        edges.sort();
+        *count.entry(edges).or_insert(0) += 1;
+    }
+
+    assert_eq!(count.len(), 16);
+    assert!(count.values().all(|&c| 800 < c && c < 1200));
+}
+
+#[test]
+fn test_003_random_connected_graph() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+
+    for n in 1..15 {
+        for m in n - 1..=n * (n - 1) / 2 {
+            let edges = random_connected_graph(&mut rng, n, m);
+            assert_eq!(edges.len(), m);
+
+            let mut uf = UnionFind::new(n);
+            let mut used = std::collections::HashSet::new();
+
+            for &(u, v) in edges.iter() {
+                assert_ne!(u, v);
+                assert!(used.insert((u.min(v), u.max(v))));
+                uf.unite(u as usize, v as usize);
+            }
+
+            assert_eq!(uf.size(0) as usize, n);
+        }
+    }
+}
+
+#[test]
+fn test_004_random_weighted_dag() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(4);
+    let edges = random_weighted_dag(&mut rng, 20, 100, -5..5i32);
+    assert_eq!(edges.len(), 100);
+
+    // Kahn のアルゴリズムで、すべての頂点を取り出せることを確かめる
+    let mut indegree = vec![0; 20];
+    for &(_, v, _) in edges.iter() {
+        indegree[v as usize] += 1;
+    }
+
+    let mut stack = (0..20).filter(|&v| indegree[v] == 0).collect::<Vec<_>>();
+    let mut visited = 0;
+
+    while let Some(u) = stack.pop() {
+        visited += 1;
+
+        for &(s, t, _) in edges.iter() {
+            if s as usize == u {
+                indegree[t as usize] -= 1;
+                if indegree[t as usize] == 0 {
+                    stack.push(t as usize);
+                }
+            }
+        }
+    }
+
+    assert_eq!(visited, 20);
+}
+
+#[test]
+fn test_005_random_arrays() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+
+    let mut a = random_distinct_array(&mut rng, 10, 0..10u32);
+    a.sort();
+    assert_eq!(a, (0..10).collect::<Vec<_>>());
+
+    let mut p = random_permutation(&mut rng, 10);
+    p.sort();
+    assert_eq!(p, (0..10).collect::<Vec<_>>());
+
+    assert!(random_array(&mut rng, 100, 3..=5u8)
+        .iter()
+        .all(|&x| (3..=5).contains(&x)));
+    assert!(random_string(&mut rng, 100, &['x', 'y'])
+        .chars()
+        .all(|c| c == 'x' || c == 'y'));
+}
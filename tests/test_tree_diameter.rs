@@ -0,0 +1,37 @@
+use library::graph::{Tree, UndirectedAdjGraph};
+use library::tree_diameter::{eccentricities, tree_diameter};
+use rand::Rng;
+
+#[test]
+fn test_001_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..20u32);
+        let edges = (1..n)
+            .map(|i| (rng.gen_range(0..i), i, rng.gen_range(0..100u64)))
+            .collect::<Vec<_>>();
+        let tree = UndirectedAdjGraph::from_edges(n, &edges);
+
+        let dist = (0..n)
+            .map(|v| <dyn Tree<Weight = u64>>::dist(&tree, v))
+            .collect::<Vec<_>>();
+        let expected = dist
+            .iter()
+            .map(|d| *d.iter().max().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(eccentricities(&tree), expected);
+
+        let diameter = tree_diameter(&tree);
+        assert_eq!(diameter.dist, *expected.iter().max().unwrap());
+
+        // 辺が木に含まれ、重みの和が直径に一致することを確認する
+        let path_edges = diameter.path_edges();
+        assert_eq!(path_edges.len() + 1, diameter.path.len());
+        for (i, &(u, v, w)) in path_edges.iter().enumerate() {
+            assert_eq!((u, v), (diameter.path[i], diameter.path[i + 1]));
+            assert!(edges.contains(&(u, v, w)) || edges.contains(&(v, u, w)));
+        }
+        assert_eq!(path_edges.iter().map(|e| e.2).sum::<u64>(), diameter.dist);
+    }
+}
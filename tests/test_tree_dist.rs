@@ -0,0 +1,42 @@
+use library::graph::*;
+use library::naive;
+use library::tree_dist::TreeDist;
+use rand::Rng;
+
+#[test]
+fn test_001_tree_dist_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let n = rng.gen_range(1..50);
+        let parent = (0..n)
+            .map(|v| {
+                if v == 0 {
+                    u32::MAX
+                } else {
+                    rng.gen_range(0..v)
+                }
+            })
+            .collect::<Vec<u32>>();
+        let edges = (1..n)
+            .map(|v| (v, parent[v as usize], rng.gen_range(0..100u64)))
+            .collect::<Vec<_>>();
+        let tree = UndirectedAdjGraph::from_edges(n, &edges);
+        let unweighted = UndirectedAdjGraph::from_edges_no_weight(
+            n,
+            &edges.iter().map(|&(u, v, _)| (u, v)).collect::<Vec<_>>(),
+        );
+        let td = TreeDist::from(&tree, 0);
+
+        for u in 0..n {
+            let dist = <dyn Tree<Weight = u64>>::dist(&tree, u);
+            let depth = <dyn Graph<Weight = ()>>::bfs(&unweighted, u);
+
+            for v in 0..n {
+                assert_eq!(td.lca(u, v), naive::lca(&parent, u, v));
+                assert_eq!(td.dist(u, v), dist[v as usize]);
+                assert_eq!(td.path_length(u, v), depth[v as usize]);
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use library::graph::UndirectedAdjGraph;
+use library::tree_isomorphism::TreeIsomorphism;
+use rand::Rng;
+
+/// 根付き木を括弧列で表した標準形
+fn canonical(adj: &[Vec<usize>], v: usize, p: usize) -> String {
+    let mut children = adj[v]
+        .iter()
+        .filter(|&&u| u != p)
+        .map(|&u| canonical(adj, u, v))
+        .collect::<Vec<_>>();
+    children.sort();
+    format!("({})", children.concat())
+}
+
+fn random_tree(rng: &mut impl Rng, n: usize) -> Vec<(u32, u32)> {
+    (1..n)
+        .map(|i| (rng.gen_range(0..i) as u32, i as u32))
+        .collect()
+}
+
+fn adjacency(n: usize, edges: &[(u32, u32)]) -> Vec<Vec<usize>> {
+    let mut adj = vec![vec![]; n];
+    for &(u, v) in edges {
+        adj[u as usize].push(v as usize);
+        adj[v as usize].push(u as usize);
+    }
+    adj
+}
+
+#[test]
+fn test_001_rooted() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(1..30);
+        let edges = random_tree(&mut rng, n);
+        let tree = UndirectedAdjGraph::from_edges_no_weight(n as u32, &edges);
+        let root = rng.gen_range(0..n);
+
+        let mut iso = TreeIsomorphism::new();
+        let id = iso.rooted(&tree, root as u32);
+
+        // 各頂点の部分木の標準形を求める
+        let adj = adjacency(n, &edges);
+        let mut parent = vec![usize::MAX; n];
+        let mut stack = vec![root];
+        while let Some(v) = stack.pop() {
+            for &u in &adj[v] {
+                if u != parent[v] {
+                    parent[u] = v;
+                    stack.push(u);
+                }
+            }
+        }
+        let forms = (0..n)
+            .map(|v| canonical(&adj, v, parent[v]))
+            .collect::<Vec<_>>();
+
+        for u in 0..n {
+            for v in 0..n {
+                assert_eq!(id[u] == id[v], forms[u] == forms[v]);
+            }
+        }
+
+        assert!(id.iter().all(|&i| i < iso.count()));
+    }
+}
+
+#[test]
+fn test_002_unrooted() {
+    let mut rng = rand::thread_rng();
+    let mut iso = TreeIsomorphism::new();
+
+    let mut trees = vec![];
+    for _ in 0..200 {
+        let n = rng.gen_range(1..9);
+        let edges = random_tree(&mut rng, n);
+
+        // 頂点番号を並べ替えたものも加える
+        let mut perm = (0..n as u32).collect::<Vec<_>>();
+        for i in (1..n).rev() {
+            perm.swap(i, rng.gen_range(0..=i));
+        }
+        let relabeled = edges
+            .iter()
+            .map(|&(u, v)| (perm[v as usize], perm[u as usize]))
+            .collect::<Vec<_>>();
+
+        for e in [edges, relabeled] {
+            let tree = UndirectedAdjGraph::from_edges_no_weight(n as u32, &e);
+            let adj = adjacency(n, &e);
+            let form = (0..n)
+                .map(|r| canonical(&adj, r, usize::MAX))
+                .min()
+                .unwrap();
+            trees.push((iso.unrooted(&tree), form));
+        }
+    }
+
+    for (a, fa) in &trees {
+        for (b, fb) in &trees {
+            assert_eq!(a == b, fa == fb);
+        }
+    }
+}
+
+#[test]
+fn test_003_long_path() {
+    let n = 200_000;
+    let edges = (1..n).map(|i| (i - 1, i)).collect::<Vec<_>>();
+    let tree = UndirectedAdjGraph::from_edges_no_weight(n, &edges);
+
+    let mut iso = TreeIsomorphism::new();
+    let id = iso.rooted(&tree, 0);
+    assert_eq!(iso.count(), n as usize);
+    assert_eq!(id[n as usize - 1], 0);
+
+    let reversed = (1..n).map(|i| (n - i, n - i - 1)).collect::<Vec<_>>();
+    let tree2 = UndirectedAdjGraph::from_edges_no_weight(n, &reversed);
+    assert_eq!(iso.unrooted(&tree), iso.unrooted(&tree2));
+}
@@ -0,0 +1,50 @@
+use library::graph::{DirectedAdjGraph, UndirectedAdjGraph};
+use library::triangle::{count_triangles, enumerate_triangles};
+use rand::Rng;
+
+#[test]
+fn test_001_random() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..300 {
+        let n = rng.gen_range(1..12u32);
+        let m = rng.gen_range(0..50);
+        let edges = (0..m)
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+            .collect::<Vec<_>>();
+
+        let mut adj = vec![vec![false; n as usize]; n as usize];
+        for &(u, v) in &edges {
+            if u != v {
+                adj[u as usize][v as usize] = true;
+                adj[v as usize][u as usize] = true;
+            }
+        }
+
+        let mut expected = vec![];
+        for a in 0..n as usize {
+            for b in a + 1..n as usize {
+                for c in b + 1..n as usize {
+                    if adj[a][b] && adj[b][c] && adj[c][a] {
+                        expected.push([a as u32, b as u32, c as u32]);
+                    }
+                }
+            }
+        }
+
+        let graph = UndirectedAdjGraph::from_edges_no_weight(n, &edges);
+        let mut triangles = vec![];
+        enumerate_triangles(&graph, |a, b, c| {
+            let mut t = [a, b, c];
+            t.sort();
+            triangles.push(t);
+        });
+        triangles.sort();
+        assert_eq!(triangles, expected);
+        assert_eq!(count_triangles(&graph), expected.len() as u64);
+
+        // 有向グラフとして与えても、向きを無視して数える
+        let graph = DirectedAdjGraph::from_edges_no_weight(n, &edges);
+        assert_eq!(count_triangles(&graph), expected.len() as u64);
+    }
+}
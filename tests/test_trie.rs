@@ -0,0 +1,94 @@
+use library::trie::Trie;
+use rand::Rng;
+
+#[test]
+fn test_001_longest_common_prefix() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let mut trie = Trie::new();
+        let mut words: Vec<Vec<u8>> = vec![];
+
+        for _ in 0..100 {
+            let len = rng.gen_range(0..6);
+            let s = (0..len).map(|_| rng.gen_range(0..3u8)).collect::<Vec<_>>();
+
+            if rng.gen_bool(0.3) {
+                if let Some(i) = words.iter().position(|w| *w == s) {
+                    words.swap_remove(i);
+                    assert!(trie.remove(&s));
+                }
+            } else {
+                words.push(s.clone());
+                trie.insert(&s);
+            }
+
+            let q = (0..rng.gen_range(0..6))
+                .map(|_| rng.gen_range(0..3u8))
+                .collect::<Vec<_>>();
+            let expected = words
+                .iter()
+                .map(|w| w.iter().zip(&q).take_while(|(a, b)| a == b).count())
+                .max()
+                .unwrap_or(0);
+
+            assert_eq!(trie.longest_common_prefix(&q), expected);
+        }
+    }
+}
+
+#[test]
+fn test_002_visit_nodes() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let mut trie = Trie::new();
+        let mut words: Vec<Vec<u8>> = vec![];
+
+        for _ in 0..rng.gen_range(0..30) {
+            let len = rng.gen_range(0..6);
+            let s = (0..len).map(|_| rng.gen_range(0..3u8)).collect::<Vec<_>>();
+            words.push(s.clone());
+            trie.insert(&s);
+        }
+        for _ in 0..rng.gen_range(0..10) {
+            if !words.is_empty() {
+                let s = words.swap_remove(rng.gen_range(0..words.len()));
+                assert!(trie.remove(&s));
+            }
+        }
+
+        // 根から辿って、各頂点に対応する列を復元する
+        let mut stack = vec![(trie.root(), vec![])];
+        let mut visited = 0;
+
+        while let Some((v, s)) = stack.pop() {
+            visited += 1;
+
+            let pass = words.iter().filter(|w| w.starts_with(&s)).count() as u32;
+            let terminal = words.iter().filter(|w| **w == s).count() as u32;
+            assert_eq!(trie.pass_count(v), pass);
+            assert_eq!(trie.terminal_count(v), terminal);
+            assert!(pass > 0 || v == trie.root());
+
+            for (c, u) in trie.children(v) {
+                assert!(u > v);
+                assert_eq!(trie.child(v, c), Some(u));
+
+                let mut t = s.clone();
+                t.push(c);
+                stack.push((u, t));
+            }
+        }
+
+        let mut prefixes = words
+            .iter()
+            .flat_map(|w| (0..=w.len()).map(move |i| w[..i].to_vec()))
+            .collect::<Vec<_>>();
+        prefixes.push(vec![]);
+        prefixes.sort();
+        prefixes.dedup();
+
+        assert_eq!(visited, prefixes.len());
+    }
+}
@@ -0,0 +1,101 @@
+use itertools::Itertools;
+use library::tsp::tsp_bitmask_dp;
+use rand::Rng;
+
+fn tour_length(dist: &[Vec<u64>], tour: &[usize]) -> u64 {
+    tour.windows(2).map(|w| dist[w[0]][w[1]]).sum()
+}
+
+#[test]
+fn test_001_bitmask_dp() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let n = rng.gen_range(1..8);
+        let dist = (0..n)
+            .map(|_| (0..n).map(|_| rng.gen_range(0..100u64)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let (len, tour) = tsp_bitmask_dp(&dist);
+
+        assert_eq!(tour.len(), n + 1);
+        assert_eq!(tour[0], 0);
+        assert_eq!(tour[n], 0);
+        let mut visited = tour[..n].to_vec();
+        visited.sort();
+        assert_eq!(visited, (0..n).collect::<Vec<_>>());
+        assert_eq!(tour_length(&dist, &tour), len);
+
+        // 全順列を試す
+        let expected = (1..n)
+            .permutations(n - 1)
+            .map(|p| {
+                let mut t = vec![0];
+                t.extend(p);
+                t.push(0);
+                tour_length(&dist, &t)
+            })
+            .min()
+            .unwrap();
+        assert_eq!(len, expected);
+    }
+}
+
+#[test]
+fn test_002_local_search() {
+    use library::tsp::{tsp_local_search, tsp_two_approximation, EuclidianSpace2D, MetricSpace};
+    use std::time::Duration;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let n = rng.gen_range(2..40);
+        let points = (0..n)
+            .map(|_| (rng.gen_range(0..1000), rng.gen_range(0..1000)))
+            .collect::<Vec<_>>();
+        let length = |tour: &[usize]| {
+            tour.windows(2)
+                .map(|w| EuclidianSpace2D::d(&points[w[0]], &points[w[1]]))
+                .sum::<u32>()
+        };
+
+        let initial = tsp_two_approximation::<EuclidianSpace2D>(&points);
+        let tour =
+            tsp_local_search::<EuclidianSpace2D>(&points, initial.clone(), Duration::from_secs(10));
+
+        assert_eq!(tour.len(), n + 1);
+        assert_eq!(tour[0], 0);
+        assert_eq!(tour[n], 0);
+        let mut visited = tour[..n].to_vec();
+        visited.sort();
+        assert_eq!(visited, (0..n).collect::<Vec<_>>());
+        assert!(length(&tour) <= length(&initial));
+
+        // 2-opt で改善できないことを確認する
+        for i in 0..n {
+            for j in i + 2..n {
+                let mut t = tour.clone();
+                t[i + 1..=j].reverse();
+                assert!(length(&t) >= length(&tour));
+            }
+        }
+    }
+
+    // 小さいケースでは厳密解と比較する
+    let points = vec![(0, 0), (3, 0), (6, 0), (6, 4), (3, 4), (0, 4)];
+    let dist = points
+        .iter()
+        .map(|p| {
+            points
+                .iter()
+                .map(|q| EuclidianSpace2D::d(p, q) as u64)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let tour = tsp_local_search::<EuclidianSpace2D>(
+        &points,
+        vec![0, 3, 1, 4, 2, 5, 0],
+        Duration::from_secs(10),
+    );
+    assert_eq!(tour_length(&dist, &tour), tsp_bitmask_dp(&dist).0);
+}
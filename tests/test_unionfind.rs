@@ -14,3 +14,130 @@ fn test_001_size_check() {
     uf.unite(0, 4);
     assert_eq!(uf.size(0), 5);
 }
+
+#[test]
+fn test_002_potential() {
+    use library::algebra::{Add, BitXor};
+    use library::unionfind::PotentializedUnionFind;
+
+    let mut uf = PotentializedUnionFind::<Add<i64>>::new(6);
+    assert!(uf.unite(0, 1, 10));
+    assert!(uf.unite(2, 3, -4));
+    assert!(uf.unite(3, 1, 7));
+    assert_eq!(uf.size(2), 4);
+
+    // p = [0, 10, 7, 3]
+    assert_eq!(uf.diff(0, 2), Some(7));
+    assert_eq!(uf.diff(3, 0), Some(-3));
+    assert_eq!(uf.diff(4, 5), None);
+    assert!(!uf.unite(0, 3, 0));
+    assert!(uf.unite(0, 3, 3));
+
+    let mut uf = PotentializedUnionFind::<BitXor<u32>>::new(3);
+    assert!(uf.unite(0, 1, 0b01));
+    assert!(uf.unite(1, 2, 0b11));
+    assert_eq!(uf.diff(2, 0), Some(0b10));
+    assert!(!uf.unite(0, 2, 0b11));
+}
+
+#[test]
+fn test_003_partially_persistent() {
+    use library::unionfind::PartiallyPersistentUnionFind;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    let n = 15;
+
+    let mut uf = PartiallyPersistentUnionFind::new(n);
+    let mut snapshots = vec![UnionFind::new(n)];
+
+    for _ in 0..30 {
+        let (a, b) = (rng.gen_range(0..n), rng.gen_range(0..n));
+        uf.unite(a, b);
+
+        let mut next = UnionFind::new(n);
+        for u in 0..n {
+            for v in 0..n {
+                if snapshots.last_mut().unwrap().is_same(u, v) {
+                    next.unite(u, v);
+                }
+            }
+        }
+        next.unite(a, b);
+        snapshots.push(next);
+    }
+
+    for (t, snapshot) in snapshots.iter_mut().enumerate() {
+        for u in 0..n {
+            assert_eq!(uf.size(u, t), snapshot.size(u) as usize);
+            for v in 0..n {
+                assert_eq!(uf.is_same(u, v, t), snapshot.is_same(u, v));
+            }
+        }
+    }
+
+    for u in 0..n {
+        for v in 0..n {
+            let expected = (0..snapshots.len()).find(|&t| snapshots[t].is_same(u, v));
+            assert_eq!(uf.connected_time(u, v), expected);
+        }
+    }
+}
+
+#[test]
+fn test_004_groups() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    let n = 20;
+    let mut uf = UnionFind::new(n);
+    assert_eq!(uf.count(), n);
+
+    for _ in 0..12 {
+        uf.unite(rng.gen_range(0..n), rng.gen_range(0..n));
+    }
+
+    let groups = uf.groups();
+    assert_eq!(groups.len(), uf.count());
+    assert_eq!(groups.iter().map(|g| g.len()).sum::<usize>(), n);
+
+    for g in &groups {
+        assert!(g.windows(2).all(|w| w[0] < w[1]));
+        for &v in g {
+            assert!(uf.is_same(g[0], v));
+            assert_eq!(uf.size(v) as usize, g.len());
+        }
+    }
+    assert!(groups.windows(2).all(|w| w[0][0] < w[1][0]));
+}
+
+#[test]
+fn test_005_with_data() {
+    use library::unionfind::UnionFindWithData;
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    let n = 30;
+
+    let mut uf = UnionFindWithData::new(
+        (0..n).map(|v| vec![v]).collect(),
+        |large: &mut Vec<usize>, small: Vec<usize>| {
+            assert!(large.len() >= small.len());
+            large.extend(small);
+        },
+    );
+    let mut naive = UnionFind::new(n);
+
+    for _ in 0..40 {
+        let (a, b) = (rng.gen_range(0..n), rng.gen_range(0..n));
+        assert_eq!(uf.unite(a, b), !naive.is_same(a, b));
+        naive.unite(a, b);
+
+        for v in 0..n {
+            let mut members = uf.data(v).clone();
+            members.sort();
+            let expected = (0..n).filter(|&u| naive.is_same(u, v)).collect::<Vec<_>>();
+            assert_eq!(members, expected);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use library::graph::DirectedAdjGraph;
+use library::modint::ModInt;
+use library::walk_count::{count_walks, count_walks_from};
+use rand::Rng;
+
+#[test]
+fn test_001_count_walks_naive_dp() {
+    type Mint = ModInt<998244353>;
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..30 {
+        let n = rng.gen_range(1..6);
+        let edges = (0..rng.gen_range(0..15))
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n)))
+            .collect::<Vec<_>>();
+        let graph = DirectedAdjGraph::from_edges_no_weight(n, &edges);
+
+        for src in 0..n {
+            // dp[v] = src から v への長さ k のウォークの数
+            let mut dp = vec![Mint::from(0); n as usize];
+            dp[src as usize] = Mint::from(1);
+
+            for k in 0..10 {
+                assert_eq!(count_walks_from::<998244353, _>(&graph, src, k), dp);
+                assert_eq!(count_walks::<998244353, _>(&graph, k)[src as usize], dp);
+
+                let mut next = vec![Mint::from(0); n as usize];
+
+                for &(u, v) in edges.iter() {
+                    next[v as usize] += dp[u as usize];
+                }
+
+                dp = next;
+            }
+        }
+    }
+}
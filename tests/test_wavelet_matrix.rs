@@ -0,0 +1,55 @@
+use library::naive;
+use library::wavelet_matrix::WaveletMatrix;
+use rand::Rng;
+
+#[test]
+fn test_001_quantile_random_naive() {
+    let mut rng = rand::thread_rng();
+
+    for n in 1..40 {
+        let a = (0..n).map(|_| rng.gen_range(0..16)).collect::<Vec<u64>>();
+        let wm: WaveletMatrix<()> = WaveletMatrix::from(&a, 4);
+
+        for l in 0..n {
+            for r in l + 1..=n {
+                for k in 0..r - l {
+                    assert_eq!(wm.quantile(l, r, k), naive::kth_smallest(&a, l..r, k));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_002_dynamic_random_naive() {
+    use library::wavelet_matrix::DynamicWaveletMatrix;
+
+    let mut rng = rand::thread_rng();
+
+    for n in 1..30 {
+        let mut a = (0..n).map(|_| rng.gen_range(0..16)).collect::<Vec<u64>>();
+        let updates = (0..30)
+            .map(|_| (rng.gen_range(0..n), rng.gen_range(0..16)))
+            .collect::<Vec<_>>();
+        let mut wm = DynamicWaveletMatrix::from(&a, &updates, 4);
+
+        for &(i, v) in &updates {
+            wm.set(i, v);
+            a[i] = v;
+
+            for l in 0..n {
+                for r in l..=n {
+                    for k in 0..r - l {
+                        assert_eq!(wm.quantile(l, r, k), naive::kth_smallest(&a, l..r, k));
+                    }
+
+                    let upper = rng.gen_range(0..20);
+                    let expected = a[l..r].iter().filter(|&&x| x < upper).count();
+                    assert_eq!(wm.range_freq(l, r, upper), expected);
+                }
+            }
+
+            assert!((0..n).all(|i| wm.access(i) == a[i]));
+        }
+    }
+}